@@ -0,0 +1,2 @@
+-- Density in grams per mililiter, used to convert between volume and mass
+ALTER TABLE ingredients ADD COLUMN density DOUBLE PRECISION CHECK (density > 0);
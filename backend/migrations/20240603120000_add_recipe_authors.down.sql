@@ -0,0 +1,2 @@
+ALTER TABLE recipes DROP COLUMN public;
+ALTER TABLE recipes DROP COLUMN author_id;
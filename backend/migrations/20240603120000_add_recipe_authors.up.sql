@@ -0,0 +1,3 @@
+-- Recipes created before accounts existed have no author
+ALTER TABLE recipes ADD COLUMN author_id UUID REFERENCES users (id) ON DELETE SET NULL;
+ALTER TABLE recipes ADD COLUMN public BOOL NOT NULL DEFAULT true;
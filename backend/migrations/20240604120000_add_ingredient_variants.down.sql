@@ -0,0 +1,2 @@
+DROP INDEX IF EXISTS ingredients_parent_id_idx;
+ALTER TABLE ingredients DROP COLUMN parent_id;
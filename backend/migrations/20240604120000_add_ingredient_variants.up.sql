@@ -0,0 +1,3 @@
+-- Variants of an ingredient, i.e. dark and light soy sauce are variants of soy sauce
+ALTER TABLE ingredients ADD COLUMN parent_id UUID REFERENCES ingredients (id) ON DELETE SET NULL;
+CREATE INDEX ingredients_parent_id_idx ON ingredients (parent_id);
@@ -0,0 +1,9 @@
+-- Ingredients that can stand in for other ingredients, i.e. applesauce for eggs when baking
+CREATE TABLE ingredient_substitutes (
+    ingredient_id UUID NOT NULL REFERENCES ingredients (id) ON DELETE CASCADE,
+    substitute_id UUID NOT NULL REFERENCES ingredients (id) ON DELETE CASCADE,
+    ratio DOUBLE PRECISION NOT NULL CHECK (ratio > 0),
+    notes TEXT,
+    PRIMARY KEY (ingredient_id, substitute_id),
+    CHECK (ingredient_id <> substitute_id)
+);
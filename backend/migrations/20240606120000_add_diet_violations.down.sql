@@ -0,0 +1,15 @@
+ALTER TABLE ingredients ADD COLUMN diet_friendly VARCHAR(50) ARRAY NOT NULL DEFAULT '{}';
+
+UPDATE ingredients
+SET diet_friendly = ARRAY(
+    SELECT d.diet
+    FROM (
+        VALUES
+            ('vegan', ARRAY['meat', 'pork', 'fish', 'shellfish', 'dairy', 'eggs', 'honey']),
+            ('vegetarian', ARRAY['meat', 'pork', 'fish', 'shellfish']),
+            ('gluten_free', ARRAY['gluten'])
+    ) AS d (diet, violations)
+    WHERE NOT diet_violations::text[] && d.violations
+);
+
+ALTER TABLE ingredients DROP COLUMN diet_violations;
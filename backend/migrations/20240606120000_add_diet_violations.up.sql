@@ -0,0 +1,22 @@
+-- Ingredients declare what they violate, and the diets they fit are derived from that
+ALTER TABLE ingredients ADD COLUMN diet_violations VARCHAR(50) ARRAY NOT NULL DEFAULT '{}';
+
+-- Every legacy diet an ingredient wasn't friendly to contributes all of its violations,
+-- except for the ones a diet it was friendly to allows. The legacy diets never said anything
+-- about alcohol or carbs, so those are assumed until someone says otherwise.
+UPDATE ingredients
+SET diet_violations = ARRAY(
+    SELECT v.violation
+    FROM unnest(
+        CASE WHEN 'vegan' = ANY(diet_friendly) THEN '{}'::text[]
+        ELSE ARRAY['meat', 'pork', 'fish', 'shellfish', 'dairy', 'eggs', 'honey'] END
+        || CASE WHEN 'gluten_free' = ANY(diet_friendly) THEN '{}'::text[]
+        ELSE ARRAY['gluten'] END
+    ) AS v (violation)
+    WHERE NOT (
+        'vegetarian' = ANY(diet_friendly)
+        AND v.violation IN ('meat', 'pork', 'fish', 'shellfish')
+    )
+) || ARRAY['alcohol', 'high_carb']::VARCHAR(50)[];
+
+ALTER TABLE ingredients DROP COLUMN diet_friendly;
@@ -0,0 +1,2 @@
+-- The EU-14 allergens an ingredient contains
+ALTER TABLE ingredients ADD COLUMN allergens VARCHAR(50) ARRAY NOT NULL DEFAULT '{}';
@@ -0,0 +1,3 @@
+DROP INDEX ingredients_name_search_idx;
+ALTER TABLE recipes DROP COLUMN search_vector;
+DROP FUNCTION recipe_steps_to_text;
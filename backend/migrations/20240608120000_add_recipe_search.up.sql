@@ -0,0 +1,15 @@
+-- array_to_string is only stable, but generated columns need immutable expressions
+CREATE FUNCTION recipe_steps_to_text(steps VARCHAR ARRAY) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE PARALLEL SAFE
+AS $$ SELECT array_to_string(steps, ' ') $$;
+
+-- The weights rank name matches above description matches above step matches,
+-- ingredient names get weight B when searching
+ALTER TABLE recipes ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', name), 'A')
+    || setweight(to_tsvector('simple', description), 'C')
+    || setweight(to_tsvector('simple', recipe_steps_to_text(steps)), 'D')
+) STORED;
+
+CREATE INDEX recipes_search_vector_idx ON recipes USING GIN (search_vector);
+CREATE INDEX ingredients_name_search_idx ON ingredients USING GIN (to_tsvector('simple', name));
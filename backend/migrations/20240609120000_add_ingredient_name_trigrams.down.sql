@@ -0,0 +1,4 @@
+DROP INDEX ingredients_name_trigram_idx;
+DROP FUNCTION immutable_unaccent;
+DROP EXTENSION unaccent;
+DROP EXTENSION pg_trgm;
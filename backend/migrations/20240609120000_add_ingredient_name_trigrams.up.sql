@@ -0,0 +1,10 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+CREATE EXTENSION IF NOT EXISTS unaccent;
+
+-- unaccent is only stable because its dictionary can change, but indexes need immutable expressions
+CREATE FUNCTION immutable_unaccent(value TEXT) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE PARALLEL SAFE STRICT
+AS $$ SELECT public.unaccent('public.unaccent'::regdictionary, value) $$;
+
+CREATE INDEX ingredients_name_trigram_idx ON ingredients
+USING GIN (immutable_unaccent(lower(name)) gin_trgm_ops);
@@ -0,0 +1,2 @@
+ALTER TABLE ingredients DROP COLUMN version;
+ALTER TABLE recipes DROP COLUMN version;
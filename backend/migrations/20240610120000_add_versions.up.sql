@@ -0,0 +1,3 @@
+-- Bumped on every change, sent to clients as an ETag for optimistic concurrency
+ALTER TABLE recipes ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
+ALTER TABLE ingredients ADD COLUMN version INTEGER NOT NULL DEFAULT 1;
@@ -0,0 +1,2 @@
+DROP TABLE recipe_revisions;
+DROP FUNCTION reject_revision_update;
@@ -0,0 +1,22 @@
+-- Every change of a recipe, numbered by the version of the recipe it produced
+CREATE TABLE recipe_revisions (
+    recipe_id UUID NOT NULL REFERENCES recipes (id) ON DELETE CASCADE,
+    revision INTEGER NOT NULL,
+    author_id UUID REFERENCES users (id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT now(),
+    restored_from INTEGER,
+    snapshot JSONB NOT NULL,
+    changes JSONB NOT NULL,
+    PRIMARY KEY (recipe_id, revision)
+);
+
+-- Revisions are history, so they can't be rewritten after the fact
+CREATE FUNCTION reject_revision_update() RETURNS trigger AS $$
+BEGIN
+    RAISE EXCEPTION 'recipe revisions are immutable';
+END;
+$$ LANGUAGE plpgsql;
+
+CREATE TRIGGER recipe_revisions_immutable
+BEFORE UPDATE ON recipe_revisions
+FOR EACH ROW EXECUTE FUNCTION reject_revision_update();
@@ -0,0 +1,13 @@
+DELETE FROM ingredients_recipes
+WHERE recipe_id IN (SELECT id FROM recipes WHERE deleted_at IS NOT NULL);
+DELETE FROM recipes WHERE deleted_at IS NOT NULL;
+DELETE FROM ingredients WHERE deleted_at IS NOT NULL;
+
+DROP INDEX ingredients_name_key;
+ALTER TABLE ingredients ADD CONSTRAINT ingredients_name_key UNIQUE (name);
+
+DROP INDEX ingredients_deleted_at_idx;
+DROP INDEX recipes_deleted_at_idx;
+
+ALTER TABLE ingredients DROP COLUMN deleted_at;
+ALTER TABLE recipes DROP COLUMN deleted_at;
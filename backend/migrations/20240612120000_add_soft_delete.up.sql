@@ -0,0 +1,10 @@
+-- Deleted recipes and ingredients stay in the trash until they're restored or purged
+ALTER TABLE recipes ADD COLUMN deleted_at TIMESTAMPTZ;
+ALTER TABLE ingredients ADD COLUMN deleted_at TIMESTAMPTZ;
+
+CREATE INDEX recipes_deleted_at_idx ON recipes (deleted_at) WHERE deleted_at IS NOT NULL;
+CREATE INDEX ingredients_deleted_at_idx ON ingredients (deleted_at) WHERE deleted_at IS NOT NULL;
+
+-- A trashed ingredient shouldn't stop a new one from taking its name
+ALTER TABLE ingredients DROP CONSTRAINT ingredients_name_key;
+CREATE UNIQUE INDEX ingredients_name_key ON ingredients (name) WHERE deleted_at IS NULL;
@@ -0,0 +1,28 @@
+ALTER TABLE recipes ADD COLUMN text_steps VARCHAR(255) ARRAY;
+
+-- Sections, durations and ingredient references get lost, and long steps get truncated
+UPDATE recipes AS r
+SET text_steps = ARRAY(
+    SELECT (s.step ->> 'text')::VARCHAR(255)
+    FROM jsonb_array_elements(r.steps) WITH ORDINALITY AS s(step, n)
+    ORDER BY s.n
+);
+
+ALTER TABLE recipes DROP COLUMN search_vector;
+DROP FUNCTION recipe_steps_to_text;
+
+ALTER TABLE recipes DROP COLUMN steps;
+ALTER TABLE recipes RENAME COLUMN text_steps TO steps;
+ALTER TABLE recipes ALTER COLUMN steps SET NOT NULL;
+
+CREATE FUNCTION recipe_steps_to_text(steps VARCHAR ARRAY) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE PARALLEL SAFE
+AS $$ SELECT array_to_string(steps, ' ') $$;
+
+ALTER TABLE recipes ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', name), 'A')
+    || setweight(to_tsvector('simple', description), 'C')
+    || setweight(to_tsvector('simple', recipe_steps_to_text(steps)), 'D')
+) STORED;
+
+CREATE INDEX recipes_search_vector_idx ON recipes USING GIN (search_vector);
@@ -0,0 +1,32 @@
+-- Steps become objects with an optional section heading, duration and ingredient references.
+-- Revisions keep their plain text steps, which still get read as steps with only text.
+ALTER TABLE recipes ADD COLUMN structured_steps JSONB;
+
+UPDATE recipes AS r
+SET structured_steps = (
+    SELECT COALESCE(jsonb_agg(jsonb_build_object('text', s.text) ORDER BY s.n), '[]')
+    FROM unnest(r.steps) WITH ORDINALITY AS s(text, n)
+);
+
+-- The search vector is generated from the old steps
+ALTER TABLE recipes DROP COLUMN search_vector;
+DROP FUNCTION recipe_steps_to_text;
+
+ALTER TABLE recipes DROP COLUMN steps;
+ALTER TABLE recipes RENAME COLUMN structured_steps TO steps;
+ALTER TABLE recipes ALTER COLUMN steps SET NOT NULL;
+
+CREATE FUNCTION recipe_steps_to_text(steps JSONB) RETURNS TEXT
+LANGUAGE SQL IMMUTABLE PARALLEL SAFE
+AS $$
+    SELECT COALESCE(string_agg(concat_ws(' ', s.step ->> 'section', s.step ->> 'text'), ' ' ORDER BY s.n), '')
+    FROM jsonb_array_elements(steps) WITH ORDINALITY AS s(step, n)
+$$;
+
+ALTER TABLE recipes ADD COLUMN search_vector TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('simple', name), 'A')
+    || setweight(to_tsvector('simple', description), 'C')
+    || setweight(to_tsvector('simple', recipe_steps_to_text(steps)), 'D')
+) STORED;
+
+CREATE INDEX recipes_search_vector_idx ON recipes USING GIN (search_vector);
@@ -0,0 +1,2 @@
+DROP INDEX recipes_total_time_idx;
+ALTER TABLE recipes DROP COLUMN total_time;
@@ -0,0 +1,15 @@
+-- Seconds, the recipe's total time if one is given and the sum of its times otherwise.
+-- NULL for recipes without any time. Kept up to date by the application.
+ALTER TABLE recipes ADD COLUMN total_time BIGINT;
+
+UPDATE recipes AS r
+SET total_time = (
+    SELECT COALESCE(
+        MAX((t.value ->> 'secs')::BIGINT)
+            FILTER (WHERE regexp_replace(lower(trim(t.key)), '\s+time$', '') = 'total'),
+        SUM((t.value ->> 'secs')::BIGINT)
+    )
+    FROM json_each(r.time) AS t
+);
+
+CREATE INDEX recipes_total_time_idx ON recipes (total_time);
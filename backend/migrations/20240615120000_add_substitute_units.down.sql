@@ -0,0 +1 @@
+ALTER TABLE ingredient_substitutes DROP COLUMN unit;
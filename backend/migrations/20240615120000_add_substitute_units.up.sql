@@ -0,0 +1,3 @@
+-- Unit the ratio gives the substitute in, i.e. g for 60 g of applesauce per egg.
+-- NULL when the substitute is measured in the same unit as the ingredient it replaces.
+ALTER TABLE ingredient_substitutes ADD COLUMN unit TEXT;
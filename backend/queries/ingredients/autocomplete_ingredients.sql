@@ -0,0 +1,14 @@
+-- $1 is already lowercased and stripped of accents
+SELECT id, name, description, diet_violations, allergens, density, parent_id, version
+FROM ingredients AS i
+CROSS JOIN LATERAL (SELECT immutable_unaccent(lower(i.name)) AS normalized) AS n
+WHERE i.deleted_at IS NULL
+AND ($1 <% n.normalized OR strpos(n.normalized, $1) > 0)
+ORDER BY
+    starts_with(n.normalized, $1) DESC,
+    strpos(' ' || n.normalized, ' ' || $1) > 0 DESC,
+    strpos(n.normalized, $1) > 0 DESC,
+    word_similarity($1, n.normalized) DESC,
+    i.name,
+    i.id
+LIMIT $2;
@@ -0,0 +1,4 @@
+UPDATE ingredients
+SET version = version + 1
+WHERE id = $1
+AND ($2::integer IS NULL OR version = $2);
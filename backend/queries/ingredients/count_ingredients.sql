@@ -0,0 +1,43 @@
+SELECT COUNT(*) AS "count!"
+FROM ingredients AS i
+WHERE i.deleted_at IS NULL
+AND (
+    (
+        ($1::text IS NULL OR strpos(lower(i.name), lower($1)) > 0)
+        AND ($4::text IS NULL OR starts_with(lower(i.name), lower($4)))
+        AND (
+            NOT $5::bool
+            OR EXISTS (
+                SELECT 1
+                FROM ingredients_recipes AS ir
+                JOIN recipes AS r ON r.id = ir.recipe_id
+                WHERE ir.ingredient_id = i.id
+                AND r.deleted_at IS NULL
+            )
+        )
+        AND NOT i.diet_violations::text[] && $2::text[]
+    )
+    -- Variants of every matching ingredient
+    OR (
+        $3::bool
+        AND EXISTS (
+            SELECT 1
+            FROM ingredients AS p
+            WHERE p.id = i.parent_id
+            AND p.deleted_at IS NULL
+            AND ($1::text IS NULL OR strpos(lower(p.name), lower($1)) > 0)
+            AND ($4::text IS NULL OR starts_with(lower(p.name), lower($4)))
+            AND (
+                NOT $5::bool
+                OR EXISTS (
+                    SELECT 1
+                    FROM ingredients_recipes AS ir
+                    JOIN recipes AS r ON r.id = ir.recipe_id
+                    WHERE ir.ingredient_id = p.id
+                    AND r.deleted_at IS NULL
+                )
+            )
+            AND NOT p.diet_violations::text[] && $2::text[]
+        )
+    )
+);
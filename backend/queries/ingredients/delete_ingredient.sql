@@ -1 +1,5 @@
-DELETE FROM ingredients WHERE id = $1;
+UPDATE ingredients
+SET deleted_at = now()
+WHERE id = $1
+AND version = $2
+AND deleted_at IS NULL;
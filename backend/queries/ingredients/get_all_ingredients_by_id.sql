@@ -1,3 +1,6 @@
-SELECT id, name, description, diet_friendly
+SELECT id, name, description, diet_violations, allergens, density, parent_id, version
 FROM ingredients
-WHERE id = ANY($1);
+WHERE id = ANY($1)
+AND deleted_at IS NULL
+ORDER BY array_position($1, id)
+FOR SHARE;
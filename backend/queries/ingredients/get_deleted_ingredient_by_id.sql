@@ -0,0 +1,4 @@
+SELECT id, name, description, diet_violations, allergens, density, parent_id, version, deleted_at AS "deleted_at!"
+FROM ingredients
+WHERE id = $1
+AND deleted_at IS NOT NULL;
@@ -1,3 +1,4 @@
-SELECT id, name, description, diet_friendly
+SELECT id, name, description, diet_violations, allergens, density, parent_id, version
 FROM ingredients
-WHERE id = $1;
+WHERE id = $1
+AND deleted_at IS NULL;
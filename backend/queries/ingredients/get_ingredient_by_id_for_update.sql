@@ -0,0 +1,5 @@
+SELECT id, name, description, diet_violations, allergens, density, parent_id, version
+FROM ingredients
+WHERE id = $1
+AND deleted_at IS NULL
+FOR UPDATE;
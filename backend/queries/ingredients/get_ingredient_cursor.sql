@@ -0,0 +1,6 @@
+SELECT
+i.id,
+lower(i.name) AS "name!"
+FROM ingredients AS i
+WHERE i.id = $1
+AND i.deleted_at IS NULL;
@@ -0,0 +1,20 @@
+SELECT
+s.ratio,
+s.unit,
+s.notes,
+(
+    i.id,
+    i.name,
+    i.description,
+    i.diet_violations,
+    i.allergens,
+    i.density,
+    i.parent_id,
+    i.version
+) as "ingredient!: IngredientModel"
+FROM ingredient_substitutes AS s
+JOIN ingredients AS i
+    ON i.id = s.substitute_id
+WHERE s.ingredient_id = $1
+AND i.deleted_at IS NULL
+ORDER BY i.name COLLATE "C", i.id;
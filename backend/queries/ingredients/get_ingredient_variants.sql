@@ -0,0 +1,5 @@
+SELECT id, name, description, diet_violations, allergens, density, parent_id, version
+FROM ingredients
+WHERE parent_id = $1
+AND deleted_at IS NULL
+ORDER BY name COLLATE "C", id;
@@ -0,0 +1,5 @@
+SELECT DISTINCT parent_id AS "parent_id!"
+FROM ingredients
+WHERE parent_id IS NOT NULL
+AND deleted_at IS NULL
+AND NOT diet_violations::text[] && $1::text[];
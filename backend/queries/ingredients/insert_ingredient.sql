@@ -1,3 +1,3 @@
-INSERT INTO ingredients (id, name, description, diet_friendly)
-VALUES ($1, $2, $3, $4)
-RETURNING id, name, description, diet_friendly;
+INSERT INTO ingredients (id, name, description, diet_violations, allergens, density, parent_id, version)
+VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+RETURNING id, name, description, diet_violations, allergens, density, parent_id, version;
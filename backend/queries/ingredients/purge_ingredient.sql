@@ -0,0 +1,3 @@
+DELETE FROM ingredients
+WHERE id = $1
+AND deleted_at IS NOT NULL;
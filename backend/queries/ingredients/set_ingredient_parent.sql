@@ -0,0 +1,3 @@
+UPDATE ingredients
+SET parent_id = $2, version = version + 1
+WHERE id = $1;
@@ -0,0 +1,4 @@
+INSERT INTO ingredient_substitutes (ingredient_id, substitute_id, ratio, unit, notes)
+VALUES ($1, $2, $3, $4, $5)
+ON CONFLICT (ingredient_id, substitute_id)
+DO UPDATE SET ratio = EXCLUDED.ratio, unit = EXCLUDED.unit, notes = EXCLUDED.notes;
@@ -0,0 +1,6 @@
+UPDATE recipes
+SET author_id = $2, version = version + 1
+WHERE id = $1
+AND author_id IS NULL
+AND version = $3
+AND deleted_at IS NULL;
@@ -0,0 +1,18 @@
+SELECT COUNT(*) AS "count!"
+FROM recipes AS r
+WHERE r.deleted_at IS NULL
+AND ($1::text IS NULL OR strpos(lower(r.name), lower($1)) > 0)
+AND NOT EXISTS (
+    SELECT 1
+    FROM ingredients_recipes AS ir
+    JOIN ingredients AS i ON i.id = ir.ingredient_id
+    WHERE ir.recipe_id = r.id
+    AND NOT ir.optional
+    AND (
+        -- Ingredients with a variant that fits the diets count as fitting them
+        (i.diet_violations::text[] && $2::text[] AND NOT i.id = ANY($6::uuid[]))
+        OR i.allergens::text[] && $4::text[]
+    )
+)
+AND (($3::uuid IS NULL AND r.public) OR r.author_id = $3)
+AND ($5::bigint IS NULL OR r.total_time <= $5);
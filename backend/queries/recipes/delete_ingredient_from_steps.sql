@@ -0,0 +1,24 @@
+-- Steps refer to ingredients by their ID, in an array under the "ingredients" key
+UPDATE recipes AS r
+SET steps = (
+    SELECT jsonb_agg(
+        CASE
+            WHEN jsonb_array_length(COALESCE(s.step -> 'ingredients', '[]')) > 0 THEN jsonb_set(
+                s.step,
+                '{ingredients}',
+                COALESCE(
+                    (
+                        SELECT jsonb_agg(i.id ORDER BY i.n)
+                        FROM jsonb_array_elements(s.step -> 'ingredients') WITH ORDINALITY AS i(id, n)
+                        WHERE i.id <> to_jsonb($2::uuid)
+                    ),
+                    '[]'
+                )
+            )
+            ELSE s.step
+        END
+        ORDER BY s.n
+    )
+    FROM jsonb_array_elements(r.steps) WITH ORDINALITY AS s(step, n)
+)
+WHERE r.id = $1;
@@ -1,2 +1,5 @@
-DELETE FROM recipes
-WHERE recipes.id = $1;
+UPDATE recipes
+SET deleted_at = now()
+WHERE recipes.id = $1
+AND recipes.version = $2
+AND recipes.deleted_at IS NULL;
@@ -0,0 +1,14 @@
+SELECT
+r.id,
+r.name,
+r.description,
+r.steps,
+r.time,
+r.servings,
+r.author_id,
+r.public,
+r.version,
+r.deleted_at AS "deleted_at!"
+FROM recipes AS r
+WHERE r.id = $1
+AND r.deleted_at IS NOT NULL;
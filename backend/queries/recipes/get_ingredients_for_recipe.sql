@@ -6,7 +6,11 @@ ir.optional,
     i.id,
     i.name,
     i.description,
-    i.diet_friendly
+    i.diet_violations,
+    i.allergens,
+    i.density,
+    i.parent_id,
+    i.version
 ) as "ingredient!: IngredientModel"
 FROM ingredients_recipes AS ir
 JOIN ingredients AS i
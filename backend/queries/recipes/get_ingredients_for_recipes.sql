@@ -0,0 +1,19 @@
+SELECT
+ir.recipe_id AS "recipe_id!",
+ir.amount,
+ir.notes,
+ir.optional,
+(
+    i.id,
+    i.name,
+    i.description,
+    i.diet_violations,
+    i.allergens,
+    i.density,
+    i.parent_id,
+    i.version
+) as "ingredient!: IngredientModel"
+FROM ingredients_recipes AS ir
+JOIN ingredients AS i
+    ON i.id = ir.ingredient_id
+WHERE ir.recipe_id = ANY($1)
@@ -4,9 +4,13 @@ r.name,
 r.description,
 r.steps,
 r.time,
-r.servings
+r.servings,
+r.author_id,
+r.public,
+r.version
 FROM recipes AS r
 JOIN ingredients_recipes AS ir ON r.id = ir.recipe_id
 JOIN ingredients AS i ON ir.ingredient_id = i.id
 WHERE r.id = $1
+AND r.deleted_at IS NULL
 
@@ -0,0 +1,7 @@
+SELECT
+r.id,
+lower(r.name) AS "name!",
+r.total_time
+FROM recipes AS r
+WHERE r.id = $1
+AND r.deleted_at IS NULL;
@@ -0,0 +1,4 @@
+SELECT recipe_id, revision, author_id, created_at, restored_from, snapshot, changes
+FROM recipe_revisions
+WHERE recipe_id = $1
+AND revision = $2;
@@ -1,3 +1,20 @@
-SELECT recipe_id
-FROM ingredients_recipes
-WHERE ingredient_id = $1;
+SELECT
+r.id,
+r.name,
+r.description,
+r.steps,
+r.time,
+r.servings,
+r.author_id,
+r.public,
+r.version
+FROM recipes AS r
+WHERE r.deleted_at IS NULL
+AND EXISTS (
+    SELECT 1
+    FROM ingredients_recipes AS ir
+    WHERE ir.recipe_id = r.id
+    AND ir.ingredient_id = $1
+)
+ORDER BY lower(r.name), r.id
+FOR UPDATE;
@@ -0,0 +1,5 @@
+SELECT ir.recipe_id
+FROM ingredients_recipes AS ir
+JOIN recipes AS r ON r.id = ir.recipe_id
+WHERE ir.ingredient_id = $1
+AND r.deleted_at IS NOT NULL;
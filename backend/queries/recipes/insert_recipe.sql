@@ -1,5 +1,5 @@
 INSERT INTO recipes
-(id, name, description, steps, time, servings, metadata)
+(id, name, description, steps, time, total_time, servings, metadata, author_id, public, version)
 VALUES
-($1, $2, $3, $4, $5, $6, $7)
+($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
 RETURNING id;
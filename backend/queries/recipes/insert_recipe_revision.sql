@@ -0,0 +1,5 @@
+INSERT INTO recipe_revisions
+(recipe_id, revision, author_id, created_at, restored_from, snapshot, changes)
+VALUES
+($1, $2, $3, $4, $5, $6, $7)
+ON CONFLICT (recipe_id, revision) DO NOTHING;
@@ -0,0 +1,26 @@
+-- Mirrors CookableRecipesQuery::matches
+SELECT
+r.id,
+r.name,
+r.description,
+r.steps,
+r.time,
+r.servings,
+r.author_id,
+r.public,
+r.version
+FROM recipes AS r
+JOIN ingredients_recipes AS ir ON ir.recipe_id = r.id
+WHERE r.deleted_at IS NULL
+AND (r.public OR r.author_id = $3)
+GROUP BY r.id
+HAVING COUNT(*) FILTER (WHERE NOT ir.optional AND NOT ir.ingredient_id = ANY($1)) <= $2
+AND (
+    COUNT(*) FILTER (WHERE NOT ir.optional AND NOT ir.ingredient_id = ANY($1)) = 0
+    OR COUNT(*) FILTER (WHERE NOT ir.optional AND ir.ingredient_id = ANY($1)) > 0
+)
+ORDER BY
+    COUNT(*) FILTER (WHERE NOT ir.optional AND NOT ir.ingredient_id = ANY($1)),
+    lower(r.name),
+    r.id
+LIMIT $4;
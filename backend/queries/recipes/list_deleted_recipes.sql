@@ -0,0 +1,15 @@
+SELECT
+r.id,
+r.name,
+r.description,
+r.steps,
+r.time,
+r.servings,
+r.author_id,
+r.public,
+r.version,
+r.deleted_at AS "deleted_at!"
+FROM recipes AS r
+WHERE r.deleted_at IS NOT NULL
+AND ($1::uuid IS NULL OR r.author_id = $1)
+ORDER BY r.deleted_at DESC, r.id;
@@ -0,0 +1,49 @@
+SELECT
+r.id,
+r.name,
+r.description,
+r.steps,
+r.time,
+r.servings,
+r.author_id,
+r.public,
+r.version
+FROM recipes AS r
+WHERE r.deleted_at IS NULL
+AND ($1::text IS NULL OR strpos(lower(r.name), lower($1)) > 0)
+AND NOT EXISTS (
+    SELECT 1
+    FROM ingredients_recipes AS ir
+    JOIN ingredients AS i ON i.id = ir.ingredient_id
+    WHERE ir.recipe_id = r.id
+    AND NOT ir.optional
+    AND (
+        -- Ingredients with a variant that fits the diets count as fitting them
+        (i.diet_violations::text[] && $8::text[] AND NOT i.id = ANY($13::uuid[]))
+        OR i.allergens::text[] && $10::text[]
+    )
+)
+AND (($9::uuid IS NULL AND r.public) OR r.author_id = $9)
+AND ($12::bigint IS NULL OR r.total_time <= $12)
+AND (
+    $4::uuid IS NULL
+    OR ($2::text = 'name' AND $3::text = 'asc' AND (lower(r.name) COLLATE "C", r.id) > ($5::text, $4))
+    OR ($2::text = 'name' AND $3::text = 'desc' AND (lower(r.name) COLLATE "C", r.id) < ($5::text, $4))
+    OR ($2::text = 'created_at' AND $3::text = 'asc' AND r.id > $4)
+    OR ($2::text = 'created_at' AND $3::text = 'desc' AND r.id < $4)
+    -- Recipes without any time come last, whichever the order
+    OR ($2::text = 'total_time' AND $3::text = 'asc'
+        AND (COALESCE(r.total_time, 9223372036854775807), r.id) > (COALESCE($11::bigint, 9223372036854775807), $4))
+    OR ($2::text = 'total_time' AND $3::text = 'desc'
+        AND (COALESCE(r.total_time, -1), r.id) < (COALESCE($11::bigint, -1), $4))
+)
+-- Names compare byte by byte rather than by the database's collation, like the in-memory repository
+ORDER BY
+    CASE WHEN $2::text = 'name' AND $3::text = 'asc' THEN lower(r.name) COLLATE "C" END ASC,
+    CASE WHEN $2::text = 'name' AND $3::text = 'desc' THEN lower(r.name) COLLATE "C" END DESC,
+    CASE WHEN $2::text = 'total_time' AND $3::text = 'asc' THEN COALESCE(r.total_time, 9223372036854775807) END ASC,
+    CASE WHEN $2::text = 'total_time' AND $3::text = 'desc' THEN COALESCE(r.total_time, -1) END DESC,
+    CASE WHEN $3::text = 'asc' THEN r.id END ASC,
+    CASE WHEN $3::text = 'desc' THEN r.id END DESC
+LIMIT $6
+OFFSET $7;
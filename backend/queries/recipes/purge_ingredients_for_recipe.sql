@@ -0,0 +1,5 @@
+DELETE FROM ingredients_recipes AS ir
+USING recipes AS r
+WHERE ir.recipe_id = r.id
+AND r.id = $1
+AND r.deleted_at IS NOT NULL;
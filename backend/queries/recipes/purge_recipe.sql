@@ -0,0 +1,3 @@
+DELETE FROM recipes
+WHERE recipes.id = $1
+AND recipes.deleted_at IS NOT NULL;
@@ -0,0 +1,7 @@
+UPDATE ingredients_recipes AS ir
+SET ingredient_id = $2
+FROM recipes AS r
+WHERE r.id = ir.recipe_id
+AND r.deleted_at IS NULL
+AND ir.ingredient_id = $1
+RETURNING ir.recipe_id;
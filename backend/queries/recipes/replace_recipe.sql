@@ -0,0 +1,3 @@
+UPDATE recipes
+SET name = $2, description = $3, steps = $4, time = $5, total_time = $6, servings = $7, public = $8
+WHERE id = $1;
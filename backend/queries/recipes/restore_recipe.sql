@@ -0,0 +1,4 @@
+UPDATE recipes
+SET deleted_at = NULL
+WHERE recipes.id = $1
+AND recipes.deleted_at IS NOT NULL;
@@ -0,0 +1,46 @@
+-- $1 requires every term, $2 any of them. The latter can use the indexes,
+-- which narrows down the recipes before ingredient names are joined in.
+SELECT
+r.id,
+r.name,
+r.description,
+r.steps,
+r.time,
+r.servings,
+r.author_id,
+r.public,
+r.version,
+ts_rank(d.document, to_tsquery('simple', $1))::float8 AS "rank!",
+ts_headline(
+    'simple',
+    -- Escaped, so that the only markup in the snippet is the one around matches
+    replace(replace(replace(replace(replace(
+        r.description || ' ' || recipe_steps_to_text(r.steps),
+        '&', '&amp;'), '<', '&lt;'), '>', '&gt;'), '"', '&quot;'), '''', '&#39;'),
+    to_tsquery('simple', $1),
+    'StartSel=<mark>, StopSel=</mark>, MinWords=10, MaxWords=20'
+) AS "snippet!"
+FROM recipes AS r
+CROSS JOIN LATERAL (
+    SELECT r.search_vector || setweight(
+        to_tsvector('simple', coalesce(string_agg(i.name, ' '), '')),
+        'B'
+    ) AS document
+    FROM ingredients_recipes AS ir
+    JOIN ingredients AS i ON i.id = ir.ingredient_id
+    WHERE ir.recipe_id = r.id
+) AS d
+WHERE r.deleted_at IS NULL
+AND (
+    r.search_vector @@ to_tsquery('simple', $2)
+    OR r.id IN (
+        SELECT ir.recipe_id
+        FROM ingredients_recipes AS ir
+        JOIN ingredients AS i ON i.id = ir.ingredient_id
+        WHERE to_tsvector('simple', i.name) @@ to_tsquery('simple', $2)
+    )
+)
+AND d.document @@ to_tsquery('simple', $1)
+AND (r.public OR r.author_id = $3)
+ORDER BY "rank!" DESC, r.id
+LIMIT $4;
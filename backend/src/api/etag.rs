@@ -0,0 +1,109 @@
+use axum::{
+    async_trait,
+    extract::FromRequestParts,
+    http::{
+        header::{ETAG, IF_MATCH},
+        request::Parts,
+        HeaderValue,
+    },
+    response::{IntoResponse, IntoResponseParts, ResponseParts},
+};
+use reqwest::StatusCode;
+
+use crate::{
+    api::errors::MakeError,
+    domain::entities::version::{ExpectedVersion, Version},
+};
+
+/// The version of the returned resource, sent back as the `ETag` header.
+pub struct ETag(pub Version);
+
+impl IntoResponseParts for ETag {
+    type Error = (StatusCode, String);
+
+    fn into_response_parts(self, res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        insert_etag(res, format!("\"{}\"", self.0))
+    }
+}
+
+/// The version of the resource a derived representation (scaled, converted or in another format)
+/// was made from, sent back as a weak `ETag` header since the bytes differ from the resource's.
+pub struct WeakETag(pub Version);
+
+impl IntoResponseParts for WeakETag {
+    type Error = (StatusCode, String);
+
+    fn into_response_parts(self, res: ResponseParts) -> Result<ResponseParts, Self::Error> {
+        insert_etag(res, format!("W/\"{}\"", self.0))
+    }
+}
+
+fn insert_etag(mut res: ResponseParts, tag: String) -> Result<ResponseParts, (StatusCode, String)> {
+    let value = HeaderValue::from_str(&tag)
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+    res.headers_mut().insert(ETAG, value);
+
+    Ok(res)
+}
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum IfMatchError {
+    #[error("The If-Match header is not a list of ETags")]
+    Malformed,
+}
+
+impl MakeError<String> for IfMatchError {
+    fn get_status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for IfMatchError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+/// The versions the client expects the resource to be at, taken from the `If-Match` header.
+/// A missing header or `*` matches any version. Weak tags never match, as the comparison is strong.
+pub struct IfMatch(pub ExpectedVersion);
+
+fn parse_if_match(header: &str) -> Result<ExpectedVersion, IfMatchError> {
+    let header = header.trim();
+    if header == "*" {
+        return Ok(ExpectedVersion::Any);
+    }
+
+    let mut versions = vec![];
+    for tag in header.split(',').map(str::trim) {
+        if tag.starts_with("W/") {
+            continue;
+        }
+
+        let version = tag
+            .strip_prefix('"')
+            .and_then(|t| t.strip_suffix('"'))
+            .and_then(|t| t.parse().ok())
+            .ok_or(IfMatchError::Malformed)?;
+        versions.push(Version(version));
+    }
+
+    Ok(ExpectedVersion::OneOf(versions))
+}
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for IfMatch {
+    type Rejection = IfMatchError;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let expected = match parts.headers.get(IF_MATCH) {
+            Some(header) => parse_if_match(header.to_str().map_err(|_| IfMatchError::Malformed)?)?,
+            None => ExpectedVersion::Any,
+        };
+
+        Ok(Self(expected))
+    }
+}
@@ -1,8 +1,12 @@
 mod errors;
+mod etag;
 mod routes;
+pub mod session;
 
 use std::sync::Arc;
 
+use crate::configuration::TrashSettings;
+use crate::domain::commands::trash::purge_expired::purge_expired_trash;
 use crate::domain::repositories::{
     ingredients::{
         in_memory::InMemoryIngredientRepository, postgres::PostgresIngredientRepository,
@@ -12,39 +16,100 @@ use crate::domain::repositories::{
         in_memory::InMemoryRecipeRepository, postgres::PostgresRecipeRepository, RecipeRepository,
         RecipeRepositoryService,
     },
+    sessions::{in_memory::InMemorySessionStore, SessionStore, SessionStoreService},
+    users::{
+        in_memory::InMemoryUserRepository, postgres::PostgresUserRepository, UserRepository,
+        UserRepositoryService,
+    },
 };
 use axum::{
     routing::{delete, get, post, put},
     Router,
 };
 use axum_tracing_opentelemetry::middleware::{OtelAxumLayer, OtelInResponseLayer};
+use chrono::Utc;
 use color_eyre::Result;
 use sqlx::PgPool;
 
-use self::routes::{ingredients::*, recipes::*};
+use self::routes::{ingredients::*, recipes::*, trash::*, users::*};
 
 pub struct App {
     router: Router,
+    state: AppState,
+    trash: Option<TrashSettings>,
 }
 
 #[derive(Clone)]
 pub struct AppState {
     pub ingredient_repository: IngredientRepositoryService,
     pub recipe_repository: RecipeRepositoryService,
+    pub user_repository: UserRepositoryService,
+    pub session_store: SessionStoreService,
 }
 
 impl App {
     fn get_router() -> Router<AppState> {
         Router::new()
             .route("/ingredient/create", post(create_ingredient_route))
+            .route(
+                "/ingredient/autocomplete",
+                get(autocomplete_ingredients_route),
+            )
             .route("/ingredient/:id", put(update_ingredient_route))
             .route("/ingredient/:id", get(get_ingredient_by_id_route))
             .route("/ingredient/:id", delete(delete_ingredient_route))
             .route("/ingredient", get(get_all_ingredients_route))
+            .route(
+                "/ingredient/:id/variants",
+                get(get_ingredient_variants_route),
+            )
+            .route(
+                "/ingredient/:id/variant/:variant_id",
+                put(attach_ingredient_variant_route),
+            )
+            .route(
+                "/ingredient/:id/variant/:variant_id",
+                delete(detach_ingredient_variant_route),
+            )
+            .route(
+                "/ingredient/:id/substitutes",
+                get(get_ingredient_substitutes_route),
+            )
+            .route(
+                "/ingredient/:id/substitute/:substitute_id",
+                put(set_ingredient_substitute_route),
+            )
+            .route(
+                "/ingredient/:id/substitute/:substitute_id",
+                delete(delete_ingredient_substitute_route),
+            )
+            .route("/recipe", get(list_recipes_route))
             .route("/recipe/create", post(create_recipe_route))
+            .route("/recipe/import", post(import_recipe_route))
+            .route("/recipe/import/html", post(import_recipe_html_route))
+            .route(
+                "/recipe/import/cooklang",
+                post(import_cooklang_recipe_route),
+            )
+            .route("/recipe/search", get(search_recipes_route))
+            .route("/recipe/cookable", post(cookable_recipes_route))
             .route("/recipe/:id", get(get_recipe_by_id_route))
             .route("/recipe/:id", delete(delete_recipe_route))
             .route("/recipe/:id", put(update_recipe_route))
+            .route("/recipe/:id/claim", post(claim_recipe_route))
+            .route("/recipe/:id/revisions", get(list_recipe_revisions_route))
+            .route(
+                "/recipe/:id/revisions/:revision",
+                get(get_recipe_revision_route),
+            )
+            .route(
+                "/recipe/:id/revisions/:revision/restore",
+                post(restore_recipe_revision_route),
+            )
+            .route(
+                "/recipe/:id/substitutions",
+                get(get_recipe_substitutions_route),
+            )
             .route(
                 "/recipe/:id/ingredient",
                 post(add_ingredient_to_recipe_route),
@@ -57,26 +122,61 @@ impl App {
                 "/recipe/:recipe_id/ingredient/:ingredient_id",
                 put(update_ingredient_in_recipe_route),
             )
+            .route("/trash", get(get_trash_route))
+            .route(
+                "/trash/recipe/:id/restore",
+                post(restore_deleted_recipe_route),
+            )
+            .route("/trash/recipe/:id", delete(purge_deleted_recipe_route))
+            .route(
+                "/trash/ingredient/:id/restore",
+                post(restore_deleted_ingredient_route),
+            )
+            .route(
+                "/trash/ingredient/:id",
+                delete(purge_deleted_ingredient_route),
+            )
+            .route("/user/signup", post(signup_route))
+            .route("/user/login", post(login_route))
+            .route("/user/logout", post(logout_route))
+            .route("/user/me", get(current_user_route))
             .layer(OtelInResponseLayer)
             .layer(OtelAxumLayer::default())
     }
 
-    pub fn new<I: IngredientRepository + 'static, R: RecipeRepository + 'static>(
+    pub fn new<
+        I: IngredientRepository + 'static,
+        R: RecipeRepository + 'static,
+        U: UserRepository + 'static,
+    >(
         irs: I,
         rrs: R,
+        urs: U,
+        session_store: SessionStoreService,
     ) -> Result<Self> {
         let ingredient_repository: IngredientRepositoryService = Arc::new(Box::new(irs));
         let recipe_repository: RecipeRepositoryService = Arc::new(Box::new(rrs));
+        let user_repository: UserRepositoryService = Arc::new(Box::new(urs));
         let state = AppState {
             ingredient_repository,
             recipe_repository,
+            user_repository,
+            session_store,
         };
-        let router = Self::get_router().with_state(state);
+        let router = Self::get_router().with_state(state.clone());
 
-        Ok(App { router })
+        Ok(App {
+            router,
+            state,
+            trash: None,
+        })
     }
 
     pub async fn serve(self, listener: tokio::net::TcpListener) -> Result<()> {
+        if let Some(settings) = self.trash {
+            tokio::spawn(purge_trash_periodically(self.state, settings));
+        }
+
         let addr = listener.local_addr()?;
         tracing::info!("Serving on {}:{}", addr.ip(), addr.port());
         axum::serve(listener, self.router).await?;
@@ -84,9 +184,36 @@ impl App {
     }
 }
 
+/// Purges whatever has been in the trash for longer than the retention, once every purge interval
+async fn purge_trash_periodically(state: AppState, settings: TrashSettings) {
+    let mut interval = tokio::time::interval(settings.purge_interval());
+
+    loop {
+        interval.tick().await;
+
+        let cutoff = Utc::now() - settings.retention();
+        match purge_expired_trash(
+            state.ingredient_repository.clone(),
+            state.recipe_repository.clone(),
+            cutoff,
+        )
+        .await
+        {
+            Ok(purged) => tracing::info!(
+                "Purged {} recipes and {} ingredients from the trash",
+                purged.recipes,
+                purged.ingredients
+            ),
+            Err(e) => tracing::error!("Could not purge the trash: {:?}", e),
+        }
+    }
+}
+
 #[derive(Default)]
 pub struct AppBuilder {
     postgres_db: Option<PgPool>,
+    session_store: Option<SessionStoreService>,
+    trash: Option<TrashSettings>,
 }
 
 impl AppBuilder {
@@ -96,18 +223,45 @@ impl AppBuilder {
         self
     }
 
+    pub fn with_session_store<S: SessionStore + 'static>(mut self, store: S) -> Self {
+        self.session_store = Some(Arc::new(Box::new(store)));
+
+        self
+    }
+
+    /// Purges the trash in the background while the app is being served
+    pub fn with_trash_purge(mut self, settings: TrashSettings) -> Self {
+        self.trash = Some(settings);
+
+        self
+    }
+
     pub fn build(self) -> Result<App> {
-        if let Some(postgres_db) = self.postgres_db {
+        let session_store = self
+            .session_store
+            .unwrap_or_else(|| Arc::new(Box::new(InMemorySessionStore::new())));
+
+        let app = if let Some(postgres_db) = self.postgres_db {
             App::new(
                 PostgresIngredientRepository::new(postgres_db.clone()),
-                PostgresRecipeRepository::new(postgres_db),
+                PostgresRecipeRepository::new(postgres_db.clone()),
+                PostgresUserRepository::new(postgres_db),
+                session_store,
             )
         } else {
+            let recipe_repository = InMemoryRecipeRepository::new();
             App::new(
-                InMemoryIngredientRepository::new(),
-                InMemoryRecipeRepository::new(),
+                InMemoryIngredientRepository::new().with_recipes(&recipe_repository),
+                recipe_repository,
+                InMemoryUserRepository::new(),
+                session_store,
             )
-        }
+        }?;
+
+        Ok(App {
+            trash: self.trash,
+            ..app
+        })
     }
 
     pub fn new() -> Self {
@@ -1,28 +1,36 @@
-use axum::{extract::State, response::IntoResponse, Json};
-use common::IngredientDTO;
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{IngredientListQueryDTO, IngredientPageDTO};
+use reqwest::StatusCode;
 
 use crate::{
     api::{errors::MakeError, AppState},
-    domain::queries::ingredients::get_all::{get_all_ingredients, GetAllIngredientsError},
+    domain::queries::ingredients::list::{list_ingredients, ListIngredientsError},
 };
 
-impl MakeError<String> for GetAllIngredientsError {
+impl MakeError<String> for ListIngredientsError {
     fn get_message(&self) -> String {
         self.to_string()
     }
-    fn get_status_code(&self) -> reqwest::StatusCode {
-        reqwest::StatusCode::BAD_REQUEST
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidCursor(_) | Self::Validation(_) => StatusCode::BAD_REQUEST,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
     }
 }
 
-impl IntoResponse for GetAllIngredientsError {
+impl IntoResponse for ListIngredientsError {
     fn into_response(self) -> axum::response::Response {
-        (self.get_status_code(), self.get_message()).into_response()
+        (self.get_status_code(), self.get_json()).into_response()
     }
 }
 
 #[tracing::instrument(
-    "[ROUTE] Getting all available ingredients",
+    "[ROUTE] Getting a page of available ingredients",
     skip(ingredient_repository)
 )]
 pub async fn get_all_ingredients_route(
@@ -30,8 +38,9 @@ pub async fn get_all_ingredients_route(
         ingredient_repository,
         ..
     }): State<AppState>,
-) -> Result<Json<Vec<IngredientDTO>>, GetAllIngredientsError> {
-    let result = get_all_ingredients(ingredient_repository).await?;
+    Query(query): Query<IngredientListQueryDTO>,
+) -> Result<Json<IngredientPageDTO>, ListIngredientsError> {
+    let result = list_ingredients(ingredient_repository, &query.try_into()?).await?;
 
-    Ok(Json(result.iter().map(IngredientDTO::from).collect()))
+    Ok(Json(result.into()))
 }
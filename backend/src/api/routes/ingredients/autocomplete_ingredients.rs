@@ -0,0 +1,45 @@
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{IngredientAutocompleteQueryDTO, IngredientDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, AppState},
+    domain::queries::ingredients::autocomplete::{
+        autocomplete_ingredients, AutocompleteIngredientsError,
+    },
+};
+
+impl MakeError<String> for AutocompleteIngredientsError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for AutocompleteIngredientsError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Autocompleting ingredients", skip(ingredient_repository))]
+pub async fn autocomplete_ingredients_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Query(query): Query<IngredientAutocompleteQueryDTO>,
+) -> Result<Json<Vec<IngredientDTO>>, AutocompleteIngredientsError> {
+    let result = autocomplete_ingredients(ingredient_repository, &query.q, query.limit).await?;
+
+    Ok(Json(result.iter().map(IngredientDTO::from).collect()))
+}
@@ -3,7 +3,7 @@ use common::{CreateIngredientDTO, IngredientDTO};
 use reqwest::StatusCode;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{errors::MakeError, etag::ETag, AppState},
     domain::commands::ingredients::create::{
         create_ingredient, CreateIngredient, CreateIngredientError,
     },
@@ -35,10 +35,14 @@ pub async fn create_ingredient_route(
     let input = CreateIngredient {
         name: &body.name,
         description: &body.description,
-        diet_friendly: body.diet_friendly.unwrap_or_default(),
+        diet_friendly: body.diet_friendly,
+        diet_violations: body.diet_violations,
+        allergens: body.allergens,
+        density: body.density,
     };
     let result = create_ingredient(ingredient_repository, &input).await?;
+    let etag = ETag(result.version);
     let result: IngredientDTO = result.into();
 
-    Ok((StatusCode::CREATED, Json(result)))
+    Ok((StatusCode::CREATED, etag, Json(result)))
 }
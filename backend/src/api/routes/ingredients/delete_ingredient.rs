@@ -1,37 +1,50 @@
 use axum::{
-    extract::{Path, State},
+    extract::{Path, Query, State},
     response::IntoResponse,
 };
+use common::{DeleteIngredientErrorDTO, DeleteIngredientQueryDTO};
 use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{errors::MakeError, etag::IfMatch, session::CurrentUser, AppState},
     domain::commands::ingredients::delete::{delete_ingredient, DeleteIngredientError},
 };
 
-impl MakeError<String> for DeleteIngredientError {
-    fn get_message(&self) -> String {
-        self.to_string()
+impl MakeError<DeleteIngredientErrorDTO> for DeleteIngredientError {
+    fn get_message(&self) -> DeleteIngredientErrorDTO {
+        DeleteIngredientErrorDTO {
+            message: self.to_string(),
+            recipes: self
+                .blocking_recipes()
+                .iter()
+                .cloned()
+                .map(Into::into)
+                .collect(),
+        }
     }
     fn get_status_code(&self) -> StatusCode {
         match self {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
-            Self::InUseByRecipe => StatusCode::CONFLICT,
-            _ => StatusCode::INTERNAL_SERVER_ERROR
+            Self::InUseByRecipe(_) | Self::ReplacementAlreadyInRecipe(_) => StatusCode::CONFLICT,
+            Self::ReplacementNotFound(_) | Self::ReplacedWithItself => StatusCode::BAD_REQUEST,
+            Self::NotLoggedIn => StatusCode::UNAUTHORIZED,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::VersionMismatch(_) => StatusCode::PRECONDITION_FAILED,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 }
 
 impl IntoResponse for DeleteIngredientError {
     fn into_response(self) -> axum::response::Response {
-        (self.get_status_code(), self.get_message()).into_response()
+        (self.get_status_code(), self.get_json()).into_response()
     }
 }
 
 #[tracing::instrument(
     "[ROUTE] Deleting an ingredient",
-    skip(ingredient_repository, recipe_repository)
+    skip(ingredient_repository, recipe_repository, user)
 )]
 pub async fn delete_ingredient_route(
     State(AppState {
@@ -40,8 +53,19 @@ pub async fn delete_ingredient_route(
         ..
     }): State<AppState>,
     Path(ingredient_id): Path<Uuid>,
+    Query(query): Query<DeleteIngredientQueryDTO>,
+    user: Option<CurrentUser>,
+    IfMatch(expected): IfMatch,
 ) -> Result<(), DeleteIngredientError> {
-    delete_ingredient(ingredient_repository, recipe_repository, &ingredient_id).await?;
+    delete_ingredient(
+        ingredient_repository,
+        recipe_repository,
+        &ingredient_id,
+        user.as_ref().map(|CurrentUser(user)| user),
+        &expected,
+        query.replace_with.as_ref(),
+    )
+    .await?;
 
     Ok(())
 }
@@ -7,7 +7,7 @@ use common::IngredientDTO;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{errors::MakeError, etag::ETag, AppState},
     domain::queries::ingredients::get_by_id::{get_ingredient_by_id, GetIngredientError},
 };
 
@@ -33,9 +33,9 @@ pub async fn get_ingredient_by_id_route(
         ingredient_repository,
         ..
     }): State<AppState>,
-) -> Result<Json<IngredientDTO>, GetIngredientError> {
+) -> Result<(ETag, Json<IngredientDTO>), GetIngredientError> {
     // TODO: Switch to storing the amount of recipes with certain ingredient in some sort of cache
     let result = get_ingredient_by_id(ingredient_repository, &ingredient_id).await?;
 
-    Ok(Json(result.into()))
+    Ok((ETag(result.version), Json(result.into())))
 }
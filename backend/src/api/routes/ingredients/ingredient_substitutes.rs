@@ -0,0 +1,116 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{IngredientSubstituteDTO, SetIngredientSubstituteDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, AppState},
+    domain::{
+        commands::ingredients::substitutes::{
+            delete::{delete_substitute, DeleteSubstituteError},
+            set::{set_substitute, SetSubstitute, SetSubstituteError},
+        },
+        queries::ingredients::{
+            get_by_id::GetIngredientError, get_substitutes::get_ingredient_substitutes,
+        },
+    },
+};
+
+impl MakeError<String> for SetSubstituteError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            SetSubstituteError::NotFound(_) => StatusCode::NOT_FOUND,
+            SetSubstituteError::SelfReference | SetSubstituteError::NotPositive(_) => {
+                StatusCode::BAD_REQUEST
+            }
+            SetSubstituteError::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for SetSubstituteError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+impl MakeError<String> for DeleteSubstituteError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            DeleteSubstituteError::NotFound(_) => StatusCode::NOT_FOUND,
+            DeleteSubstituteError::NotASubstitute(_, _) => StatusCode::BAD_REQUEST,
+            DeleteSubstituteError::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for DeleteSubstituteError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Getting substitutes of an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn get_ingredient_substitutes_route(
+    Path(ingredient_id): Path<Uuid>,
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+) -> Result<Json<Vec<IngredientSubstituteDTO>>, GetIngredientError> {
+    let result = get_ingredient_substitutes(ingredient_repository, &ingredient_id).await?;
+
+    Ok(Json(result.into_iter().map(Into::into).collect()))
+}
+
+#[tracing::instrument(
+    "[ROUTE] Setting a substitute of an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn set_ingredient_substitute_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Path((ingredient_id, substitute_id)): Path<(Uuid, Uuid)>,
+    Json(body): Json<SetIngredientSubstituteDTO>,
+) -> Result<Json<Vec<IngredientSubstituteDTO>>, SetSubstituteError> {
+    let input = SetSubstitute {
+        ratio: body.ratio,
+        unit: body.unit,
+        notes: body.notes,
+    };
+    let result =
+        set_substitute(ingredient_repository, &ingredient_id, &substitute_id, input).await?;
+
+    Ok(Json(result.into_iter().map(Into::into).collect()))
+}
+
+#[tracing::instrument(
+    "[ROUTE] Deleting a substitute of an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn delete_ingredient_substitute_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Path((ingredient_id, substitute_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<Vec<IngredientSubstituteDTO>>, DeleteSubstituteError> {
+    let result = delete_substitute(ingredient_repository, &ingredient_id, &substitute_id).await?;
+
+    Ok(Json(result.into_iter().map(Into::into).collect()))
+}
@@ -0,0 +1,110 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use common::IngredientWithVariantsDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, AppState},
+    domain::{
+        commands::ingredients::variants::{
+            attach::{attach_variant, AttachVariantError},
+            detach::{detach_variant, DetachVariantError},
+        },
+        queries::ingredients::{
+            get_by_id::GetIngredientError, get_with_variants::get_ingredient_with_variants,
+        },
+    },
+};
+
+impl MakeError<String> for AttachVariantError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            AttachVariantError::NotFound(_) => StatusCode::NOT_FOUND,
+            AttachVariantError::SelfReference
+            | AttachVariantError::ParentIsAVariant(_)
+            | AttachVariantError::VariantHasVariants(_) => StatusCode::BAD_REQUEST,
+            AttachVariantError::AlreadyAVariant(_, _) => StatusCode::CONFLICT,
+            AttachVariantError::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for AttachVariantError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+impl MakeError<String> for DetachVariantError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            DetachVariantError::NotFound(_) => StatusCode::NOT_FOUND,
+            DetachVariantError::NotAVariant(_, _) => StatusCode::BAD_REQUEST,
+            DetachVariantError::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for DetachVariantError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Getting ingredient with its variants",
+    skip(ingredient_repository)
+)]
+pub async fn get_ingredient_variants_route(
+    Path(ingredient_id): Path<Uuid>,
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+) -> Result<Json<IngredientWithVariantsDTO>, GetIngredientError> {
+    let result = get_ingredient_with_variants(ingredient_repository, &ingredient_id).await?;
+
+    Ok(Json(result.into()))
+}
+
+#[tracing::instrument(
+    "[ROUTE] Attaching a variant to an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn attach_ingredient_variant_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Path((ingredient_id, variant_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<IngredientWithVariantsDTO>, AttachVariantError> {
+    let result = attach_variant(ingredient_repository, &ingredient_id, &variant_id).await?;
+
+    Ok(Json(result.into()))
+}
+
+#[tracing::instrument(
+    "[ROUTE] Detaching a variant from an ingredient",
+    skip(ingredient_repository)
+)]
+pub async fn detach_ingredient_variant_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    Path((ingredient_id, variant_id)): Path<(Uuid, Uuid)>,
+) -> Result<Json<IngredientWithVariantsDTO>, DetachVariantError> {
+    let result = detach_variant(ingredient_repository, &ingredient_id, &variant_id).await?;
+
+    Ok(Json(result.into()))
+}
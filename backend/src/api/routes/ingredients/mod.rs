@@ -1,11 +1,17 @@
 mod all_ingredients;
+mod autocomplete_ingredients;
 mod create_ingredient;
+mod delete_ingredient;
 mod get_ingredient_by_id;
+mod ingredient_substitutes;
+mod ingredient_variants;
 mod update_ingredient;
-mod delete_ingredient;
 
 pub use all_ingredients::*;
+pub use autocomplete_ingredients::*;
 pub use create_ingredient::*;
+pub use delete_ingredient::*;
 pub use get_ingredient_by_id::*;
+pub use ingredient_substitutes::*;
+pub use ingredient_variants::*;
 pub use update_ingredient::*;
-pub use delete_ingredient::*;
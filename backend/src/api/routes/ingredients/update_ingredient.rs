@@ -7,7 +7,11 @@ use common::{IngredientDTO, UpdateIngredientDTO};
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{
+        errors::MakeError,
+        etag::{ETag, IfMatch},
+        AppState,
+    },
     domain::commands::ingredients::update::{update_ingredient, UpdateIngredientError},
 };
 
@@ -18,6 +22,8 @@ impl MakeError<String> for UpdateIngredientError {
     fn get_status_code(&self) -> reqwest::StatusCode {
         match self {
             Self::NotFound(_) => reqwest::StatusCode::NOT_FOUND,
+            Self::ValidationError(_) => reqwest::StatusCode::BAD_REQUEST,
+            Self::VersionMismatch(_) => reqwest::StatusCode::PRECONDITION_FAILED,
             _ => reqwest::StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -36,9 +42,16 @@ pub async fn update_ingredient_route(
         ingredient_repository,
         ..
     }): State<AppState>,
+    IfMatch(expected): IfMatch,
     Json(body): Json<UpdateIngredientDTO>,
-) -> Result<Json<IngredientDTO>, UpdateIngredientError> {
-    let result = update_ingredient(ingredient_repository, ingredient_id, &body.into()).await?;
+) -> Result<(ETag, Json<IngredientDTO>), UpdateIngredientError> {
+    let result = update_ingredient(
+        ingredient_repository,
+        ingredient_id,
+        &body.into(),
+        &expected,
+    )
+    .await?;
 
-    Ok(Json(result.into()))
+    Ok((ETag(result.version), Json(result.into())))
 }
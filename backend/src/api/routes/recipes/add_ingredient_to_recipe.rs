@@ -8,7 +8,12 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{
+        errors::MakeError,
+        etag::{ETag, IfMatch},
+        session::CurrentUser,
+        AppState,
+    },
     domain::{
         commands::recipes::ingredients::add::{
             add_ingredient_to_recipe, AddIngredientToRecipeError,
@@ -24,6 +29,9 @@ impl MakeError<String> for AddIngredientToRecipeError {
         match self {
             Self::GetIngredient(GetIngredientByIdError::NotFound(_)) => StatusCode::BAD_REQUEST,
             Self::GetRecipe(GetRecipeByIdError::NotFound(_)) => StatusCode::NOT_FOUND,
+            Self::Validation(_) => StatusCode::BAD_REQUEST,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::VersionMismatch(_) => StatusCode::PRECONDITION_FAILED,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -44,17 +52,21 @@ pub async fn add_ingredient_to_recipe_route(
         ingredient_repository,
         ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Path(recipe_id): Path<Uuid>,
+    IfMatch(expected): IfMatch,
     Json(body): Json<IngredientAmountDTO>,
-) -> Result<Json<RecipeDTO>, AddIngredientToRecipeError> {
-    let ingredient_to_add = body.into();
+) -> Result<(ETag, Json<RecipeDTO>), AddIngredientToRecipeError> {
+    let ingredient_to_add = body.try_into()?;
     let result = add_ingredient_to_recipe(
         recipe_repository,
         ingredient_repository,
         &recipe_id,
         ingredient_to_add,
+        &user,
+        &expected,
     )
     .await?;
 
-    Ok(axum::Json(result.into()))
+    Ok((ETag(result.version), axum::Json(result.into())))
 }
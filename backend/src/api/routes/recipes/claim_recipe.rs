@@ -0,0 +1,45 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use common::RecipeDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, etag::ETag, session::CurrentUser, AppState},
+    domain::commands::recipes::claim::{claim_recipe, ClaimRecipeError},
+};
+
+impl MakeError<String> for ClaimRecipeError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::AlreadyAuthored(_) => StatusCode::CONFLICT,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ClaimRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Claiming a recipe", skip(recipe_repository, user))]
+pub async fn claim_recipe_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<(ETag, Json<RecipeDTO>), ClaimRecipeError> {
+    let recipe = claim_recipe(recipe_repository, &recipe_id, &user).await?;
+
+    Ok((ETag(recipe.version), Json(recipe.into())))
+}
@@ -0,0 +1,48 @@
+use axum::{extract::State, response::IntoResponse, Json};
+use common::{CookableRecipeDTO, CookableRecipesQueryDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, session::CurrentUser, AppState},
+    domain::queries::recipes::cookable::{list_cookable_recipes, ListCookableRecipesError},
+};
+
+impl MakeError<String> for ListCookableRecipesError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ListCookableRecipesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Listing cookable recipes", skip(recipe_repository, user))]
+pub async fn cookable_recipes_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    user: Option<CurrentUser>,
+    Json(body): Json<CookableRecipesQueryDTO>,
+) -> Result<Json<Vec<CookableRecipeDTO>>, ListCookableRecipesError> {
+    let viewer = user.as_ref().map(|CurrentUser(user)| user);
+    let result = list_cookable_recipes(
+        recipe_repository,
+        &body.ingredient_ids,
+        body.max_missing,
+        body.limit,
+        viewer,
+    )
+    .await?;
+
+    Ok(Json(
+        result.into_iter().map(CookableRecipeDTO::from).collect(),
+    ))
+}
@@ -3,7 +3,8 @@ use common::{CreateRecipeDTO, RecipeDTO};
 use reqwest::StatusCode;
 
 use crate::api::errors::MakeError;
-use crate::api::AppState;
+use crate::api::etag::ETag;
+use crate::api::{session::CurrentUser, AppState};
 use crate::domain::commands::recipes::create::{create_recipe, CreateRecipe, CreateRecipeError};
 use crate::domain::entities::recipe::IngredientAmountData;
 
@@ -11,6 +12,7 @@ impl MakeError<String> for CreateRecipeError {
     fn get_status_code(&self) -> StatusCode {
         match self {
             Self::IngredientsNotFound(_) => StatusCode::BAD_REQUEST,
+            Self::Validation(_) => StatusCode::BAD_REQUEST,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -27,7 +29,7 @@ impl IntoResponse for CreateRecipeError {
 
 #[tracing::instrument(
     "[ROUTE] Creating a new recipe",
-    skip(ingredient_repository, recipe_repository)
+    skip(ingredient_repository, recipe_repository, user)
 )]
 pub async fn create_recipe_route(
     State(AppState {
@@ -35,32 +37,28 @@ pub async fn create_recipe_route(
         recipe_repository,
         ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Json(body): Json<CreateRecipeDTO>,
 ) -> Result<impl IntoResponse, CreateRecipeError> {
     let ingredients: Vec<IngredientAmountData> = body
         .ingredients
         .into_iter()
-        .map(|i| IngredientAmountData {
-            notes: i.notes,
-            amount: i.amount.into(),
-            optional: i.optional,
-            ingredient_id: i.ingredient_id,
-        })
-        .collect();
+        .map(IngredientAmountData::try_from)
+        .collect::<Result<_, _>>()?;
 
     let input = CreateRecipe {
         name: body.name,
         description: body.description,
         servings: body.servings.into(),
-        time: body
-            .time
-            .into_iter()
-            .map(|(k, v)| (k, std::time::Duration::from_secs(v)))
-            .collect(),
-        steps: body.steps,
+        time: body.time.try_into()?,
+        steps: body.steps.into_iter().map(Into::into).collect(),
         ingredients,
+        author_id: Some(user.id),
+        public: body.public.unwrap_or(true),
     };
-    let result: RecipeDTO = create_recipe(recipe_repository, ingredient_repository, &input).await?.into();
+    let result = create_recipe(recipe_repository, ingredient_repository, &input).await?;
+    let etag = ETag(result.version);
+    let result: RecipeDTO = result.into();
 
-    Ok((StatusCode::CREATED, Json(result)))
+    Ok((StatusCode::CREATED, etag, Json(result)))
 }
@@ -6,7 +6,7 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{errors::MakeError, etag::IfMatch, session::CurrentUser, AppState},
     domain::commands::recipes::ingredients::delete::{
         delete_ingredient_from_recipe, DeleteIngredientFromRecipeError,
     },
@@ -22,6 +22,8 @@ impl MakeError<String> for DeleteIngredientFromRecipeError {
             DeleteIngredientFromRecipeError::LastIngredientError => {
                 StatusCode::UNPROCESSABLE_ENTITY
             }
+            DeleteIngredientFromRecipeError::Forbidden(_) => StatusCode::FORBIDDEN,
+            DeleteIngredientFromRecipeError::VersionMismatch(_) => StatusCode::PRECONDITION_FAILED,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -40,9 +42,18 @@ pub async fn delete_ingredient_from_recipe_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Path((recipe_id, ingredient_id)): Path<(Uuid, Uuid)>,
+    IfMatch(expected): IfMatch,
 ) -> Result<(), DeleteIngredientFromRecipeError> {
-    delete_ingredient_from_recipe(recipe_repository, &recipe_id, &ingredient_id).await?;
+    delete_ingredient_from_recipe(
+        recipe_repository,
+        &recipe_id,
+        &ingredient_id,
+        &user,
+        &expected,
+    )
+    .await?;
 
     Ok(())
 }
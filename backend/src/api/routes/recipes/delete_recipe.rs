@@ -6,7 +6,7 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{errors::MakeError, etag::IfMatch, session::CurrentUser, AppState},
     domain::commands::recipes::delete::{delete_recipe, DeleteRecipeError},
 };
 
@@ -14,6 +14,8 @@ impl MakeError<String> for DeleteRecipeError {
     fn get_status_code(&self) -> StatusCode {
         match self {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::VersionMismatch(_) => StatusCode::PRECONDITION_FAILED,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -28,14 +30,16 @@ impl IntoResponse for DeleteRecipeError {
     }
 }
 
-#[tracing::instrument("[ROUTE] Deleting a recipe", skip(recipe_repository))]
+#[tracing::instrument("[ROUTE] Deleting a recipe", skip(recipe_repository, user))]
 pub async fn delete_recipe_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Path(recipe_id): Path<Uuid>,
+    IfMatch(expected): IfMatch,
 ) -> Result<(), DeleteRecipeError> {
-    delete_recipe(recipe_repository, &recipe_id).await?;
+    delete_recipe(recipe_repository, &recipe_id, &user, &expected).await?;
 
     Ok(())
 }
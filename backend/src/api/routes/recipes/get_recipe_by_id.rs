@@ -1,19 +1,34 @@
 use axum::{
-    extract::{Path, State},
-    response::IntoResponse,
+    extract::{Path, Query, State},
+    http::{
+        header::{ACCEPT, CONTENT_TYPE, VARY},
+        HeaderMap,
+    },
+    response::{IntoResponse, Response},
     Json,
 };
-use common::RecipeDTO;
+use common::{GetRecipeQueryDTO, RecipeDTO, SchemaOrgRecipeDTO};
 use reqwest::StatusCode;
 use uuid::Uuid;
 
-use crate::api::{errors::MakeError, AppState};
-use crate::domain::queries::recipes::get_by_id::{get_recipe_by_id, GetRecipeError};
+use crate::api::{
+    errors::MakeError,
+    etag::{ETag, WeakETag},
+    session::CurrentUser,
+    AppState,
+};
+use crate::domain::entities::recipe::cooklang;
+use crate::domain::queries::recipes::{
+    convert_units::convert_recipe_units,
+    get_by_id::{get_recipe_by_id, GetRecipeError},
+    scale::scale_recipe,
+};
 
 impl MakeError<String> for GetRecipeError {
     fn get_status_code(&self) -> StatusCode {
         match self {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Scaling(_) => StatusCode::BAD_REQUEST,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -22,20 +37,68 @@ impl MakeError<String> for GetRecipeError {
     }
 }
 
+/// Media type of the schema.org JSON-LD representation of a recipe
+const JSON_LD: &str = "application/ld+json";
+/// Media type of the Cooklang representation of a recipe
+const COOKLANG: &str = "text/x-cooklang";
+
 impl IntoResponse for GetRecipeError {
     fn into_response(self) -> axum::response::Response {
         (self.get_status_code(), self.get_json()).into_response()
     }
 }
 
-#[tracing::instrument("[ROUTE] Getting a recipe by ID", skip(recipe_repository))]
+#[tracing::instrument("[ROUTE] Getting a recipe by ID", skip(recipe_repository, user))]
 pub async fn get_recipe_by_id_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
+    user: Option<CurrentUser>,
     Path(recipe_id): Path<Uuid>,
-) -> Result<Json<RecipeDTO>, GetRecipeError> {
-    let result = get_recipe_by_id(recipe_repository, &recipe_id).await?;
+    Query(query): Query<GetRecipeQueryDTO>,
+    headers: HeaderMap,
+) -> Result<Response, GetRecipeError> {
+    let viewer = user.as_ref().map(|CurrentUser(user)| user);
+    let result = get_recipe_by_id(recipe_repository, &recipe_id, viewer).await?;
+    let transformed = query.servings.is_some() || query.units.is_some();
+    let result = match query.servings {
+        Some(servings) => scale_recipe(result, servings)?,
+        None => result,
+    };
+    let result = match query.units {
+        Some(system) => convert_recipe_units(result, system.into()),
+        None => result,
+    };
+
+    let version = result.version;
+    let accepts = |media_type: &str| {
+        headers
+            .get_all(ACCEPT)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .any(|value| value.contains(media_type))
+    };
+    if accepts(JSON_LD) {
+        let result: SchemaOrgRecipeDTO = result.into();
+        return Ok((
+            WeakETag(version),
+            [(CONTENT_TYPE, JSON_LD), (VARY, "Accept")],
+            Json(result),
+        )
+            .into_response());
+    }
+    if accepts(COOKLANG) {
+        return Ok((
+            WeakETag(version),
+            [(CONTENT_TYPE, COOKLANG), (VARY, "Accept")],
+            cooklang::serialize(&result),
+        )
+            .into_response());
+    }
+    let result: RecipeDTO = result.into();
+    if transformed {
+        return Ok((WeakETag(version), [(VARY, "Accept")], Json(result)).into_response());
+    }
 
-    Ok(axum::Json(result.into()))
+    Ok((ETag(version), [(VARY, "Accept")], Json(result)).into_response())
 }
@@ -0,0 +1,134 @@
+use axum::{
+    extract::{Multipart, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{ImportRecipeQueryDTO, ImportedRecipeDTO, RecipeImportDraftDTO};
+use reqwest::StatusCode;
+
+use crate::api::errors::MakeError;
+use crate::api::etag::ETag;
+use crate::api::{session::CurrentUser, AppState};
+use crate::domain::commands::recipes::import::{
+    draft_recipe_from_html, import_recipe, ImportRecipeError,
+};
+use crate::domain::entities::recipe::{cooklang, draft::RecipeDraft, schema_org::find_recipe};
+
+impl MakeError<String> for ImportRecipeError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NoRecipe => StatusCode::BAD_REQUEST,
+            Self::NoDocument => StatusCode::BAD_REQUEST,
+            Self::Validation(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ImportRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Importing a schema.org recipe",
+    skip(ingredient_repository, recipe_repository, user, body)
+)]
+pub async fn import_recipe_route(
+    State(AppState {
+        ingredient_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Query(query): Query<ImportRecipeQueryDTO>,
+    Json(body): Json<serde_json::Value>,
+) -> Result<impl IntoResponse, ImportRecipeError> {
+    let recipe = find_recipe(&body).ok_or(ImportRecipeError::NoRecipe)?;
+    let result = import_recipe(
+        recipe_repository,
+        ingredient_repository,
+        RecipeDraft::from(recipe),
+        Some(user.id),
+        query.public.unwrap_or(false),
+    )
+    .await?;
+    let etag = ETag(result.recipe.version);
+    let result: ImportedRecipeDTO = result.into();
+
+    Ok((StatusCode::CREATED, etag, Json(result)))
+}
+
+#[tracing::instrument(
+    "[ROUTE] Importing a Cooklang recipe",
+    skip(ingredient_repository, recipe_repository, user, body)
+)]
+pub async fn import_cooklang_recipe_route(
+    State(AppState {
+        ingredient_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Query(query): Query<ImportRecipeQueryDTO>,
+    body: String,
+) -> Result<impl IntoResponse, ImportRecipeError> {
+    let result = import_recipe(
+        recipe_repository,
+        ingredient_repository,
+        cooklang::parse(&body),
+        Some(user.id),
+        query.public.unwrap_or(false),
+    )
+    .await?;
+    let etag = ETag(result.recipe.version);
+    let result: ImportedRecipeDTO = result.into();
+
+    Ok((StatusCode::CREATED, etag, Json(result)))
+}
+
+#[tracing::instrument(
+    "[ROUTE] Drafting a recipe from an uploaded web page",
+    skip(ingredient_repository, user, multipart)
+)]
+pub async fn import_recipe_html_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Query(query): Query<ImportRecipeQueryDTO>,
+    mut multipart: Multipart,
+) -> Result<Json<RecipeImportDraftDTO>, ImportRecipeError> {
+    let mut html = None;
+    while let Some(field) = multipart
+        .next_field()
+        .await
+        .map_err(|_| ImportRecipeError::NoDocument)?
+    {
+        if field.name() == Some("file") {
+            html = Some(
+                field
+                    .text()
+                    .await
+                    .map_err(|_| ImportRecipeError::NoDocument)?,
+            );
+            break;
+        }
+    }
+    let html = html.ok_or(ImportRecipeError::NoDocument)?;
+
+    let result = draft_recipe_from_html(
+        ingredient_repository,
+        &html,
+        Some(user.id),
+        query.public.unwrap_or(false),
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
@@ -0,0 +1,60 @@
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{RecipeListQueryDTO, RecipePageDTO};
+use reqwest::StatusCode;
+
+use crate::api::{errors::MakeError, session::CurrentUser, AppState};
+use crate::domain::entities::recipe::RecipeListQuery;
+use crate::domain::queries::recipes::list::{list_recipes, ListRecipesError};
+use crate::domain::queries::users::get_by_session::GetUserBySessionError;
+
+impl MakeError<String> for ListRecipesError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::InvalidCursor(_) | Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ListRecipesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Listing recipes",
+    skip(recipe_repository, ingredient_repository, user)
+)]
+pub async fn list_recipes_route(
+    State(AppState {
+        recipe_repository,
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    user: Option<CurrentUser>,
+    Query(query): Query<RecipeListQueryDTO>,
+) -> Result<impl IntoResponse, ListRecipesError> {
+    let mine = query.mine.unwrap_or(false);
+    let mut query: RecipeListQuery = query.try_into()?;
+
+    if mine {
+        match user {
+            Some(CurrentUser(user)) => query.author_id = Some(user.id),
+            None => return Ok(GetUserBySessionError::NotLoggedIn.into_response()),
+        }
+    }
+
+    let result: RecipePageDTO = list_recipes(recipe_repository, ingredient_repository, &query)
+        .await?
+        .into();
+
+    Ok(Json(result).into_response())
+}
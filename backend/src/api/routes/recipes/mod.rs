@@ -1,15 +1,29 @@
 mod add_ingredient_to_recipe;
+mod claim_recipe;
+mod cookable_recipes;
 mod create_recipe;
 mod delete_ingredient_from_recipe;
 mod delete_recipe;
 mod get_recipe_by_id;
+mod import_recipe;
+mod list_recipes;
+mod recipe_revisions;
+mod recipe_substitutions;
+mod search_recipes;
 mod update_ingredient_in_recipe;
 mod update_recipe;
 
 pub use add_ingredient_to_recipe::*;
+pub use claim_recipe::*;
+pub use cookable_recipes::*;
 pub use create_recipe::create_recipe_route;
 pub use delete_ingredient_from_recipe::*;
 pub use delete_recipe::*;
 pub use get_recipe_by_id::*;
+pub use import_recipe::*;
+pub use list_recipes::*;
+pub use recipe_revisions::*;
+pub use recipe_substitutions::*;
+pub use search_recipes::*;
 pub use update_ingredient_in_recipe::*;
 pub use update_recipe::*;
@@ -0,0 +1,125 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{RecipeDTO, RecipeRevisionDTO, RecipeRevisionSummaryDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        etag::{ETag, IfMatch},
+        session::CurrentUser,
+        AppState,
+    },
+    domain::{
+        commands::recipes::restore::{restore_recipe_revision, RestoreRecipeError},
+        entities::version::Version,
+        queries::recipes::revisions::{
+            get_recipe_revision, list_recipe_revisions, GetRecipeRevisionsError,
+        },
+    },
+};
+
+impl MakeError<String> for GetRecipeRevisionsError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::RevisionNotFound(_, _) => StatusCode::NOT_FOUND,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetRecipeRevisionsError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+impl MakeError<String> for RestoreRecipeError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::RevisionNotFound(_, _) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::VersionMismatch(_) => StatusCode::PRECONDITION_FAILED,
+            Self::IngredientsNotFound(_) => StatusCode::CONFLICT,
+            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for RestoreRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Listing revisions of a recipe", skip(recipe_repository, user))]
+pub async fn list_recipe_revisions_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    user: Option<CurrentUser>,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<Json<Vec<RecipeRevisionSummaryDTO>>, GetRecipeRevisionsError> {
+    let viewer = user.as_ref().map(|CurrentUser(user)| user);
+    let result = list_recipe_revisions(recipe_repository, &recipe_id, viewer).await?;
+
+    Ok(Json(result.into_iter().map(Into::into).collect()))
+}
+
+#[tracing::instrument(
+    "[ROUTE] Getting a revision of a recipe",
+    skip(recipe_repository, user)
+)]
+pub async fn get_recipe_revision_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    user: Option<CurrentUser>,
+    Path((recipe_id, revision)): Path<(Uuid, i32)>,
+) -> Result<Json<RecipeRevisionDTO>, GetRecipeRevisionsError> {
+    let viewer = user.as_ref().map(|CurrentUser(user)| user);
+    let result =
+        get_recipe_revision(recipe_repository, &recipe_id, Version(revision), viewer).await?;
+
+    Ok(Json(result.into()))
+}
+
+#[tracing::instrument(
+    "[ROUTE] Restoring a revision of a recipe",
+    skip(recipe_repository, ingredient_repository, user)
+)]
+pub async fn restore_recipe_revision_route(
+    State(AppState {
+        recipe_repository,
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path((recipe_id, revision)): Path<(Uuid, i32)>,
+    IfMatch(expected): IfMatch,
+) -> Result<(ETag, Json<RecipeDTO>), RestoreRecipeError> {
+    let recipe = restore_recipe_revision(
+        recipe_repository,
+        ingredient_repository,
+        &recipe_id,
+        Version(revision),
+        &user,
+        &expected,
+    )
+    .await?;
+
+    Ok((ETag(recipe.version), Json(recipe.into())))
+}
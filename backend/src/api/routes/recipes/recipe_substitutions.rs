@@ -0,0 +1,63 @@
+use axum::{
+    extract::{Path, Query, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{RecipeSubstitutionsDTO, RecipeSubstitutionsQueryDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, session::CurrentUser, AppState},
+    domain::{
+        entities::ingredient::types::WhichDiets,
+        queries::recipes::substitutions::{propose_substitutions, ProposeSubstitutionsError},
+    },
+};
+
+impl MakeError<String> for ProposeSubstitutionsError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Validation(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for ProposeSubstitutionsError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Proposing substitutions for a recipe",
+    skip(recipe_repository, ingredient_repository, user)
+)]
+pub async fn get_recipe_substitutions_route(
+    State(AppState {
+        recipe_repository,
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    user: Option<CurrentUser>,
+    Path(recipe_id): Path<Uuid>,
+    Query(query): Query<RecipeSubstitutionsQueryDTO>,
+) -> Result<Json<RecipeSubstitutionsDTO>, ProposeSubstitutionsError> {
+    let viewer = user.as_ref().map(|CurrentUser(user)| user);
+    let diets: WhichDiets = query.diet_friendly.parse()?;
+    let result = propose_substitutions(
+        recipe_repository,
+        ingredient_repository,
+        &recipe_id,
+        &diets,
+        viewer,
+    )
+    .await?;
+
+    Ok(Json(result.into()))
+}
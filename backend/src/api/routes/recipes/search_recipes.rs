@@ -0,0 +1,49 @@
+use axum::{
+    extract::{Query, State},
+    response::IntoResponse,
+    Json,
+};
+use common::{RecipeSearchQueryDTO, RecipeSearchResultDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, session::CurrentUser, AppState},
+    domain::queries::recipes::search::{search_recipes, SearchRecipesError},
+};
+
+impl MakeError<String> for SearchRecipesError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::ValidationError(_) => StatusCode::BAD_REQUEST,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for SearchRecipesError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Searching recipes", skip(recipe_repository, user))]
+pub async fn search_recipes_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    user: Option<CurrentUser>,
+    Query(query): Query<RecipeSearchQueryDTO>,
+) -> Result<Json<Vec<RecipeSearchResultDTO>>, SearchRecipesError> {
+    let viewer = user.as_ref().map(|CurrentUser(user)| user);
+    let result = search_recipes(recipe_repository, &query.q, query.limit, viewer).await?;
+
+    Ok(Json(
+        result
+            .into_iter()
+            .map(RecipeSearchResultDTO::from)
+            .collect(),
+    ))
+}
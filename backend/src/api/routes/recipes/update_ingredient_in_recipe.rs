@@ -8,7 +8,12 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
-    api::{errors::MakeError, AppState},
+    api::{
+        errors::MakeError,
+        etag::{ETag, IfMatch},
+        session::CurrentUser,
+        AppState,
+    },
     domain::{
         commands::recipes::ingredients::update::{
             update_ingredient_in_recipe, UpdateIngredientInRecipeError,
@@ -25,6 +30,8 @@ impl MakeError<String> for UpdateIngredientInRecipeError {
             | UpdateIngredientInRecipeError::GetRecipe(GetRecipeByIdError::NotFound(_)) => {
                 StatusCode::NOT_FOUND
             }
+            UpdateIngredientInRecipeError::Forbidden(_) => StatusCode::FORBIDDEN,
+            UpdateIngredientInRecipeError::VersionMismatch(_) => StatusCode::PRECONDITION_FAILED,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
@@ -43,12 +50,21 @@ pub async fn update_ingredient_in_recipe_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Path((recipe_id, ingredient_id)): Path<(Uuid, Uuid)>,
+    IfMatch(expected): IfMatch,
     Json(body): Json<IngredientUnitDTO>,
-) -> Result<Json<RecipeDTO>, UpdateIngredientInRecipeError> {
+) -> Result<(ETag, Json<RecipeDTO>), UpdateIngredientInRecipeError> {
     let amount: IngredientUnit = body.into();
-    let recipe =
-        update_ingredient_in_recipe(recipe_repository, &recipe_id, &ingredient_id, amount).await?;
+    let recipe = update_ingredient_in_recipe(
+        recipe_repository,
+        &recipe_id,
+        &ingredient_id,
+        amount,
+        &user,
+        &expected,
+    )
+    .await?;
 
-    Ok(Json(recipe.into()))
+    Ok((ETag(recipe.version), Json(recipe.into())))
 }
@@ -5,13 +5,16 @@ use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::api::errors::MakeError;
-use crate::api::AppState;
+use crate::api::etag::{ETag, IfMatch};
+use crate::api::{session::CurrentUser, AppState};
 use crate::domain::commands::recipes::update::{update_recipe, UpdateRecipeError};
 
 impl MakeError<String> for UpdateRecipeError {
     fn get_status_code(&self) -> StatusCode {
         match self {
             Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::VersionMismatch(_) => StatusCode::PRECONDITION_FAILED,
             Self::ValidationError(_) => StatusCode::BAD_REQUEST,
             Self::ChangesetEmpty => StatusCode::BAD_REQUEST,
             _ => StatusCode::INTERNAL_SERVER_ERROR,
@@ -28,15 +31,24 @@ impl IntoResponse for UpdateRecipeError {
     }
 }
 
-#[tracing::instrument("[ROUTE] Creating a new recipe", skip(recipe_repository))]
+#[tracing::instrument("[ROUTE] Creating a new recipe", skip(recipe_repository, user))]
 pub async fn update_recipe_route(
     State(AppState {
         recipe_repository, ..
     }): State<AppState>,
+    CurrentUser(user): CurrentUser,
     Path(recipe_id): Path<Uuid>,
+    IfMatch(expected): IfMatch,
     Json(body): Json<UpdateRecipeDTO>,
-) -> Result<Json<RecipeDTO>, UpdateRecipeError> {
-    let recipe = update_recipe(recipe_repository, &recipe_id, body.into()).await?;
+) -> Result<(ETag, Json<RecipeDTO>), UpdateRecipeError> {
+    let recipe = update_recipe(
+        recipe_repository,
+        &recipe_id,
+        body.try_into()?,
+        &user,
+        &expected,
+    )
+    .await?;
 
-    Ok(axum::Json(recipe.into()))
+    Ok((ETag(recipe.version), axum::Json(recipe.into())))
 }
@@ -0,0 +1,42 @@
+use axum::{extract::State, response::IntoResponse, Json};
+use common::TrashDTO;
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, session::CurrentUser, AppState},
+    domain::queries::trash::get::{get_trash, GetTrashError},
+};
+
+impl MakeError<String> for GetTrashError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetTrashError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Getting the trash",
+    skip(ingredient_repository, recipe_repository, user)
+)]
+pub async fn get_trash_route(
+    State(AppState {
+        ingredient_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+) -> Result<Json<TrashDTO>, GetTrashError> {
+    let trash = get_trash(ingredient_repository, recipe_repository, &user).await?;
+
+    Ok(Json(trash.into()))
+}
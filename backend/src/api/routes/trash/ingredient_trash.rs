@@ -0,0 +1,89 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use common::IngredientDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, etag::ETag, session::CurrentUser, AppState},
+    domain::commands::ingredients::trash::{
+        purge::{purge_deleted_ingredient, PurgeDeletedIngredientError},
+        restore::{restore_deleted_ingredient, RestoreDeletedIngredientError},
+    },
+};
+
+impl MakeError<String> for RestoreDeletedIngredientError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            Self::UnknownError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for RestoreDeletedIngredientError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+impl MakeError<String> for PurgeDeletedIngredientError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::InUseByRecipe => StatusCode::CONFLICT,
+            Self::UnknownError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for PurgeDeletedIngredientError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Restoring a deleted ingredient",
+    skip(ingredient_repository, _user)
+)]
+pub async fn restore_deleted_ingredient_route(
+    State(AppState {
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    _user: CurrentUser,
+    Path(ingredient_id): Path<Uuid>,
+) -> Result<(ETag, Json<IngredientDTO>), RestoreDeletedIngredientError> {
+    let ingredient = restore_deleted_ingredient(ingredient_repository, &ingredient_id).await?;
+
+    Ok((ETag(ingredient.version), Json(ingredient.into())))
+}
+
+#[tracing::instrument(
+    "[ROUTE] Purging a deleted ingredient",
+    skip(ingredient_repository, recipe_repository, _user)
+)]
+pub async fn purge_deleted_ingredient_route(
+    State(AppState {
+        ingredient_repository,
+        recipe_repository,
+        ..
+    }): State<AppState>,
+    _user: CurrentUser,
+    Path(ingredient_id): Path<Uuid>,
+) -> Result<(), PurgeDeletedIngredientError> {
+    purge_deleted_ingredient(ingredient_repository, recipe_repository, &ingredient_id).await?;
+
+    Ok(())
+}
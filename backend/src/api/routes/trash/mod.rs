@@ -0,0 +1,7 @@
+mod get_trash;
+mod ingredient_trash;
+mod recipe_trash;
+
+pub use get_trash::*;
+pub use ingredient_trash::*;
+pub use recipe_trash::*;
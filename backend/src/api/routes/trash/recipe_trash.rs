@@ -0,0 +1,87 @@
+use axum::{
+    extract::{Path, State},
+    response::IntoResponse,
+    Json,
+};
+use common::RecipeDTO;
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    api::{errors::MakeError, etag::ETag, session::CurrentUser, AppState},
+    domain::commands::recipes::trash::{
+        purge::{purge_deleted_recipe, PurgeDeletedRecipeError},
+        restore::{restore_deleted_recipe, RestoreDeletedRecipeError},
+    },
+};
+
+impl MakeError<String> for RestoreDeletedRecipeError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::IngredientsDeleted(_) => StatusCode::CONFLICT,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for RestoreDeletedRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+impl MakeError<String> for PurgeDeletedRecipeError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::Forbidden(_) => StatusCode::FORBIDDEN,
+            Self::Unknown(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for PurgeDeletedRecipeError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument(
+    "[ROUTE] Restoring a deleted recipe",
+    skip(recipe_repository, ingredient_repository, user)
+)]
+pub async fn restore_deleted_recipe_route(
+    State(AppState {
+        recipe_repository,
+        ingredient_repository,
+        ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<(ETag, Json<RecipeDTO>), RestoreDeletedRecipeError> {
+    let recipe =
+        restore_deleted_recipe(recipe_repository, ingredient_repository, &recipe_id, &user).await?;
+
+    Ok((ETag(recipe.version), Json(recipe.into())))
+}
+
+#[tracing::instrument("[ROUTE] Purging a deleted recipe", skip(recipe_repository, user))]
+pub async fn purge_deleted_recipe_route(
+    State(AppState {
+        recipe_repository, ..
+    }): State<AppState>,
+    CurrentUser(user): CurrentUser,
+    Path(recipe_id): Path<Uuid>,
+) -> Result<(), PurgeDeletedRecipeError> {
+    purge_deleted_recipe(recipe_repository, &recipe_id, &user).await?;
+
+    Ok(())
+}
@@ -0,0 +1,9 @@
+use axum::Json;
+use common::user::UserDataDTO;
+
+use crate::api::session::CurrentUser;
+
+#[tracing::instrument("[ROUTE] Getting the current user", skip(user))]
+pub async fn current_user_route(CurrentUser(user): CurrentUser) -> Json<UserDataDTO> {
+    Json(user.into())
+}
@@ -0,0 +1,47 @@
+use axum::{extract::State, response::IntoResponse, Json};
+use axum_extra::extract::CookieJar;
+use common::user::{LoginUserDTO, UserDataDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, session::session_cookie, AppState},
+    domain::commands::users::login::{login, Login, LoginError},
+};
+
+impl MakeError<String> for LoginError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotFound(_) => StatusCode::NOT_FOUND,
+            Self::WrongPassword => StatusCode::BAD_REQUEST,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for LoginError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Logging in", skip(user_repository, session_store, jar, body))]
+pub async fn login_route(
+    State(AppState {
+        user_repository,
+        session_store,
+        ..
+    }): State<AppState>,
+    jar: CookieJar,
+    Json(body): Json<LoginUserDTO>,
+) -> Result<(CookieJar, Json<UserDataDTO>), LoginError> {
+    let input = Login {
+        username: &body.username,
+        password: &body.password,
+    };
+    let (user, session) = login(user_repository, session_store, &input).await?;
+
+    Ok((jar.add(session_cookie(&session)), Json(user.into())))
+}
@@ -0,0 +1,36 @@
+use axum::{extract::State, response::IntoResponse};
+use axum_extra::extract::CookieJar;
+use reqwest::StatusCode;
+
+use crate::{
+    api::{
+        errors::MakeError,
+        session::{removal_cookie, SESSION_COOKIE},
+        AppState,
+    },
+    domain::commands::users::logout::{logout, LogoutError},
+};
+
+impl MakeError<String> for LogoutError {
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for LogoutError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Logging out", skip(session_store, jar))]
+pub async fn logout_route(
+    State(AppState { session_store, .. }): State<AppState>,
+    jar: CookieJar,
+) -> Result<(StatusCode, CookieJar), LogoutError> {
+    if let Some(cookie) = jar.get(SESSION_COOKIE) {
+        logout(session_store, cookie.value()).await?;
+    }
+
+    Ok((StatusCode::NO_CONTENT, jar.remove(removal_cookie())))
+}
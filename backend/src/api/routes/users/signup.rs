@@ -0,0 +1,44 @@
+use axum::{extract::State, response::IntoResponse, Json};
+use common::user::{CreateNewUserDTO, UserDataDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, AppState},
+    domain::commands::users::signup::{signup, Signup, SignupError},
+};
+
+impl MakeError<String> for SignupError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::Validation(_) => StatusCode::BAD_REQUEST,
+            Self::Conflict(_) => StatusCode::CONFLICT,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for SignupError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+#[tracing::instrument("[ROUTE] Signing up a new user", skip(user_repository, body))]
+pub async fn signup_route(
+    State(AppState {
+        user_repository, ..
+    }): State<AppState>,
+    Json(body): Json<CreateNewUserDTO>,
+) -> Result<Json<UserDataDTO>, SignupError> {
+    let input = Signup {
+        username: &body.username,
+        email: &body.email,
+        password: &body.password,
+    };
+    let result = signup(user_repository, &input).await?;
+
+    Ok(Json(result.into()))
+}
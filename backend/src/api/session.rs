@@ -0,0 +1,79 @@
+use axum::{async_trait, extract::FromRequestParts, http::request::Parts, response::IntoResponse};
+use axum_extra::extract::{
+    cookie::{Cookie, SameSite},
+    CookieJar,
+};
+use reqwest::StatusCode;
+
+use crate::{
+    api::{errors::MakeError, AppState},
+    domain::{
+        entities::{
+            session::{session_lifetime, Session},
+            user::User,
+        },
+        queries::users::get_by_session::{get_user_by_session, GetUserBySessionError},
+    },
+};
+
+pub const SESSION_COOKIE: &str = "session";
+
+pub fn session_cookie(session: &Session) -> Cookie<'static> {
+    Cookie::build((SESSION_COOKIE, session.token.clone()))
+        .http_only(true)
+        .same_site(SameSite::Lax)
+        .path("/")
+        .max_age(time::Duration::seconds(session_lifetime().num_seconds()))
+        .build()
+}
+
+pub fn removal_cookie() -> Cookie<'static> {
+    Cookie::build((SESSION_COOKIE, "")).path("/").build()
+}
+
+impl MakeError<String> for GetUserBySessionError {
+    fn get_status_code(&self) -> StatusCode {
+        match self {
+            Self::NotLoggedIn => StatusCode::UNAUTHORIZED,
+            _ => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+    fn get_message(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl IntoResponse for GetUserBySessionError {
+    fn into_response(self) -> axum::response::Response {
+        (self.get_status_code(), self.get_json()).into_response()
+    }
+}
+
+/// The user the session cookie of the request belongs to.
+/// Rejects the request with 401 if there's no valid session.
+pub struct CurrentUser(pub User);
+
+#[async_trait]
+impl FromRequestParts<AppState> for CurrentUser {
+    type Rejection = GetUserBySessionError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        AppState {
+            user_repository,
+            session_store,
+            ..
+        }: &AppState,
+    ) -> Result<Self, Self::Rejection> {
+        let jar = CookieJar::from_headers(&parts.headers);
+        let token = jar
+            .get(SESSION_COOKIE)
+            .map(|cookie| cookie.value().to_string())
+            .ok_or(GetUserBySessionError::NotLoggedIn)?;
+
+        let user =
+            get_user_by_session(user_repository.clone(), session_store.clone(), &token).await?;
+
+        Ok(Self(user))
+    }
+}
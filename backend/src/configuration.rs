@@ -33,6 +33,7 @@ pub struct Settings {
     pub database: DatabaseSettings,
     pub application: ApplicationSettings,
     pub session: SessionSettings,
+    pub trash: TrashSettings,
 }
 
 #[derive(Deserialize, Clone, Debug)]
@@ -66,6 +67,16 @@ pub struct SessionSettings {
     pub ssl: bool,
 }
 
+#[derive(Deserialize, Clone, Debug)]
+pub struct TrashSettings {
+    /// How many days deleted recipes and ingredients stay in the trash before they're purged
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub retention_days: u32,
+    /// How many minutes pass between looking for expired items in the trash
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    pub purge_interval_minutes: u64,
+}
+
 impl Settings {
     #[tracing::instrument("Getting configuration")]
     pub fn get() -> color_eyre::Result<Self> {
@@ -134,3 +145,13 @@ impl ApplicationSettings {
         Ok(TcpListener::bind((self.host.clone(), self.port)).await?)
     }
 }
+
+impl TrashSettings {
+    pub fn retention(&self) -> chrono::Duration {
+        chrono::Duration::days(self.retention_days.into())
+    }
+
+    pub fn purge_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.purge_interval_minutes.max(1) * 60)
+    }
+}
@@ -1,17 +1,22 @@
 use uuid::Uuid;
 
 use crate::domain::entities::ingredient::*;
-use crate::domain::repositories::ingredients::{
-    errors::InsertIngredientError, IngredientRepositoryService,
+use crate::domain::repositories::{
+    ingredients::{errors::InsertIngredientError, IngredientRepositoryService},
+    unit_of_work::UnitOfWork,
 };
 
 use self::errors::ValidationError;
-use self::types::DietFriendly;
+use self::types::{Allergens, DietViolations};
 
 #[derive(thiserror::Error, Debug, strum::AsRefStr)]
 pub enum CreateIngredientError {
     #[error("The field {0} was empty")]
     EmptyField(&'static str),
+    #[error("The field {0} has to be a positive number")]
+    NotPositive(&'static str),
+    #[error("The field {0} does not match any of: {1:?}")]
+    DoesNotMatch(&'static str, &'static [&'static str]),
     #[error(
         "A conflict has occured - an ingredient with field {0} of the given value already exists."
     )]
@@ -33,6 +38,8 @@ impl From<ValidationError> for CreateIngredientError {
     fn from(value: ValidationError) -> Self {
         match value {
             ValidationError::EmptyField(field) => Self::EmptyField(field[0]),
+            ValidationError::NotPositive(field) => Self::NotPositive(field),
+            ValidationError::DoesNotMatch(field, values) => Self::DoesNotMatch(field, values),
             e => Self::Internal(e.into()),
         }
     }
@@ -42,7 +49,11 @@ impl From<ValidationError> for CreateIngredientError {
 pub struct CreateIngredient<'a> {
     pub name: &'a str,
     pub description: &'a str,
-    pub diet_friendly: Vec<String>,
+    /// Only used to infer the violations when they're missing
+    pub diet_friendly: Option<Vec<String>>,
+    pub diet_violations: Option<Vec<String>>,
+    pub allergens: Option<Vec<String>>,
+    pub density: Option<f64>,
 }
 
 impl<'a> TryFrom<&CreateIngredient<'a>> for Ingredient {
@@ -52,13 +63,20 @@ impl<'a> TryFrom<&CreateIngredient<'a>> for Ingredient {
             id: Uuid::now_v7(),
             name: value.name.try_into()?,
             description: value.description.try_into()?,
-            diet_friendly: value
-                .diet_friendly
+            diet_violations: DietViolations::from_input(
+                value.diet_violations.clone(),
+                value.diet_friendly.clone(),
+            )?
+            .unwrap_or_else(DietViolations::all),
+            allergens: value
+                .allergens
                 .clone()
-                .into_iter()
-                .filter_map(|x| DietFriendly::try_from(x).ok())
-                .collect::<Vec<_>>()
-                .into(),
+                .map(Allergens::try_from)
+                .transpose()?
+                .unwrap_or_default(),
+            density: value.density.map(TryInto::try_into).transpose()?,
+            parent_id: None,
+            version: Default::default(),
         })
     }
 }
@@ -73,5 +91,17 @@ pub async fn create_ingredient(
     Ok(ingredient)
 }
 
+/// Creates the ingredient as part of the unit of work
+pub(crate) async fn create_ingredient_in(
+    uow: &mut UnitOfWork,
+    repo: &IngredientRepositoryService,
+    input: &CreateIngredient<'_>,
+) -> Result<Ingredient, CreateIngredientError> {
+    let ingredient = Ingredient::try_from(input)?;
+    let ingredient = repo.insert_in(uow, ingredient).await?;
+
+    Ok(ingredient)
+}
+
 #[cfg(test)]
 mod tests;
@@ -5,7 +5,7 @@ use uuid::Uuid;
 use crate::domain::{
     commands::ingredients::create::{create_ingredient, CreateIngredient, CreateIngredientError},
     entities::ingredient::{
-        types::{DietFriendly, WhichDiets},
+        types::{Allergen, Allergens, DietFriendly, DietViolation, DietViolations},
         Ingredient,
     },
     repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
@@ -15,7 +15,10 @@ pub async fn creates_an_ingredient(repo: impl IngredientRepository) {
     let given = CreateIngredient {
         name: "Tomato",
         description: "Description of a tomato",
-        diet_friendly: vec!["Vegan".into()],
+        diet_friendly: Some(vec!["Vegan".into()]),
+        diet_violations: None,
+        allergens: None,
+        density: None,
     };
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
 
@@ -25,14 +28,17 @@ pub async fn creates_an_ingredient(repo: impl IngredientRepository) {
 
     assert_eq!(when.name.as_ref(), "Tomato");
     assert_eq!(when.description.as_ref(), "Description of a tomato");
-    assert!(when.diet_friendly.contains(&DietFriendly::Vegan));
+    assert!(when.diet_friendly().contains(&DietFriendly::Vegan));
 }
 
 pub async fn incorrect_diets_do_not_get_included(repo: impl IngredientRepository) {
     let given = CreateIngredient {
         name: "Tomato",
         description: "Description of a tomato",
-        diet_friendly: vec!["Vegan".into(), "INVALID DIET".into()],
+        diet_friendly: Some(vec!["Vegan".into(), "INVALID DIET".into()]),
+        diet_violations: None,
+        allergens: None,
+        density: None,
     };
 
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
@@ -41,15 +47,171 @@ pub async fn incorrect_diets_do_not_get_included(repo: impl IngredientRepository
 
     // THEN
 
-    assert!(when.diet_friendly.contains(&DietFriendly::Vegan));
-    assert_eq!(when.diet_friendly.len(), 1);
+    assert!(when.diet_friendly().contains(&DietFriendly::Vegan));
+    assert_eq!(
+        when.diet_violations,
+        vec![
+            DietViolation::Gluten,
+            DietViolation::Alcohol,
+            DietViolation::HighCarb
+        ]
+        .into()
+    );
+}
+
+pub async fn declared_violations_take_precedence_over_diets(repo: impl IngredientRepository) {
+    let given = CreateIngredient {
+        name: "Cheddar",
+        description: "A hard cheese",
+        diet_friendly: Some(vec!["vegan".into()]),
+        diet_violations: Some(vec!["dairy".into()]),
+        allergens: None,
+        density: None,
+    };
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_ingredient(repo.clone(), &given).await.unwrap();
+
+    // THEN
+
+    assert_eq!(when.diet_violations, vec![DietViolation::Dairy].into());
+    assert!(!when.diet_friendly().contains(&DietFriendly::Vegan));
+    assert!(when.diet_friendly().contains(&DietFriendly::Vegetarian));
+}
+
+pub async fn unknown_violations_fail(repo: impl IngredientRepository) {
+    let given = CreateIngredient {
+        name: "Cheddar",
+        description: "A hard cheese",
+        diet_friendly: None,
+        diet_violations: Some(vec!["dairy".into(), "INVALID VIOLATION".into()]),
+        allergens: None,
+        density: None,
+    };
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_ingredient(repo.clone(), &given).await.unwrap_err();
+
+    // THEN
+
+    assert!(matches!(
+        when,
+        CreateIngredientError::DoesNotMatch("diet_violations", _)
+    ));
+    assert!(repo.get_all(&Default::default()).await.unwrap().is_empty());
+}
+
+pub async fn ingredients_without_diet_information_fit_no_diet(repo: impl IngredientRepository) {
+    let given = CreateIngredient {
+        name: "Mystery sauce",
+        description: "Nobody knows what is in it",
+        diet_friendly: None,
+        diet_violations: None,
+        allergens: None,
+        density: None,
+    };
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_ingredient(repo.clone(), &given).await.unwrap();
+
+    // THEN
+
+    assert_eq!(when.diet_violations, DietViolations::all());
+    assert!(when.diet_friendly().0.is_empty());
+}
+
+pub async fn creates_an_ingredient_with_density(repo: impl IngredientRepository) {
+    let given = CreateIngredient {
+        name: "Flour",
+        description: "All-purpose wheat flour",
+        diet_friendly: Some(vec!["vegan".into()]),
+        diet_violations: None,
+        allergens: None,
+        density: Some(0.53),
+    };
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_ingredient(repo.clone(), &given).await.unwrap();
+
+    // THEN
+
+    assert_eq!(when.density.map(|d| *d), Some(0.53));
+    let persisted = repo.get_by_id(&when.id).await.unwrap();
+    assert_eq!(persisted.density, when.density);
+}
+
+pub async fn non_positive_density_fails(repo: impl IngredientRepository) {
+    let given = CreateIngredient {
+        name: "Flour",
+        description: "All-purpose wheat flour",
+        diet_friendly: None,
+        diet_violations: None,
+        allergens: None,
+        density: Some(0.0),
+    };
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_ingredient(repo.clone(), &given).await.unwrap_err();
+
+    // THEN
+
+    assert!(matches!(
+        when,
+        CreateIngredientError::NotPositive("density")
+    ));
+}
+
+pub async fn creates_an_ingredient_with_allergens(repo: impl IngredientRepository) {
+    let given = CreateIngredient {
+        name: "Pesto",
+        description: "Basil, pine nuts and parmesan",
+        diet_friendly: None,
+        diet_violations: Some(vec!["dairy".into()]),
+        allergens: Some(vec!["Milk".into(), "nuts".into(), "milk".into()]),
+        density: None,
+    };
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_ingredient(repo.clone(), &given).await.unwrap();
+
+    // THEN
+
+    assert_eq!(when.allergens, vec![Allergen::Milk, Allergen::Nuts].into());
+    let persisted = repo.get_by_id(&when.id).await.unwrap();
+    assert_eq!(persisted.allergens, when.allergens);
+}
+
+pub async fn unknown_allergens_fail(repo: impl IngredientRepository) {
+    let given = CreateIngredient {
+        name: "Pesto",
+        description: "Basil, pine nuts and parmesan",
+        diet_friendly: None,
+        diet_violations: None,
+        allergens: Some(vec!["milk".into(), "pine nuts".into()]),
+        density: None,
+    };
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let when = create_ingredient(repo.clone(), &given).await.unwrap_err();
+
+    // THEN
+
+    assert!(matches!(
+        when,
+        CreateIngredientError::DoesNotMatch("allergens", _)
+    ));
+    assert!(repo.get_all(&Default::default()).await.unwrap().is_empty());
 }
 
 pub async fn empty_name_fails(repo: impl IngredientRepository) {
     let given = CreateIngredient {
         name: "",
         description: "Description of a tomato",
-        diet_friendly: vec![],
+        diet_friendly: None,
+        diet_violations: None,
+        allergens: None,
+        density: None,
     };
 
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
@@ -65,7 +227,10 @@ pub async fn empty_description_fails(repo: impl IngredientRepository) {
     let given = CreateIngredient {
         name: "Tomato",
         description: "",
-        diet_friendly: vec![],
+        diet_friendly: None,
+        diet_violations: None,
+        allergens: None,
+        density: None,
     };
 
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
@@ -84,7 +249,10 @@ pub async fn incorrect_ingredient_is_not_persisted(repo: impl IngredientReposito
     let given = CreateIngredient {
         name: "",
         description: "Description of a tomato",
-        diet_friendly: vec![],
+        diet_friendly: None,
+        diet_violations: None,
+        allergens: None,
+        density: None,
     };
 
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
@@ -96,7 +264,7 @@ pub async fn incorrect_ingredient_is_not_persisted(repo: impl IngredientReposito
     assert!(matches!(when, CreateIngredientError::EmptyField(_)));
 
     assert!(!&repo
-        .get_all()
+        .get_all(&Default::default())
         .await
         .unwrap()
         .into_iter()
@@ -110,7 +278,11 @@ pub async fn inserting_an_ingredient_with_a_name_that_already_exists_fails(
         id: Uuid::from_u128(1),
         name: "Ingredient name".try_into().unwrap(),
         description: "Ingredient description".try_into().unwrap(),
-        diet_friendly: WhichDiets::new(),
+        diet_violations: DietViolations::new(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: None,
+        version: Default::default(),
     };
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
 
@@ -121,7 +293,10 @@ pub async fn inserting_an_ingredient_with_a_name_that_already_exists_fails(
         &CreateIngredient {
             name: given.name.as_str(),
             description: "This is a different description",
-            diet_friendly: vec![],
+            diet_friendly: None,
+            diet_violations: None,
+            allergens: None,
+            density: None,
         },
     )
     .await
@@ -13,6 +13,47 @@ mod in_memory {
         __tests__::incorrect_diets_do_not_get_included(InMemoryIngredientRepository::new()).await;
     }
 
+    #[tokio::test]
+    async fn declared_violations_take_precedence_over_diets() {
+        __tests__::declared_violations_take_precedence_over_diets(
+            InMemoryIngredientRepository::new(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn unknown_violations_fail() {
+        __tests__::unknown_violations_fail(InMemoryIngredientRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn ingredients_without_diet_information_fit_no_diet() {
+        __tests__::ingredients_without_diet_information_fit_no_diet(
+            InMemoryIngredientRepository::new(),
+        )
+        .await;
+    }
+
+    #[tokio::test]
+    async fn creates_an_ingredient_with_density() {
+        __tests__::creates_an_ingredient_with_density(InMemoryIngredientRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn non_positive_density_fails() {
+        __tests__::non_positive_density_fails(InMemoryIngredientRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn creates_an_ingredient_with_allergens() {
+        __tests__::creates_an_ingredient_with_allergens(InMemoryIngredientRepository::new()).await;
+    }
+
+    #[tokio::test]
+    async fn unknown_allergens_fail() {
+        __tests__::unknown_allergens_fail(InMemoryIngredientRepository::new()).await;
+    }
+
     #[tokio::test]
     async fn empty_name_fails() {
         __tests__::empty_name_fails(InMemoryIngredientRepository::new()).await;
@@ -52,6 +93,49 @@ mod sql {
             .await;
     }
 
+    #[sqlx::test]
+    async fn declared_violations_take_precedence_over_diets(pool: PgPool) {
+        __tests__::declared_violations_take_precedence_over_diets(
+            PostgresIngredientRepository::new(pool),
+        )
+        .await;
+    }
+
+    #[sqlx::test]
+    async fn unknown_violations_fail(pool: PgPool) {
+        __tests__::unknown_violations_fail(PostgresIngredientRepository(pool)).await;
+    }
+
+    #[sqlx::test]
+    async fn ingredients_without_diet_information_fit_no_diet(pool: PgPool) {
+        __tests__::ingredients_without_diet_information_fit_no_diet(PostgresIngredientRepository(
+            pool,
+        ))
+        .await;
+    }
+
+    #[sqlx::test]
+    async fn creates_an_ingredient_with_density(pool: PgPool) {
+        __tests__::creates_an_ingredient_with_density(PostgresIngredientRepository::new(pool))
+            .await;
+    }
+
+    #[sqlx::test]
+    async fn non_positive_density_fails(pool: PgPool) {
+        __tests__::non_positive_density_fails(PostgresIngredientRepository::new(pool)).await;
+    }
+
+    #[sqlx::test]
+    async fn creates_an_ingredient_with_allergens(pool: PgPool) {
+        __tests__::creates_an_ingredient_with_allergens(PostgresIngredientRepository::new(pool))
+            .await;
+    }
+
+    #[sqlx::test]
+    async fn unknown_allergens_fail(pool: PgPool) {
+        __tests__::unknown_allergens_fail(PostgresIngredientRepository::new(pool)).await;
+    }
+
     #[sqlx::test]
     async fn empty_name_fails(pool: PgPool) {
         __tests__::empty_name_fails(PostgresIngredientRepository::new(pool)).await;
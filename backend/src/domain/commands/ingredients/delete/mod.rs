@@ -1,29 +1,94 @@
+use common::BlockingRecipeDTO;
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::domain::commands::recipes::record_revision;
+use crate::domain::entities::{recipe::Recipe, user::User, version::ExpectedVersion};
 use crate::domain::repositories::{
     ingredients::{
         errors::{DeleteIngredientError as DeleteIngredientErrorInternal, GetIngredientByIdError},
         IngredientRepositoryService,
     },
-    recipe::RecipeRepositoryService,
+    recipe::{
+        errors::{GetRecipeByIdError, UpdateIngredientInRecipeError},
+        RecipeRepositoryService,
+    },
 };
 
+/// A recipe standing in the way of deleting an ingredient
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockingRecipe {
+    pub id: Uuid,
+    /// Only known for public recipes, so the names of private ones don't leak out
+    pub name: Option<String>,
+}
+
+impl From<&Recipe> for BlockingRecipe {
+    fn from(value: &Recipe) -> Self {
+        Self {
+            id: value.id,
+            name: value.public.then(|| value.name.clone()),
+        }
+    }
+}
+
+impl From<BlockingRecipe> for BlockingRecipeDTO {
+    fn from(value: BlockingRecipe) -> Self {
+        Self {
+            id: value.id,
+            name: value.name,
+        }
+    }
+}
+
 #[derive(Error, Debug, strum::AsRefStr)]
 pub enum DeleteIngredientError {
     #[error("The ingredient with ID of {0} was not found.")]
     NotFound(Uuid),
 
-    #[error("There are recipes that use this ingredient. Delete them first, then you will be able to delete this ingredient.")]
-    InUseByRecipe,
+    #[error("The ingredient with ID {0} was changed in the meantime")]
+    VersionMismatch(Uuid),
+
+    #[error("There are recipes that use this ingredient. Delete them or replace the ingredient in them first, then you will be able to delete this ingredient.")]
+    InUseByRecipe(Vec<BlockingRecipe>),
+
+    #[error("The replacement ingredient with ID of {0} was not found.")]
+    ReplacementNotFound(Uuid),
+
+    #[error("An ingredient can't be replaced with itself.")]
+    ReplacedWithItself,
+
+    #[error("There are recipes that already use the replacement ingredient, so it can't take the place of this one.")]
+    ReplacementAlreadyInRecipe(Vec<BlockingRecipe>),
+
+    #[error("You need to be logged in to replace an ingredient in recipes.")]
+    NotLoggedIn,
+
+    #[error("There are recipes using this ingredient that you are not the author of, so you can't replace it in them.")]
+    Forbidden(Vec<BlockingRecipe>),
 
     #[error(transparent)]
     UnknownError(#[from] eyre::Error),
 }
 
+impl DeleteIngredientError {
+    /// The recipes that kept the ingredient from being deleted, if that's what went wrong
+    pub fn blocking_recipes(&self) -> &[BlockingRecipe] {
+        match self {
+            Self::InUseByRecipe(recipes)
+            | Self::ReplacementAlreadyInRecipe(recipes)
+            | Self::Forbidden(recipes) => recipes,
+            _ => &[],
+        }
+    }
+}
+
 impl From<DeleteIngredientErrorInternal> for DeleteIngredientError {
     fn from(value: DeleteIngredientErrorInternal) -> Self {
-        Self::UnknownError(value.into())
+        match value {
+            DeleteIngredientErrorInternal::VersionMismatch(id) => Self::VersionMismatch(id),
+            e => Self::UnknownError(e.into()),
+        }
     }
 }
 
@@ -36,22 +101,113 @@ impl From<GetIngredientByIdError> for DeleteIngredientError {
     }
 }
 
-#[tracing::instrument("[COMMAND] Deleting a new ingredient", skip(repo, recipe_repo))]
+impl From<GetRecipeByIdError> for DeleteIngredientError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        Self::UnknownError(value.into())
+    }
+}
+
+impl From<UpdateIngredientInRecipeError> for DeleteIngredientError {
+    fn from(value: UpdateIngredientInRecipeError) -> Self {
+        match value {
+            // A recipe got the replacement after it was checked, so which one isn't known
+            UpdateIngredientInRecipeError::AlreadyInRecipe => {
+                Self::ReplacementAlreadyInRecipe(vec![])
+            }
+            e => Self::UnknownError(e.into()),
+        }
+    }
+}
+
+/// Deletes the ingredient, as long as no recipe uses it.
+/// With a replacement, it's swapped for the replacement in every recipe using it first,
+/// which takes the user to be the author of all of them.
+#[tracing::instrument("[COMMAND] Deleting a new ingredient", skip(repo, recipe_repo, user))]
 pub async fn delete_ingredient(
     repo: IngredientRepositoryService,
     recipe_repo: RecipeRepositoryService,
     input: &Uuid,
+    user: Option<&User>,
+    expected: &ExpectedVersion,
+    replace_with: Option<&Uuid>,
 ) -> Result<(), DeleteIngredientError> {
-    let ingredient = repo.get_by_id(input).await?;
-    let recipes_with_ingredient_exist = recipe_repo
-        .recipes_containing_ingredient_exist(ingredient.clone())
+    let mut uow = repo.begin().await?;
+
+    // Locked until the end, so no recipe starts using it before it's gone
+    let ingredient = repo.get_by_id_for_update(&mut uow, input).await?;
+    if !expected.matches(ingredient.version) {
+        return Err(DeleteIngredientError::VersionMismatch(ingredient.id));
+    }
+
+    let recipes_with_ingredient = recipe_repo
+        .recipes_containing_ingredient(&mut uow, &ingredient)
         .await?;
 
-    if recipes_with_ingredient_exist {
-        return Err(DeleteIngredientError::InUseByRecipe);
-    };
+    match replace_with {
+        None if !recipes_with_ingredient.is_empty() => {
+            return Err(DeleteIngredientError::InUseByRecipe(
+                recipes_with_ingredient.iter().map(Into::into).collect(),
+            ));
+        }
+        None => {}
+        Some(replacement_id) => {
+            let Some(user) = user else {
+                return Err(DeleteIngredientError::NotLoggedIn);
+            };
+            if *replacement_id == ingredient.id {
+                return Err(DeleteIngredientError::ReplacedWithItself);
+            }
+
+            let not_authored: Vec<BlockingRecipe> = recipes_with_ingredient
+                .iter()
+                .filter(|r| !r.is_authored_by(&user.id))
+                .map(Into::into)
+                .collect();
+            if !not_authored.is_empty() {
+                return Err(DeleteIngredientError::Forbidden(not_authored));
+            }
+
+            let replacement = repo
+                .get_by_id_for_update(&mut uow, replacement_id)
+                .await
+                .map_err(|e| match e {
+                    GetIngredientByIdError::NotFound(id) => {
+                        DeleteIngredientError::ReplacementNotFound(id)
+                    }
+                    e => DeleteIngredientError::UnknownError(e.into()),
+                })?;
+
+            let already_using_replacement: Vec<BlockingRecipe> = recipes_with_ingredient
+                .iter()
+                .filter(|r| {
+                    r.ingredients
+                        .iter()
+                        .any(|i| i.ingredient.id == replacement.id)
+                })
+                .map(Into::into)
+                .collect();
+            if !already_using_replacement.is_empty() {
+                return Err(DeleteIngredientError::ReplacementAlreadyInRecipe(
+                    already_using_replacement,
+                ));
+            }
+
+            recipe_repo
+                .replace_ingredient(&mut uow, &ingredient, &replacement)
+                .await?;
+
+            for before in &recipes_with_ingredient {
+                let after = recipe_repo
+                    .get_by_id_for_update(&mut uow, &before.id)
+                    .await?;
+                record_revision(&mut uow, &recipe_repo, Some(before), &after, Some(user.id))
+                    .await?;
+            }
+        }
+    }
 
-    repo.delete(ingredient).await?;
+    repo.delete_in(&mut uow, ingredient).await?;
+    uow.commit().await?;
 
     Ok(())
 }
@@ -4,14 +4,22 @@ use uuid::Uuid;
 
 use crate::{
     domain::{
-        commands::ingredients::delete::{delete_ingredient, DeleteIngredientError},
-        entities::ingredient::{types::WhichDiets, Ingredient},
+        commands::ingredients::delete::{delete_ingredient, BlockingRecipe, DeleteIngredientError},
+        entities::ingredient::{
+            types::{Allergens, DietViolations},
+            Ingredient, IngredientListQuery,
+        },
+        entities::{user::User, version::ExpectedVersion},
         repositories::{
             ingredients::{IngredientRepository, IngredientRepositoryService},
             recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
         },
     },
-    test_utils::{ingredient_fixture, insert_all_ingredients_of_recipe, recipe_fixture},
+    test_utils::{
+        authored_recipe_fixture, ingredient_fixture, insert_all_ingredients_of_recipe,
+        recipe_fixture, user_fixture,
+    },
 };
 
 pub async fn deleting_works(repo: impl IngredientRepository, recipe_repo: impl RecipeRepository) {
@@ -21,13 +29,37 @@ pub async fn deleting_works(repo: impl IngredientRepository, recipe_repo: impl R
         id: Uuid::from_u128(1),
         name: "Ingredient name 1".try_into().unwrap(),
         description: "Ingredient description 1".try_into().unwrap(),
-        diet_friendly: WhichDiets::new(),
+        diet_violations: DietViolations::new(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: None,
+        version: Default::default(),
     };
 
     let insert_result = repo.insert(input).await.unwrap();
-    delete_ingredient(repo, recipe_repo, &insert_result.id)
+    delete_ingredient(
+        repo.clone(),
+        recipe_repo,
+        &insert_result.id,
+        None,
+        &ExpectedVersion::Any,
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(repo
+        .get_all(&IngredientListQuery::default())
         .await
-        .unwrap();
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        repo.get_deleted_by_id(&insert_result.id)
+            .await
+            .unwrap()
+            .item,
+        insert_result
+    );
 }
 
 pub async fn deleting_nonexistent_ingredient_errors(
@@ -37,9 +69,16 @@ pub async fn deleting_nonexistent_ingredient_errors(
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
     let ingredient = ingredient_fixture();
-    let error = delete_ingredient(repo, recipe_repo, &ingredient.id)
-        .await
-        .unwrap_err();
+    let error = delete_ingredient(
+        repo,
+        recipe_repo,
+        &ingredient.id,
+        None,
+        &ExpectedVersion::Any,
+        None,
+    )
+    .await
+    .unwrap_err();
 
     assert!(matches!(
         error,
@@ -59,9 +98,215 @@ pub async fn deleting_an_ingredient_still_in_use_by_recipes_errors(
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
 
-    let error = delete_ingredient(repo, recipe_repo, input)
+    let error = delete_ingredient(repo, recipe_repo, input, None, &ExpectedVersion::Any, None)
         .await
         .unwrap_err();
 
-    assert!(matches!(error, DeleteIngredientError::InUseByRecipe));
+    assert!(matches!(
+        error,
+        DeleteIngredientError::InUseByRecipe(recipes) if recipes == vec![BlockingRecipe {
+            id: recipe.id,
+            name: Some(recipe.name.clone()),
+        }]
+    ));
+}
+
+pub async fn replacing_an_ingredient_swaps_it_in_every_recipe(
+    repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user_fixture()).await.unwrap();
+    let replacement = repo.insert(ingredient_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let replaced = recipe.ingredients.first().unwrap();
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    delete_ingredient(
+        repo.clone(),
+        recipe_repo.clone(),
+        &replaced.ingredient.id,
+        Some(&user_fixture()),
+        &ExpectedVersion::Any,
+        Some(&replacement.id),
+    )
+    .await
+    .unwrap();
+
+    let stored = recipe_repo.get_by_id(&recipe.id).await.unwrap();
+    let swapped = stored
+        .ingredients
+        .iter()
+        .find(|i| i.ingredient.id == replacement.id)
+        .unwrap();
+    assert_eq!(swapped.amount, replaced.amount);
+    assert!(!stored
+        .ingredients
+        .iter()
+        .any(|i| i.ingredient.id == replaced.ingredient.id));
+    assert_eq!(stored.version, recipe.version.next());
+    assert_eq!(
+        recipe_repo.list_revisions(&recipe.id).await.unwrap().len(),
+        1
+    );
+    assert!(repo
+        .get_deleted_by_id(&replaced.ingredient.id)
+        .await
+        .is_ok());
+}
+
+pub async fn replacing_an_ingredient_records_who_changed_the_recipes(
+    repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user_fixture()).await.unwrap();
+    let replacement = repo.insert(ingredient_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let replaced = recipe.ingredients.first().unwrap();
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    delete_ingredient(
+        repo,
+        recipe_repo.clone(),
+        &replaced.ingredient.id,
+        Some(&user_fixture()),
+        &ExpectedVersion::Any,
+        Some(&replacement.id),
+    )
+    .await
+    .unwrap();
+
+    let revisions = recipe_repo.list_revisions(&recipe.id).await.unwrap();
+    assert_eq!(revisions.len(), 1);
+    assert_eq!(revisions[0].author_id, Some(user_fixture().id));
+}
+
+pub async fn replacing_with_an_ingredient_already_in_the_recipe_errors(
+    repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let input = &recipe.ingredients[0].ingredient.id;
+    let replacement = &recipe.ingredients[1].ingredient.id;
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = delete_ingredient(
+        repo.clone(),
+        recipe_repo.clone(),
+        input,
+        Some(&user_fixture()),
+        &ExpectedVersion::Any,
+        Some(replacement),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        DeleteIngredientError::ReplacementAlreadyInRecipe(recipes) if recipes.len() == 1
+    ));
+    assert_eq!(
+        recipe_repo.get_by_id(&recipe.id).await.unwrap().version,
+        recipe.version
+    );
+    assert!(repo.get_by_id(input).await.is_ok());
+}
+
+pub async fn replacing_with_a_missing_ingredient_errors(
+    repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let input = &recipe.ingredients[0].ingredient.id;
+    let replacement = ingredient_fixture().id;
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = delete_ingredient(
+        repo,
+        recipe_repo,
+        input,
+        Some(&user_fixture()),
+        &ExpectedVersion::Any,
+        Some(&replacement),
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        error,
+        DeleteIngredientError::ReplacementNotFound(id) if id == replacement
+    ));
+}
+
+pub async fn replacing_in_recipes_of_someone_else_is_refused(
+    repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user_fixture()).await.unwrap();
+    let replacement = repo.insert(ingredient_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let input = &recipe.ingredients[0].ingredient.id;
+    let someone_else = User {
+        id: Uuid::from_u128(129),
+        ..user_fixture()
+    };
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = delete_ingredient(
+        repo.clone(),
+        recipe_repo.clone(),
+        input,
+        None,
+        &ExpectedVersion::Any,
+        Some(&replacement.id),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(error, DeleteIngredientError::NotLoggedIn));
+
+    let error = delete_ingredient(
+        repo.clone(),
+        recipe_repo.clone(),
+        input,
+        Some(&someone_else),
+        &ExpectedVersion::Any,
+        Some(&replacement.id),
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        DeleteIngredientError::Forbidden(recipes) if recipes == vec![BlockingRecipe::from(&recipe)]
+    ));
+    assert_eq!(
+        recipe_repo.get_by_id(&recipe.id).await.unwrap().version,
+        recipe.version
+    );
+    assert!(repo.get_by_id(input).await.is_ok());
 }
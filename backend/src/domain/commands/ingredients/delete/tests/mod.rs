@@ -3,7 +3,7 @@ mod in_memory {
     use super::__tests__;
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
     };
 
     #[tokio::test]
@@ -26,13 +26,65 @@ mod in_memory {
         let recipe_repo = InMemoryRecipeRepository::new();
         __tests__::deleting_an_ingredient_still_in_use_by_recipes_errors(repo, recipe_repo).await
     }
+
+    #[tokio::test]
+    async fn replacing_an_ingredient_swaps_it_in_every_recipe() {
+        let repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::replacing_an_ingredient_swaps_it_in_every_recipe(repo, recipe_repo, user_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn replacing_an_ingredient_records_who_changed_the_recipes() {
+        let repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::replacing_an_ingredient_records_who_changed_the_recipes(
+            repo,
+            recipe_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn replacing_with_an_ingredient_already_in_the_recipe_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::replacing_with_an_ingredient_already_in_the_recipe_errors(
+            repo,
+            recipe_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn replacing_with_a_missing_ingredient_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::replacing_with_a_missing_ingredient_errors(repo, recipe_repo, user_repo).await
+    }
+
+    #[tokio::test]
+    async fn replacing_in_recipes_of_someone_else_is_refused() {
+        let repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::replacing_in_recipes_of_someone_else_is_refused(repo, recipe_repo, user_repo)
+            .await
+    }
 }
 
 mod sql {
     use super::__tests__;
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
     };
 
     use sqlx::PgPool;
@@ -57,4 +109,56 @@ mod sql {
         let recipe_repo = PostgresRecipeRepository::new(pool);
         __tests__::deleting_an_ingredient_still_in_use_by_recipes_errors(repo, recipe_repo).await
     }
+
+    #[sqlx::test]
+    async fn replacing_an_ingredient_swaps_it_in_every_recipe(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool);
+        __tests__::replacing_an_ingredient_swaps_it_in_every_recipe(repo, recipe_repo, user_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn replacing_an_ingredient_records_who_changed_the_recipes(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool);
+        __tests__::replacing_an_ingredient_records_who_changed_the_recipes(
+            repo,
+            recipe_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn replacing_with_an_ingredient_already_in_the_recipe_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool);
+        __tests__::replacing_with_an_ingredient_already_in_the_recipe_errors(
+            repo,
+            recipe_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn replacing_with_a_missing_ingredient_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool);
+        __tests__::replacing_with_a_missing_ingredient_errors(repo, recipe_repo, user_repo).await
+    }
+
+    #[sqlx::test]
+    async fn replacing_in_recipes_of_someone_else_is_refused(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool);
+        __tests__::replacing_in_recipes_of_someone_else_is_refused(repo, recipe_repo, user_repo)
+            .await
+    }
 }
@@ -1,3 +1,6 @@
 pub mod create;
-pub mod update;
 pub mod delete;
+pub mod substitutes;
+pub mod trash;
+pub mod update;
+pub mod variants;
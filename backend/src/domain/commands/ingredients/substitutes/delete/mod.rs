@@ -0,0 +1,71 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::IngredientSubstitute,
+    repositories::ingredients::{
+        errors::{DeleteIngredientError, GetAllIngredientsError, GetIngredientByIdError},
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DeleteSubstituteError {
+    #[error("Could not find the ingredient with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("The ingredient with ID {0} is not a substitute for the ingredient with ID {1}")]
+    NotASubstitute(Uuid, Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for DeleteSubstituteError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetAllIngredientsError> for DeleteSubstituteError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<DeleteIngredientError> for DeleteSubstituteError {
+    fn from(value: DeleteIngredientError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument("[COMMAND] Deleting a substitute of an ingredient", skip(repo))]
+pub async fn delete_substitute(
+    repo: IngredientRepositoryService,
+    ingredient_id: &Uuid,
+    substitute_id: &Uuid,
+) -> Result<Vec<IngredientSubstitute>, DeleteSubstituteError> {
+    let ingredient = repo.get_by_id(ingredient_id).await?;
+    let substitute = repo.get_by_id(substitute_id).await?;
+
+    let mut substitutes = repo.get_substitutes(&ingredient).await?;
+    let Some(index) = substitutes
+        .iter()
+        .position(|s| s.ingredient.id == substitute.id)
+    else {
+        return Err(DeleteSubstituteError::NotASubstitute(
+            substitute.id,
+            ingredient.id,
+        ));
+    };
+
+    repo.delete_substitute(&ingredient, &substitute).await?;
+    substitutes.remove(index);
+
+    Ok(substitutes)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,80 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::ingredients::substitutes::delete::{delete_substitute, DeleteSubstituteError},
+        entities::ingredient::{types::SubstituteRatio, IngredientSubstitute},
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::named_ingredient,
+};
+
+async fn seed(repo: impl IngredientRepository) -> IngredientRepositoryService {
+    let egg = repo.insert(named_ingredient(1, "Egg")).await.unwrap();
+    let applesauce = repo
+        .insert(named_ingredient(2, "Applesauce"))
+        .await
+        .unwrap();
+    repo.insert(named_ingredient(3, "Banana")).await.unwrap();
+    repo.set_substitute(
+        &egg,
+        &IngredientSubstitute {
+            ingredient: applesauce,
+            ratio: SubstituteRatio(60.0),
+            unit: None,
+            notes: None,
+        },
+    )
+    .await
+    .unwrap();
+    Arc::new(Box::new(repo))
+}
+
+pub async fn deleting_substitute_works(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = delete_substitute(repo.clone(), &Uuid::from_u128(1), &Uuid::from_u128(2))
+        .await
+        .unwrap();
+
+    assert!(result.is_empty());
+
+    let egg = repo.get_by_id(&Uuid::from_u128(1)).await.unwrap();
+    assert!(repo.get_substitutes(&egg).await.unwrap().is_empty());
+}
+
+pub async fn deleting_ingredient_that_is_not_a_substitute_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = delete_substitute(repo, &Uuid::from_u128(1), &Uuid::from_u128(3))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        result,
+        DeleteSubstituteError::NotASubstitute(substitute, ingredient)
+            if substitute == Uuid::from_u128(3) && ingredient == Uuid::from_u128(1)
+    ));
+}
+
+pub async fn deleting_substitute_of_missing_ingredient_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = delete_substitute(repo, &Uuid::from_u128(100), &Uuid::from_u128(2))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, DeleteSubstituteError::NotFound(id) if id == Uuid::from_u128(100)));
+}
+
+pub async fn deleting_ingredient_deletes_its_substitutes(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let applesauce = repo.get_by_id(&Uuid::from_u128(2)).await.unwrap();
+    repo.delete(applesauce).await.unwrap();
+
+    let egg = repo.get_by_id(&Uuid::from_u128(1)).await.unwrap();
+    assert!(repo.get_substitutes(&egg).await.unwrap().is_empty());
+}
@@ -0,0 +1,61 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn deleting_substitute_works() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::deleting_substitute_works(repo).await;
+    }
+
+    #[tokio::test]
+    async fn deleting_ingredient_that_is_not_a_substitute_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::deleting_ingredient_that_is_not_a_substitute_errors(repo).await;
+    }
+
+    #[tokio::test]
+    async fn deleting_substitute_of_missing_ingredient_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::deleting_substitute_of_missing_ingredient_errors(repo).await;
+    }
+
+    #[tokio::test]
+    async fn deleting_ingredient_deletes_its_substitutes() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::deleting_ingredient_deletes_its_substitutes(repo).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn deleting_substitute_works(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::deleting_substitute_works(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn deleting_ingredient_that_is_not_a_substitute_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::deleting_ingredient_that_is_not_a_substitute_errors(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn deleting_substitute_of_missing_ingredient_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::deleting_substitute_of_missing_ingredient_errors(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn deleting_ingredient_deletes_its_substitutes(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::deleting_ingredient_deletes_its_substitutes(repo).await;
+    }
+}
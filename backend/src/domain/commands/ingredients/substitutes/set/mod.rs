@@ -0,0 +1,98 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::{errors::ValidationError, IngredientSubstitute},
+    repositories::ingredients::{
+        errors::{GetAllIngredientsError, GetIngredientByIdError, UpdateIngredientError},
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum SetSubstituteError {
+    #[error("Could not find the ingredient with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("An ingredient can't be a substitute for itself")]
+    SelfReference,
+
+    #[error("The field {0} has to be a positive number")]
+    NotPositive(&'static str),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for SetSubstituteError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetAllIngredientsError> for SetSubstituteError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<UpdateIngredientError> for SetSubstituteError {
+    fn from(value: UpdateIngredientError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<ValidationError> for SetSubstituteError {
+    fn from(value: ValidationError) -> Self {
+        match value {
+            ValidationError::NotPositive(field) => Self::NotPositive(field),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct SetSubstitute {
+    pub ratio: f64,
+    pub unit: Option<String>,
+    pub notes: Option<String>,
+}
+
+/// Records that the substitute can be used in place of the ingredient, i.e. 60 g of applesauce
+/// for every egg when baking. Setting an existing substitute again replaces its ratio, unit and
+/// notes.
+#[tracing::instrument("[COMMAND] Setting a substitute of an ingredient", skip(repo))]
+pub async fn set_substitute(
+    repo: IngredientRepositoryService,
+    ingredient_id: &Uuid,
+    substitute_id: &Uuid,
+    input: SetSubstitute,
+) -> Result<Vec<IngredientSubstitute>, SetSubstituteError> {
+    if ingredient_id == substitute_id {
+        return Err(SetSubstituteError::SelfReference);
+    }
+
+    let ratio = input.ratio.try_into()?;
+    let ingredient = repo.get_by_id(ingredient_id).await?;
+    let substitute = IngredientSubstitute {
+        ingredient: repo.get_by_id(substitute_id).await?,
+        ratio,
+        unit: input
+            .unit
+            .map(|unit| unit.trim().to_string())
+            .filter(|unit| !unit.is_empty()),
+        notes: input
+            .notes
+            .map(|notes| notes.trim().to_string())
+            .filter(|notes| !notes.is_empty()),
+    };
+
+    repo.set_substitute(&ingredient, &substitute).await?;
+
+    Ok(repo.get_substitutes(&ingredient).await?)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,135 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::ingredients::substitutes::set::{
+            set_substitute, SetSubstitute, SetSubstituteError,
+        },
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{ingredient_repo_with, named_ingredient},
+};
+
+async fn seed(repo: impl IngredientRepository) -> IngredientRepositoryService {
+    ingredient_repo_with(
+        repo,
+        [
+            named_ingredient(1, "Egg"),
+            named_ingredient(2, "Applesauce"),
+        ],
+    )
+    .await
+}
+
+pub async fn setting_substitute_works(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = set_substitute(
+        repo,
+        &Uuid::from_u128(1),
+        &Uuid::from_u128(2),
+        SetSubstitute {
+            ratio: 60.0,
+            unit: Some(" g ".to_string()),
+            notes: Some(" For baking ".to_string()),
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(result[0].ingredient.id, Uuid::from_u128(2));
+    assert_eq!(*result[0].ratio, 60.0);
+    assert_eq!(result[0].unit.as_deref(), Some("g"));
+    assert_eq!(result[0].notes.as_deref(), Some("For baking"));
+}
+
+pub async fn setting_substitute_again_replaces_it(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    set_substitute(
+        repo.clone(),
+        &Uuid::from_u128(1),
+        &Uuid::from_u128(2),
+        SetSubstitute {
+            ratio: 60.0,
+            unit: None,
+            notes: Some("For baking".to_string()),
+        },
+    )
+    .await
+    .unwrap();
+    let result = set_substitute(
+        repo,
+        &Uuid::from_u128(1),
+        &Uuid::from_u128(2),
+        SetSubstitute {
+            ratio: 50.0,
+            unit: None,
+            notes: None,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(result.len(), 1);
+    assert_eq!(*result[0].ratio, 50.0);
+    assert_eq!(result[0].notes, None);
+}
+
+pub async fn setting_substitute_for_itself_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = set_substitute(
+        repo,
+        &Uuid::from_u128(1),
+        &Uuid::from_u128(1),
+        SetSubstitute {
+            ratio: 1.0,
+            unit: None,
+            notes: None,
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, SetSubstituteError::SelfReference));
+}
+
+pub async fn setting_substitute_with_invalid_ratio_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = set_substitute(
+        repo,
+        &Uuid::from_u128(1),
+        &Uuid::from_u128(2),
+        SetSubstitute {
+            ratio: 0.0,
+            unit: None,
+            notes: None,
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, SetSubstituteError::NotPositive("ratio")));
+}
+
+pub async fn setting_missing_substitute_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = set_substitute(
+        repo,
+        &Uuid::from_u128(1),
+        &Uuid::from_u128(100),
+        SetSubstitute {
+            ratio: 1.0,
+            unit: None,
+            notes: None,
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, SetSubstituteError::NotFound(id) if id == Uuid::from_u128(100)));
+}
@@ -0,0 +1,73 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn setting_substitute_works() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::setting_substitute_works(repo).await;
+    }
+
+    #[tokio::test]
+    async fn setting_substitute_again_replaces_it() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::setting_substitute_again_replaces_it(repo).await;
+    }
+
+    #[tokio::test]
+    async fn setting_substitute_for_itself_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::setting_substitute_for_itself_errors(repo).await;
+    }
+
+    #[tokio::test]
+    async fn setting_substitute_with_invalid_ratio_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::setting_substitute_with_invalid_ratio_errors(repo).await;
+    }
+
+    #[tokio::test]
+    async fn setting_missing_substitute_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::setting_missing_substitute_errors(repo).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn setting_substitute_works(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::setting_substitute_works(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn setting_substitute_again_replaces_it(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::setting_substitute_again_replaces_it(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn setting_substitute_for_itself_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::setting_substitute_for_itself_errors(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn setting_substitute_with_invalid_ratio_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::setting_substitute_with_invalid_ratio_errors(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn setting_missing_substitute_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::setting_missing_substitute_errors(repo).await;
+    }
+}
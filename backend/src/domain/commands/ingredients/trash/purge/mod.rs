@@ -0,0 +1,60 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::repositories::{
+    ingredients::{
+        errors::{DeleteIngredientError, GetIngredientByIdError},
+        IngredientRepositoryService,
+    },
+    recipe::RecipeRepositoryService,
+};
+
+#[derive(Error, Debug, strum::AsRefStr)]
+pub enum PurgeDeletedIngredientError {
+    #[error("There is no ingredient with ID {0} in the trash")]
+    NotFound(Uuid),
+
+    #[error("There are recipes in the trash that use this ingredient. Purge them first, then you will be able to purge this ingredient.")]
+    InUseByRecipe,
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for PurgeDeletedIngredientError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::UnknownError(e.into()),
+        }
+    }
+}
+
+impl From<DeleteIngredientError> for PurgeDeletedIngredientError {
+    fn from(value: DeleteIngredientError) -> Self {
+        Self::UnknownError(value.into())
+    }
+}
+
+#[tracing::instrument("[COMMAND] Purging a deleted ingredient", skip(repo, recipe_repo))]
+pub async fn purge_deleted_ingredient(
+    repo: IngredientRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    input: &Uuid,
+) -> Result<(), PurgeDeletedIngredientError> {
+    let ingredient = repo.get_deleted_by_id(input).await?.item;
+
+    if recipe_repo
+        .trashed_recipes_containing_ingredient_exist(&ingredient)
+        .await?
+    {
+        return Err(PurgeDeletedIngredientError::InUseByRecipe);
+    }
+
+    repo.purge(&ingredient).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
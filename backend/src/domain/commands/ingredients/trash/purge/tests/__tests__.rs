@@ -0,0 +1,58 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::ingredients::trash::purge::{
+            purge_deleted_ingredient, PurgeDeletedIngredientError,
+        },
+        repositories::{
+            ingredients::{
+                errors::GetIngredientByIdError, IngredientRepository, IngredientRepositoryService,
+            },
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{ingredient_fixture, insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+pub async fn purging_a_deleted_ingredient_removes_it_for_good(
+    repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+) {
+    let ingredient = ingredient_fixture();
+    repo.insert(ingredient.clone()).await.unwrap();
+    repo.delete(ingredient.clone()).await.unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    purge_deleted_ingredient(repo.clone(), recipe_repo, &ingredient.id)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        repo.get_deleted_by_id(&ingredient.id).await.unwrap_err(),
+        GetIngredientByIdError::NotFound(_)
+    ));
+}
+
+pub async fn purging_an_ingredient_used_by_a_deleted_recipe_errors(
+    repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    recipe_repo.delete(&recipe).await.unwrap();
+    let ingredient = recipe.ingredients.first().unwrap().ingredient.clone();
+    repo.delete(ingredient.clone()).await.unwrap();
+
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = purge_deleted_ingredient(repo.clone(), recipe_repo, &ingredient.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, PurgeDeletedIngredientError::InUseByRecipe));
+    assert!(repo.get_deleted_by_id(&ingredient.id).await.is_ok());
+}
@@ -0,0 +1,47 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn purging_a_deleted_ingredient_removes_it_for_good() {
+        let repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        __tests__::purging_a_deleted_ingredient_removes_it_for_good(repo, recipe_repo).await
+    }
+
+    #[tokio::test]
+    async fn purging_an_ingredient_used_by_a_deleted_recipe_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+        __tests__::purging_an_ingredient_used_by_a_deleted_recipe_errors(repo, recipe_repo).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    use sqlx::PgPool;
+
+    #[sqlx::test]
+    async fn purging_a_deleted_ingredient_removes_it_for_good(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool);
+        __tests__::purging_a_deleted_ingredient_removes_it_for_good(repo, recipe_repo).await
+    }
+
+    #[sqlx::test]
+    async fn purging_an_ingredient_used_by_a_deleted_recipe_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool);
+        __tests__::purging_an_ingredient_used_by_a_deleted_recipe_errors(repo, recipe_repo).await
+    }
+}
@@ -0,0 +1,55 @@
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::Ingredient,
+    repositories::ingredients::{
+        errors::{GetIngredientByIdError, UpdateIngredientError},
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(Error, Debug, strum::AsRefStr)]
+pub enum RestoreDeletedIngredientError {
+    #[error("There is no ingredient with ID {0} in the trash")]
+    NotFound(Uuid),
+
+    #[error("Another ingredient with the same {0} was created in the meantime")]
+    Conflict(String),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for RestoreDeletedIngredientError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::UnknownError(e.into()),
+        }
+    }
+}
+
+impl From<UpdateIngredientError> for RestoreDeletedIngredientError {
+    fn from(value: UpdateIngredientError) -> Self {
+        match value {
+            UpdateIngredientError::Conflict(field) => Self::Conflict(field),
+            e => Self::UnknownError(e.into()),
+        }
+    }
+}
+
+#[tracing::instrument("[COMMAND] Restoring a deleted ingredient", skip(repo))]
+pub async fn restore_deleted_ingredient(
+    repo: IngredientRepositoryService,
+    input: &Uuid,
+) -> Result<Ingredient, RestoreDeletedIngredientError> {
+    let ingredient = repo.get_deleted_by_id(input).await?.item;
+
+    repo.restore(&ingredient).await?;
+
+    Ok(repo.get_by_id(input).await?)
+}
+
+#[cfg(test)]
+mod tests;
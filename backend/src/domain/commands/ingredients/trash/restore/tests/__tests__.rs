@@ -0,0 +1,69 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::ingredients::trash::restore::{
+            restore_deleted_ingredient, RestoreDeletedIngredientError,
+        },
+        entities::ingredient::Ingredient,
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::ingredient_fixture,
+};
+
+pub async fn restoring_a_deleted_ingredient_brings_it_back(repo: impl IngredientRepository) {
+    let ingredient = ingredient_fixture();
+    repo.insert(ingredient.clone()).await.unwrap();
+    repo.delete(ingredient.clone()).await.unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let restored = restore_deleted_ingredient(repo.clone(), &ingredient.id)
+        .await
+        .unwrap();
+
+    assert_eq!(restored, ingredient);
+    assert!(repo.list_deleted().await.unwrap().is_empty());
+}
+
+pub async fn restoring_an_ingredient_whose_name_was_taken_conflicts(
+    repo: impl IngredientRepository,
+) {
+    let ingredient = ingredient_fixture();
+    repo.insert(ingredient.clone()).await.unwrap();
+    repo.delete(ingredient.clone()).await.unwrap();
+    repo.insert(Ingredient {
+        id: Uuid::from_u128(65),
+        ..ingredient.clone()
+    })
+    .await
+    .unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let error = restore_deleted_ingredient(repo.clone(), &ingredient.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        RestoreDeletedIngredientError::Conflict(field) if field == "name"
+    ));
+    assert!(repo.get_deleted_by_id(&ingredient.id).await.is_ok());
+}
+
+pub async fn restoring_an_ingredient_that_is_not_deleted_fails(repo: impl IngredientRepository) {
+    let ingredient = ingredient_fixture();
+    repo.insert(ingredient.clone()).await.unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let error = restore_deleted_ingredient(repo, &ingredient.id)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        RestoreDeletedIngredientError::NotFound(id) if id == ingredient.id
+    ));
+}
@@ -0,0 +1,49 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn restoring_a_deleted_ingredient_brings_it_back() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::restoring_a_deleted_ingredient_brings_it_back(repo).await;
+    }
+
+    #[tokio::test]
+    async fn restoring_an_ingredient_whose_name_was_taken_conflicts() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::restoring_an_ingredient_whose_name_was_taken_conflicts(repo).await;
+    }
+
+    #[tokio::test]
+    async fn restoring_an_ingredient_that_is_not_deleted_fails() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::restoring_an_ingredient_that_is_not_deleted_fails(repo).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn restoring_a_deleted_ingredient_brings_it_back(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::restoring_a_deleted_ingredient_brings_it_back(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn restoring_an_ingredient_whose_name_was_taken_conflicts(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::restoring_an_ingredient_whose_name_was_taken_conflicts(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn restoring_an_ingredient_that_is_not_deleted_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::restoring_an_ingredient_that_is_not_deleted_fails(repo).await;
+    }
+}
@@ -2,7 +2,12 @@ use common::UpdateIngredientDTO;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::ingredient::{errors::ValidationError, Ingredient, IngredientChangeset},
+    entities::ingredient::{
+        errors::ValidationError,
+        types::{Allergens, DietViolations},
+        Ingredient, IngredientChangeset,
+    },
+    entities::version::ExpectedVersion,
     repositories::ingredients::{
         errors::{GetIngredientByIdError, UpdateIngredientError as UpdateIngredientErrorInternal},
         IngredientRepositoryService,
@@ -14,6 +19,9 @@ pub struct UpdateIngredient {
     pub name: Option<String>,
     pub description: Option<String>,
     pub diet_friendly: Option<Vec<String>>,
+    pub diet_violations: Option<Vec<String>>,
+    pub allergens: Option<Vec<String>>,
+    pub density: Option<f64>,
 }
 
 impl From<UpdateIngredientDTO> for UpdateIngredient {
@@ -22,6 +30,9 @@ impl From<UpdateIngredientDTO> for UpdateIngredient {
             name: value.name,
             description: value.description,
             diet_friendly: value.diet_friendly,
+            diet_violations: value.diet_violations,
+            allergens: value.allergens,
+            density: value.density,
         }
     }
 }
@@ -39,12 +50,23 @@ impl TryFrom<&UpdateIngredient> for IngredientChangeset {
             None => None,
         };
 
-        let diet_friendly = value.diet_friendly.as_ref().map(|x| x.clone().into());
+        let diet_violations =
+            DietViolations::from_input(value.diet_violations.clone(), value.diet_friendly.clone())?;
+
+        let allergens = value
+            .allergens
+            .clone()
+            .map(Allergens::try_from)
+            .transpose()?;
+
+        let density = value.density.map(TryInto::try_into).transpose()?;
 
         Ok(Self {
             name,
             description,
-            diet_friendly,
+            diet_violations,
+            allergens,
+            density,
         })
     }
 }
@@ -54,6 +76,9 @@ pub enum UpdateIngredientError {
     #[error("Could not find the ingredient with ID {0}")]
     NotFound(Uuid),
 
+    #[error("The ingredient with ID {0} was changed in the meantime")]
+    VersionMismatch(Uuid),
+
     #[error(transparent)]
     ValidationError(ValidationError),
 
@@ -71,7 +96,8 @@ impl From<UpdateIngredientErrorInternal> for UpdateIngredientError {
     fn from(value: UpdateIngredientErrorInternal) -> Self {
         match value {
             UpdateIngredientErrorInternal::ValidationError(v) => Self::ValidationError(v),
-            e => e.into(),
+            UpdateIngredientErrorInternal::VersionMismatch(id) => Self::VersionMismatch(id),
+            e => Self::Internal(e.into()),
         }
     }
 }
@@ -80,7 +106,7 @@ impl From<GetIngredientByIdError> for UpdateIngredientError {
     fn from(value: GetIngredientByIdError) -> Self {
         match value {
             GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
-            e => e.into(),
+            e => Self::Internal(e.into()),
         }
     }
 }
@@ -90,8 +116,12 @@ pub async fn update_ingredient(
     repo: IngredientRepositoryService,
     id: Uuid,
     input: &UpdateIngredient,
+    expected: &ExpectedVersion,
 ) -> Result<Ingredient, UpdateIngredientError> {
     let ingredient_to_change = repo.get_by_id(&id).await?;
+    if !expected.matches(ingredient_to_change.version) {
+        return Err(UpdateIngredientError::VersionMismatch(id));
+    }
 
     tracing::info!("Serializing input into a changeset");
     let ingredient: IngredientChangeset = input.try_into()?;
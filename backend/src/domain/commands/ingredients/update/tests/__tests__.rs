@@ -9,10 +9,14 @@ use crate::{
         },
         entities::ingredient::{
             errors::ValidationError,
-            types::{IngredientName, WhichDiets},
+            types::{Allergens, DietViolations, IngredientDensity, IngredientName},
             Ingredient,
         },
-        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+        entities::version::ExpectedVersion,
+        repositories::ingredients::{
+            errors::UpdateIngredientError as UpdateIngredientErrorInternal, IngredientRepository,
+            IngredientRepositoryService,
+        },
     },
     test_utils::ingredient_fixture,
 };
@@ -28,7 +32,7 @@ pub async fn updating_an_ingredient_success(repo: impl IngredientRepository) {
 
     repo.insert(input.clone()).await.unwrap();
 
-    update_ingredient(repo.clone(), input.id, &changeset)
+    update_ingredient(repo.clone(), input.id, &changeset, &ExpectedVersion::Any)
         .await
         .unwrap();
 
@@ -38,6 +42,34 @@ pub async fn updating_an_ingredient_success(repo: impl IngredientRepository) {
         result,
         Ingredient {
             name: IngredientName("Ingredient name changed".to_string()),
+            version: input.version.next(),
+            ..input
+        }
+    )
+}
+
+pub async fn updating_an_ingredients_density_success(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let input = ingredient_fixture();
+    let changeset = UpdateIngredient {
+        density: Some(1.05),
+        ..Default::default()
+    };
+
+    repo.insert(input.clone()).await.unwrap();
+
+    update_ingredient(repo.clone(), input.id, &changeset, &ExpectedVersion::Any)
+        .await
+        .unwrap();
+
+    let result = repo.get_by_id(&input.id).await.unwrap();
+
+    assert_eq!(
+        result,
+        Ingredient {
+            density: Some(IngredientDensity(1.05)),
+            version: input.version.next(),
             ..input
         }
     )
@@ -50,18 +82,22 @@ pub async fn updating_with_empty_changeset_fails(repo: impl IngredientRepository
         id: Uuid::from_u128(1),
         name: "Ingredient name 1".try_into().unwrap(),
         description: "Ingredient description 1".try_into().unwrap(),
-        diet_friendly: WhichDiets::new(),
+        diet_violations: DietViolations::new(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: None,
+        version: Default::default(),
     };
     let changeset = UpdateIngredient::default();
 
     repo.insert(input.clone()).await.unwrap();
 
-    let error = update_ingredient(repo.clone(), input.id, &changeset)
+    let error = update_ingredient(repo.clone(), input.id, &changeset, &ExpectedVersion::Any)
         .await
         .unwrap_err();
 
     assert!(
-        matches!(error, UpdateIngredientError::ValidationError(ValidationError::EmptyField(fields)) if fields == ["name", "description", "diet_friendly"])
+        matches!(error, UpdateIngredientError::ValidationError(ValidationError::EmptyField(fields)) if fields == ["name", "description", "diet_violations", "allergens", "density"])
     );
 }
 
@@ -73,9 +109,47 @@ pub async fn updating_a_missing_file_fails(repo: impl IngredientRepository) {
         ..Default::default()
     };
 
-    let error = update_ingredient(repo.clone(), input.id, &changeset)
+    let error = update_ingredient(repo.clone(), input.id, &changeset, &ExpectedVersion::Any)
         .await
         .unwrap_err();
 
     assert!(matches!(error, UpdateIngredientError::NotFound(id) if id == Uuid::from_u128(64)));
 }
+
+pub async fn updating_with_stale_version_fails(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let input = ingredient_fixture();
+    let changeset = UpdateIngredient {
+        name: Some("Ingredient name changed".to_string()),
+        ..Default::default()
+    };
+    repo.insert(input.clone()).await.unwrap();
+
+    let expected = ExpectedVersion::OneOf(vec![input.version.next()]);
+    let error = update_ingredient(repo.clone(), input.id, &changeset, &expected)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, UpdateIngredientError::VersionMismatch(id) if id == input.id));
+    assert_eq!(repo.get_by_id(&input.id).await.unwrap(), input);
+}
+
+pub async fn updating_an_outdated_ingredient_conflicts(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let input = ingredient_fixture();
+    let changeset = UpdateIngredient {
+        name: Some("Ingredient name changed".to_string()),
+        ..Default::default()
+    };
+    repo.insert(input.clone()).await.unwrap();
+    update_ingredient(repo.clone(), input.id, &changeset, &ExpectedVersion::Any)
+        .await
+        .unwrap();
+
+    let error = repo
+        .update(&input, (&changeset).try_into().unwrap())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, UpdateIngredientErrorInternal::VersionMismatch(id) if id == input.id));
+}
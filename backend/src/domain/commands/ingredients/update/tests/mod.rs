@@ -11,6 +11,12 @@ mod in_memory {
         __tests__::updating_an_ingredient_success(repo).await
     }
 
+    #[tokio::test]
+    async fn updating_an_ingredients_density_success() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::updating_an_ingredients_density_success(repo).await
+    }
+
     #[tokio::test]
     async fn updating_with_empty_changeset_fails() {
         let repo = InMemoryIngredientRepository::new();
@@ -22,6 +28,18 @@ mod in_memory {
         let repo = InMemoryIngredientRepository::new();
         __tests__::updating_a_missing_file_fails(repo).await
     }
+
+    #[tokio::test]
+    async fn updating_with_stale_version_fails() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::updating_with_stale_version_fails(repo).await
+    }
+
+    #[tokio::test]
+    async fn updating_an_outdated_ingredient_conflicts() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::updating_an_outdated_ingredient_conflicts(repo).await
+    }
 }
 
 mod sql {
@@ -36,6 +54,12 @@ mod sql {
         __tests__::updating_an_ingredient_success(repo).await
     }
 
+    #[sqlx::test]
+    async fn updating_an_ingredients_density_success(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        __tests__::updating_an_ingredients_density_success(repo).await
+    }
+
     #[sqlx::test]
     async fn updating_with_empty_changeset_fails(pool: PgPool) {
         let repo = PostgresIngredientRepository::new(pool.clone());
@@ -47,4 +71,16 @@ mod sql {
         let repo = PostgresIngredientRepository::new(pool.clone());
         __tests__::updating_a_missing_file_fails(repo).await
     }
+
+    #[sqlx::test]
+    async fn updating_with_stale_version_fails(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::updating_with_stale_version_fails(repo).await
+    }
+
+    #[sqlx::test]
+    async fn updating_an_outdated_ingredient_conflicts(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::updating_an_outdated_ingredient_conflicts(repo).await
+    }
 }
@@ -0,0 +1,95 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::IngredientWithVariants,
+    repositories::ingredients::{
+        errors::{GetAllIngredientsError, GetIngredientByIdError, UpdateIngredientError},
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum AttachVariantError {
+    #[error("Could not find the ingredient with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("An ingredient can't be a variant of itself")]
+    SelfReference,
+
+    #[error("The ingredient with ID {0} is a variant itself, so it can't have variants")]
+    ParentIsAVariant(Uuid),
+
+    #[error("The ingredient with ID {0} has variants of its own, so it can't become a variant")]
+    VariantHasVariants(Uuid),
+
+    #[error("The ingredient with ID {0} is already a variant of the ingredient with ID {1}")]
+    AlreadyAVariant(Uuid, Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for AttachVariantError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetAllIngredientsError> for AttachVariantError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<UpdateIngredientError> for AttachVariantError {
+    fn from(value: UpdateIngredientError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Makes one ingredient a variant of another, i.e. dark soy sauce of soy sauce.
+/// Variants only go one level deep, so variants can't have variants of their own.
+#[tracing::instrument("[COMMAND] Attaching a variant to an ingredient", skip(repo))]
+pub async fn attach_variant(
+    repo: IngredientRepositoryService,
+    parent_id: &Uuid,
+    variant_id: &Uuid,
+) -> Result<IngredientWithVariants, AttachVariantError> {
+    if parent_id == variant_id {
+        return Err(AttachVariantError::SelfReference);
+    }
+
+    let parent = repo.get_by_id(parent_id).await?;
+    let variant = repo.get_by_id(variant_id).await?;
+
+    if parent.parent_id.is_some() {
+        return Err(AttachVariantError::ParentIsAVariant(parent.id));
+    }
+
+    match variant.parent_id {
+        Some(id) if id != parent.id => {
+            return Err(AttachVariantError::AlreadyAVariant(variant.id, id));
+        }
+        Some(_) => {}
+        None => {
+            if !repo.get_variants(&variant).await?.is_empty() {
+                return Err(AttachVariantError::VariantHasVariants(variant.id));
+            }
+
+            repo.set_parent(&variant, Some(&parent)).await?;
+        }
+    }
+
+    let variants = repo.get_variants(&parent).await?;
+
+    Ok(IngredientWithVariants {
+        ingredient: parent,
+        variants,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,122 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::ingredients::variants::attach::{attach_variant, AttachVariantError},
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{ingredient_repo_with, named_ingredient},
+};
+
+async fn seed(repo: impl IngredientRepository) -> IngredientRepositoryService {
+    ingredient_repo_with(
+        repo,
+        [
+            named_ingredient(1, "Soy sauce"),
+            named_ingredient(2, "Dark soy sauce"),
+            named_ingredient(3, "Light soy sauce"),
+        ],
+    )
+    .await
+}
+
+pub async fn attaching_variant_works(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    attach_variant(repo.clone(), &Uuid::from_u128(1), &Uuid::from_u128(3))
+        .await
+        .unwrap();
+    let result = attach_variant(repo.clone(), &Uuid::from_u128(1), &Uuid::from_u128(2))
+        .await
+        .unwrap();
+
+    assert_eq!(result.ingredient.id, Uuid::from_u128(1));
+    assert_eq!(
+        result.variants.iter().map(|v| v.id).collect::<Vec<_>>(),
+        vec![Uuid::from_u128(2), Uuid::from_u128(3)]
+    );
+
+    let variant = repo.get_by_id(&Uuid::from_u128(2)).await.unwrap();
+    assert_eq!(variant.parent_id, Some(Uuid::from_u128(1)));
+}
+
+pub async fn attaching_variant_twice_is_idempotent(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    attach_variant(repo.clone(), &Uuid::from_u128(1), &Uuid::from_u128(2))
+        .await
+        .unwrap();
+    let result = attach_variant(repo.clone(), &Uuid::from_u128(1), &Uuid::from_u128(2))
+        .await
+        .unwrap();
+
+    assert_eq!(result.variants.len(), 1);
+}
+
+pub async fn attaching_ingredient_to_itself_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = attach_variant(repo, &Uuid::from_u128(1), &Uuid::from_u128(1))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, AttachVariantError::SelfReference));
+}
+
+pub async fn attaching_missing_ingredient_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = attach_variant(repo, &Uuid::from_u128(1), &Uuid::from_u128(100))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, AttachVariantError::NotFound(id) if id == Uuid::from_u128(100)));
+}
+
+pub async fn attaching_to_a_variant_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    attach_variant(repo.clone(), &Uuid::from_u128(1), &Uuid::from_u128(2))
+        .await
+        .unwrap();
+    let result = attach_variant(repo, &Uuid::from_u128(2), &Uuid::from_u128(3))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, AttachVariantError::ParentIsAVariant(id) if id == Uuid::from_u128(2)));
+}
+
+pub async fn attaching_ingredient_with_variants_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    attach_variant(repo.clone(), &Uuid::from_u128(1), &Uuid::from_u128(2))
+        .await
+        .unwrap();
+    let result = attach_variant(repo, &Uuid::from_u128(3), &Uuid::from_u128(1))
+        .await
+        .unwrap_err();
+
+    assert!(
+        matches!(result, AttachVariantError::VariantHasVariants(id) if id == Uuid::from_u128(1))
+    );
+}
+
+pub async fn attaching_variant_of_another_ingredient_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+    repo.insert(named_ingredient(4, "Fish sauce"))
+        .await
+        .unwrap();
+
+    attach_variant(repo.clone(), &Uuid::from_u128(1), &Uuid::from_u128(2))
+        .await
+        .unwrap();
+    let result = attach_variant(repo, &Uuid::from_u128(4), &Uuid::from_u128(2))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        result,
+        AttachVariantError::AlreadyAVariant(variant, parent)
+            if variant == Uuid::from_u128(2) && parent == Uuid::from_u128(1)
+    ));
+}
@@ -0,0 +1,97 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn attaching_variant_works() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::attaching_variant_works(repo).await;
+    }
+
+    #[tokio::test]
+    async fn attaching_variant_twice_is_idempotent() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::attaching_variant_twice_is_idempotent(repo).await;
+    }
+
+    #[tokio::test]
+    async fn attaching_ingredient_to_itself_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::attaching_ingredient_to_itself_errors(repo).await;
+    }
+
+    #[tokio::test]
+    async fn attaching_missing_ingredient_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::attaching_missing_ingredient_errors(repo).await;
+    }
+
+    #[tokio::test]
+    async fn attaching_to_a_variant_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::attaching_to_a_variant_errors(repo).await;
+    }
+
+    #[tokio::test]
+    async fn attaching_ingredient_with_variants_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::attaching_ingredient_with_variants_errors(repo).await;
+    }
+
+    #[tokio::test]
+    async fn attaching_variant_of_another_ingredient_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::attaching_variant_of_another_ingredient_errors(repo).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn attaching_variant_works(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::attaching_variant_works(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn attaching_variant_twice_is_idempotent(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::attaching_variant_twice_is_idempotent(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn attaching_ingredient_to_itself_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::attaching_ingredient_to_itself_errors(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn attaching_missing_ingredient_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::attaching_missing_ingredient_errors(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn attaching_to_a_variant_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::attaching_to_a_variant_errors(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn attaching_ingredient_with_variants_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::attaching_ingredient_with_variants_errors(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn attaching_variant_of_another_ingredient_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::attaching_variant_of_another_ingredient_errors(repo).await;
+    }
+}
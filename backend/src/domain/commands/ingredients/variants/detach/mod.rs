@@ -0,0 +1,68 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::IngredientWithVariants,
+    repositories::ingredients::{
+        errors::{GetAllIngredientsError, GetIngredientByIdError, UpdateIngredientError},
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum DetachVariantError {
+    #[error("Could not find the ingredient with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("The ingredient with ID {0} is not a variant of the ingredient with ID {1}")]
+    NotAVariant(Uuid, Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetIngredientByIdError> for DetachVariantError {
+    fn from(value: GetIngredientByIdError) -> Self {
+        match value {
+            GetIngredientByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetAllIngredientsError> for DetachVariantError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<UpdateIngredientError> for DetachVariantError {
+    fn from(value: UpdateIngredientError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument("[COMMAND] Detaching a variant from an ingredient", skip(repo))]
+pub async fn detach_variant(
+    repo: IngredientRepositoryService,
+    parent_id: &Uuid,
+    variant_id: &Uuid,
+) -> Result<IngredientWithVariants, DetachVariantError> {
+    let parent = repo.get_by_id(parent_id).await?;
+    let variant = repo.get_by_id(variant_id).await?;
+
+    if variant.parent_id != Some(parent.id) {
+        return Err(DetachVariantError::NotAVariant(variant.id, parent.id));
+    }
+
+    repo.set_parent(&variant, None).await?;
+
+    let variants = repo.get_variants(&parent).await?;
+
+    Ok(IngredientWithVariants {
+        ingredient: parent,
+        variants,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,63 @@
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        commands::ingredients::variants::detach::{detach_variant, DetachVariantError},
+        entities::ingredient::Ingredient,
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::{ingredient_repo_with, named_ingredient},
+};
+
+async fn seed(repo: impl IngredientRepository) -> IngredientRepositoryService {
+    ingredient_repo_with(
+        repo,
+        [
+            named_ingredient(1, "Soy sauce"),
+            Ingredient {
+                parent_id: Some(Uuid::from_u128(1)),
+                ..named_ingredient(2, "Dark soy sauce")
+            },
+            named_ingredient(3, "Fish sauce"),
+        ],
+    )
+    .await
+}
+
+pub async fn detaching_variant_works(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = detach_variant(repo.clone(), &Uuid::from_u128(1), &Uuid::from_u128(2))
+        .await
+        .unwrap();
+
+    assert_eq!(result.ingredient.id, Uuid::from_u128(1));
+    assert!(result.variants.is_empty());
+
+    let variant = repo.get_by_id(&Uuid::from_u128(2)).await.unwrap();
+    assert_eq!(variant.parent_id, None);
+}
+
+pub async fn detaching_ingredient_that_is_not_a_variant_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = detach_variant(repo, &Uuid::from_u128(3), &Uuid::from_u128(2))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        result,
+        DetachVariantError::NotAVariant(variant, parent)
+            if variant == Uuid::from_u128(2) && parent == Uuid::from_u128(3)
+    ));
+}
+
+pub async fn detaching_missing_ingredient_errors(repo: impl IngredientRepository) {
+    let repo = seed(repo).await;
+
+    let result = detach_variant(repo, &Uuid::from_u128(100), &Uuid::from_u128(2))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, DetachVariantError::NotFound(id) if id == Uuid::from_u128(100)));
+}
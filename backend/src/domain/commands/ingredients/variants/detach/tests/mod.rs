@@ -0,0 +1,49 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn detaching_variant_works() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::detaching_variant_works(repo).await;
+    }
+
+    #[tokio::test]
+    async fn detaching_ingredient_that_is_not_a_variant_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::detaching_ingredient_that_is_not_a_variant_errors(repo).await;
+    }
+
+    #[tokio::test]
+    async fn detaching_missing_ingredient_errors() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::detaching_missing_ingredient_errors(repo).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn detaching_variant_works(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::detaching_variant_works(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn detaching_ingredient_that_is_not_a_variant_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::detaching_ingredient_that_is_not_a_variant_errors(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn detaching_missing_ingredient_errors(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::detaching_missing_ingredient_errors(repo).await;
+    }
+}
@@ -1,2 +1,4 @@
 pub mod ingredients;
 pub mod recipes;
+pub mod trash;
+pub mod users;
@@ -0,0 +1,63 @@
+use uuid::Uuid;
+
+use crate::domain::entities::recipe::Recipe;
+use crate::domain::entities::user::User;
+use crate::domain::repositories::recipe::errors::{
+    GetRecipeByIdError, UpdateRecipeError as UpdateRecipeErrorInternal,
+};
+use crate::domain::repositories::recipe::RecipeRepositoryService;
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ClaimRecipeError {
+    #[error("Could not find the recipe with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("The recipe with ID {0} already has an author")]
+    AlreadyAuthored(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<UpdateRecipeErrorInternal> for ClaimRecipeError {
+    fn from(value: UpdateRecipeErrorInternal) -> Self {
+        match value {
+            // Someone else claimed or changed it first
+            UpdateRecipeErrorInternal::VersionMismatch(id) => Self::AlreadyAuthored(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetRecipeByIdError> for ClaimRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+/// Makes the user the author of a public recipe that has none, i.e. one created before user
+/// accounts existed, so that it can be changed again. The first user to claim it gets it.
+pub async fn claim_recipe(
+    recipe_repo: RecipeRepositoryService,
+    recipe_id: &Uuid,
+    user: &User,
+) -> Result<Recipe, ClaimRecipeError> {
+    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+    // Private recipes of users that are gone stay hidden
+    if !recipe.is_visible_to(Some(&user.id)) {
+        return Err(ClaimRecipeError::NotFound(recipe.id));
+    }
+    if recipe.author_id.is_some() {
+        return Err(ClaimRecipeError::AlreadyAuthored(recipe.id));
+    }
+
+    recipe_repo.claim(&recipe, &user.id).await?;
+
+    Ok(recipe_repo.get_by_id(recipe_id).await?)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::recipes::claim::{claim_recipe, ClaimRecipeError},
+        entities::{recipe::Recipe, user::User},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{authored_recipe_fixture, insert_all_ingredients_of_recipe, user_fixture},
+};
+
+pub async fn claiming_a_recipe_without_an_author_works(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let user = user_fixture();
+    let recipe = Recipe {
+        author_id: None,
+        ..authored_recipe_fixture()
+    };
+
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    user_repo.insert(user.clone()).await.unwrap();
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let claimed = claim_recipe(repo.clone(), &recipe.id, &user).await.unwrap();
+
+    assert_eq!(claimed.author_id, Some(user.id));
+    assert_eq!(claimed.version, recipe.version.next());
+    assert_eq!(repo.get_by_id(&recipe.id).await.unwrap(), claimed);
+}
+
+pub async fn claiming_an_authored_recipe_fails(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let recipe = authored_recipe_fixture();
+    let stranger = User {
+        id: uuid::Uuid::from_u128(256),
+        username: "stranger".try_into().unwrap(),
+        email: "stranger@example.com".try_into().unwrap(),
+        ..user_fixture()
+    };
+
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    user_repo.insert(user_fixture()).await.unwrap();
+    user_repo.insert(stranger.clone()).await.unwrap();
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let result = claim_recipe(repo.clone(), &recipe.id, &stranger)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, ClaimRecipeError::AlreadyAuthored(id) if id == recipe.id));
+    assert_eq!(repo.get_by_id(&recipe.id).await.unwrap(), recipe);
+}
@@ -0,0 +1,51 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn claiming_a_recipe_without_an_author_works() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::claiming_a_recipe_without_an_author_works(repo, ingredient_repo, user_repo).await
+    }
+
+    #[tokio::test]
+    async fn claiming_an_authored_recipe_fails() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::claiming_an_authored_recipe_fails(repo, ingredient_repo, user_repo).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn claiming_a_recipe_without_an_author_works(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::claiming_a_recipe_without_an_author_works(repo, ingredient_repo, user_repo).await
+    }
+
+    #[sqlx::test]
+    async fn claiming_an_authored_recipe_fails(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::claiming_an_authored_recipe_fails(repo, ingredient_repo, user_repo).await
+    }
+}
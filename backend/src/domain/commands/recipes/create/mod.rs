@@ -1,16 +1,18 @@
-use std::collections::BTreeMap;
-
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 use uuid::Uuid;
 
+use crate::domain::commands::recipes::record_revision;
+use crate::domain::entities::recipe::time::RecipeTime;
 use crate::domain::entities::recipe::IngredientAmountData;
 use crate::domain::entities::recipe::{
-    errors::ValidationError, IngredientWithAmount, Recipe, ServingsType,
+    errors::ValidationError, IngredientWithAmount, Recipe, RecipeIngredients, RecipeStep,
+    RecipeSteps, ServingsType,
 };
 use crate::domain::repositories::recipe::errors::GetRecipeByIdError;
 use crate::domain::repositories::{
     ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
     recipe::{errors::InsertRecipeError, RecipeRepositoryService},
+    unit_of_work::UnitOfWork,
 };
 
 #[derive(thiserror::Error, Debug, strum::AsRefStr)]
@@ -55,10 +57,12 @@ impl From<GetRecipeByIdError> for CreateRecipeError {
 pub struct CreateRecipe {
     pub name: String,
     pub description: String,
-    pub steps: Vec<String>,
-    pub time: BTreeMap<String, std::time::Duration>,
+    pub steps: Vec<RecipeStep>,
+    pub time: RecipeTime,
     pub ingredients: Vec<IngredientAmountData>,
     pub servings: ServingsType,
+    pub author_id: Option<Uuid>,
+    pub public: bool,
 }
 
 impl From<Recipe> for CreateRecipe {
@@ -70,6 +74,8 @@ impl From<Recipe> for CreateRecipe {
             steps: value.steps.as_ref().to_vec(),
             time: value.time,
             ingredients: value.ingredients.into(),
+            author_id: value.author_id,
+            public: value.public,
         }
     }
 }
@@ -78,11 +84,25 @@ pub async fn create_recipe(
     recipe_repo: RecipeRepositoryService,
     ingredient_repo: IngredientRepositoryService,
     input: &CreateRecipe,
+) -> Result<Recipe, CreateRecipeError> {
+    let mut uow = recipe_repo.begin().await?;
+    let recipe = create_recipe_in(&mut uow, &recipe_repo, &ingredient_repo, input).await?;
+    uow.commit().await?;
+
+    Ok(recipe)
+}
+
+/// Creates the recipe, along with its first revision, as part of the unit of work
+pub(crate) async fn create_recipe_in(
+    uow: &mut UnitOfWork,
+    recipe_repo: &RecipeRepositoryService,
+    ingredient_repo: &IngredientRepositoryService,
+    input: &CreateRecipe,
 ) -> Result<Recipe, CreateRecipeError> {
     let ingredient_ids: Vec<Uuid> = input.ingredients.iter().map(|i| i.ingredient_id).collect();
 
     let ingredients_in_recipe: Vec<_> = ingredient_repo
-        .get_all_by_id(&ingredient_ids)
+        .get_all_by_id(uow, &ingredient_ids)
         .await
         .map_err(CreateRecipeError::from)?
         .into_par_iter()
@@ -106,22 +126,33 @@ pub async fn create_recipe(
             },
         )
         .collect();
+    let ingredients_in_recipe: RecipeIngredients = ingredients_in_recipe.try_into()?;
+
+    let steps: RecipeSteps = input.steps.clone().try_into()?;
+    steps.check_ingredients(&ingredients_in_recipe)?;
 
     let id = Uuid::now_v7();
 
     recipe_repo
-        .insert(Recipe {
-            id,
-            name: input.name.to_string(),
-            description: input.description.to_string(),
-            steps: input.steps.clone().try_into()?,
-            ingredients: ingredients_in_recipe.try_into()?,
-            time: input.time.clone(),
-            servings: input.servings.clone(),
-        })
+        .insert_in(
+            uow,
+            Recipe {
+                id,
+                name: input.name.to_string(),
+                description: input.description.to_string(),
+                steps,
+                ingredients: ingredients_in_recipe,
+                time: input.time.clone(),
+                servings: input.servings.clone(),
+                author_id: input.author_id,
+                public: input.public,
+                version: Default::default(),
+            },
+        )
         .await?;
 
-    let recipe = recipe_repo.get_by_id(&id).await?;
+    let recipe = recipe_repo.get_by_id_for_update(uow, &id).await?;
+    record_revision(uow, recipe_repo, None, &recipe, input.author_id).await?;
 
     Ok(recipe)
 }
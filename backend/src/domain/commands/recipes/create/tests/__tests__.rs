@@ -5,7 +5,8 @@ use uuid::Uuid;
 
 use crate::{
     domain::{
-        commands::recipes::create::{create_recipe, CreateRecipeError},
+        commands::recipes::create::{create_recipe, CreateRecipe, CreateRecipeError},
+        entities::recipe::{errors::ValidationError, RecipeStep},
         repositories::{
             ingredients::{IngredientRepository, IngredientRepositoryService},
             recipe::{errors::InsertRecipeError, RecipeRepository, RecipeRepositoryService},
@@ -64,3 +65,60 @@ pub async fn inserting_recipe_with_same_id_fails(
 
     assert!(matches!(error, InsertRecipeError::Conflict(a) if a == "recipe id"));
 }
+
+pub async fn create_recipe_with_structured_steps(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
+
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let garlic = recipe.ingredients[1].ingredient.id;
+    let step = RecipeStep {
+        section: Some("Sauce".to_string()),
+        text: "Mince the garlic and let it rest".to_string(),
+        duration: Some(std::time::Duration::from_secs(300)),
+        ingredients: vec![garlic],
+    };
+    let mut input: CreateRecipe = recipe.into();
+    input.steps = vec![step.clone(), "Serve".to_string().into()];
+
+    let result = create_recipe(recipe_repo, ingredient_repo, &input)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.steps.as_ref(),
+        &[step, RecipeStep::from("Serve".to_string())]
+    );
+}
+
+pub async fn create_recipe_with_unknown_step_ingredient_errors(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
+
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let unknown = Uuid::from_u128(404);
+    let mut input: CreateRecipe = recipe.into();
+    input.steps = vec![RecipeStep {
+        ingredients: vec![unknown],
+        ..RecipeStep::from("Add the mystery ingredient".to_string())
+    }];
+
+    let result = create_recipe(recipe_repo, ingredient_repo, &input)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        result,
+        CreateRecipeError::Validation(ValidationError::UnknownStepIngredient(id)) if id == unknown
+    ));
+}
@@ -30,6 +30,23 @@ mod in_memory {
         let ingredient_repo = InMemoryIngredientRepository::new();
         __tests__::inserting_recipe_with_same_id_fails(repo, ingredient_repo).await
     }
+
+    #[tokio::test]
+    async fn create_recipe_with_structured_steps() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+
+        __tests__::create_recipe_with_structured_steps(recipe_repo, ingredient_repo).await;
+    }
+
+    #[tokio::test]
+    async fn create_recipe_with_unknown_step_ingredient_errors() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let recipe_repo = InMemoryRecipeRepository::new();
+
+        __tests__::create_recipe_with_unknown_step_ingredient_errors(recipe_repo, ingredient_repo)
+            .await;
+    }
 }
 
 mod sql {
@@ -64,4 +81,21 @@ mod sql {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
         __tests__::inserting_recipe_with_same_id_fails(repo, ingredient_repo).await
     }
+
+    #[sqlx::test]
+    async fn create_recipe_with_structured_steps(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+
+        __tests__::create_recipe_with_structured_steps(recipe_repo, ingredient_repo).await;
+    }
+
+    #[sqlx::test]
+    async fn create_recipe_with_unknown_step_ingredient_errors(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
+
+        __tests__::create_recipe_with_unknown_step_ingredient_errors(recipe_repo, ingredient_repo)
+            .await;
+    }
 }
@@ -1,5 +1,7 @@
 use uuid::Uuid;
 
+use crate::domain::entities::user::User;
+use crate::domain::entities::version::ExpectedVersion;
 use crate::domain::repositories::recipe::errors::{
     DeleteRecipeError as DeleteRecipeErrorInternal, GetRecipeByIdError,
 };
@@ -10,13 +12,22 @@ pub enum DeleteRecipeError {
     #[error("Could not found the recipe with the ID {0}")]
     NotFound(Uuid),
 
+    #[error("You are not the author of the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} was changed in the meantime")]
+    VersionMismatch(Uuid),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Error),
 }
 
 impl From<DeleteRecipeErrorInternal> for DeleteRecipeError {
     fn from(value: DeleteRecipeErrorInternal) -> Self {
-        Self::Unknown(value.into())
+        match value {
+            DeleteRecipeErrorInternal::VersionMismatch(id) => Self::VersionMismatch(id),
+            e => Self::Unknown(e.into()),
+        }
     }
 }
 
@@ -24,7 +35,7 @@ impl From<GetRecipeByIdError> for DeleteRecipeError {
     fn from(value: GetRecipeByIdError) -> Self {
         match value {
             GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
-            e => e.into(),
+            e => Self::Unknown(e.into()),
         }
     }
 }
@@ -32,8 +43,16 @@ impl From<GetRecipeByIdError> for DeleteRecipeError {
 pub async fn delete_recipe(
     recipe_repo: RecipeRepositoryService,
     input: &Uuid,
+    user: &User,
+    expected: &ExpectedVersion,
 ) -> Result<(), DeleteRecipeError> {
     let recipe = recipe_repo.get_by_id(input).await?;
+    if !recipe.is_authored_by(&user.id) {
+        return Err(DeleteRecipeError::Forbidden(recipe.id));
+    }
+    if !expected.matches(recipe.version) {
+        return Err(DeleteRecipeError::VersionMismatch(recipe.id));
+    }
 
     recipe_repo.delete(&recipe).await?;
 
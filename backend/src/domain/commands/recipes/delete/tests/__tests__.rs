@@ -3,33 +3,76 @@ use std::sync::Arc;
 use crate::{
     domain::{
         commands::recipes::delete::{delete_recipe, DeleteRecipeError},
+        entities::{user::User, version::ExpectedVersion},
         repositories::{
             ingredients::IngredientRepository,
-            recipe::{RecipeRepository, RecipeRepositoryService},
+            recipe::{errors::GetRecipeByIdError, RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
         },
     },
-    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+    test_utils::{authored_recipe_fixture, insert_all_ingredients_of_recipe, user_fixture},
 };
 
 pub async fn deleting_a_recipe_succeeds(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
     let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let recipe = recipe_fixture();
+    let user = user_fixture();
+    let recipe = authored_recipe_fixture();
 
     insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
 
+    user_repo.insert(user.clone()).await.unwrap();
     repo.insert(recipe.clone()).await.unwrap();
 
-    delete_recipe(repo, &recipe.id).await.unwrap();
+    delete_recipe(repo.clone(), &recipe.id, &user, &ExpectedVersion::Any)
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        repo.get_by_id(&recipe.id).await.unwrap_err(),
+        GetRecipeByIdError::NotFound(_)
+    ));
+    assert_eq!(
+        repo.get_deleted_by_id(&recipe.id).await.unwrap().item,
+        recipe
+    );
 }
 
 pub async fn deleting_a_nonexistent_recipe_fails(repo: impl RecipeRepository) {
     let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
 
-    let recipe = recipe_fixture();
-    let result = delete_recipe(repo, &recipe.id).await.unwrap_err();
+    let recipe = authored_recipe_fixture();
+    let result = delete_recipe(repo, &recipe.id, &user_fixture(), &ExpectedVersion::Any)
+        .await
+        .unwrap_err();
 
     assert!(matches!(result, DeleteRecipeError::NotFound(id) if id == recipe.id))
 }
+
+pub async fn deleting_someone_elses_recipe_is_forbidden(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let recipe = authored_recipe_fixture();
+    let stranger = User {
+        id: uuid::Uuid::from_u128(256),
+        ..user_fixture()
+    };
+
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+
+    user_repo.insert(user_fixture()).await.unwrap();
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let result = delete_recipe(repo.clone(), &recipe.id, &stranger, &ExpectedVersion::Any)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, DeleteRecipeError::Forbidden(id) if id == recipe.id));
+    assert!(repo.get_by_id(&recipe.id).await.is_ok());
+}
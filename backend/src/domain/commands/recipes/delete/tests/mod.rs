@@ -1,18 +1,18 @@
 mod __tests__;
 
 mod in_memory {
+    use super::__tests__;
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
     };
 
-    use super::__tests__;
-
     #[tokio::test]
     async fn deleting_a_recipe_succeeds() {
-        let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo).await
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo, user_repo).await
     }
 
     #[tokio::test]
@@ -20,28 +20,46 @@ mod in_memory {
         let repo = InMemoryRecipeRepository::new();
         __tests__::deleting_a_nonexistent_recipe_fails(repo).await
     }
+
+    #[tokio::test]
+    async fn deleting_someone_elses_recipe_is_forbidden() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::deleting_someone_elses_recipe_is_forbidden(repo, ingredient_repo, user_repo)
+            .await
+    }
 }
 
 mod sql {
+    use super::__tests__;
     use sqlx::PgPool;
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
     };
 
-    use super::__tests__;
-
     #[sqlx::test]
     async fn deleting_a_recipe_succeeds(pool: PgPool) {
-        let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo).await
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::deleting_a_recipe_succeeds(repo, ingredient_repo, user_repo).await
     }
 
     #[sqlx::test]
     async fn deleting_a_nonexistent_recipe_fails(pool: PgPool) {
-        let repo = PostgresRecipeRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
         __tests__::deleting_a_nonexistent_recipe_fails(repo).await
     }
+
+    #[sqlx::test]
+    async fn deleting_someone_elses_recipe_is_forbidden(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::deleting_someone_elses_recipe_is_forbidden(repo, ingredient_repo, user_repo)
+            .await
+    }
 }
@@ -0,0 +1,332 @@
+use common::{
+    CreateRecipeDTO, ImportedRecipeDTO, IngredientAmountDTO, IngredientUnitDTO,
+    RecipeImportDraftDTO, RecipeStepInputDTO, UnresolvedIngredientDTO,
+};
+use uuid::Uuid;
+
+use crate::domain::commands::{
+    ingredients::create::{create_ingredient_in, CreateIngredient, CreateIngredientError},
+    recipes::create::{create_recipe_in, CreateRecipe, CreateRecipeError},
+};
+use crate::domain::entities::{
+    ingredient::Ingredient,
+    recipe::{
+        draft::{DraftStep, RecipeDraft},
+        errors::ValidationError,
+        schema_org::html::find_recipe_in_html,
+        IngredientAmountData, IngredientLine, Recipe, RecipeStep, RecipeSteps,
+    },
+};
+use crate::domain::repositories::{
+    ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+    recipe::RecipeRepositoryService,
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ImportRecipeError {
+    #[error("The document does not contain a schema.org recipe")]
+    NoRecipe,
+
+    #[error("The upload does not contain an HTML document")]
+    NoDocument,
+
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Report),
+}
+
+impl From<CreateRecipeError> for ImportRecipeError {
+    fn from(value: CreateRecipeError) -> Self {
+        match value {
+            CreateRecipeError::Validation(e) => Self::Validation(e),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<CreateIngredientError> for ImportRecipeError {
+    fn from(value: CreateIngredientError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetAllIngredientsError> for ImportRecipeError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[derive(Debug)]
+pub struct ImportedRecipe {
+    pub recipe: Recipe,
+    /// Ingredients the recipe used that didn't exist yet
+    pub created_ingredients: Vec<Ingredient>,
+}
+
+impl From<ImportedRecipe> for ImportedRecipeDTO {
+    fn from(value: ImportedRecipe) -> Self {
+        Self {
+            recipe: value.recipe.into(),
+            created_ingredients: value
+                .created_ingredients
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+/// The ingredient with exactly that name, or one that only differs in case
+fn find_by_name<'a>(ingredients: &'a [Ingredient], name: &str) -> Option<&'a Ingredient> {
+    ingredients
+        .iter()
+        .find(|ingredient| ingredient.name.as_str() == name)
+        .or_else(|| {
+            ingredients
+                .iter()
+                .find(|ingredient| ingredient.name.to_lowercase() == name.to_lowercase())
+        })
+}
+
+/// A recipe lists every ingredient once, so lines naming the same one are merged.
+/// The first amount wins when they can't be added up.
+fn merge_lines(lines: Vec<IngredientLine>) -> Vec<IngredientLine> {
+    let mut merged: Vec<IngredientLine> = vec![];
+    for line in lines {
+        match merged
+            .iter_mut()
+            .find(|listed| listed.name.to_lowercase() == line.name.to_lowercase())
+        {
+            Some(listed) => {
+                if let Some(amount) = listed.amount.checked_add(&line.amount) {
+                    listed.amount = amount;
+                }
+            }
+            None => merged.push(line),
+        }
+    }
+    merged
+}
+
+fn amount_data(ingredient_id: Uuid, line: IngredientLine) -> IngredientAmountData {
+    IngredientAmountData {
+        ingredient_id,
+        amount: line.amount,
+        optional: line.optional,
+        notes: line.notes,
+    }
+}
+
+/// The steps, referring to their ingredients by ID. Names without an ingredient are left out.
+fn resolve_steps(steps: Vec<DraftStep>, ingredients: &[Ingredient]) -> Vec<RecipeStep> {
+    steps
+        .into_iter()
+        .map(
+            |DraftStep {
+                 step,
+                 ingredients: names,
+             }| {
+                let mut ids: Vec<Uuid> = vec![];
+                for ingredient in names
+                    .iter()
+                    .filter_map(|name| find_by_name(ingredients, name))
+                {
+                    if !ids.contains(&ingredient.id) {
+                        ids.push(ingredient.id);
+                    }
+                }
+                RecipeStep {
+                    ingredients: ids,
+                    ..step
+                }
+            },
+        )
+        .collect()
+}
+
+/// The recipe needs a name, valid steps and an ingredient, checked before anything is created
+fn validate_draft(draft: &RecipeDraft) -> Result<(), ValidationError> {
+    if draft.name.trim().is_empty() {
+        return Err(ValidationError::EmptyField(vec!["name"]));
+    }
+    RecipeSteps::try_from(
+        draft
+            .steps
+            .iter()
+            .map(|step| step.step.clone())
+            .collect::<Vec<_>>(),
+    )?;
+    if draft.ingredients.is_empty() {
+        return Err(ValidationError::EmptyField(vec!["ingredients"]));
+    }
+
+    Ok(())
+}
+
+/// Creates the recipe, matching its ingredients to existing ones by name and creating the rest.
+/// Either all of them are created or none are.
+#[tracing::instrument("[COMMAND] Importing a recipe", skip(recipe_repo, ingredient_repo))]
+pub async fn import_recipe(
+    recipe_repo: RecipeRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    draft: RecipeDraft,
+    author_id: Option<Uuid>,
+    public: bool,
+) -> Result<ImportedRecipe, ImportRecipeError> {
+    validate_draft(&draft)?;
+
+    let lines = merge_lines(draft.ingredients);
+    let names: Vec<String> = lines.iter().map(|line| line.name.clone()).collect();
+    let existing = ingredient_repo.get_all_by_name(&names).await?;
+    let description = format!("Imported with the recipe {}", draft.name);
+
+    let mut uow = recipe_repo.begin().await?;
+    let mut created_ingredients: Vec<Ingredient> = vec![];
+    let mut ingredients: Vec<IngredientAmountData> = vec![];
+    for line in lines {
+        let ingredient_id = match find_by_name(&existing, &line.name) {
+            Some(ingredient) => ingredient.id,
+            None => {
+                let ingredient = create_ingredient_in(
+                    &mut uow,
+                    &ingredient_repo,
+                    &CreateIngredient {
+                        name: &line.name,
+                        description: &description,
+                        diet_friendly: None,
+                        diet_violations: None,
+                        allergens: None,
+                        density: None,
+                    },
+                )
+                .await?;
+                created_ingredients.push(ingredient.clone());
+                ingredient.id
+            }
+        };
+        ingredients.push(amount_data(ingredient_id, line));
+    }
+    let known: Vec<Ingredient> = existing
+        .into_iter()
+        .chain(created_ingredients.iter().cloned())
+        .collect();
+
+    let recipe = create_recipe_in(
+        &mut uow,
+        &recipe_repo,
+        &ingredient_repo,
+        &CreateRecipe {
+            name: draft.name,
+            description: draft.description,
+            steps: resolve_steps(draft.steps, &known),
+            time: draft.time,
+            ingredients,
+            servings: draft.servings,
+            author_id,
+            public,
+        },
+    )
+    .await?;
+    uow.commit().await?;
+
+    Ok(ImportedRecipe {
+        recipe,
+        created_ingredients,
+    })
+}
+
+#[derive(Debug)]
+pub struct RecipeImportDraft {
+    /// Only lists the ingredients that matched existing ones
+    pub recipe: CreateRecipe,
+    pub unresolved_ingredients: Vec<IngredientLine>,
+}
+
+impl From<RecipeImportDraft> for RecipeImportDraftDTO {
+    fn from(value: RecipeImportDraft) -> Self {
+        let recipe = value.recipe;
+
+        Self {
+            recipe: CreateRecipeDTO {
+                name: recipe.name,
+                description: recipe.description,
+                steps: recipe
+                    .steps
+                    .into_iter()
+                    .map(|step| RecipeStepInputDTO::Structured(step.into()))
+                    .collect(),
+                time: recipe.time.into(),
+                ingredients: recipe
+                    .ingredients
+                    .into_iter()
+                    .map(|i| IngredientAmountDTO {
+                        ingredient_id: i.ingredient_id,
+                        amount: IngredientUnitDTO::from(i.amount).into(),
+                        optional: i.optional,
+                        notes: i.notes,
+                    })
+                    .collect(),
+                servings: recipe.servings.into(),
+                public: Some(recipe.public),
+            },
+            unresolved_ingredients: value
+                .unresolved_ingredients
+                .into_iter()
+                .map(|line| UnresolvedIngredientDTO {
+                    name: line.name,
+                    amount: line.amount.into(),
+                    optional: line.optional,
+                    notes: line.notes,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Reads the recipe embedded in a saved web page, without creating anything. Ingredients that
+/// don't match an existing one by name are left for the user to resolve.
+#[tracing::instrument(
+    "[COMMAND] Drafting a recipe from a web page",
+    skip(ingredient_repo, html)
+)]
+pub async fn draft_recipe_from_html(
+    ingredient_repo: IngredientRepositoryService,
+    html: &str,
+    author_id: Option<Uuid>,
+    public: bool,
+) -> Result<RecipeImportDraft, ImportRecipeError> {
+    let draft = RecipeDraft::from(find_recipe_in_html(html).ok_or(ImportRecipeError::NoRecipe)?);
+    validate_draft(&draft)?;
+
+    let lines = merge_lines(draft.ingredients);
+    let names: Vec<String> = lines.iter().map(|line| line.name.clone()).collect();
+    let existing = ingredient_repo.get_all_by_name(&names).await?;
+
+    let mut ingredients: Vec<IngredientAmountData> = vec![];
+    let mut unresolved_ingredients: Vec<IngredientLine> = vec![];
+    for line in lines {
+        match find_by_name(&existing, &line.name) {
+            Some(ingredient) => ingredients.push(amount_data(ingredient.id, line)),
+            None => unresolved_ingredients.push(line),
+        }
+    }
+
+    Ok(RecipeImportDraft {
+        recipe: CreateRecipe {
+            name: draft.name,
+            description: draft.description,
+            steps: resolve_steps(draft.steps, &existing),
+            time: draft.time,
+            ingredients,
+            servings: draft.servings,
+            author_id,
+            public,
+        },
+        unresolved_ingredients,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,176 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::recipes::import::{draft_recipe_from_html, import_recipe, ImportRecipeError},
+        entities::recipe::{
+            draft::RecipeDraft, errors::ValidationError, IngredientUnit, RecipeStep, ServingsType,
+        },
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::ingredient_fixture,
+};
+
+fn draft_fixture() -> RecipeDraft {
+    RecipeDraft {
+        name: "Beef pie".to_string(),
+        description: "".to_string(),
+        steps: vec![RecipeStep::from("Bake it".to_string()).into()],
+        time: Default::default(),
+        servings: ServingsType::Exact(4),
+        ingredients: ["500g beef", "200g Flour", "50 g flour", "a pinch of salt"]
+            .into_iter()
+            .map(|line| line.parse().unwrap())
+            .collect(),
+    }
+}
+
+pub async fn importing_matches_ingredients_by_name_and_creates_the_rest(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+    let beef = ingredient_repo.insert(ingredient_fixture()).await.unwrap();
+
+    let imported = import_recipe(
+        recipe_repo.clone(),
+        ingredient_repo.clone(),
+        draft_fixture(),
+        None,
+        false,
+    )
+    .await
+    .unwrap();
+
+    assert!(!imported.recipe.public);
+
+    assert_eq!(
+        imported
+            .created_ingredients
+            .iter()
+            .map(|i| i.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["Flour", "a pinch of salt"]
+    );
+    assert_eq!(
+        imported
+            .recipe
+            .ingredients
+            .iter()
+            .map(|i| (i.ingredient.id, i.amount.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            (beef.id, IngredientUnit::Grams(500.0)),
+            (
+                imported.created_ingredients[0].id,
+                IngredientUnit::Grams(250.0)
+            ),
+            (
+                imported.created_ingredients[1].id,
+                IngredientUnit::Other {
+                    amount: 1.0,
+                    unit: "".to_string()
+                }
+            ),
+        ]
+    );
+    assert_eq!(
+        recipe_repo.get_by_id(&imported.recipe.id).await.unwrap(),
+        imported.recipe
+    );
+}
+
+pub async fn importing_an_invalid_recipe_creates_no_ingredients(
+    repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+
+    let result = import_recipe(
+        recipe_repo,
+        ingredient_repo.clone(),
+        RecipeDraft {
+            steps: vec![],
+            ..draft_fixture()
+        },
+        None,
+        false,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(
+        result,
+        ImportRecipeError::Validation(ValidationError::EmptyField(_))
+    ));
+    assert_eq!(
+        ingredient_repo
+            .get_all_by_name(&["flour".to_string(), "a pinch of salt".to_string()])
+            .await
+            .unwrap(),
+        vec![]
+    );
+}
+
+pub async fn drafting_from_html_leaves_unknown_ingredients_unresolved(
+    ing_repo: impl IngredientRepository,
+) {
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+    let beef = ingredient_repo.insert(ingredient_fixture()).await.unwrap();
+    let html = r#"<script type="application/ld+json">{
+        "@context": "https://schema.org",
+        "@type": "Recipe",
+        "name": "Beef pie",
+        "recipeYield": "4",
+        "recipeIngredient": ["500g beef", "200g Flour", "50 g flour"],
+        "recipeInstructions": "Bake it"
+    }</script>"#;
+
+    let draft = draft_recipe_from_html(ingredient_repo.clone(), html, None, true)
+        .await
+        .unwrap();
+
+    assert_eq!(draft.recipe.name, "Beef pie");
+    assert_eq!(draft.recipe.servings, ServingsType::Exact(4));
+    assert_eq!(
+        draft
+            .recipe
+            .ingredients
+            .iter()
+            .map(|i| (i.ingredient_id, i.amount.clone()))
+            .collect::<Vec<_>>(),
+        vec![(beef.id, IngredientUnit::Grams(500.0))]
+    );
+    assert_eq!(
+        draft
+            .unresolved_ingredients
+            .iter()
+            .map(|i| (i.name.as_str(), i.amount.clone()))
+            .collect::<Vec<_>>(),
+        vec![("Flour", IngredientUnit::Grams(250.0))]
+    );
+    assert_eq!(
+        ingredient_repo
+            .get_all_by_name(&["flour".to_string()])
+            .await
+            .unwrap(),
+        vec![]
+    );
+}
+
+pub async fn drafting_from_html_without_a_recipe_fails(ing_repo: impl IngredientRepository) {
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+
+    let result = draft_recipe_from_html(ingredient_repo, "<h1>Not a recipe</h1>", None, false)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, ImportRecipeError::NoRecipe));
+}
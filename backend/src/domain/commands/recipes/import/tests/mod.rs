@@ -0,0 +1,73 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn importing_matches_ingredients_by_name_and_creates_the_rest() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::importing_matches_ingredients_by_name_and_creates_the_rest(repo, ingredient_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn importing_an_invalid_recipe_creates_no_ingredients() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::importing_an_invalid_recipe_creates_no_ingredients(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn drafting_from_html_leaves_unknown_ingredients_unresolved() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::drafting_from_html_leaves_unknown_ingredients_unresolved(ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn drafting_from_html_without_a_recipe_fails() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::drafting_from_html_without_a_recipe_fails(ingredient_repo).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn importing_matches_ingredients_by_name_and_creates_the_rest(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::importing_matches_ingredients_by_name_and_creates_the_rest(repo, ingredient_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn importing_an_invalid_recipe_creates_no_ingredients(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::importing_an_invalid_recipe_creates_no_ingredients(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn drafting_from_html_leaves_unknown_ingredients_unresolved(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::drafting_from_html_leaves_unknown_ingredients_unresolved(ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn drafting_from_html_without_a_recipe_fails(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::drafting_from_html_without_a_recipe_fails(ingredient_repo).await
+    }
+}
@@ -1,9 +1,17 @@
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::recipe::{IngredientAmountData, IngredientWithAmount, Recipe},
+    commands::recipes::record_revision,
+    entities::{
+        recipe::{errors::ValidationError, IngredientAmountData, IngredientWithAmount, Recipe},
+        user::User,
+        version::ExpectedVersion,
+    },
     repositories::{
-        ingredients::{errors::GetIngredientByIdError, IngredientRepositoryService},
+        ingredients::{
+            errors::{GetAllIngredientsError, GetIngredientByIdError},
+            IngredientRepositoryService,
+        },
         recipe::{
             errors::{
                 AddIngredientIntoRecipeError as AddIngredientIntoRecipeErrorInternal,
@@ -22,13 +30,25 @@ pub enum AddIngredientToRecipeError {
     #[error(transparent)]
     GetRecipe(#[from] GetRecipeByIdError),
 
+    #[error("You are not the author of the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} was changed in the meantime")]
+    VersionMismatch(Uuid),
+
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Report),
 }
 
 impl From<AddIngredientIntoRecipeErrorInternal> for AddIngredientToRecipeError {
     fn from(value: AddIngredientIntoRecipeErrorInternal) -> Self {
-        value.into()
+        match value {
+            AddIngredientIntoRecipeErrorInternal::VersionMismatch(id) => Self::VersionMismatch(id),
+            e => Self::Unknown(e.into()),
+        }
     }
 }
 
@@ -37,11 +57,32 @@ pub async fn add_ingredient_to_recipe(
     ingredient_repo: IngredientRepositoryService,
     recipe_id: &Uuid,
     ingredient_amount: IngredientAmountData,
+    user: &User,
+    expected: &ExpectedVersion,
 ) -> Result<Recipe, AddIngredientToRecipeError> {
-    let recipe = recipe_repo.get_by_id(recipe_id).await?;
-    let ingredient = ingredient_repo
-        .get_by_id(&ingredient_amount.ingredient_id)
+    let mut uow = recipe_repo.begin().await?;
+    let recipe = recipe_repo
+        .get_by_id_for_update(&mut uow, recipe_id)
         .await?;
+    if !recipe.is_authored_by(&user.id) {
+        return Err(AddIngredientToRecipeError::Forbidden(recipe.id));
+    }
+    if !expected.matches(recipe.version) {
+        return Err(AddIngredientToRecipeError::VersionMismatch(recipe.id));
+    }
+    // Read as part of the unit of work, so the ingredient can't be deleted before it's used
+    let ingredient_id = ingredient_amount.ingredient_id;
+    let ingredient = ingredient_repo
+        .get_all_by_id(&mut uow, &[ingredient_id])
+        .await
+        .map_err(|e| match e {
+            GetAllIngredientsError::MultipleIngredientsMissing(_) => {
+                GetIngredientByIdError::NotFound(ingredient_id).into()
+            }
+            e => AddIngredientToRecipeError::Unknown(e.into()),
+        })?
+        .pop()
+        .ok_or(GetIngredientByIdError::NotFound(ingredient_id))?;
     let IngredientAmountData {
         amount,
         notes,
@@ -51,6 +92,7 @@ pub async fn add_ingredient_to_recipe(
 
     recipe_repo
         .add_ingredient(
+            &mut uow,
             &recipe,
             IngredientWithAmount {
                 ingredient,
@@ -61,9 +103,20 @@ pub async fn add_ingredient_to_recipe(
         )
         .await?;
 
-    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+    let updated = recipe_repo
+        .get_by_id_for_update(&mut uow, recipe_id)
+        .await?;
+    record_revision(
+        &mut uow,
+        &recipe_repo,
+        Some(&recipe),
+        &updated,
+        Some(user.id),
+    )
+    .await?;
+    uow.commit().await?;
 
-    Ok(recipe)
+    Ok(updated)
 }
 
 #[cfg(test)]
@@ -5,25 +5,32 @@ use crate::{
         commands::recipes::ingredients::add::{
             add_ingredient_to_recipe, AddIngredientToRecipeError,
         },
-        entities::recipe::{IngredientAmountData, IngredientUnit, IngredientWithAmount},
+        entities::{
+            recipe::{IngredientAmountData, IngredientUnit, IngredientWithAmount},
+            user::User,
+            version::ExpectedVersion,
+        },
         repositories::{
             ingredients::{
                 errors::GetIngredientByIdError, IngredientRepository, IngredientRepositoryService,
             },
             recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
         },
     },
     test_utils::{
-        ingredient_fixture, insert_all_ingredients, insert_all_ingredients_of_recipe,
-        recipe_fixture,
+        authored_recipe_fixture, ingredient_fixture, insert_all_ingredients,
+        insert_all_ingredients_of_recipe, user_fixture,
     },
 };
 
 pub async fn adding_an_ingredient_to_a_recipe_works(
     repo: impl RecipeRepository,
     ing_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let recipe = recipe_fixture();
+    let user = user_fixture();
+    let recipe = authored_recipe_fixture();
     let ingredient = IngredientWithAmount {
         ingredient: ingredient_fixture(),
         amount: IngredientUnit::Grams(666.0),
@@ -40,12 +47,19 @@ pub async fn adding_an_ingredient_to_a_recipe_works(
 
     let ingredient_payload = IngredientAmountData::from(ingredient.clone());
 
+    user_repo.insert(user.clone()).await.unwrap();
     recipe_repo.insert(recipe.clone()).await.unwrap();
 
-    let updated_recipe =
-        add_ingredient_to_recipe(recipe_repo, ingredient_repo, &recipe.id, ingredient_payload)
-            .await
-            .unwrap();
+    let updated_recipe = add_ingredient_to_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &recipe.id,
+        ingredient_payload,
+        &user,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap();
 
     let expected: HashSet<_> = all_ingredients
         .iter()
@@ -61,8 +75,10 @@ pub async fn adding_an_ingredient_to_a_recipe_works(
 pub async fn adding_a_nonexistent_ingredient_to_a_recipe_errors(
     recipe_repo: impl RecipeRepository,
     ing_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let recipe = recipe_fixture();
+    let user = user_fixture();
+    let recipe = authored_recipe_fixture();
     let ingredient = IngredientWithAmount {
         ingredient: ingredient_fixture(),
         amount: IngredientUnit::Grams(666.0),
@@ -71,6 +87,7 @@ pub async fn adding_a_nonexistent_ingredient_to_a_recipe_errors(
     };
 
     insert_all_ingredients_of_recipe(&ing_repo, &recipe).await;
+    user_repo.insert(user.clone()).await.unwrap();
     recipe_repo.insert(recipe.clone()).await.unwrap();
 
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
@@ -78,13 +95,59 @@ pub async fn adding_a_nonexistent_ingredient_to_a_recipe_errors(
 
     let ingredient_payload = IngredientAmountData::from(ingredient.clone());
 
-    let error =
-        add_ingredient_to_recipe(recipe_repo, ingredient_repo, &recipe.id, ingredient_payload)
-            .await
-            .unwrap_err();
+    let error = add_ingredient_to_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &recipe.id,
+        ingredient_payload,
+        &user,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap_err();
 
     assert!(matches!(
         error,
         AddIngredientToRecipeError::GetIngredient(GetIngredientByIdError::NotFound(_))
     ))
 }
+
+pub async fn adding_an_ingredient_to_someone_elses_recipe_is_forbidden(
+    recipe_repo: impl RecipeRepository,
+    ing_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = authored_recipe_fixture();
+    let ingredient = IngredientWithAmount {
+        ingredient: ingredient_fixture(),
+        amount: IngredientUnit::Grams(666.0),
+        notes: None,
+        optional: true,
+    };
+    let stranger = User {
+        id: uuid::Uuid::from_u128(256),
+        ..user_fixture()
+    };
+    let mut all_ingredients = recipe.ingredients.to_vec().clone();
+    all_ingredients.push(ingredient.clone());
+
+    insert_all_ingredients(&ing_repo, &all_ingredients).await;
+    user_repo.insert(user_fixture()).await.unwrap();
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ing_repo));
+
+    let error = add_ingredient_to_recipe(
+        recipe_repo,
+        ingredient_repo,
+        &recipe.id,
+        IngredientAmountData::from(ingredient),
+        &stranger,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, AddIngredientToRecipeError::Forbidden(id) if id == recipe.id))
+}
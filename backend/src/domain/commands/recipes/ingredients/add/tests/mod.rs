@@ -4,21 +4,41 @@ mod in_memory {
     use super::__tests__;
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
     };
 
     #[tokio::test]
     async fn adding_an_ingredient_to_a_recipe_works() {
         let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::adding_an_ingredient_to_a_recipe_works(repo, ingredient_repo).await
+        __tests__::adding_an_ingredient_to_a_recipe_works(repo, ingredient_repo, user_repo).await
     }
 
     #[tokio::test]
     async fn adding_a_nonexistent_ingredient_to_a_recipe_errors() {
         let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(repo, ingredient_repo).await
+        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn adding_an_ingredient_to_someone_elses_recipe_is_forbidden() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::adding_an_ingredient_to_someone_elses_recipe_is_forbidden(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
     }
 }
 
@@ -28,20 +48,40 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn adding_an_ingredient_to_a_recipe_works(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
         let repo = PostgresRecipeRepository::new(pool);
-        __tests__::adding_an_ingredient_to_a_recipe_works(repo, ingredient_repo).await
+        __tests__::adding_an_ingredient_to_a_recipe_works(repo, ingredient_repo, user_repo).await
     }
 
     #[sqlx::test]
     async fn adding_a_nonexistent_ingredient_to_a_recipe_errors(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn adding_an_ingredient_to_someone_elses_recipe_is_forbidden(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
         let repo = PostgresRecipeRepository::new(pool);
-        __tests__::adding_a_nonexistent_ingredient_to_a_recipe_errors(repo, ingredient_repo).await
+        __tests__::adding_an_ingredient_to_someone_elses_recipe_is_forbidden(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
     }
 }
@@ -2,7 +2,10 @@ use strum::AsRefStr;
 use thiserror::Error;
 use uuid::Uuid;
 
+use crate::domain::commands::recipes::record_revision;
 use crate::domain::entities::recipe::errors::ValidationError;
+use crate::domain::entities::user::User;
+use crate::domain::entities::version::ExpectedVersion;
 use crate::domain::repositories::recipe::errors::{
     DeleteIngredientFromRecipeError as DeleteIngredientFromRecipeErrorInternal, GetRecipeByIdError,
 };
@@ -16,6 +19,12 @@ pub enum DeleteIngredientFromRecipeError {
     #[error("The recipe has no ingredient with ID of {0}")]
     RecipeHasNoIngredientError(Uuid),
 
+    #[error("You are not the author of the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} was changed in the meantime")]
+    VersionMismatch(Uuid),
+
     #[error("There is only one ingredient in the recipe. A recipe should have one ingredient at minimum.")]
     LastIngredientError,
 
@@ -29,7 +38,10 @@ impl From<DeleteIngredientFromRecipeErrorInternal> for DeleteIngredientFromRecip
             DeleteIngredientFromRecipeErrorInternal::ValidationError(
                 ValidationError::EmptyField(field),
             ) if field == vec!["steps"] => Self::LastIngredientError,
-            e => e.into(),
+            DeleteIngredientFromRecipeErrorInternal::VersionMismatch(id) => {
+                Self::VersionMismatch(id)
+            }
+            e => Self::UnknownError(e.into()),
         }
     }
 }
@@ -38,7 +50,7 @@ impl From<GetRecipeByIdError> for DeleteIngredientFromRecipeError {
     fn from(value: GetRecipeByIdError) -> Self {
         match value {
             GetRecipeByIdError::NotFound(id) => Self::RecipeNotFoundError(id),
-            e => e.into(),
+            e => Self::UnknownError(e.into()),
         }
     }
 }
@@ -47,8 +59,19 @@ pub async fn delete_ingredient_from_recipe(
     recipe_repo: RecipeRepositoryService,
     recipe_id: &Uuid,
     ingredient_id: &Uuid,
+    user: &User,
+    expected: &ExpectedVersion,
 ) -> Result<(), DeleteIngredientFromRecipeError> {
-    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+    let mut uow = recipe_repo.begin().await?;
+    let recipe = recipe_repo
+        .get_by_id_for_update(&mut uow, recipe_id)
+        .await?;
+    if !recipe.is_authored_by(&user.id) {
+        return Err(DeleteIngredientFromRecipeError::Forbidden(recipe.id));
+    }
+    if !expected.matches(recipe.version) {
+        return Err(DeleteIngredientFromRecipeError::VersionMismatch(recipe.id));
+    }
 
     if recipe.ingredients.len() == 1 {
         return Err(DeleteIngredientFromRecipeError::LastIngredientError);
@@ -63,8 +86,21 @@ pub async fn delete_ingredient_from_recipe(
         })?;
 
     recipe_repo
-        .delete_ingredient(&recipe, ingredient_in_recipe)
+        .delete_ingredient(&mut uow, &recipe, ingredient_in_recipe)
+        .await?;
+
+    let updated = recipe_repo
+        .get_by_id_for_update(&mut uow, recipe_id)
         .await?;
+    record_revision(
+        &mut uow,
+        &recipe_repo,
+        Some(&recipe),
+        &updated,
+        Some(user.id),
+    )
+    .await?;
+    uow.commit().await?;
 
     Ok(())
 }
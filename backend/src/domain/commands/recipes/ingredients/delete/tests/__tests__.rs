@@ -9,18 +9,41 @@ use uuid::Uuid;
 
 use crate::{
     domain::{
-        entities::recipe::{IngredientWithAmount, Recipe},
-        repositories::{ingredients::IngredientRepository, recipe::RecipeRepository},
+        entities::{
+            recipe::{IngredientWithAmount, Recipe, RecipeStep},
+            user::User,
+            version::ExpectedVersion,
+        },
+        repositories::{
+            ingredients::IngredientRepository, recipe::RecipeRepository, users::UserRepository,
+        },
+    },
+    test_utils::{
+        authored_recipe_fixture, ingredient_fixture, insert_all_ingredients_of_recipe, user_fixture,
     },
-    test_utils::{ingredient_fixture, insert_all_ingredients_of_recipe, recipe_fixture},
 };
 
 pub async fn deleting_an_existing_ingredient_works(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let initial_recipe = recipe_fixture();
+    let mut initial_recipe = authored_recipe_fixture();
+    let ingredient_ids: Vec<Uuid> = initial_recipe
+        .ingredients
+        .iter()
+        .map(|i| i.ingredient.id)
+        .collect();
+    initial_recipe.steps = vec![RecipeStep {
+        section: None,
+        text: "Mix everything together".to_string(),
+        duration: None,
+        ingredients: ingredient_ids.clone(),
+    }]
+    .try_into()
+    .unwrap();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    user_repo.insert(user_fixture()).await.unwrap();
     repo.insert(initial_recipe.clone()).await.unwrap();
 
     let ingredient_to_delete = initial_recipe.ingredients.first().unwrap();
@@ -30,29 +53,39 @@ pub async fn deleting_an_existing_ingredient_works(
         repo.clone(),
         &initial_recipe.id,
         &ingredient_to_delete.ingredient.id,
+        &user_fixture(),
+        &ExpectedVersion::Any,
     )
     .await
     .unwrap();
 
     let recipe = repo.get_by_id(&initial_recipe.id).await.unwrap();
 
-    assert!(recipe.ingredients.len() < initial_recipe.ingredients.len())
+    assert!(recipe.ingredients.len() < initial_recipe.ingredients.len());
+    assert_eq!(recipe.steps.as_ref()[0].ingredients, ingredient_ids[1..]);
 }
 
 pub async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let initial_recipe = recipe_fixture();
+    let initial_recipe = authored_recipe_fixture();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    user_repo.insert(user_fixture()).await.unwrap();
     repo.insert(initial_recipe.clone()).await.unwrap();
 
     let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
 
-    let error =
-        delete_ingredient_from_recipe(repo.clone(), &initial_recipe.id, &Uuid::from_u128(999))
-            .await
-            .unwrap_err();
+    let error = delete_ingredient_from_recipe(
+        repo.clone(),
+        &initial_recipe.id,
+        &Uuid::from_u128(999),
+        &user_fixture(),
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap_err();
 
     assert!(
         matches!(error, DeleteIngredientFromRecipeError::RecipeHasNoIngredientError(id) if id == Uuid::from_u128(999))
@@ -63,13 +96,19 @@ pub async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
 ) {
-    let initial_recipe = recipe_fixture();
+    let initial_recipe = authored_recipe_fixture();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
 
     let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let error = delete_ingredient_from_recipe(repo.clone(), &initial_recipe.id, &Uuid::nil())
-        .await
-        .unwrap_err();
+    let error = delete_ingredient_from_recipe(
+        repo.clone(),
+        &initial_recipe.id,
+        &Uuid::nil(),
+        &user_fixture(),
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap_err();
 
     assert!(
         matches!(error, DeleteIngredientFromRecipeError::RecipeNotFoundError(id) if id == initial_recipe.id)
@@ -79,6 +118,7 @@ pub async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(
 pub async fn deleting_the_last_ingredient_in_recipe_errors(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
     let ingredient = IngredientWithAmount {
         ingredient: ingredient_fixture(),
@@ -89,11 +129,13 @@ pub async fn deleting_the_last_ingredient_in_recipe_errors(
 
     let initial_recipe = Recipe {
         ingredients: vec![ingredient.clone()].try_into().unwrap(),
-        ..recipe_fixture()
+        ..authored_recipe_fixture()
     };
 
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
 
+    user_repo.insert(user_fixture()).await.unwrap();
+
     repo.insert(initial_recipe.clone()).await.unwrap();
 
     let ingredient_to_delete = initial_recipe.ingredients.first().unwrap();
@@ -103,6 +145,8 @@ pub async fn deleting_the_last_ingredient_in_recipe_errors(
         repo.clone(),
         &initial_recipe.id,
         &ingredient_to_delete.ingredient.id,
+        &user_fixture(),
+        &ExpectedVersion::Any,
     )
     .await
     .unwrap_err();
@@ -112,3 +156,39 @@ pub async fn deleting_the_last_ingredient_in_recipe_errors(
         DeleteIngredientFromRecipeError::LastIngredientError
     ))
 }
+
+pub async fn deleting_an_ingredient_from_someone_elses_recipe_is_forbidden(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let initial_recipe = authored_recipe_fixture();
+    let stranger = User {
+        id: Uuid::from_u128(256),
+        ..user_fixture()
+    };
+    insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    user_repo.insert(user_fixture()).await.unwrap();
+    repo.insert(initial_recipe.clone()).await.unwrap();
+
+    let ingredient_to_delete = initial_recipe.ingredients.first().unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let error = delete_ingredient_from_recipe(
+        repo.clone(),
+        &initial_recipe.id,
+        &ingredient_to_delete.ingredient.id,
+        &stranger,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(error, DeleteIngredientFromRecipeError::Forbidden(id) if id == initial_recipe.id)
+    );
+    assert_eq!(
+        repo.get_by_id(&initial_recipe.id).await.unwrap(),
+        initial_recipe
+    );
+}
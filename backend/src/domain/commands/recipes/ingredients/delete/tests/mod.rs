@@ -1,91 +1,121 @@
 mod __tests__;
 
 mod in_memory {
+    use super::__tests__;
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
     };
 
     #[tokio::test]
     async fn deleting_an_existing_ingredient_works() {
-        let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-        super::__tests__::deleting_an_existing_ingredient_works(repo, ingredient_repo).await;
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::deleting_an_existing_ingredient_works(repo, ingredient_repo, user_repo).await
     }
 
     #[tokio::test]
     async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors() {
-        let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-        super::__tests__::deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
             repo,
             ingredient_repo,
+            user_repo,
         )
-        .await;
+        .await
     }
 
     #[tokio::test]
     async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors() {
-        let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-        super::__tests__::deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(
-            repo,
-            ingredient_repo,
-        )
-        .await;
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(repo, ingredient_repo)
+            .await
     }
 
     #[tokio::test]
     async fn deleting_the_last_ingredient_in_recipe_errors() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
         let repo = InMemoryRecipeRepository::new();
+        __tests__::deleting_the_last_ingredient_in_recipe_errors(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn deleting_an_ingredient_from_someone_elses_recipe_is_forbidden() {
         let ingredient_repo = InMemoryIngredientRepository::new();
-        super::__tests__::deleting_the_last_ingredient_in_recipe_errors(repo, ingredient_repo)
-            .await;
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::deleting_an_ingredient_from_someone_elses_recipe_is_forbidden(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
     }
 }
 
 mod sql {
+    use super::__tests__;
     use sqlx::PgPool;
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn deleting_an_existing_ingredient_works(pool: PgPool) {
-        let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        super::__tests__::deleting_an_existing_ingredient_works(repo, ingredient_repo).await;
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::deleting_an_existing_ingredient_works(repo, ingredient_repo, user_repo).await
     }
 
     #[sqlx::test]
     async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(pool: PgPool) {
-        let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        super::__tests__::deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::deleting_an_ingredient_that_doesnt_appear_in_recipe_errors(
             repo,
             ingredient_repo,
+            user_repo,
         )
-        .await;
+        .await
     }
 
     #[sqlx::test]
     async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(pool: PgPool) {
-        let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        super::__tests__::deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(
-            repo,
-            ingredient_repo,
-        )
-        .await;
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::deleting_an_ingredient_in_recipe_that_doesnt_exist_errors(repo, ingredient_repo)
+            .await
     }
 
     #[sqlx::test]
     async fn deleting_the_last_ingredient_in_recipe_errors(pool: PgPool) {
-        let repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-        super::__tests__::deleting_the_last_ingredient_in_recipe_errors(repo, ingredient_repo)
-            .await;
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::deleting_the_last_ingredient_in_recipe_errors(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn deleting_an_ingredient_from_someone_elses_recipe_is_forbidden(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::deleting_an_ingredient_from_someone_elses_recipe_is_forbidden(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
     }
 }
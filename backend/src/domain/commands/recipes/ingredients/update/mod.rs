@@ -2,7 +2,12 @@ use thiserror::Error;
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::recipe::{IngredientUnit, Recipe},
+    commands::recipes::record_revision,
+    entities::{
+        recipe::{IngredientUnit, Recipe},
+        user::User,
+        version::ExpectedVersion,
+    },
     repositories::recipe::{
         errors::{
             GetRecipeByIdError,
@@ -20,13 +25,22 @@ pub enum UpdateIngredientInRecipeError {
     #[error("Could not find ingredient with ID {0} in this recipe.")]
     MissingIngredient(Uuid),
 
+    #[error("You are not the author of the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} was changed in the meantime")]
+    VersionMismatch(Uuid),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Report),
 }
 
 impl From<UpdateIngredientInRecipeErrorInternal> for UpdateIngredientInRecipeError {
     fn from(value: UpdateIngredientInRecipeErrorInternal) -> Self {
-        value.into()
+        match value {
+            UpdateIngredientInRecipeErrorInternal::VersionMismatch(id) => Self::VersionMismatch(id),
+            e => Self::Unknown(e.into()),
+        }
     }
 }
 
@@ -35,8 +49,19 @@ pub async fn update_ingredient_in_recipe(
     recipe_id: &Uuid,
     ingredient_id: &Uuid,
     amount: IngredientUnit,
+    user: &User,
+    expected: &ExpectedVersion,
 ) -> Result<Recipe, UpdateIngredientInRecipeError> {
-    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+    let mut uow = recipe_repo.begin().await?;
+    let recipe = recipe_repo
+        .get_by_id_for_update(&mut uow, recipe_id)
+        .await?;
+    if !recipe.is_authored_by(&user.id) {
+        return Err(UpdateIngredientInRecipeError::Forbidden(recipe.id));
+    }
+    if !expected.matches(recipe.version) {
+        return Err(UpdateIngredientInRecipeError::VersionMismatch(recipe.id));
+    }
 
     let ingredient_in_recipe = &recipe
         .ingredients
@@ -47,12 +72,23 @@ pub async fn update_ingredient_in_recipe(
         ))?;
 
     recipe_repo
-        .update_ingredient_amount(&recipe, ingredient_in_recipe, &amount)
+        .update_ingredient_amount(&mut uow, &recipe, ingredient_in_recipe, &amount)
         .await?;
 
-    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+    let updated = recipe_repo
+        .get_by_id_for_update(&mut uow, recipe_id)
+        .await?;
+    record_revision(
+        &mut uow,
+        &recipe_repo,
+        Some(&recipe),
+        &updated,
+        Some(user.id),
+    )
+    .await?;
+    uow.commit().await?;
 
-    Ok(recipe)
+    Ok(updated)
 }
 
 #[cfg(test)]
@@ -5,22 +5,25 @@ use crate::{
         commands::recipes::ingredients::update::{
             update_ingredient_in_recipe, UpdateIngredientInRecipeError,
         },
-        entities::recipe::IngredientUnit,
+        entities::{recipe::IngredientUnit, user::User, version::ExpectedVersion},
         repositories::{
             ingredients::IngredientRepository,
             recipe::{errors::GetRecipeByIdError, RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
         },
     },
-    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+    test_utils::{authored_recipe_fixture, insert_all_ingredients_of_recipe, user_fixture},
 };
 use std::sync::Arc;
 
 pub async fn updating_ingredient_in_recipe_works(
     recipe_repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let initial_recipe = recipe_fixture();
+    let initial_recipe = authored_recipe_fixture();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    user_repo.insert(user_fixture()).await.unwrap();
     recipe_repo.insert(initial_recipe.clone()).await.unwrap();
 
     let ingredient_to_update = initial_recipe.ingredients.first().unwrap();
@@ -33,6 +36,8 @@ pub async fn updating_ingredient_in_recipe_works(
         &initial_recipe.id,
         &ingredient_to_update.ingredient.id,
         amount.clone(),
+        &user_fixture(),
+        &ExpectedVersion::Any,
     )
     .await
     .unwrap();
@@ -44,7 +49,7 @@ pub async fn updating_ingredient_in_nonexistent_recipe_errors(
     recipe_repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
 ) {
-    let initial_recipe = recipe_fixture();
+    let initial_recipe = authored_recipe_fixture();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
 
     let ingredient_to_update = initial_recipe.ingredients.first().unwrap();
@@ -57,6 +62,8 @@ pub async fn updating_ingredient_in_nonexistent_recipe_errors(
         &initial_recipe.id,
         &ingredient_to_update.ingredient.id,
         amount.clone(),
+        &user_fixture(),
+        &ExpectedVersion::Any,
     )
     .await
     .unwrap_err();
@@ -69,9 +76,11 @@ pub async fn updating_ingredient_in_nonexistent_recipe_errors(
 pub async fn updating_nonexistent_ingredient_in_recipe_errors(
     recipe_repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
-    let initial_recipe = recipe_fixture();
+    let initial_recipe = authored_recipe_fixture();
     insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    user_repo.insert(user_fixture()).await.unwrap();
     recipe_repo.insert(initial_recipe.clone()).await.unwrap();
 
     let amount = IngredientUnit::Cups(2.0);
@@ -83,6 +92,8 @@ pub async fn updating_nonexistent_ingredient_in_recipe_errors(
         &initial_recipe.id,
         &Uuid::from_u128(0xff),
         amount.clone(),
+        &user_fixture(),
+        &ExpectedVersion::Any,
     )
     .await
     .unwrap_err();
@@ -91,3 +102,41 @@ pub async fn updating_nonexistent_ingredient_in_recipe_errors(
         matches!(error, UpdateIngredientInRecipeError::MissingIngredient(id) if id == Uuid::from_u128(0xff))
     )
 }
+
+pub async fn updating_ingredient_in_someone_elses_recipe_is_forbidden(
+    recipe_repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let initial_recipe = authored_recipe_fixture();
+    let stranger = User {
+        id: Uuid::from_u128(256),
+        ..user_fixture()
+    };
+    insert_all_ingredients_of_recipe(&ingredient_repo, &initial_recipe).await;
+    user_repo.insert(user_fixture()).await.unwrap();
+    recipe_repo.insert(initial_recipe.clone()).await.unwrap();
+
+    let ingredient_to_update = initial_recipe.ingredients.first().unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(recipe_repo));
+
+    let error = update_ingredient_in_recipe(
+        recipe_repo.clone(),
+        &initial_recipe.id,
+        &ingredient_to_update.ingredient.id,
+        IngredientUnit::Cups(2.0),
+        &stranger,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(error, UpdateIngredientInRecipeError::Forbidden(id) if id == initial_recipe.id)
+    );
+    assert_eq!(
+        recipe_repo.get_by_id(&initial_recipe.id).await.unwrap(),
+        initial_recipe
+    );
+}
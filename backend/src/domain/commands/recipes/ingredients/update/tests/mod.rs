@@ -4,33 +4,48 @@ mod in_memory {
     use super::__tests__;
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
     };
 
     #[tokio::test]
     async fn updating_ingredient_in_recipe_works() {
-        let recipe_repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-
-        __tests__::updating_ingredient_in_recipe_works(recipe_repo, ingredient_repo).await
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::updating_ingredient_in_recipe_works(repo, ingredient_repo, user_repo).await
     }
 
     #[tokio::test]
     async fn updating_ingredient_in_nonexistent_recipe_errors() {
-        let recipe_repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
-
-        __tests__::updating_ingredient_in_nonexistent_recipe_errors(recipe_repo, ingredient_repo)
-            .await
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::updating_ingredient_in_nonexistent_recipe_errors(repo, ingredient_repo).await
     }
 
     #[tokio::test]
     async fn updating_nonexistent_ingredient_in_recipe_errors() {
-        let recipe_repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::updating_nonexistent_ingredient_in_recipe_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
 
-        __tests__::updating_nonexistent_ingredient_in_recipe_errors(recipe_repo, ingredient_repo)
-            .await
+    #[tokio::test]
+    async fn updating_ingredient_in_someone_elses_recipe_is_forbidden() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::updating_ingredient_in_someone_elses_recipe_is_forbidden(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
     }
 }
 
@@ -40,32 +55,47 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn updating_ingredient_in_recipe_works(pool: PgPool) {
-        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-
-        __tests__::updating_ingredient_in_recipe_works(recipe_repo, ingredient_repo).await
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::updating_ingredient_in_recipe_works(repo, ingredient_repo, user_repo).await
     }
 
     #[sqlx::test]
     async fn updating_ingredient_in_nonexistent_recipe_errors(pool: PgPool) {
-        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
-
-        __tests__::updating_ingredient_in_nonexistent_recipe_errors(recipe_repo, ingredient_repo)
-            .await
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::updating_ingredient_in_nonexistent_recipe_errors(repo, ingredient_repo).await
     }
 
     #[sqlx::test]
     async fn updating_nonexistent_ingredient_in_recipe_errors(pool: PgPool) {
-        let recipe_repo = PostgresRecipeRepository::new(pool.clone());
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::updating_nonexistent_ingredient_in_recipe_errors(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
 
-        __tests__::updating_nonexistent_ingredient_in_recipe_errors(recipe_repo, ingredient_repo)
-            .await
+    #[sqlx::test]
+    async fn updating_ingredient_in_someone_elses_recipe_is_forbidden(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::updating_ingredient_in_someone_elses_recipe_is_forbidden(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
     }
 }
@@ -1,4 +1,31 @@
+pub mod claim;
 pub mod create;
 pub mod delete;
+pub mod import;
 pub mod ingredients;
+pub mod restore;
+pub mod trash;
 pub mod update;
+
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::recipe::{revision::RecipeRevision, Recipe},
+    repositories::{recipe::RecipeRepositoryService, unit_of_work::UnitOfWork},
+};
+
+/// Adds the change from `before` to `after` to the history of the recipe, as part of the
+/// unit of work that made it
+pub(crate) async fn record_revision(
+    uow: &mut UnitOfWork,
+    recipe_repo: &RecipeRepositoryService,
+    before: Option<&Recipe>,
+    after: &Recipe,
+    author_id: Option<Uuid>,
+) -> eyre::Result<()> {
+    recipe_repo
+        .insert_revision_in(uow, &RecipeRevision::new(before, after, author_id))
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        recipe::{errors::ValidationError, revision::RecipeRevision, IngredientWithAmount, Recipe},
+        user::User,
+        version::{ExpectedVersion, Version},
+    },
+    repositories::{
+        ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+        recipe::{
+            errors::{GetRecipeByIdError, RecipeRevisionError, UpdateRecipeError},
+            RecipeRepositoryService,
+        },
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum RestoreRecipeError {
+    #[error("Could not find the recipe with ID {0}")]
+    NotFound(Uuid),
+
+    #[error("The recipe with ID {0} has no revision {1}")]
+    RevisionNotFound(Uuid, Version),
+
+    #[error("You are not the author of the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} was changed in the meantime")]
+    VersionMismatch(Uuid),
+
+    #[error("The ingredients with the following IDs no longer exist: {0:?}")]
+    IngredientsNotFound(Vec<Uuid>),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for RestoreRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<RecipeRevisionError> for RestoreRecipeError {
+    fn from(value: RecipeRevisionError) -> Self {
+        match value {
+            RecipeRevisionError::NotFound(id, revision) => Self::RevisionNotFound(id, revision),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetAllIngredientsError> for RestoreRecipeError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        match value {
+            GetAllIngredientsError::MultipleIngredientsMissing(ids) => {
+                Self::IngredientsNotFound(ids)
+            }
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<UpdateRecipeError> for RestoreRecipeError {
+    fn from(value: UpdateRecipeError) -> Self {
+        match value {
+            UpdateRecipeError::VersionMismatch(id) => Self::VersionMismatch(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+/// Rolls the recipe back to how it was at the given revision, which adds a new revision
+pub async fn restore_recipe_revision(
+    recipe_repo: RecipeRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    recipe_id: &Uuid,
+    revision: Version,
+    user: &User,
+    expected: &ExpectedVersion,
+) -> Result<Recipe, RestoreRecipeError> {
+    let mut uow = recipe_repo.begin().await?;
+    let recipe = recipe_repo
+        .get_by_id_for_update(&mut uow, recipe_id)
+        .await?;
+    if !recipe.is_authored_by(&user.id) {
+        return Err(RestoreRecipeError::Forbidden(recipe.id));
+    }
+    if !expected.matches(recipe.version) {
+        return Err(RestoreRecipeError::VersionMismatch(recipe.id));
+    }
+
+    let snapshot = recipe_repo
+        .get_revision(recipe_id, revision)
+        .await?
+        .snapshot;
+
+    // The amounts come from the revision, but the ingredients themselves are the current ones
+    let ids: Vec<Uuid> = snapshot
+        .ingredients
+        .iter()
+        .map(|i| i.ingredient_id)
+        .collect();
+    let ingredients = ingredient_repo.get_all_by_id(&mut uow, &ids).await?;
+    let ingredients: Vec<IngredientWithAmount> = snapshot
+        .ingredients
+        .into_iter()
+        .map(|i| {
+            let ingredient = ingredients
+                .iter()
+                .find(|ingredient| ingredient.id == i.ingredient_id)
+                .cloned()
+                .ok_or(RestoreRecipeError::IngredientsNotFound(vec![
+                    i.ingredient_id,
+                ]))?;
+
+            Ok(IngredientWithAmount {
+                ingredient,
+                amount: i.amount,
+                notes: i.notes,
+                optional: i.optional,
+            })
+        })
+        .collect::<Result<_, RestoreRecipeError>>()?;
+
+    let replacement = Recipe {
+        name: snapshot.name,
+        description: snapshot.description,
+        steps: snapshot.steps.try_into()?,
+        ingredients: ingredients.try_into()?,
+        time: snapshot.time,
+        servings: snapshot.servings,
+        public: snapshot.public,
+        ..recipe.clone()
+    };
+    recipe_repo.replace(&mut uow, &recipe, &replacement).await?;
+
+    let restored = recipe_repo
+        .get_by_id_for_update(&mut uow, recipe_id)
+        .await?;
+    recipe_repo
+        .insert_revision_in(
+            &mut uow,
+            &RecipeRevision::new(Some(&recipe), &restored, Some(user.id)).restored_from(revision),
+        )
+        .await?;
+    uow.commit().await?;
+
+    Ok(restored)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,152 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::recipes::{
+            create::create_recipe,
+            restore::{restore_recipe_revision, RestoreRecipeError},
+            update::update_recipe,
+        },
+        entities::{
+            recipe::{revision::RecipeChange, Recipe},
+            user::User,
+            version::{ExpectedVersion, Version},
+        },
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{
+        authored_recipe_fixture, insert_all_ingredients_of_recipe, recipe_changeset, user_fixture,
+    },
+};
+
+/// Creates the authored recipe fixture and updates it, so it has two revisions
+async fn create_and_update(
+    recipe_repo: &RecipeRepositoryService,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) -> (Recipe, IngredientRepositoryService) {
+    let user = user_fixture();
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user.clone()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let created = create_recipe(recipe_repo.clone(), ingredient_repo.clone(), &recipe.into())
+        .await
+        .unwrap();
+    update_recipe(
+        recipe_repo.clone(),
+        &created.id,
+        recipe_changeset(),
+        &user,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap();
+
+    (created, ingredient_repo)
+}
+
+pub async fn restoring_a_revision_rolls_the_recipe_back(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let (created, ingredient_repo) =
+        create_and_update(&recipe_repo, ingredient_repo, user_repo).await;
+
+    let result = restore_recipe_revision(
+        recipe_repo.clone(),
+        ingredient_repo,
+        &created.id,
+        Version(1),
+        &user_fixture(),
+        &ExpectedVersion::OneOf(vec![Version(2)]),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        result,
+        Recipe {
+            version: Version(3),
+            ..created.clone()
+        }
+    );
+
+    let revisions = recipe_repo.list_revisions(&created.id).await.unwrap();
+    assert_eq!(
+        revisions.iter().map(|r| r.revision).collect::<Vec<_>>(),
+        vec![Version(3), Version(2), Version(1)]
+    );
+    assert_eq!(revisions[0].restored_from, Some(Version(1)));
+    assert_eq!(revisions[0].author_id, Some(user_fixture().id));
+    assert!(revisions[0].changes.contains(&RecipeChange::Name {
+        from: "WE UPDATED THIS THING".to_string(),
+        to: created.name,
+    }));
+    assert_eq!(revisions[0].snapshot, revisions[2].snapshot);
+}
+
+pub async fn restoring_a_missing_revision_fails(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let (created, ingredient_repo) =
+        create_and_update(&recipe_repo, ingredient_repo, user_repo).await;
+
+    let result = restore_recipe_revision(
+        recipe_repo,
+        ingredient_repo,
+        &created.id,
+        Version(5),
+        &user_fixture(),
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(
+        matches!(result, RestoreRecipeError::RevisionNotFound(id, revision) if id == created.id && revision == Version(5))
+    );
+}
+
+pub async fn restoring_someone_elses_recipe_is_forbidden(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let (created, ingredient_repo) =
+        create_and_update(&recipe_repo, ingredient_repo, user_repo).await;
+    let stranger = User {
+        id: uuid::Uuid::from_u128(256),
+        ..user_fixture()
+    };
+
+    let result = restore_recipe_revision(
+        recipe_repo.clone(),
+        ingredient_repo,
+        &created.id,
+        Version(1),
+        &stranger,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, RestoreRecipeError::Forbidden(id) if id == created.id));
+    assert_eq!(
+        recipe_repo.get_by_id(&created.id).await.unwrap().version,
+        Version(2)
+    );
+}
@@ -0,0 +1,55 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::user::User,
+    repositories::recipe::{
+        errors::{DeleteRecipeError, GetRecipeByIdError},
+        RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum PurgeDeletedRecipeError {
+    #[error("There is no recipe with ID {0} in the trash")]
+    NotFound(Uuid),
+
+    #[error("You are not the author of the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for PurgeDeletedRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<DeleteRecipeError> for PurgeDeletedRecipeError {
+    fn from(value: DeleteRecipeError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Removes the recipe from the trash for good, there's no restoring it afterwards
+pub async fn purge_deleted_recipe(
+    recipe_repo: RecipeRepositoryService,
+    recipe_id: &Uuid,
+    user: &User,
+) -> Result<(), PurgeDeletedRecipeError> {
+    let recipe = recipe_repo.get_deleted_by_id(recipe_id).await?.item;
+    if !recipe.is_authored_by(&user.id) {
+        return Err(PurgeDeletedRecipeError::Forbidden(recipe.id));
+    }
+
+    recipe_repo.purge(&recipe).await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::recipes::trash::purge::{purge_deleted_recipe, PurgeDeletedRecipeError},
+        entities::{recipe::revision::RecipeRevision, version::Version},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{
+                errors::{GetRecipeByIdError, RecipeRevisionError},
+                RecipeRepository, RecipeRepositoryService,
+            },
+            users::UserRepository,
+        },
+    },
+    test_utils::{authored_recipe_fixture, insert_all_ingredients_of_recipe, user_fixture},
+};
+
+pub async fn purging_a_deleted_recipe_removes_it_for_good(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    repo.insert_revision(&RecipeRevision::new(None, &recipe, recipe.author_id))
+        .await
+        .unwrap();
+    repo.delete(&recipe).await.unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    purge_deleted_recipe(repo.clone(), &recipe.id, &user_fixture())
+        .await
+        .unwrap();
+
+    assert!(matches!(
+        repo.get_deleted_by_id(&recipe.id).await.unwrap_err(),
+        GetRecipeByIdError::NotFound(_)
+    ));
+    assert!(matches!(
+        repo.get_revision(&recipe.id, Version(1)).await.unwrap_err(),
+        RecipeRevisionError::NotFound(_, _)
+    ));
+}
+
+pub async fn purging_a_recipe_that_is_not_deleted_fails(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let error = purge_deleted_recipe(repo.clone(), &recipe.id, &user_fixture())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, PurgeDeletedRecipeError::NotFound(id) if id == recipe.id));
+    assert!(repo.get_by_id(&recipe.id).await.is_ok());
+}
@@ -0,0 +1,79 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{recipe::Recipe, user::User},
+    repositories::{
+        ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+        recipe::{
+            errors::{GetRecipeByIdError, UpdateRecipeError},
+            RecipeRepositoryService,
+        },
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum RestoreDeletedRecipeError {
+    #[error("There is no recipe with ID {0} in the trash")]
+    NotFound(Uuid),
+
+    #[error("You are not the author of the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The ingredients with the following IDs are in the trash, restore them first: {0:?}")]
+    IngredientsDeleted(Vec<Uuid>),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for RestoreDeletedRecipeError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetAllIngredientsError> for RestoreDeletedRecipeError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        match value {
+            GetAllIngredientsError::MultipleIngredientsMissing(ids) => {
+                Self::IngredientsDeleted(ids)
+            }
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<UpdateRecipeError> for RestoreDeletedRecipeError {
+    fn from(value: UpdateRecipeError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Takes the recipe out of the trash, as long as none of its ingredients are in there
+pub async fn restore_deleted_recipe(
+    recipe_repo: RecipeRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    recipe_id: &Uuid,
+    user: &User,
+) -> Result<Recipe, RestoreDeletedRecipeError> {
+    let recipe = recipe_repo.get_deleted_by_id(recipe_id).await?.item;
+    if !recipe.is_authored_by(&user.id) {
+        return Err(RestoreDeletedRecipeError::Forbidden(recipe.id));
+    }
+
+    let ids: Vec<Uuid> = recipe.ingredients.iter().map(|i| i.ingredient.id).collect();
+    // Keeps the ingredients out of the trash until the recipe is out of it
+    let mut uow = ingredient_repo.begin().await?;
+    ingredient_repo.get_all_by_id(&mut uow, &ids).await?;
+
+    recipe_repo.restore(&recipe).await?;
+    uow.commit().await?;
+
+    Ok(recipe_repo.get_by_id(recipe_id).await?)
+}
+
+#[cfg(test)]
+mod tests;
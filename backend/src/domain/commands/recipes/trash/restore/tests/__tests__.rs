@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::recipes::trash::restore::{restore_deleted_recipe, RestoreDeletedRecipeError},
+        entities::user::User,
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{authored_recipe_fixture, insert_all_ingredients_of_recipe, user_fixture},
+};
+
+pub async fn restoring_a_deleted_recipe_brings_it_back(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    repo.delete(&recipe).await.unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let restored =
+        restore_deleted_recipe(repo.clone(), ingredient_repo, &recipe.id, &user_fixture())
+            .await
+            .unwrap();
+
+    assert_eq!(restored, recipe);
+    assert_eq!(repo.get_by_id(&recipe.id).await.unwrap(), recipe);
+    assert!(repo.list_deleted(None).await.unwrap().is_empty());
+}
+
+pub async fn restoring_a_recipe_with_deleted_ingredients_conflicts(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    repo.delete(&recipe).await.unwrap();
+
+    let deleted_ingredient = recipe.ingredients.first().unwrap().ingredient.clone();
+    ingredient_repo
+        .delete(deleted_ingredient.clone())
+        .await
+        .unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let error = restore_deleted_recipe(repo.clone(), ingredient_repo, &recipe.id, &user_fixture())
+        .await
+        .unwrap_err();
+
+    assert!(matches!(
+        error,
+        RestoreDeletedRecipeError::IngredientsDeleted(ids) if ids == vec![deleted_ingredient.id]
+    ));
+    assert!(repo.get_by_id(&recipe.id).await.is_err());
+}
+
+pub async fn restoring_someone_elses_deleted_recipe_is_forbidden(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    repo.delete(&recipe).await.unwrap();
+
+    let stranger = User {
+        id: uuid::Uuid::from_u128(256),
+        ..user_fixture()
+    };
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let error = restore_deleted_recipe(repo, ingredient_repo, &recipe.id, &stranger)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, RestoreDeletedRecipeError::Forbidden(id) if id == recipe.id));
+}
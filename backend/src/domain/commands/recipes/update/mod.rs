@@ -1,10 +1,12 @@
-use std::collections::BTreeMap;
-
 use common::{ServingsTypeDTO, UpdateRecipeDTO};
 use uuid::Uuid;
 
+use crate::domain::commands::recipes::record_revision;
 use crate::domain::entities::recipe::errors::ValidationError;
-use crate::domain::entities::recipe::{Recipe, RecipeChangeset};
+use crate::domain::entities::recipe::time::RecipeTime;
+use crate::domain::entities::recipe::{Recipe, RecipeChangeset, RecipeStep};
+use crate::domain::entities::user::User;
+use crate::domain::entities::version::ExpectedVersion;
 use crate::domain::repositories::recipe::errors::{
     GetRecipeByIdError, UpdateRecipeError as UpdateRecipeErrorInternal,
 };
@@ -18,6 +20,12 @@ pub enum UpdateRecipeError {
     #[error("Could not find the ingredient with ID {0}")]
     NotFound(Uuid),
 
+    #[error("You are not the author of the recipe with ID {0}")]
+    Forbidden(Uuid),
+
+    #[error("The recipe with ID {0} was changed in the meantime")]
+    VersionMismatch(Uuid),
+
     #[error(transparent)]
     ValidationError(#[from] ValidationError),
 
@@ -27,7 +35,10 @@ pub enum UpdateRecipeError {
 
 impl From<UpdateRecipeErrorInternal> for UpdateRecipeError {
     fn from(value: UpdateRecipeErrorInternal) -> Self {
-        Self::Unknown(value.into())
+        match value {
+            UpdateRecipeErrorInternal::VersionMismatch(id) => Self::VersionMismatch(id),
+            e => Self::Unknown(e.into()),
+        }
     }
 }
 
@@ -45,9 +56,10 @@ impl From<GetRecipeByIdError> for UpdateRecipeError {
 pub struct UpdateRecipe {
     pub name: Option<String>,
     pub description: Option<String>,
-    pub steps: Option<Vec<String>>,
-    pub time: Option<BTreeMap<String, std::time::Duration>>,
+    pub steps: Option<Vec<RecipeStep>>,
+    pub time: Option<RecipeTime>,
     pub servings: Option<ServingsTypeDTO>,
+    pub public: Option<bool>,
 }
 
 impl TryFrom<UpdateRecipe> for RecipeChangeset {
@@ -62,24 +74,24 @@ impl TryFrom<UpdateRecipe> for RecipeChangeset {
             name: value.name,
             servings: value.servings.map(|s| s.into()),
             description: value.description,
+            public: value.public,
         })
     }
 }
 
-impl From<UpdateRecipeDTO> for UpdateRecipe {
-    fn from(value: UpdateRecipeDTO) -> Self {
-        Self {
+impl TryFrom<UpdateRecipeDTO> for UpdateRecipe {
+    type Error = ValidationError;
+    fn try_from(value: UpdateRecipeDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
             name: value.name,
-            time: value.time.map(|times| {
-                times
-                    .into_iter()
-                    .map(|(k, v)| (k, std::time::Duration::from_secs(v)))
-                    .collect()
-            }),
+            time: value.time.map(TryInto::try_into).transpose()?,
             description: value.description,
-            steps: value.steps,
+            steps: value
+                .steps
+                .map(|steps| steps.into_iter().map(Into::into).collect()),
             servings: value.servings,
-        }
+            public: value.public,
+        })
     }
 }
 
@@ -87,22 +99,40 @@ pub async fn update_recipe(
     recipe_repo: RecipeRepositoryService,
     input: &Uuid,
     update: UpdateRecipe,
+    user: &User,
+    expected: &ExpectedVersion,
 ) -> Result<Recipe, UpdateRecipeError> {
     let changeset: RecipeChangeset = update.try_into()?;
     if changeset.is_empty() {
         return Err(UpdateRecipeError::ChangesetEmpty);
     };
 
-    let recipe = recipe_repo.get_by_id(input).await?;
+    let mut uow = recipe_repo.begin().await?;
+    let recipe = recipe_repo.get_by_id_for_update(&mut uow, input).await?;
+    if !recipe.is_authored_by(&user.id) {
+        return Err(UpdateRecipeError::Forbidden(recipe.id));
+    }
+    if !expected.matches(recipe.version) {
+        return Err(UpdateRecipeError::VersionMismatch(recipe.id));
+    }
+    if let Some(steps) = &changeset.steps {
+        steps.check_ingredients(&recipe.ingredients)?;
+    }
 
-    recipe_repo.update(&recipe, changeset).await?;
+    recipe_repo.update(&mut uow, &recipe, changeset).await?;
 
-    let recipe = recipe_repo
-        .get_by_id(input)
-        .await
-        .map_err(UpdateRecipeError::from)?;
+    let updated = recipe_repo.get_by_id_for_update(&mut uow, input).await?;
+    record_revision(
+        &mut uow,
+        &recipe_repo,
+        Some(&recipe),
+        &updated,
+        Some(user.id),
+    )
+    .await?;
+    uow.commit().await?;
 
-    Ok(recipe)
+    Ok(updated)
 }
 
 #[cfg(test)]
@@ -1,28 +1,42 @@
-use std::collections::BTreeMap;
 use std::sync::Arc;
 use std::time::Duration;
 
 use crate::domain::commands::recipes::update::{update_recipe, UpdateRecipe, UpdateRecipeError};
 use crate::domain::entities::recipe::{Recipe, ServingsType};
+use crate::domain::entities::user::User;
+use crate::domain::entities::version::ExpectedVersion;
 use crate::domain::repositories::ingredients::IngredientRepository;
 
+use crate::domain::repositories::recipe::errors::UpdateRecipeError as UpdateRecipeErrorInternal;
 use crate::domain::repositories::recipe::{RecipeRepository, RecipeRepositoryService};
-use crate::test_utils::{insert_all_ingredients_of_recipe, recipe_changeset, recipe_fixture};
+use crate::domain::repositories::users::UserRepository;
+use crate::test_utils::{
+    authored_recipe_fixture, insert_all_ingredients_of_recipe, recipe_changeset, user_fixture,
+};
 
 pub async fn updating_a_recipe_succeeds(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let recipe = recipe_fixture();
+    let user = user_fixture();
+    let recipe = authored_recipe_fixture();
     let changeset = recipe_changeset();
+    user_repo.insert(user.clone()).await.unwrap();
     insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
 
     recipe_repo.insert(recipe.clone()).await.unwrap();
 
-    let result = update_recipe(recipe_repo, &recipe.id, changeset)
-        .await
-        .unwrap();
+    let result = update_recipe(
+        recipe_repo,
+        &recipe.id,
+        changeset,
+        &user,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap();
 
     assert_eq!(
         result,
@@ -32,8 +46,11 @@ pub async fn updating_a_recipe_succeeds(
             steps: vec!["WE UPDATED ANOTHER THING".to_string()]
                 .try_into()
                 .unwrap(),
-            time: BTreeMap::from([("Prep time".to_string(), Duration::from_secs(60))]),
+            time: [("Prep time", Duration::from_secs(60))]
+                .into_iter()
+                .collect(),
             servings: ServingsType::Exact(4),
+            version: recipe.version.next(),
             ..recipe
         }
     );
@@ -42,15 +59,21 @@ pub async fn updating_a_recipe_succeeds(
 pub async fn updating_a_nonexistent_recipe_fails(repo: impl RecipeRepository) {
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
 
-    let recipe = recipe_fixture();
+    let recipe = authored_recipe_fixture();
     let changeset = UpdateRecipe {
         name: Some("WE UPDATED THIS THING".to_string()),
         ..Default::default()
     };
 
-    let result = update_recipe(recipe_repo, &recipe.id, changeset)
-        .await
-        .unwrap_err();
+    let result = update_recipe(
+        recipe_repo,
+        &recipe.id,
+        changeset,
+        &user_fixture(),
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap_err();
 
     assert!(matches!(result, UpdateRecipeError::NotFound(id) if id == recipe.id))
 }
@@ -58,19 +81,115 @@ pub async fn updating_a_nonexistent_recipe_fails(repo: impl RecipeRepository) {
 pub async fn updating_a_recipe_with_empty_changeset_errors(
     repo: impl RecipeRepository,
     ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
 ) {
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
 
-    let recipe = recipe_fixture();
+    let user = user_fixture();
+    let recipe = authored_recipe_fixture();
     let changeset = UpdateRecipe {
         ..Default::default()
     };
+    user_repo.insert(user.clone()).await.unwrap();
     insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
     recipe_repo.insert(recipe.clone()).await.unwrap();
 
-    let result = update_recipe(recipe_repo, &recipe.id, changeset)
+    let result = update_recipe(
+        recipe_repo,
+        &recipe.id,
+        changeset,
+        &user,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, UpdateRecipeError::ChangesetEmpty))
+}
+
+pub async fn updating_someone_elses_recipe_is_forbidden(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let recipe = authored_recipe_fixture();
+    let stranger = User {
+        id: uuid::Uuid::from_u128(256),
+        ..user_fixture()
+    };
+    user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let result = update_recipe(
+        recipe_repo.clone(),
+        &recipe.id,
+        recipe_changeset(),
+        &stranger,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, UpdateRecipeError::Forbidden(id) if id == recipe.id));
+    assert_eq!(recipe_repo.get_by_id(&recipe.id).await.unwrap(), recipe);
+}
+
+pub async fn updating_with_stale_version_fails(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let user = user_fixture();
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user.clone()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+
+    let expected = ExpectedVersion::OneOf(vec![recipe.version.next()]);
+    let result = update_recipe(
+        recipe_repo.clone(),
+        &recipe.id,
+        recipe_changeset(),
+        &user,
+        &expected,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, UpdateRecipeError::VersionMismatch(id) if id == recipe.id));
+    assert_eq!(recipe_repo.get_by_id(&recipe.id).await.unwrap(), recipe);
+}
+
+pub async fn updating_an_outdated_recipe_conflicts(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let user = user_fixture();
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user.clone()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    update_recipe(
+        recipe_repo.clone(),
+        &recipe.id,
+        recipe_changeset(),
+        &user,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap();
+
+    let mut uow = recipe_repo.begin().await.unwrap();
+    let result = recipe_repo
+        .update(&mut uow, &recipe, recipe_changeset().try_into().unwrap())
         .await
         .unwrap_err();
 
-    assert!(matches!(result, UpdateRecipeError::ChangesetEmpty))
+    assert!(matches!(result, UpdateRecipeErrorInternal::VersionMismatch(id) if id == recipe.id));
 }
@@ -2,7 +2,7 @@ mod __tests__;
 mod in_memory {
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
     };
 
     use super::__tests__;
@@ -11,7 +11,8 @@ mod in_memory {
     async fn updating_a_recipe_succeeds() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::updating_a_recipe_succeeds(repo, ingredient_repo).await
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_a_recipe_succeeds(repo, ingredient_repo, user_repo).await
     }
 
     #[tokio::test]
@@ -24,7 +25,34 @@ mod in_memory {
     async fn updating_a_recipe_with_empty_changeset_does_nothing() {
         let ingredient_repo = InMemoryIngredientRepository::new();
         let repo = InMemoryRecipeRepository::new();
-        __tests__::updating_a_recipe_with_empty_changeset_errors(repo, ingredient_repo).await
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_a_recipe_with_empty_changeset_errors(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn updating_someone_elses_recipe_is_forbidden() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_someone_elses_recipe_is_forbidden(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[tokio::test]
+    async fn updating_with_stale_version_fails() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_with_stale_version_fails(repo, ingredient_repo, user_repo).await
+    }
+
+    #[tokio::test]
+    async fn updating_an_outdated_recipe_conflicts() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::updating_an_outdated_recipe_conflicts(repo, ingredient_repo, user_repo).await
     }
 }
 
@@ -34,14 +62,15 @@ mod sql {
 
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn updating_a_recipe_succeeds(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
         let repo = PostgresRecipeRepository::new(pool);
-        __tests__::updating_a_recipe_succeeds(repo, ingredient_repo).await
+        __tests__::updating_a_recipe_succeeds(repo, ingredient_repo, user_repo).await
     }
 
     #[sqlx::test]
@@ -53,7 +82,34 @@ mod sql {
     #[sqlx::test]
     async fn updating_a_recipe_with_empty_changeset_does_nothing(pool: PgPool) {
         let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::updating_a_recipe_with_empty_changeset_errors(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn updating_someone_elses_recipe_is_forbidden(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::updating_someone_elses_recipe_is_forbidden(repo, ingredient_repo, user_repo)
+            .await
+    }
+
+    #[sqlx::test]
+    async fn updating_with_stale_version_fails(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::updating_with_stale_version_fails(repo, ingredient_repo, user_repo).await
+    }
+
+    #[sqlx::test]
+    async fn updating_an_outdated_recipe_conflicts(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
         let repo = PostgresRecipeRepository::new(pool);
-        __tests__::updating_a_recipe_with_empty_changeset_errors(repo, ingredient_repo).await
+        __tests__::updating_an_outdated_recipe_conflicts(repo, ingredient_repo, user_repo).await
     }
 }
@@ -0,0 +1,51 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::repositories::{
+    ingredients::IngredientRepositoryService, recipe::RecipeRepositoryService,
+};
+
+/// How many recipes and ingredients were purged from the trash
+#[derive(PartialEq, Debug, Default, Clone, Copy)]
+pub struct PurgedTrash {
+    pub recipes: usize,
+    pub ingredients: usize,
+}
+
+/// Purges everything that was deleted before the cutoff. The recipes go first, as an ingredient
+/// can't be purged while a recipe in the trash still uses it, so those ingredients stay until it's gone.
+#[tracing::instrument(
+    "[COMMAND] Purging expired items from the trash",
+    skip(ingredient_repo, recipe_repo)
+)]
+pub async fn purge_expired_trash(
+    ingredient_repo: IngredientRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    cutoff: DateTime<Utc>,
+) -> eyre::Result<PurgedTrash> {
+    let mut purged = PurgedTrash::default();
+
+    for trashed in recipe_repo.list_deleted(None).await? {
+        if trashed.is_expired(cutoff) {
+            recipe_repo.purge(&trashed.item).await?;
+            purged.recipes += 1;
+        }
+    }
+
+    for trashed in ingredient_repo.list_deleted().await? {
+        if !trashed.is_expired(cutoff)
+            || recipe_repo
+                .trashed_recipes_containing_ingredient_exist(&trashed.item)
+                .await?
+        {
+            continue;
+        }
+
+        ingredient_repo.purge(&trashed.item).await?;
+        purged.ingredients += 1;
+    }
+
+    Ok(purged)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,93 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::trash::purge_expired::{purge_expired_trash, PurgedTrash},
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+        },
+    },
+    test_utils::{ingredient_fixture, insert_all_ingredients_of_recipe, recipe_fixture},
+};
+
+pub async fn only_expired_items_are_purged(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    let ingredient = ingredient_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    ingredient_repo.insert(ingredient.clone()).await.unwrap();
+    repo.insert(recipe.clone()).await.unwrap();
+    repo.delete(&recipe).await.unwrap();
+    ingredient_repo.delete(ingredient.clone()).await.unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let purged = purge_expired_trash(
+        ingredient_repo.clone(),
+        repo.clone(),
+        Utc::now() - Duration::days(30),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(purged, PurgedTrash::default());
+    assert_eq!(repo.list_deleted(None).await.unwrap().len(), 1);
+    assert_eq!(ingredient_repo.list_deleted().await.unwrap().len(), 1);
+
+    let purged = purge_expired_trash(
+        ingredient_repo.clone(),
+        repo.clone(),
+        Utc::now() + Duration::minutes(1),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        purged,
+        PurgedTrash {
+            recipes: 1,
+            ingredients: 1
+        }
+    );
+    assert!(repo.list_deleted(None).await.unwrap().is_empty());
+    assert!(ingredient_repo.list_deleted().await.unwrap().is_empty());
+}
+
+pub async fn ingredients_of_recipes_still_in_the_trash_are_kept(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    repo.insert(recipe.clone()).await.unwrap();
+    let ingredient = recipe.ingredients.first().unwrap().ingredient.clone();
+    ingredient_repo.delete(ingredient.clone()).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+    repo.delete(&recipe).await.unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    // Only the ingredient is old enough, but the recipe still needs it in case it gets restored
+    let trashed_recipe = repo.get_deleted_by_id(&recipe.id).await.unwrap();
+    let purged = purge_expired_trash(
+        ingredient_repo.clone(),
+        repo.clone(),
+        trashed_recipe.deleted_at,
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(purged, PurgedTrash::default());
+    assert!(ingredient_repo
+        .get_deleted_by_id(&ingredient.id)
+        .await
+        .is_ok());
+}
@@ -0,0 +1,47 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn only_expired_items_are_purged() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::only_expired_items_are_purged(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn ingredients_of_recipes_still_in_the_trash_are_kept() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::ingredients_of_recipes_still_in_the_trash_are_kept(repo, ingredient_repo).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn only_expired_items_are_purged(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::only_expired_items_are_purged(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn ingredients_of_recipes_still_in_the_trash_are_kept(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::ingredients_of_recipes_still_in_the_trash_are_kept(repo, ingredient_repo).await
+    }
+}
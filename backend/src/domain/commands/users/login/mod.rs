@@ -0,0 +1,60 @@
+use crate::domain::{
+    entities::{session::Session, user::User},
+    repositories::{
+        sessions::{errors::InsertSessionError, SessionStoreService},
+        users::{errors::GetUserByUsernameError, UserRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum LoginError {
+    #[error("Could not find person")]
+    NotFound(String),
+
+    #[error("Wrong password")]
+    WrongPassword,
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetUserByUsernameError> for LoginError {
+    fn from(value: GetUserByUsernameError) -> Self {
+        match value {
+            GetUserByUsernameError::NotFound(username) => Self::NotFound(username),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<InsertSessionError> for LoginError {
+    fn from(value: InsertSessionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[derive(Debug)]
+pub struct Login<'a> {
+    pub username: &'a str,
+    pub password: &'a str,
+}
+
+#[tracing::instrument("[COMMAND] Logging in", skip(user_repo, session_store, input), fields(username = input.username))]
+pub async fn login(
+    user_repo: UserRepositoryService,
+    session_store: SessionStoreService,
+    input: &Login<'_>,
+) -> Result<(User, Session), LoginError> {
+    let user = user_repo.get_by_username(input.username.trim()).await?;
+
+    if !user.password_hash.verify(input.password) {
+        return Err(LoginError::WrongPassword);
+    }
+
+    let session = session_store.insert(Session::new(user.id)).await?;
+
+    Ok((user, session))
+}
+
+#[cfg(test)]
+mod tests;
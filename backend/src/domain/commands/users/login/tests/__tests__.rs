@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::users::login::{login, Login, LoginError},
+        repositories::{
+            sessions::{SessionStore, SessionStoreService},
+            users::{UserRepository, UserRepositoryService},
+        },
+    },
+    test_utils::{user_fixture, USER_FIXTURE_PASSWORD},
+};
+
+pub async fn logging_in_works(repo: impl UserRepository, session_store: impl SessionStore) {
+    let user = user_fixture();
+    repo.insert(user.clone()).await.unwrap();
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+    let session_store: SessionStoreService = Arc::new(Box::new(session_store));
+
+    let (logged_in, session) = login(
+        repo,
+        session_store.clone(),
+        &Login {
+            username: &user.username,
+            password: USER_FIXTURE_PASSWORD,
+        },
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(logged_in, user);
+    assert_eq!(session.user_id, user.id);
+    assert!(!session.is_expired());
+    assert_eq!(session_store.get(&session.token).await.unwrap(), session);
+}
+
+pub async fn logging_in_with_a_wrong_password_fails(
+    repo: impl UserRepository,
+    session_store: impl SessionStore,
+) {
+    let user = user_fixture();
+    repo.insert(user.clone()).await.unwrap();
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+    let session_store: SessionStoreService = Arc::new(Box::new(session_store));
+
+    let error = login(
+        repo,
+        session_store,
+        &Login {
+            username: &user.username,
+            password: "not the password",
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, LoginError::WrongPassword));
+}
+
+pub async fn logging_in_as_a_nonexistent_user_fails(
+    repo: impl UserRepository,
+    session_store: impl SessionStore,
+) {
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+    let session_store: SessionStoreService = Arc::new(Box::new(session_store));
+
+    let error = login(
+        repo,
+        session_store,
+        &Login {
+            username: "idontexistyet",
+            password: "meaningless",
+        },
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(error, LoginError::NotFound(username) if username == "idontexistyet"));
+}
@@ -0,0 +1,60 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        sessions::in_memory::InMemorySessionStore, users::in_memory::InMemoryUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn logging_in_works() {
+        let repo = InMemoryUserRepository::new();
+        let session_store = InMemorySessionStore::new();
+        __tests__::logging_in_works(repo, session_store).await
+    }
+
+    #[tokio::test]
+    async fn logging_in_with_a_wrong_password_fails() {
+        let repo = InMemoryUserRepository::new();
+        let session_store = InMemorySessionStore::new();
+        __tests__::logging_in_with_a_wrong_password_fails(repo, session_store).await
+    }
+
+    #[tokio::test]
+    async fn logging_in_as_a_nonexistent_user_fails() {
+        let repo = InMemoryUserRepository::new();
+        let session_store = InMemorySessionStore::new();
+        __tests__::logging_in_as_a_nonexistent_user_fails(repo, session_store).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::{
+        sessions::in_memory::InMemorySessionStore, users::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn logging_in_works(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        let session_store = InMemorySessionStore::new();
+        __tests__::logging_in_works(repo, session_store).await
+    }
+
+    #[sqlx::test]
+    async fn logging_in_with_a_wrong_password_fails(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        let session_store = InMemorySessionStore::new();
+        __tests__::logging_in_with_a_wrong_password_fails(repo, session_store).await
+    }
+
+    #[sqlx::test]
+    async fn logging_in_as_a_nonexistent_user_fails(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        let session_store = InMemorySessionStore::new();
+        __tests__::logging_in_as_a_nonexistent_user_fails(repo, session_store).await
+    }
+}
@@ -0,0 +1,19 @@
+use crate::domain::repositories::sessions::{errors::DeleteSessionError, SessionStoreService};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum LogoutError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<DeleteSessionError> for LogoutError {
+    fn from(value: DeleteSessionError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument("[COMMAND] Logging out", skip_all)]
+pub async fn logout(session_store: SessionStoreService, token: &str) -> Result<(), LogoutError> {
+    session_store.delete(token).await?;
+    Ok(())
+}
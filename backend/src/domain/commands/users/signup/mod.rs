@@ -0,0 +1,57 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::user::{errors::ValidationError, types::PasswordHash, User},
+    repositories::users::{errors::InsertUserError, UserRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum SignupError {
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+
+    #[error("A user with this {0} already exists")]
+    Conflict(String),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<InsertUserError> for SignupError {
+    fn from(value: InsertUserError) -> Self {
+        match value {
+            InsertUserError::Conflict(field) => Self::Conflict(field),
+            InsertUserError::ValidationError(e) => Self::Validation(e),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct Signup<'a> {
+    pub username: &'a str,
+    pub email: &'a str,
+    pub password: &'a str,
+}
+
+impl<'a> TryFrom<&Signup<'a>> for User {
+    type Error = ValidationError;
+    fn try_from(value: &Signup<'a>) -> Result<Self, Self::Error> {
+        Ok(User {
+            id: Uuid::now_v7(),
+            username: value.username.try_into()?,
+            email: value.email.try_into()?,
+            password_hash: PasswordHash::new(value.password)?,
+        })
+    }
+}
+
+#[tracing::instrument("[COMMAND] Signing up a new user", skip(repo, input), fields(username = input.username))]
+pub async fn signup(repo: UserRepositoryService, input: &Signup<'_>) -> Result<User, SignupError> {
+    let user = User::try_from(input)?;
+    let user = repo.insert(user).await?;
+    Ok(user)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,97 @@
+use std::sync::Arc;
+
+use crate::{
+    domain::{
+        commands::users::signup::{signup, Signup, SignupError},
+        entities::user::errors::ValidationError,
+        repositories::users::{UserRepository, UserRepositoryService},
+    },
+    test_utils::user_fixture,
+};
+
+pub async fn signing_up_works(repo: impl UserRepository) {
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+    let given = Signup {
+        username: "newuser",
+        email: "newuser@example.com",
+        password: "hunter2",
+    };
+
+    let user = signup(repo.clone(), &given).await.unwrap();
+
+    assert_eq!(user.username.as_str(), "newuser");
+    assert_eq!(user.email.as_str(), "newuser@example.com");
+    assert_ne!(user.password_hash.as_str(), "hunter2");
+    assert!(user.password_hash.verify("hunter2"));
+
+    let persisted = repo.get_by_username("newuser").await.unwrap();
+    assert_eq!(persisted, user);
+}
+
+pub async fn signing_up_with_a_taken_username_fails(repo: impl UserRepository) {
+    let existing = user_fixture();
+    repo.insert(existing.clone()).await.unwrap();
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    let given = Signup {
+        username: &existing.username,
+        email: "someoneelse@example.com",
+        password: "hunter2",
+    };
+
+    let error = signup(repo, &given).await.unwrap_err();
+
+    assert!(matches!(error, SignupError::Conflict(field) if field == "username"));
+}
+
+pub async fn signing_up_with_a_taken_email_fails(repo: impl UserRepository) {
+    let existing = user_fixture();
+    repo.insert(existing.clone()).await.unwrap();
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    let given = Signup {
+        username: "someoneelse",
+        email: &existing.email,
+        password: "hunter2",
+    };
+
+    let error = signup(repo, &given).await.unwrap_err();
+
+    assert!(matches!(error, SignupError::Conflict(field) if field == "email"));
+}
+
+pub async fn signing_up_with_invalid_data_fails(repo: impl UserRepository) {
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+
+    let error = signup(
+        repo.clone(),
+        &Signup {
+            username: "newuser",
+            email: "not an e-mail",
+            password: "hunter2",
+        },
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        SignupError::Validation(ValidationError::InvalidEmail(_))
+    ));
+
+    let error = signup(
+        repo.clone(),
+        &Signup {
+            username: "newuser",
+            email: "newuser@example.com",
+            password: "",
+        },
+    )
+    .await
+    .unwrap_err();
+    assert!(matches!(
+        error,
+        SignupError::Validation(ValidationError::EmptyField(fields)) if fields == ["password"]
+    ));
+
+    assert!(repo.get_by_username("newuser").await.is_err());
+}
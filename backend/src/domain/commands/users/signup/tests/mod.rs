@@ -0,0 +1,62 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::users::in_memory::InMemoryUserRepository;
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn signing_up_works() {
+        let repo = InMemoryUserRepository::new();
+        __tests__::signing_up_works(repo).await
+    }
+
+    #[tokio::test]
+    async fn signing_up_with_a_taken_username_fails() {
+        let repo = InMemoryUserRepository::new();
+        __tests__::signing_up_with_a_taken_username_fails(repo).await
+    }
+
+    #[tokio::test]
+    async fn signing_up_with_a_taken_email_fails() {
+        let repo = InMemoryUserRepository::new();
+        __tests__::signing_up_with_a_taken_email_fails(repo).await
+    }
+
+    #[tokio::test]
+    async fn signing_up_with_invalid_data_fails() {
+        let repo = InMemoryUserRepository::new();
+        __tests__::signing_up_with_invalid_data_fails(repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::users::postgres::PostgresUserRepository;
+
+    #[sqlx::test]
+    async fn signing_up_works(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        __tests__::signing_up_works(repo).await
+    }
+
+    #[sqlx::test]
+    async fn signing_up_with_a_taken_username_fails(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        __tests__::signing_up_with_a_taken_username_fails(repo).await
+    }
+
+    #[sqlx::test]
+    async fn signing_up_with_a_taken_email_fails(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        __tests__::signing_up_with_a_taken_email_fails(repo).await
+    }
+
+    #[sqlx::test]
+    async fn signing_up_with_invalid_data_fails(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        __tests__::signing_up_with_invalid_data_fails(repo).await
+    }
+}
@@ -0,0 +1,124 @@
+use unicode_normalization::{char::is_combining_mark, UnicodeNormalization};
+
+use super::errors::ValidationError;
+
+pub const DEFAULT_AUTOCOMPLETE_LIMIT: u64 = 10;
+
+/// Lowercases the text, strips accents and collapses whitespace, so names compare the way people type them
+/// ```rust
+/// use crate::backend::domain::entities::ingredient::autocomplete::normalize;
+///
+/// assert_eq!(normalize("  Jalapeño   PEPPER "), "jalapeno pepper");
+/// ```
+pub fn normalize(text: &str) -> String {
+    text.nfd()
+        .filter(|c| !is_combining_mark(*c))
+        .collect::<String>()
+        .to_lowercase()
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// The fewest single-character edits turning the query into the beginning of the text
+/// ```rust
+/// use crate::backend::domain::entities::ingredient::autocomplete::prefix_distance;
+///
+/// assert_eq!(prefix_distance("tom", "tomato"), 0);
+/// assert_eq!(prefix_distance("tomatoe", "tomato"), 1);
+/// assert_eq!(prefix_distance("chedar", "cheddar cheese"), 1);
+/// ```
+pub fn prefix_distance(query: &str, text: &str) -> usize {
+    let query: Vec<char> = query.chars().collect();
+    // Distances between every beginning of the query and the part of the text read so far
+    let mut row: Vec<usize> = (0..=query.len()).collect();
+    let mut best = row[query.len()];
+
+    for t in text.chars() {
+        let mut diagonal = row[0];
+        row[0] += 1;
+        for (i, q) in query.iter().enumerate() {
+            let above = row[i + 1];
+            row[i + 1] = (diagonal + usize::from(*q != t))
+                .min(above + 1)
+                .min(row[i] + 1);
+            diagonal = above;
+        }
+        best = best.min(row[query.len()]);
+    }
+
+    best
+}
+
+/// Short queries have to be typed correctly, longer ones can contain a typo or two
+pub fn allowed_typos(query: &str) -> usize {
+    match query.chars().count() {
+        0..=3 => 0,
+        4..=7 => 1,
+        _ => 2,
+    }
+}
+
+/// What was typed into an ingredient picker, normalized
+#[derive(Debug, Clone, PartialEq)]
+pub struct AutocompleteText(String);
+
+impl AutocompleteText {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl TryFrom<&str> for AutocompleteText {
+    type Error = ValidationError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let text = normalize(value);
+
+        if text.is_empty() {
+            Err(ValidationError::EmptyField(vec!["q"]))
+        } else {
+            Ok(Self(text))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngredientAutocompleteQuery {
+    pub text: AutocompleteText,
+    pub limit: u64,
+}
+
+impl IngredientAutocompleteQuery {
+    /// How well the name matches the text, from 0 to 1, or nothing if it doesn't match at all.
+    /// Names starting with the text come first, then names with a word starting with it,
+    /// then names containing it anywhere, then names close enough to it.
+    pub fn score(&self, name: &str) -> Option<f64> {
+        let name = normalize(name);
+        let text = self.text.as_str();
+
+        if name.starts_with(text) {
+            return Some(1.0);
+        }
+
+        let words: Vec<&str> = std::iter::once(0)
+            .chain(name.match_indices(' ').map(|(i, _)| i + 1))
+            .map(|i| &name[i..])
+            .collect();
+
+        if words.iter().any(|word| word.starts_with(text)) {
+            return Some(0.9);
+        }
+        if name.contains(text) {
+            return Some(0.8);
+        }
+
+        let distance = words
+            .iter()
+            .map(|word| prefix_distance(text, word))
+            .min()
+            .unwrap_or(usize::MAX);
+        let length = text.chars().count();
+
+        (distance <= allowed_typos(text)).then(|| 0.7 * (1.0 - distance as f64 / length as f64))
+    }
+}
@@ -6,6 +6,12 @@ pub enum ValidationError {
     #[error("Field {0} does not match any of: {1:?}")]
     DoesNotMatch(&'static str, &'static [&'static str]),
 
+    #[error("Field {0} has to be a positive number")]
+    NotPositive(&'static str),
+
+    #[error("Field {0} has unknown values: {1:?}")]
+    UnknownValues(&'static str, Vec<String>),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Error),
 }
@@ -1,26 +1,49 @@
+pub mod autocomplete;
 pub mod errors;
 pub mod types;
 
-use common::IngredientDTO;
-use sqlx::FromRow;
+use common::{
+    IngredientDTO, IngredientListQueryDTO, IngredientPageDTO, IngredientSubstituteDTO,
+    IngredientWithVariantsDTO,
+};
+use sqlx::{
+    error::BoxDynError,
+    postgres::{types::PgRecordDecoder, PgValueRef},
+    FromRow, Postgres,
+};
 use uuid::Uuid;
 
+use super::{
+    pagination::{page_size, Page, Pagination},
+    version::Version,
+};
+
 use self::{
     errors::ValidationError,
-    types::{DietFriendly, IngredientDescription, IngredientName, WhichDiets},
+    types::{
+        Allergens, DietFriendly, DietViolations, IngredientDensity, IngredientDescription,
+        IngredientName, SubstituteRatio, WhichDiets,
+    },
 };
 
-// TODO: Consider ingredients that are variants of other ingredients
-// i.e.: soy sauce (dark, light)
-
-// TODO: Include possible substitutes
-#[derive(FromRow, Debug, Clone, PartialEq, Eq)]
+#[derive(FromRow, Debug, Clone, PartialEq)]
 pub struct Ingredient {
     pub id: Uuid,
     pub name: IngredientName,
     pub description: IngredientDescription,
-    // TODO: change to diet_violations for easier filtering
-    pub diet_friendly: WhichDiets,
+    /// What the ingredient contains that some diets don't allow, the diets it fits follow from it
+    pub diet_violations: DietViolations,
+    pub allergens: Allergens,
+    pub density: Option<IngredientDensity>,
+    /// The ingredient this one is a variant of, i.e. soy sauce for dark soy sauce
+    pub parent_id: Option<Uuid>,
+    pub version: Version,
+}
+
+impl Ingredient {
+    pub fn diet_friendly(&self) -> WhichDiets {
+        self.diet_violations.diet_friendly()
+    }
 }
 
 impl From<Ingredient> for IngredientDTO {
@@ -29,7 +52,11 @@ impl From<Ingredient> for IngredientDTO {
             id: value.id,
             name: value.name.to_string(),
             description: value.description.to_string(),
-            diet_friendly: value.diet_friendly.clone().into(),
+            diet_friendly: value.diet_friendly().into(),
+            diet_violations: value.diet_violations.clone().into(),
+            allergens: value.allergens.clone().into(),
+            density: value.density.map(|d| *d),
+            parent_id: value.parent_id,
         }
     }
 }
@@ -40,17 +67,43 @@ impl From<&Ingredient> for IngredientDTO {
             id: value.id,
             name: value.name.to_string(),
             description: value.description.to_string(),
-            diet_friendly: value.diet_friendly.clone().into(),
+            diet_friendly: value.diet_friendly().into(),
+            diet_violations: value.diet_violations.clone().into(),
+            allergens: value.allergens.clone().into(),
+            density: value.density.map(|d| *d),
+            parent_id: value.parent_id,
         }
     }
 }
 
-#[derive(FromRow, Debug, Clone, PartialEq, sqlx::Decode)]
+#[derive(FromRow, Debug, Clone, PartialEq)]
 pub struct IngredientModel {
     pub id: Uuid,
     pub name: String,
     pub description: String,
-    pub diet_friendly: Vec<String>,
+    pub diet_violations: Vec<String>,
+    pub allergens: Vec<String>,
+    pub density: Option<f64>,
+    pub parent_id: Option<Uuid>,
+    pub version: i32,
+}
+
+// Written by hand, because the derived implementation can't decode optional UUIDs
+impl<'r> sqlx::Decode<'r, Postgres> for IngredientModel {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let mut decoder = PgRecordDecoder::new(value)?;
+
+        Ok(Self {
+            id: decoder.try_decode()?,
+            name: decoder.try_decode()?,
+            description: decoder.try_decode()?,
+            diet_violations: decoder.try_decode()?,
+            allergens: decoder.try_decode()?,
+            density: decoder.try_decode()?,
+            parent_id: decoder.try_decode()?,
+            version: decoder.try_decode()?,
+        })
+    }
 }
 
 impl TryFrom<&IngredientModel> for Ingredient {
@@ -60,7 +113,11 @@ impl TryFrom<&IngredientModel> for Ingredient {
             id: value.id,
             name: value.name.clone().try_into()?,
             description: value.description.clone().try_into()?,
-            diet_friendly: value.diet_friendly.clone().into(),
+            diet_violations: value.diet_violations.clone().try_into()?,
+            allergens: value.allergens.clone().try_into()?,
+            density: value.density.map(IngredientDensity::try_from).transpose()?,
+            parent_id: value.parent_id,
+            version: Version(value.version),
         })
     }
 }
@@ -88,26 +145,30 @@ impl From<Ingredient> for IngredientModel {
             id,
             name,
             description,
-            diet_friendly,
+            diet_violations,
+            allergens,
+            density,
+            parent_id,
+            version,
         }: Ingredient,
     ) -> Self {
         Self {
             id,
             name: name.to_string(),
             description: description.to_string(),
-            diet_friendly: diet_friendly.into(),
+            diet_violations: diet_violations.into(),
+            allergens: allergens.into(),
+            density: density.map(|d| *d),
+            parent_id,
+            version: *version,
         }
     }
 }
 
-impl From<IngredientModel> for common::IngredientDTO {
-    fn from(value: IngredientModel) -> Self {
-        Self {
-            id: value.id,
-            name: value.name.to_string(),
-            description: value.description.to_string(),
-            diet_friendly: value.diet_friendly,
-        }
+impl TryFrom<IngredientModel> for common::IngredientDTO {
+    type Error = ValidationError;
+    fn try_from(value: IngredientModel) -> Result<Self, Self::Error> {
+        Ok(Ingredient::try_from(value)?.into())
     }
 }
 
@@ -115,5 +176,158 @@ impl From<IngredientModel> for common::IngredientDTO {
 pub struct IngredientChangeset {
     pub name: Option<IngredientName>,
     pub description: Option<IngredientDescription>,
-    pub diet_friendly: Option<WhichDiets>,
+    pub diet_violations: Option<DietViolations>,
+    pub allergens: Option<Allergens>,
+    pub density: Option<IngredientDensity>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngredientWithVariants {
+    pub ingredient: Ingredient,
+    pub variants: Vec<Ingredient>,
+}
+
+impl From<IngredientWithVariants> for IngredientWithVariantsDTO {
+    fn from(value: IngredientWithVariants) -> Self {
+        Self {
+            ingredient: value.ingredient.into(),
+            variants: value
+                .variants
+                .into_iter()
+                .map(IngredientDTO::from)
+                .collect(),
+        }
+    }
+}
+
+/// An ingredient that can be used in place of another one
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngredientSubstitute {
+    pub ingredient: Ingredient,
+    pub ratio: SubstituteRatio,
+    /// Unit the ratio gives the substitute in, i.e. grams for 60 g of applesauce per egg.
+    /// Without one, the substitute is measured in the unit of the ingredient it replaces.
+    pub unit: Option<String>,
+    /// When and how to substitute, i.e. "only for baking"
+    pub notes: Option<String>,
+}
+
+impl From<IngredientSubstitute> for IngredientSubstituteDTO {
+    fn from(value: IngredientSubstitute) -> Self {
+        Self {
+            ingredient: value.ingredient.into(),
+            ratio: *value.ratio,
+            unit: value.unit,
+            notes: value.notes,
+        }
+    }
+}
+
+#[derive(FromRow, Debug, Clone, PartialEq)]
+pub struct IngredientSubstituteModel {
+    pub ingredient: IngredientModel,
+    pub ratio: f64,
+    pub unit: Option<String>,
+    pub notes: Option<String>,
+}
+
+impl TryFrom<IngredientSubstituteModel> for IngredientSubstitute {
+    type Error = ValidationError;
+    fn try_from(value: IngredientSubstituteModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            ingredient: value.ingredient.try_into()?,
+            ratio: value.ratio.try_into()?,
+            unit: value.unit,
+            notes: value.notes,
+        })
+    }
+}
+
+#[derive(Debug, Default, Clone, PartialEq)]
+pub struct IngredientListQuery {
+    /// Case-insensitive substring of the ingredient name
+    pub name: Option<String>,
+    /// Case-insensitive beginning of the ingredient name
+    pub name_prefix: Option<String>,
+    /// Diets every returned ingredient has to be compatible with
+    pub diet_friendly: WhichDiets,
+    /// Only ingredients used in at least one recipe outside of the trash
+    pub used_in_recipes: bool,
+    /// Also return the variants of every ingredient that matched the filters
+    pub include_variants: bool,
+}
+
+impl IngredientListQuery {
+    /// Whether the ingredient matches the filters on its own, disregarding variants and whether
+    /// it's used in recipes, which only the recipes know
+    pub fn matches(&self, ingredient: &Ingredient) -> bool {
+        let name = ingredient.name.to_lowercase();
+        let matches_name = self
+            .name
+            .as_ref()
+            .is_none_or(|substring| name.contains(&substring.to_lowercase()));
+        let matches_prefix = self
+            .name_prefix
+            .as_ref()
+            .is_none_or(|prefix| name.starts_with(&prefix.to_lowercase()));
+
+        matches_name && matches_prefix && ingredient.diet_violations.allows_all(&self.diet_friendly)
+    }
+}
+
+impl TryFrom<IngredientListQueryDTO> for IngredientListQuery {
+    type Error = ValidationError;
+    fn try_from(value: IngredientListQueryDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            name: value.name.filter(|name| !name.trim().is_empty()),
+            name_prefix: value.name_prefix.filter(|prefix| !prefix.trim().is_empty()),
+            diet_friendly: value
+                .diet_friendly
+                .map(|diets| diets.parse())
+                .transpose()?
+                .unwrap_or_default(),
+            used_in_recipes: value.used_in_recipes.unwrap_or(false),
+            include_variants: value.include_variants.unwrap_or(false),
+        })
+    }
+}
+
+/// A page of ingredients matching the filters, ordered by name
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngredientPageQuery {
+    pub filters: IngredientListQuery,
+    pub pagination: Pagination,
+    pub limit: u64,
+}
+
+impl Default for IngredientPageQuery {
+    fn default() -> Self {
+        Self {
+            filters: IngredientListQuery::default(),
+            pagination: Pagination::default(),
+            limit: page_size(None),
+        }
+    }
+}
+
+impl TryFrom<IngredientListQueryDTO> for IngredientPageQuery {
+    type Error = ValidationError;
+    fn try_from(value: IngredientListQueryDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pagination: Pagination::new(value.offset, value.cursor),
+            limit: page_size(value.limit),
+            filters: value.try_into()?,
+        })
+    }
+}
+
+impl From<Page<Ingredient>> for IngredientPageDTO {
+    fn from(value: Page<Ingredient>) -> Self {
+        Self {
+            items: value.items.iter().map(IngredientDTO::from).collect(),
+            total: value.total,
+            limit: value.limit,
+            next_cursor: value.next_cursor,
+        }
+    }
 }
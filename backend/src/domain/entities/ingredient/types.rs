@@ -2,7 +2,7 @@ use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
 use shrinkwraprs::Shrinkwrap;
-use strum::{Display, EnumString, VariantNames};
+use strum::{Display, EnumIter, EnumString, IntoEnumIterator, VariantNames};
 
 use super::errors::ValidationError;
 
@@ -62,12 +62,85 @@ impl std::fmt::Display for IngredientDescription {
     }
 }
 
+/// Density of an ingredient in grams per mililiter
+#[derive(Serialize, Deserialize, Shrinkwrap, Debug, Clone, Copy, PartialEq)]
+pub struct IngredientDensity(pub f64);
+
+/// ```rust
+/// use crate::backend::domain::entities::ingredient::types::IngredientDensity;
+///
+/// assert!(IngredientDensity::try_from(0.59).is_ok());
+/// assert!(IngredientDensity::try_from(0.0).is_err());
+/// ```
+impl TryFrom<f64> for IngredientDensity {
+    type Error = ValidationError;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() || value <= 0.0 {
+            return Err(ValidationError::NotPositive("density"));
+        }
+        Ok(Self(value))
+    }
+}
+
+/// How much of a substitute to use per one unit of the ingredient it replaces
+#[derive(Serialize, Deserialize, Shrinkwrap, Debug, Clone, Copy, PartialEq)]
+pub struct SubstituteRatio(pub f64);
+
+/// ```rust
+/// use crate::backend::domain::entities::ingredient::types::SubstituteRatio;
+///
+/// assert!(SubstituteRatio::try_from(60.0).is_ok());
+/// assert!(SubstituteRatio::try_from(-1.0).is_err());
+/// ```
+impl TryFrom<f64> for SubstituteRatio {
+    type Error = ValidationError;
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() || value <= 0.0 {
+            return Err(ValidationError::NotPositive("ratio"));
+        }
+        Ok(Self(value))
+    }
+}
+
+/// Something an ingredient contains that some diets don't allow
+#[derive(
+    Serialize,
+    Deserialize,
+    VariantNames,
+    EnumString,
+    EnumIter,
+    Display,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Copy,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum DietViolation {
+    Meat,
+    Pork,
+    Fish,
+    Shellfish,
+    Dairy,
+    Eggs,
+    Honey,
+    Gluten,
+    Nuts,
+    Alcohol,
+    /// Sugar, grains, starchy vegetables and the like
+    HighCarb,
+}
+
 #[derive(
     Serialize,
     Deserialize,
     VariantNames,
     sqlx::Type,
     EnumString,
+    EnumIter,
     Display,
     Debug,
     PartialEq,
@@ -79,24 +152,167 @@ impl std::fmt::Display for IngredientDescription {
 pub enum DietFriendly {
     Vegan,
     Vegetarian,
+    Pescatarian,
+    Halal,
+    KosherStyle,
+    Keto,
     GlutenFree,
 }
 
+impl DietFriendly {
+    /// The diets that existed before ingredients declared their violations
+    pub const LEGACY: [DietFriendly; 3] = [Self::Vegan, Self::Vegetarian, Self::GlutenFree];
+
+    /// What an ingredient must not contain to fit the diet
+    pub fn violations(&self) -> &'static [DietViolation] {
+        use DietViolation::*;
+
+        match self {
+            Self::Vegan => &[Meat, Pork, Fish, Shellfish, Dairy, Eggs, Honey],
+            Self::Vegetarian => &[Meat, Pork, Fish, Shellfish],
+            Self::Pescatarian => &[Meat, Pork],
+            Self::Halal => &[Pork, Alcohol],
+            Self::KosherStyle => &[Pork, Shellfish],
+            Self::Keto => &[HighCarb],
+            Self::GlutenFree => &[Gluten],
+        }
+    }
+}
+
 impl TryFrom<String> for DietFriendly {
     type Error = ValidationError;
     fn try_from(value: String) -> Result<Self, Self::Error> {
-        match value.to_lowercase().as_str() {
-            "vegan" => Ok(Self::Vegan),
-            "vegetarian" => Ok(Self::Vegetarian),
-            "gluten_free" => Ok(Self::GlutenFree),
-            _ => Err(ValidationError::DoesNotMatch(
-                "diet_friendly",
-                Self::VARIANTS,
-            )),
+        Self::from_str(&value.to_lowercase())
+            .map_err(|_| ValidationError::DoesNotMatch("diet_friendly", Self::VARIANTS))
+    }
+}
+
+/// Everything an ingredient contains that some diets don't allow, sorted and without duplicates
+#[derive(Serialize, Deserialize, Shrinkwrap, PartialEq, Eq, Clone, Debug, Default)]
+pub struct DietViolations(Vec<DietViolation>);
+
+impl DietViolations {
+    pub fn new() -> Self {
+        Self(vec![])
+    }
+
+    /// Every violation there is, so an ingredient nobody said anything about fits no diet
+    pub fn all() -> Self {
+        Self(DietViolation::iter().collect())
+    }
+
+    /// ```rust
+    /// use crate::backend::domain::entities::ingredient::types::{
+    ///     DietFriendly, DietViolation, DietViolations,
+    /// };
+    ///
+    /// let violations = DietViolations::from(vec![DietViolation::Dairy]);
+    /// assert!(violations.allows(&DietFriendly::Vegetarian));
+    /// assert!(!violations.allows(&DietFriendly::Vegan));
+    /// ```
+    pub fn allows(&self, diet: &DietFriendly) -> bool {
+        !diet.violations().iter().any(|v| self.contains(v))
+    }
+
+    pub fn allows_all(&self, diets: &WhichDiets) -> bool {
+        diets.iter().all(|diet| self.allows(diet))
+    }
+
+    /// Every diet that none of the violations rule out
+    pub fn diet_friendly(&self) -> WhichDiets {
+        DietFriendly::iter()
+            .filter(|diet| self.allows(diet))
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Infers violations from the diets an ingredient was said to fit, before it could declare them.
+    ///
+    /// The list only ever described the legacy diets, so every one of those missing from it
+    /// contributes all of its violations, except for the ones that a listed diet allows.
+    /// It never said anything about alcohol or carbs, so those are assumed to be there.
+    /// ```rust
+    /// use crate::backend::domain::entities::ingredient::types::{
+    ///     DietFriendly, DietViolation, DietViolations,
+    /// };
+    ///
+    /// let violations = DietViolations::from_diet_friendly(&vec![DietFriendly::Vegetarian].into());
+    /// assert_eq!(
+    ///     violations,
+    ///     vec![
+    ///         DietViolation::Dairy,
+    ///         DietViolation::Eggs,
+    ///         DietViolation::Honey,
+    ///         DietViolation::Gluten,
+    ///         DietViolation::Alcohol,
+    ///         DietViolation::HighCarb,
+    ///     ]
+    ///     .into()
+    /// );
+    /// ```
+    pub fn from_diet_friendly(diets: &WhichDiets) -> Self {
+        DietFriendly::LEGACY
+            .iter()
+            .filter(|diet| !diets.contains(diet))
+            .flat_map(|diet| diet.violations())
+            .filter(|violation| {
+                !diets
+                    .iter()
+                    .any(|diet| diet.violations().contains(violation))
+            })
+            .copied()
+            .chain([DietViolation::Alcohol, DietViolation::HighCarb])
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+impl DietViolations {
+    /// Takes the violations a client declared, or infers them from the diets it said the
+    /// ingredient fits if it only sent those, like clients did before violations existed
+    pub fn from_input(
+        diet_violations: Option<Vec<String>>,
+        diet_friendly: Option<Vec<String>>,
+    ) -> Result<Option<Self>, ValidationError> {
+        match (diet_violations, diet_friendly) {
+            (Some(violations), _) => Ok(Some(violations.try_into()?)),
+            (None, Some(diets)) => Ok(Some(Self::from_diet_friendly(&diets.into_iter().collect()))),
+            (None, None) => Ok(None),
         }
     }
 }
 
+impl From<Vec<DietViolation>> for DietViolations {
+    fn from(mut value: Vec<DietViolation>) -> Self {
+        value.sort();
+        value.dedup();
+        Self(value)
+    }
+}
+
+impl TryFrom<Vec<String>> for DietViolations {
+    type Error = ValidationError;
+
+    /// Unknown violations are rejected, so a typo doesn't make an ingredient fit a diet
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        value
+            .iter()
+            .map(|v| {
+                DietViolation::from_str(&v.trim().to_lowercase()).map_err(|_| {
+                    ValidationError::DoesNotMatch("diet_violations", DietViolation::VARIANTS)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl From<DietViolations> for Vec<String> {
+    fn from(val: DietViolations) -> Self {
+        val.0.iter().map(|v| v.to_string()).collect()
+    }
+}
+
 #[derive(
     Serialize, Deserialize, Shrinkwrap, sqlx::Type, sqlx::FromRow, PartialEq, Eq, Clone, Debug,
 )]
@@ -106,6 +322,34 @@ impl WhichDiets {
     pub fn new() -> Self {
         Self(vec![])
     }
+
+    pub fn all() -> Self {
+        Self(DietFriendly::iter().collect())
+    }
+
+    /// Keeps only the diets that are also in `other`
+    pub fn intersect(&self, other: &WhichDiets) -> Self {
+        Self(
+            self.0
+                .iter()
+                .filter(|diet| other.contains(diet))
+                .copied()
+                .collect(),
+        )
+    }
+
+    pub fn is_superset_of(&self, other: &WhichDiets) -> bool {
+        other.iter().all(|diet| self.contains(diet))
+    }
+
+    /// Everything that at least one of the diets doesn't allow
+    pub fn violations(&self) -> DietViolations {
+        self.iter()
+            .flat_map(|diet| diet.violations())
+            .copied()
+            .collect::<Vec<_>>()
+            .into()
+    }
 }
 
 impl Default for WhichDiets {
@@ -114,6 +358,32 @@ impl Default for WhichDiets {
     }
 }
 
+impl FromStr for WhichDiets {
+    type Err = ValidationError;
+
+    /// Parses a comma-separated list of diets. Unknown diets are rejected, so a typo doesn't
+    /// silently remove a filter.
+    /// ```rust
+    /// use crate::backend::domain::entities::ingredient::types::{DietFriendly, WhichDiets};
+    ///
+    /// let diets: WhichDiets = "vegan, gluten_free".parse().unwrap();
+    /// assert_eq!(diets, vec![DietFriendly::Vegan, DietFriendly::GlutenFree].into());
+    /// assert!("vegan,paleo".parse::<WhichDiets>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        s.split(',')
+            .map(str::trim)
+            .filter(|d| !d.is_empty())
+            .map(|d| {
+                DietFriendly::from_str(d).map_err(|_| {
+                    ValidationError::DoesNotMatch("diet_friendly", DietFriendly::VARIANTS)
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self)
+    }
+}
+
 // TODO: add doctest
 impl From<Vec<String>> for WhichDiets {
     fn from(value: Vec<String>) -> Self {
@@ -149,3 +419,143 @@ impl PartialEq<DietFriendly> for String {
         self == &other.to_string()
     }
 }
+
+/// One of the 14 allergens that EU food law requires to be declared
+#[derive(
+    Serialize,
+    Deserialize,
+    VariantNames,
+    EnumString,
+    EnumIter,
+    Display,
+    Debug,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Clone,
+    Copy,
+)]
+#[strum(serialize_all = "snake_case")]
+pub enum Allergen {
+    /// Cereals containing gluten, i.e. wheat, rye or barley
+    Gluten,
+    Crustaceans,
+    Eggs,
+    Fish,
+    Peanuts,
+    Soybeans,
+    Milk,
+    /// Tree nuts, i.e. almonds, hazelnuts or walnuts
+    Nuts,
+    Celery,
+    Mustard,
+    Sesame,
+    /// Sulphur dioxide and sulphites
+    Sulphites,
+    Lupin,
+    Molluscs,
+}
+
+/// The allergens an ingredient contains, sorted and without duplicates
+#[derive(Serialize, Deserialize, Shrinkwrap, PartialEq, Eq, Clone, Debug, Default)]
+pub struct Allergens(Vec<Allergen>);
+
+impl Allergens {
+    pub fn new() -> Self {
+        Self(vec![])
+    }
+
+    /// ```rust
+    /// use crate::backend::domain::entities::ingredient::types::{Allergen, Allergens};
+    ///
+    /// let allergens = Allergens::from(vec![Allergen::Milk, Allergen::Eggs]);
+    /// assert!(allergens.contains_any(&vec![Allergen::Eggs, Allergen::Nuts].into()));
+    /// assert!(!allergens.contains_any(&vec![Allergen::Nuts].into()));
+    /// ```
+    pub fn contains_any(&self, other: &Allergens) -> bool {
+        other.iter().any(|allergen| self.contains(allergen))
+    }
+
+    /// Every allergen that is in either of the sets
+    pub fn union(&self, other: &Allergens) -> Self {
+        self.iter()
+            .chain(other.iter())
+            .copied()
+            .collect::<Vec<_>>()
+            .into()
+    }
+
+    /// Every allergen that isn't in `other`
+    pub fn difference(&self, other: &Allergens) -> Self {
+        self.iter()
+            .filter(|allergen| !other.contains(allergen))
+            .copied()
+            .collect::<Vec<_>>()
+            .into()
+    }
+}
+
+impl From<Vec<Allergen>> for Allergens {
+    fn from(mut value: Vec<Allergen>) -> Self {
+        value.sort();
+        value.dedup();
+        Self(value)
+    }
+}
+
+impl TryFrom<Vec<String>> for Allergens {
+    type Error = ValidationError;
+
+    /// Unlike diets, unknown allergens are rejected, so a typo doesn't hide an allergen
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        value
+            .iter()
+            .map(|a| {
+                Allergen::from_str(&a.trim().to_lowercase())
+                    .map_err(|_| ValidationError::DoesNotMatch("allergens", Allergen::VARIANTS))
+            })
+            .collect::<Result<Vec<_>, _>>()
+            .map(Self::from)
+    }
+}
+
+impl FromStr for Allergens {
+    type Err = ValidationError;
+
+    /// Parses a comma-separated list of allergens. Unknown allergens are rejected and listed,
+    /// so a typo doesn't silently let an allergen through.
+    /// ```rust
+    /// use crate::backend::domain::entities::ingredient::types::{Allergen, Allergens};
+    ///
+    /// let allergens: Allergens = "peanuts, milk".parse().unwrap();
+    /// assert_eq!(allergens, vec![Allergen::Milk, Allergen::Peanuts].into());
+    /// assert!("peanuts,chocolate".parse::<Allergens>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (known, unknown): (Vec<_>, Vec<_>) = s
+            .split(',')
+            .map(str::trim)
+            .filter(|a| !a.is_empty())
+            .map(|a| Allergen::from_str(&a.to_lowercase()).map_err(|_| a.to_string()))
+            .partition(Result::is_ok);
+
+        if !unknown.is_empty() {
+            return Err(ValidationError::UnknownValues(
+                "exclude_allergens",
+                unknown.into_iter().filter_map(Result::err).collect(),
+            ));
+        }
+        Ok(known
+            .into_iter()
+            .filter_map(Result::ok)
+            .collect::<Vec<_>>()
+            .into())
+    }
+}
+
+impl From<Allergens> for Vec<String> {
+    fn from(val: Allergens) -> Self {
+        val.0.iter().map(|a| a.to_string()).collect()
+    }
+}
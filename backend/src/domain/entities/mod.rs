@@ -1,2 +1,7 @@
 pub mod ingredient;
+pub mod pagination;
 pub mod recipe;
+pub mod session;
+pub mod trash;
+pub mod user;
+pub mod version;
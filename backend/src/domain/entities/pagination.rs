@@ -0,0 +1,117 @@
+use common::SortOrderDTO;
+use uuid::Uuid;
+
+pub const DEFAULT_PAGE_SIZE: u64 = 20;
+pub const MAX_PAGE_SIZE: u64 = 100;
+
+#[derive(strum::Display, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    #[strum(serialize = "asc")]
+    Ascending,
+    #[strum(serialize = "desc")]
+    Descending,
+}
+
+impl From<SortOrderDTO> for SortOrder {
+    fn from(value: SortOrderDTO) -> Self {
+        match value {
+            SortOrderDTO::Asc => Self::Ascending,
+            SortOrderDTO::Desc => Self::Descending,
+        }
+    }
+}
+
+/// Where a page starts: either after skipping a number of items,
+/// or right after the item with the given ID (keyset pagination).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pagination {
+    Offset(u64),
+    After(Uuid),
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self::Offset(0)
+    }
+}
+
+impl Pagination {
+    pub fn new(offset: Option<u64>, cursor: Option<Uuid>) -> Self {
+        match cursor {
+            Some(id) => Self::After(id),
+            None => Self::Offset(offset.unwrap_or_default()),
+        }
+    }
+
+    pub fn offset(&self) -> u64 {
+        match self {
+            Self::Offset(offset) => *offset,
+            Self::After(_) => 0,
+        }
+    }
+
+    pub fn cursor(&self) -> Option<Uuid> {
+        match self {
+            Self::Offset(_) => None,
+            Self::After(id) => Some(*id),
+        }
+    }
+}
+
+/// Clamps the requested page size to `1..=MAX_PAGE_SIZE`
+/// ```rust
+/// use crate::backend::domain::entities::pagination::{page_size, DEFAULT_PAGE_SIZE, MAX_PAGE_SIZE};
+///
+/// assert_eq!(page_size(None), DEFAULT_PAGE_SIZE);
+/// assert_eq!(page_size(Some(0)), 1);
+/// assert_eq!(page_size(Some(1000)), MAX_PAGE_SIZE);
+/// ```
+pub fn page_size(limit: Option<u64>) -> u64 {
+    limit.unwrap_or(DEFAULT_PAGE_SIZE).clamp(1, MAX_PAGE_SIZE)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    pub total: u64,
+    pub limit: u64,
+    /// ID of the last item on this page, if there are more items after it
+    pub next_cursor: Option<Uuid>,
+}
+
+impl<T> Page<T> {
+    /// Builds a page out of `limit + 1` fetched items - the extra item only tells us
+    /// whether there is a next page, and is dropped.
+    pub fn from_overfetched(
+        mut items: Vec<T>,
+        total: u64,
+        limit: u64,
+        get_id: impl Fn(&T) -> Uuid,
+    ) -> Self {
+        let has_more = items.len() as u64 > limit;
+        items.truncate(limit as usize);
+
+        let next_cursor = if has_more {
+            items.last().map(get_id)
+        } else {
+            None
+        };
+
+        Self {
+            items,
+            total,
+            limit,
+            next_cursor,
+        }
+    }
+
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> Page<U> {
+        Page {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+            limit: self.limit,
+            next_cursor: self.next_cursor,
+        }
+    }
+}
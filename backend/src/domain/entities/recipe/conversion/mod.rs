@@ -0,0 +1,315 @@
+use common::MeasurementSystemDTO;
+use strum::EnumString;
+
+use crate::domain::entities::ingredient::types::IngredientDensity;
+
+use super::IngredientUnit;
+
+const MILILITERS_PER_LITER: f64 = 1000.0;
+const MILILITERS_PER_TEASPOON: f64 = 4.928_921_593_75;
+const MILILITERS_PER_TABLESPOON: f64 = MILILITERS_PER_TEASPOON * 3.0;
+const MILILITERS_PER_FLUID_OUNCE: f64 = MILILITERS_PER_TABLESPOON * 2.0;
+const MILILITERS_PER_CUP: f64 = MILILITERS_PER_FLUID_OUNCE * 8.0;
+const GRAMS_PER_KILOGRAM: f64 = 1000.0;
+const GRAMS_PER_OUNCE: f64 = 28.349_523_125;
+const GRAMS_PER_POUND: f64 = GRAMS_PER_OUNCE * 16.0;
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ConversionError {
+    #[error("Unit {0} cannot be converted to any other unit")]
+    UnknownUnit(String),
+
+    #[error("Converting between volume and mass requires the ingredient's density")]
+    MissingDensity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MeasurementSystem {
+    Metric,
+    Imperial,
+}
+
+impl From<MeasurementSystemDTO> for MeasurementSystem {
+    fn from(value: MeasurementSystemDTO) -> Self {
+        match value {
+            MeasurementSystemDTO::Metric => Self::Metric,
+            MeasurementSystemDTO::Imperial => Self::Imperial,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Dimension {
+    Volume,
+    Mass,
+}
+
+/// A unit of measurement without an amount
+/// ```rust
+/// use std::str::FromStr;
+/// use crate::backend::domain::entities::recipe::conversion::Unit;
+///
+/// assert_eq!(Unit::from_str("tbsp"), Ok(Unit::Tablespoons));
+/// assert_eq!(Unit::from_str("Fl Oz"), Ok(Unit::FluidOunces));
+/// assert!(Unit::from_str("clove").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, EnumString)]
+#[strum(ascii_case_insensitive)]
+pub enum Unit {
+    #[strum(serialize = "ml", serialize = "mililiter", serialize = "mililiters")]
+    #[strum(serialize = "milliliter", serialize = "milliliters")]
+    #[strum(serialize = "millilitre", serialize = "millilitres")]
+    Mililiters,
+    #[strum(serialize = "l", serialize = "liter", serialize = "liters")]
+    #[strum(serialize = "litre", serialize = "litres")]
+    Liters,
+    #[strum(serialize = "tsp", serialize = "teaspoon", serialize = "teaspoons")]
+    Teaspoons,
+    #[strum(serialize = "tbsp", serialize = "tbs", serialize = "tablespoon")]
+    #[strum(serialize = "tablespoons")]
+    Tablespoons,
+    #[strum(serialize = "c", serialize = "cup", serialize = "cups")]
+    Cups,
+    #[strum(serialize = "fl oz", serialize = "fl. oz", serialize = "fl. oz.")]
+    #[strum(serialize = "fluid ounce", serialize = "fluid ounces")]
+    FluidOunces,
+    #[strum(serialize = "g", serialize = "gram", serialize = "grams")]
+    #[strum(serialize = "gramme", serialize = "grammes")]
+    Grams,
+    #[strum(serialize = "kg", serialize = "kilogram", serialize = "kilograms")]
+    #[strum(serialize = "kilo", serialize = "kilos")]
+    Kilograms,
+    #[strum(serialize = "oz", serialize = "ounce", serialize = "ounces")]
+    Ounces,
+    #[strum(
+        serialize = "lb",
+        serialize = "lbs",
+        serialize = "pound",
+        serialize = "pounds"
+    )]
+    Pounds,
+}
+
+impl Unit {
+    pub fn dimension(&self) -> Dimension {
+        match self {
+            Self::Mililiters
+            | Self::Liters
+            | Self::Teaspoons
+            | Self::Tablespoons
+            | Self::Cups
+            | Self::FluidOunces => Dimension::Volume,
+            Self::Grams | Self::Kilograms | Self::Ounces | Self::Pounds => Dimension::Mass,
+        }
+    }
+
+    /// How many mililiters or grams, depending on the dimension, are in one of this unit
+    pub fn base_factor(&self) -> f64 {
+        match self {
+            Self::Mililiters => 1.0,
+            Self::Liters => MILILITERS_PER_LITER,
+            Self::Teaspoons => MILILITERS_PER_TEASPOON,
+            Self::Tablespoons => MILILITERS_PER_TABLESPOON,
+            Self::Cups => MILILITERS_PER_CUP,
+            Self::FluidOunces => MILILITERS_PER_FLUID_OUNCE,
+            Self::Grams => 1.0,
+            Self::Kilograms => GRAMS_PER_KILOGRAM,
+            Self::Ounces => GRAMS_PER_OUNCE,
+            Self::Pounds => GRAMS_PER_POUND,
+        }
+    }
+
+    /// The system this unit belongs to. Spoons are used in both, so they have none.
+    pub fn system(&self) -> Option<MeasurementSystem> {
+        match self {
+            Self::Teaspoons | Self::Tablespoons => None,
+            Self::Mililiters | Self::Liters | Self::Grams | Self::Kilograms => {
+                Some(MeasurementSystem::Metric)
+            }
+            Self::Cups | Self::FluidOunces | Self::Ounces | Self::Pounds => {
+                Some(MeasurementSystem::Imperial)
+            }
+        }
+    }
+
+    /// Picks the unit of a system that reads best for the given amount of mililiters or grams
+    pub fn best_for(system: MeasurementSystem, dimension: Dimension, base_amount: f64) -> Self {
+        match (system, dimension) {
+            (MeasurementSystem::Metric, Dimension::Volume)
+                if base_amount < MILILITERS_PER_LITER =>
+            {
+                Self::Mililiters
+            }
+            (MeasurementSystem::Metric, Dimension::Volume) => Self::Liters,
+            (MeasurementSystem::Metric, Dimension::Mass) if base_amount < GRAMS_PER_KILOGRAM => {
+                Self::Grams
+            }
+            (MeasurementSystem::Metric, Dimension::Mass) => Self::Kilograms,
+            (MeasurementSystem::Imperial, Dimension::Volume)
+                if base_amount < MILILITERS_PER_TABLESPOON =>
+            {
+                Self::Teaspoons
+            }
+            (MeasurementSystem::Imperial, Dimension::Volume)
+                if base_amount < MILILITERS_PER_CUP / 4.0 =>
+            {
+                Self::Tablespoons
+            }
+            (MeasurementSystem::Imperial, Dimension::Volume) => Self::Cups,
+            (MeasurementSystem::Imperial, Dimension::Mass) if base_amount < GRAMS_PER_POUND => {
+                Self::Ounces
+            }
+            (MeasurementSystem::Imperial, Dimension::Mass) => Self::Pounds,
+        }
+    }
+
+    pub fn with_amount(self, amount: f64) -> IngredientUnit {
+        match self {
+            Self::Mililiters => IngredientUnit::Mililiters(amount),
+            Self::Liters => IngredientUnit::Liters(amount),
+            Self::Teaspoons => IngredientUnit::Teaspoons(amount),
+            Self::Tablespoons => IngredientUnit::Tablespoons(amount),
+            Self::Cups => IngredientUnit::Cups(amount),
+            Self::FluidOunces => IngredientUnit::FluidOunces(amount),
+            Self::Grams => IngredientUnit::Grams(amount),
+            Self::Kilograms => IngredientUnit::Kilograms(amount),
+            Self::Ounces => IngredientUnit::Ounces(amount),
+            Self::Pounds => IngredientUnit::Pounds(amount),
+        }
+    }
+}
+
+impl IngredientUnit {
+    pub fn amount(&self) -> f64 {
+        match self {
+            Self::Mililiters(amount)
+            | Self::Liters(amount)
+            | Self::Teaspoons(amount)
+            | Self::Tablespoons(amount)
+            | Self::Cups(amount)
+            | Self::FluidOunces(amount)
+            | Self::Grams(amount)
+            | Self::Kilograms(amount)
+            | Self::Ounces(amount)
+            | Self::Pounds(amount)
+            | Self::Other { amount, .. } => *amount,
+        }
+    }
+
+    /// Replaces the amount, keeping the unit
+    pub fn with_amount(self, amount: f64) -> Self {
+        match self {
+            Self::Mililiters(_) => Self::Mililiters(amount),
+            Self::Liters(_) => Self::Liters(amount),
+            Self::Teaspoons(_) => Self::Teaspoons(amount),
+            Self::Tablespoons(_) => Self::Tablespoons(amount),
+            Self::Cups(_) => Self::Cups(amount),
+            Self::FluidOunces(_) => Self::FluidOunces(amount),
+            Self::Grams(_) => Self::Grams(amount),
+            Self::Kilograms(_) => Self::Kilograms(amount),
+            Self::Ounces(_) => Self::Ounces(amount),
+            Self::Pounds(_) => Self::Pounds(amount),
+            Self::Other { unit, .. } => Self::Other { amount, unit },
+        }
+    }
+
+    /// Adds the amounts up, which only works when they're in the same unit
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let same_unit = match (self, other) {
+            (Self::Other { unit: a, .. }, Self::Other { unit: b, .. }) => a == b,
+            (a, b) => a.unit().is_some() && a.unit() == b.unit(),
+        };
+
+        same_unit.then(|| self.clone().with_amount(self.amount() + other.amount()))
+    }
+
+    /// The unit of this amount, if it is one we know how to convert.
+    /// [`IngredientUnit::Other`] is recognized by its name, i.e. "tbsp" or "lb".
+    pub fn unit(&self) -> Option<Unit> {
+        match self {
+            Self::Mililiters(_) => Some(Unit::Mililiters),
+            Self::Liters(_) => Some(Unit::Liters),
+            Self::Teaspoons(_) => Some(Unit::Teaspoons),
+            Self::Tablespoons(_) => Some(Unit::Tablespoons),
+            Self::Cups(_) => Some(Unit::Cups),
+            Self::FluidOunces(_) => Some(Unit::FluidOunces),
+            Self::Grams(_) => Some(Unit::Grams),
+            Self::Kilograms(_) => Some(Unit::Kilograms),
+            Self::Ounces(_) => Some(Unit::Ounces),
+            Self::Pounds(_) => Some(Unit::Pounds),
+            Self::Other { unit, .. } => unit.trim().parse().ok(),
+        }
+    }
+
+    /// The amount expressed in mililiters or grams, depending on the dimension
+    pub fn base_amount(
+        &self,
+        dimension: Dimension,
+        density: Option<IngredientDensity>,
+    ) -> Result<f64, ConversionError> {
+        let unit = self.unit().ok_or_else(|| match self {
+            Self::Other { unit, .. } => ConversionError::UnknownUnit(unit.clone()),
+            _ => unreachable!("Every unit other than Other is known"),
+        })?;
+        let amount = self.amount() * unit.base_factor();
+
+        match (unit.dimension(), dimension, density) {
+            (from, to, _) if from == to => Ok(amount),
+            (Dimension::Volume, Dimension::Mass, Some(density)) => Ok(amount * *density),
+            (Dimension::Mass, Dimension::Volume, Some(density)) => Ok(amount / *density),
+            _ => Err(ConversionError::MissingDensity),
+        }
+    }
+
+    /// Converts the amount to the given unit
+    /// ```rust
+    /// use crate::backend::domain::entities::recipe::{conversion::Unit, IngredientUnit};
+    ///
+    /// assert_eq!(
+    ///     IngredientUnit::Kilograms(1.5).convert(Unit::Grams, None).unwrap(),
+    ///     IngredientUnit::Grams(1500.0)
+    /// );
+    /// ```
+    pub fn convert(
+        self,
+        to: Unit,
+        density: Option<IngredientDensity>,
+    ) -> Result<Self, ConversionError> {
+        let amount = self.base_amount(to.dimension(), density)?;
+        Ok(to.with_amount(amount / to.base_factor()))
+    }
+
+    /// Converts the amount to the most readable unit of the given system.
+    ///
+    /// Amounts that are already in that system, or in spoons which both systems use,
+    /// are left as-is, as are [`IngredientUnit::Other`] amounts in units we do not know.
+    /// When the ingredient's density is known, metric amounts are given as mass and imperial
+    /// ones as volume, since that's how people using those systems usually measure.
+    pub fn to_system(self, system: MeasurementSystem, density: Option<IngredientDensity>) -> Self {
+        let Some(unit) = self.unit() else {
+            return self;
+        };
+
+        let dimension = match (density, system) {
+            (Some(_), MeasurementSystem::Metric) => Dimension::Mass,
+            (Some(_), MeasurementSystem::Imperial) => Dimension::Volume,
+            (None, _) => unit.dimension(),
+        };
+
+        if unit.dimension() == dimension && unit.system().is_none_or(|s| s == system) {
+            return self;
+        }
+
+        let Ok(base_amount) = self.base_amount(dimension, density) else {
+            return self;
+        };
+
+        let target = Unit::best_for(system, dimension, base_amount);
+        let amount = (base_amount / target.base_factor() * 100.0).round() / 100.0;
+
+        target.with_amount(amount)
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,207 @@
+use crate::domain::entities::{
+    ingredient::types::IngredientDensity,
+    recipe::{
+        conversion::{ConversionError, MeasurementSystem, Unit},
+        IngredientUnit,
+    },
+};
+
+fn assert_close(actual: IngredientUnit, expected: IngredientUnit) {
+    assert_eq!(actual.unit(), expected.unit());
+    assert!(
+        (actual.amount() - expected.amount()).abs() < 0.01,
+        "{actual:?} is not close to {expected:?}"
+    );
+}
+
+fn flour() -> Option<IngredientDensity> {
+    Some(IngredientDensity::try_from(0.53).unwrap())
+}
+
+#[test]
+fn converting_between_volume_units_works() {
+    assert_close(
+        IngredientUnit::Cups(1.0)
+            .convert(Unit::Tablespoons, None)
+            .unwrap(),
+        IngredientUnit::Tablespoons(16.0),
+    );
+    assert_close(
+        IngredientUnit::Tablespoons(1.0)
+            .convert(Unit::Teaspoons, None)
+            .unwrap(),
+        IngredientUnit::Teaspoons(3.0),
+    );
+    assert_close(
+        IngredientUnit::Cups(2.0)
+            .convert(Unit::FluidOunces, None)
+            .unwrap(),
+        IngredientUnit::FluidOunces(16.0),
+    );
+    assert_close(
+        IngredientUnit::Liters(0.5)
+            .convert(Unit::Mililiters, None)
+            .unwrap(),
+        IngredientUnit::Mililiters(500.0),
+    );
+    assert_close(
+        IngredientUnit::Cups(1.0)
+            .convert(Unit::Mililiters, None)
+            .unwrap(),
+        IngredientUnit::Mililiters(236.59),
+    );
+}
+
+#[test]
+fn converting_between_mass_units_works() {
+    assert_close(
+        IngredientUnit::Pounds(1.0)
+            .convert(Unit::Ounces, None)
+            .unwrap(),
+        IngredientUnit::Ounces(16.0),
+    );
+    assert_close(
+        IngredientUnit::Ounces(1.0)
+            .convert(Unit::Grams, None)
+            .unwrap(),
+        IngredientUnit::Grams(28.35),
+    );
+    assert_close(
+        IngredientUnit::Grams(2500.0)
+            .convert(Unit::Kilograms, None)
+            .unwrap(),
+        IngredientUnit::Kilograms(2.5),
+    );
+}
+
+#[test]
+fn converting_known_other_units_works() {
+    let amount = IngredientUnit::Other {
+        amount: 2.0,
+        unit: "Tbsp".to_string(),
+    };
+
+    assert_close(
+        amount.convert(Unit::Teaspoons, None).unwrap(),
+        IngredientUnit::Teaspoons(6.0),
+    );
+}
+
+#[test]
+fn converting_unknown_other_units_errors() {
+    let amount = IngredientUnit::Other {
+        amount: 2.0,
+        unit: "clove".to_string(),
+    };
+
+    let error = amount.convert(Unit::Grams, None).unwrap_err();
+
+    assert!(matches!(error, ConversionError::UnknownUnit(unit) if unit == "clove"));
+}
+
+#[test]
+fn converting_between_volume_and_mass_uses_density() {
+    assert_close(
+        IngredientUnit::Cups(1.0)
+            .convert(Unit::Grams, flour())
+            .unwrap(),
+        IngredientUnit::Grams(125.39),
+    );
+    assert_close(
+        IngredientUnit::Grams(125.39)
+            .convert(Unit::Cups, flour())
+            .unwrap(),
+        IngredientUnit::Cups(1.0),
+    );
+}
+
+#[test]
+fn converting_between_volume_and_mass_without_density_errors() {
+    let error = IngredientUnit::Cups(1.0)
+        .convert(Unit::Grams, None)
+        .unwrap_err();
+
+    assert!(matches!(error, ConversionError::MissingDensity));
+}
+
+#[test]
+fn converting_to_metric_picks_readable_units() {
+    assert_eq!(
+        IngredientUnit::Cups(2.0).to_system(MeasurementSystem::Metric, None),
+        IngredientUnit::Mililiters(473.18)
+    );
+    assert_eq!(
+        IngredientUnit::Cups(5.0).to_system(MeasurementSystem::Metric, None),
+        IngredientUnit::Liters(1.18)
+    );
+    assert_eq!(
+        IngredientUnit::Pounds(1.0).to_system(MeasurementSystem::Metric, None),
+        IngredientUnit::Grams(453.59)
+    );
+    assert_eq!(
+        IngredientUnit::Pounds(3.0).to_system(MeasurementSystem::Metric, None),
+        IngredientUnit::Kilograms(1.36)
+    );
+}
+
+#[test]
+fn converting_to_imperial_picks_readable_units() {
+    assert_eq!(
+        IngredientUnit::Mililiters(5.0).to_system(MeasurementSystem::Imperial, None),
+        IngredientUnit::Teaspoons(1.01)
+    );
+    assert_eq!(
+        IngredientUnit::Mililiters(30.0).to_system(MeasurementSystem::Imperial, None),
+        IngredientUnit::Tablespoons(2.03)
+    );
+    assert_eq!(
+        IngredientUnit::Mililiters(500.0).to_system(MeasurementSystem::Imperial, None),
+        IngredientUnit::Cups(2.11)
+    );
+    assert_eq!(
+        IngredientUnit::Grams(100.0).to_system(MeasurementSystem::Imperial, None),
+        IngredientUnit::Ounces(3.53)
+    );
+    assert_eq!(
+        IngredientUnit::Kilograms(1.0).to_system(MeasurementSystem::Imperial, None),
+        IngredientUnit::Pounds(2.2)
+    );
+}
+
+#[test]
+fn converting_to_a_system_leaves_familiar_units_alone() {
+    let spoons = IngredientUnit::Tablespoons(2.0);
+    let clove = IngredientUnit::Other {
+        amount: 4.0,
+        unit: "clove".to_string(),
+    };
+
+    assert_eq!(
+        spoons.clone().to_system(MeasurementSystem::Metric, None),
+        spoons
+    );
+    assert_eq!(
+        spoons.clone().to_system(MeasurementSystem::Imperial, None),
+        spoons
+    );
+    assert_eq!(
+        IngredientUnit::Grams(200.0).to_system(MeasurementSystem::Metric, None),
+        IngredientUnit::Grams(200.0)
+    );
+    assert_eq!(
+        clove.clone().to_system(MeasurementSystem::Imperial, None),
+        clove
+    );
+}
+
+#[test]
+fn converting_to_a_system_with_density_prefers_its_dimension() {
+    assert_eq!(
+        IngredientUnit::Cups(2.0).to_system(MeasurementSystem::Metric, flour()),
+        IngredientUnit::Grams(250.78)
+    );
+    assert_eq!(
+        IngredientUnit::Grams(250.0).to_system(MeasurementSystem::Imperial, flour()),
+        IngredientUnit::Cups(1.99)
+    );
+}
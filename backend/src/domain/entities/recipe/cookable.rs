@@ -0,0 +1,56 @@
+use common::CookableRecipeDTO;
+use uuid::Uuid;
+
+use super::{IngredientWithAmount, Recipe};
+
+/// How many required ingredients a recipe may be missing by default and still be worth listing
+pub const DEFAULT_MAX_MISSING: u64 = 2;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CookableRecipesQuery {
+    /// IDs of the ingredients the user has at hand
+    pub available: Vec<Uuid>,
+    pub max_missing: u64,
+    pub limit: u64,
+    /// Private recipes of this user are listed too
+    pub viewer: Option<Uuid>,
+}
+
+impl CookableRecipesQuery {
+    /// A recipe is listed when it misses few enough ingredients, but only if the user has at
+    /// least one of the required ones, unless it can be cooked outright
+    pub fn matches(&self, recipe: &Recipe) -> bool {
+        let missing = recipe.missing_ingredients(&self.available).len();
+        let required = recipe.ingredients.iter().filter(|i| !i.optional).count();
+
+        missing as u64 <= self.max_missing && (missing == 0 || missing < required)
+    }
+}
+
+/// A recipe, with the required ingredients the user doesn't have
+#[derive(Debug, Clone, PartialEq)]
+pub struct CookableRecipe {
+    pub recipe: Recipe,
+    pub missing: Vec<IngredientWithAmount>,
+}
+
+impl CookableRecipe {
+    pub fn new(recipe: Recipe, available: &[Uuid]) -> Self {
+        let missing = recipe
+            .missing_ingredients(available)
+            .into_iter()
+            .cloned()
+            .collect();
+
+        Self { recipe, missing }
+    }
+}
+
+impl From<CookableRecipe> for CookableRecipeDTO {
+    fn from(value: CookableRecipe) -> Self {
+        Self {
+            recipe: value.recipe.into(),
+            missing: value.missing.into_iter().map(Into::into).collect(),
+        }
+    }
+}
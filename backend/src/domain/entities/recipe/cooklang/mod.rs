@@ -0,0 +1,663 @@
+use std::time::Duration;
+
+use uuid::Uuid;
+
+use super::{
+    draft::{DraftStep, RecipeDraft},
+    schema_org::{parse_servings, LONGEST_DURATION_SECS},
+    time::{RecipeTime, TimeKind},
+    IngredientLine, IngredientUnit, IngredientWithAmount, Recipe, RecipeStep, ServingsType,
+};
+
+/// Units a timer can be given in, and how many seconds they are
+const TIME_UNITS: &[(&[&str], u64)] = &[
+    (&["s", "sec", "secs", "second", "seconds"], 1),
+    (&["m", "min", "mins", "minute", "minutes"], 60),
+    (&["h", "hr", "hrs", "hour", "hours"], 3600),
+    (&["d", "day", "days"], 86_400),
+];
+
+/// Reads a recipe written in [Cooklang](https://cooklang.org/docs/spec/), where ingredients,
+/// cookware and timers are marked up right in the steps. The amounts of the same ingredient are
+/// added up, the rest of the recipe comes from its metadata: `title`, `description`, `servings`
+/// and times, i.e. `>> prep time: 15 minutes`.
+///
+/// Paragraphs that are only made of hidden ingredients, i.e. `@-salt{1%pinch}`, list ingredients
+/// without being a step.
+/// ```rust
+/// use crate::backend::domain::entities::recipe::{cooklang, IngredientUnit};
+///
+/// let draft = cooklang::parse("Cook @rice{200%g} in a #pot{} for ~{20%minutes}.");
+/// assert_eq!(draft.ingredients[0].name, "rice");
+/// assert_eq!(draft.ingredients[0].amount, IngredientUnit::Grams(200.0));
+/// assert_eq!(draft.steps[0].step.text, "Cook rice in a pot for 20 minutes.");
+/// ```
+pub fn parse(text: &str) -> RecipeDraft {
+    let mut draft = Draft::default();
+    let mut paragraph: Vec<String> = vec![];
+
+    for line in strip_comments(text).lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            draft.add_step(&paragraph.join(" "));
+            paragraph.clear();
+        } else if let Some(metadata) = line.strip_prefix(">>") {
+            draft.add_step(&paragraph.join(" "));
+            paragraph.clear();
+            if let Some((key, value)) = metadata.split_once(':') {
+                draft.add_metadata(&unescape(key.trim()), &unescape(value.trim()));
+            }
+        } else if let Some(note) = line.strip_prefix('>') {
+            let note = note.strip_prefix(' ').unwrap_or(note);
+            draft.notes.push(unescape(note));
+        } else if line.starts_with('=') {
+            draft.add_step(&paragraph.join(" "));
+            paragraph.clear();
+            let section = unescape(line.trim_matches('=').trim());
+            draft.section = Some(section).filter(|section| !section.is_empty());
+        } else {
+            paragraph.push(line.to_string());
+        }
+    }
+    draft.add_step(&paragraph.join(" "));
+
+    draft.finish()
+}
+
+/// Writes the recipe as Cooklang, so that [`parse`] reads it back the same.
+///
+/// Ingredients are marked up where a step mentions them by name, and added to its end as hidden
+/// ingredients otherwise. The ones no step uses are listed in a paragraph of their own. A step's
+/// duration becomes a timer where the step says it, i.e. "10 minutes", and is added to its end
+/// otherwise.
+pub fn serialize(recipe: &Recipe) -> String {
+    let mut lines = vec![format!(">> title: {}", escape(&recipe.name))];
+    lines.push(format!(
+        ">> servings: {}",
+        match recipe.servings {
+            ServingsType::Exact(servings) => servings.to_string(),
+            ServingsType::FromTo(from, to) => format!("{from}-{to}"),
+        }
+    ));
+    for (kind, duration) in recipe.time.iter() {
+        let label = String::from(kind.clone());
+        lines.push(format!(
+            ">> {} time: {}",
+            escape(&label),
+            format_duration(*duration)
+        ));
+    }
+    if !recipe.description.is_empty() {
+        lines.extend(
+            recipe
+                .description
+                .lines()
+                .map(|l| format!("> {}", escape(l))),
+        );
+    }
+
+    let mut marked: Vec<Uuid> = vec![];
+    let unused: Vec<String> = recipe
+        .ingredients
+        .iter()
+        .filter(|i| {
+            !recipe
+                .steps
+                .as_ref()
+                .iter()
+                .any(|step| step.ingredients.contains(&i.ingredient.id))
+        })
+        .map(|i| ingredient_markup(i, i.ingredient.name.as_str(), true, &mut marked))
+        .collect();
+    if !unused.is_empty() {
+        lines.push(String::new());
+        lines.push(unused.join(" "));
+    }
+
+    let mut section = None;
+    for step in recipe.steps.as_ref() {
+        lines.push(String::new());
+        if step.section != section {
+            section.clone_from(&step.section);
+            match &section {
+                Some(name) => lines.push(format!("== {} ==", escape(name))),
+                None => lines.push("=".to_string()),
+            }
+        }
+        lines.push(step_markup(recipe, step, &mut marked));
+    }
+
+    lines.push(String::new());
+    lines.join("\n")
+}
+
+#[derive(Default)]
+struct Draft {
+    name: String,
+    description: Option<String>,
+    notes: Vec<String>,
+    servings: Option<ServingsType>,
+    time: Vec<(String, Duration)>,
+    section: Option<String>,
+    steps: Vec<DraftStep>,
+    /// Whether each ingredient was given an amount yet, since the ones without count as one
+    ingredients: Vec<(IngredientLine, bool)>,
+}
+
+impl Draft {
+    fn add_metadata(&mut self, key: &str, value: &str) {
+        match key.to_lowercase().as_str() {
+            "title" | "name" => self.name = value.to_string(),
+            "description" => self.description = Some(value.to_string()),
+            "servings" | "serves" | "yield" => self.servings = parse_servings(value),
+            "time" | "duration" => self.add_time("total", value),
+            // Custom times keep the case of their label, i.e. `Proofing time`
+            lowercase if lowercase.ends_with(" time") => {
+                self.add_time(key.get(..key.len() - " time".len()).unwrap_or(key), value)
+            }
+            _ => {}
+        }
+    }
+
+    fn add_time(&mut self, label: &str, value: &str) {
+        let duration = parse_duration(value).or_else(|| super::schema_org::parse_duration(value));
+        if let Some(duration) = duration {
+            self.time
+                .push((TimeKind::from(label.to_string()).into(), duration));
+        }
+    }
+
+    fn add_step(&mut self, paragraph: &str) {
+        let step = parse_step(paragraph);
+
+        let mut names: Vec<String> = vec![];
+        for ingredient in step.ingredients {
+            let name = self.add_ingredient(ingredient);
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+
+        if !step.text.is_empty() {
+            self.steps.push(DraftStep {
+                step: RecipeStep {
+                    section: self.section.clone(),
+                    duration: step.duration,
+                    ..RecipeStep::from(step.text)
+                },
+                ingredients: names,
+            });
+        }
+    }
+
+    /// Adds the amount to the ingredient with the same name, and returns the name it's listed as
+    fn add_ingredient(&mut self, ingredient: Ingredient) -> String {
+        let listed = self
+            .ingredients
+            .iter_mut()
+            .find(|(line, _)| line.name.to_lowercase() == ingredient.name.to_lowercase());
+        let Some((line, has_amount)) = listed else {
+            let name = ingredient.name.clone();
+            let has_amount = ingredient.amount.is_some();
+            self.ingredients.push((
+                IngredientLine {
+                    name: ingredient.name,
+                    amount: ingredient.amount.unwrap_or_else(|| IngredientUnit::Other {
+                        amount: 1.0,
+                        unit: String::new(),
+                    }),
+                    notes: ingredient.notes,
+                    optional: ingredient.optional,
+                },
+                has_amount,
+            ));
+            return name;
+        };
+
+        match ingredient.amount {
+            Some(amount) if !*has_amount => {
+                line.amount = amount;
+                *has_amount = true;
+            }
+            Some(amount) => {
+                if let Some(sum) = line.amount.checked_add(&amount) {
+                    line.amount = sum;
+                }
+            }
+            None => {}
+        }
+        line.optional |= ingredient.optional;
+        if line.notes.is_none() {
+            line.notes = ingredient.notes;
+        }
+        line.name.clone()
+    }
+
+    fn finish(self) -> RecipeDraft {
+        RecipeDraft {
+            name: self.name,
+            description: self.description.unwrap_or_else(|| self.notes.join("\n")),
+            steps: self.steps,
+            time: self.time.into_iter().collect::<RecipeTime>(),
+            servings: self.servings.unwrap_or(ServingsType::Exact(1)),
+            ingredients: self.ingredients.into_iter().map(|(line, _)| line).collect(),
+        }
+    }
+}
+
+struct Ingredient {
+    name: String,
+    amount: Option<IngredientUnit>,
+    notes: Option<String>,
+    optional: bool,
+}
+
+struct Step {
+    text: String,
+    ingredients: Vec<Ingredient>,
+    duration: Option<Duration>,
+}
+
+/// A piece of markup, i.e. `@-?olive oil{2%tbsp}(extra virgin)`
+struct Component {
+    name: String,
+    quantity: Option<String>,
+    notes: Option<String>,
+    hidden: bool,
+    optional: bool,
+    end: usize,
+}
+
+fn parse_step(paragraph: &str) -> Step {
+    let chars: Vec<char> = paragraph.chars().collect();
+    let mut step = Step {
+        text: String::new(),
+        ingredients: vec![],
+        duration: None,
+    };
+
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c == '\\' && i + 1 < chars.len() {
+            step.text.push(chars[i + 1]);
+            i += 2;
+            continue;
+        }
+        let Some(component) = matches!(c, '@' | '#' | '~')
+            .then(|| parse_component(&chars, i))
+            .flatten()
+        else {
+            step.text.push(c);
+            i += 1;
+            continue;
+        };
+        i = component.end;
+
+        match c {
+            '@' => {
+                if !component.hidden {
+                    step.text.push_str(&component.name);
+                }
+                step.ingredients.push(Ingredient {
+                    amount: component.quantity.as_deref().map(parse_quantity),
+                    name: component.name,
+                    notes: component.notes,
+                    optional: component.optional,
+                });
+            }
+            '#' => step.text.push_str(&component.name),
+            _ => {
+                let quantity = component.quantity.unwrap_or_default();
+                let (amount, unit) = quantity.split_once('%').unwrap_or((&quantity, ""));
+                let (amount, unit) = (amount.trim(), unit.trim());
+                step.text.push_str(format!("{amount} {unit}").trim());
+                let total = timer_duration(amount, unit)
+                    .and_then(|duration| step.duration.unwrap_or_default().checked_add(duration));
+                if let Some(total) = total {
+                    step.duration = Some(total);
+                }
+            }
+        }
+    }
+
+    step.text = step.text.trim().to_string();
+    step
+}
+
+/// Names of more than one word need braces after them, i.e. `@olive oil{}`. Timers always do.
+fn parse_component(chars: &[char], start: usize) -> Option<Component> {
+    let is_word = |c: &char| c.is_alphanumeric() || matches!(c, '_' | '-' | '\'');
+    let mut i = start + 1;
+
+    let (mut hidden, mut optional) = (false, false);
+    if chars[start] == '@' {
+        while let Some(modifier) = chars.get(i) {
+            match modifier {
+                '-' => hidden = true,
+                '?' => optional = true,
+                _ => break,
+            }
+            i += 1;
+        }
+    }
+
+    // Escaped characters are part of the name, anything else that's markup ends it
+    let mut braced_name_end = None;
+    let mut j = i;
+    while j < chars.len() {
+        match chars[j] {
+            '\\' => j += 2,
+            '{' => {
+                braced_name_end = Some(j);
+                break;
+            }
+            '@' | '#' | '~' | '}' => break,
+            _ => j += 1,
+        }
+    }
+    let closing = braced_name_end.and_then(|open| {
+        (open..chars.len())
+            .find(|&j| chars[j] == '}')
+            .map(|close| (open, close))
+    });
+
+    let (name, quantity, mut end) = match closing {
+        Some((open, close)) => (
+            unescape(&chars[i..open].iter().collect::<String>()),
+            Some(chars[open + 1..close].iter().collect::<String>()),
+            close + 1,
+        ),
+        None if chars[start] == '~' => return None,
+        None => {
+            let end = (i..chars.len())
+                .find(|&j| !is_word(&chars[j]))
+                .unwrap_or(chars.len());
+            (chars[i..end].iter().collect(), None, end)
+        }
+    };
+    let name = name.trim().to_string();
+    if name.is_empty() && chars[start] != '~' {
+        return None;
+    }
+
+    let mut notes = None;
+    if chars[start] == '@' && quantity.is_some() && chars.get(end) == Some(&'(') {
+        if let Some(close) = (end..chars.len()).find(|&j| chars[j] == ')') {
+            notes = Some(
+                chars[end + 1..close]
+                    .iter()
+                    .collect::<String>()
+                    .trim()
+                    .to_string(),
+            )
+            .filter(|notes| !notes.is_empty());
+            end = close + 1;
+        }
+    }
+
+    Some(Component {
+        name,
+        quantity: quantity
+            .map(|q| q.trim().to_string())
+            .filter(|q| !q.is_empty()),
+        notes,
+        hidden,
+        optional,
+        end,
+    })
+}
+
+/// Quantities are written as `amount%unit`, anything that isn't a number counts as one of its unit
+fn parse_quantity(quantity: &str) -> IngredientUnit {
+    let text = quantity.replacen('%', " ", 1);
+    text.parse().unwrap_or_else(|_| IngredientUnit::Other {
+        amount: 1.0,
+        unit: text.trim().to_string(),
+    })
+}
+
+fn timer_duration(amount: &str, unit: &str) -> Option<Duration> {
+    let amount = amount.parse::<IngredientUnit>().ok()?.amount();
+    let (_, seconds) = TIME_UNITS
+        .iter()
+        .find(|(names, _)| names.contains(&unit.to_lowercase().as_str()))?;
+    let total = amount * *seconds as f64;
+
+    (total.is_finite() && (0.0..=LONGEST_DURATION_SECS).contains(&total))
+        .then(|| Duration::from_secs(total.round() as u64))
+}
+
+/// Parses durations like "1 hour 30 minutes", "90 min" or "1h30m"
+fn parse_duration(s: &str) -> Option<Duration> {
+    let mut total = Duration::ZERO;
+    let mut rest = s.trim();
+    if rest.is_empty() {
+        return None;
+    }
+
+    while !rest.is_empty() {
+        let number_end = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let (amount, after) = rest.split_at(number_end);
+        let after = after.trim_start();
+        let unit_end = after
+            .find(|c: char| !c.is_alphabetic())
+            .unwrap_or(after.len());
+        let (unit, after) = after.split_at(unit_end);
+
+        total = total.checked_add(timer_duration(amount, unit)?)?;
+        rest = after.trim_start();
+    }
+
+    Some(total)
+}
+
+/// Formats the duration the way [`parse_duration`] reads it back, i.e. "1 hour 30 minutes"
+fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let parts: Vec<String> = [
+        (secs / 3600, "hour"),
+        (secs % 3600 / 60, "minute"),
+        (secs % 60, "second"),
+    ]
+    .into_iter()
+    .filter(|(amount, _)| *amount > 0)
+    .map(|(amount, unit)| match amount {
+        1 => format!("1 {unit}"),
+        _ => format!("{amount} {unit}s"),
+    })
+    .collect();
+
+    match parts.is_empty() {
+        true => "0 seconds".to_string(),
+        false => parts.join(" "),
+    }
+}
+
+/// The ways a duration can be written in a step, i.e. "90 seconds" or "1 1/2 minutes" aren't
+fn duration_phrases(duration: Duration) -> Vec<String> {
+    let secs = duration.as_secs();
+    let (amount, units): (u64, &[&str]) = match secs {
+        0 => return vec![],
+        secs if secs % 3600 == 0 => (secs / 3600, &["hours", "hour", "hrs", "hr", "h"]),
+        secs if secs % 60 == 0 => (secs / 60, &["minutes", "minute", "mins", "min"]),
+        secs => (secs, &["seconds", "second", "secs", "sec"]),
+    };
+
+    units
+        .iter()
+        .map(|unit| format!("{amount} {unit}"))
+        .collect()
+}
+
+/// Where the phrase first appears in the text as whole words, ignoring case
+fn find_phrase(text: &str, phrase: &str, taken: &[(usize, usize)]) -> Option<(usize, usize)> {
+    text.char_indices()
+        .map(|(start, _)| start)
+        .find_map(|start| {
+            let end = start + phrase.len();
+            let matches = text
+                .get(start..end)
+                .is_some_and(|found| found.eq_ignore_ascii_case(phrase));
+            let bounded = !text[..start]
+                .chars()
+                .next_back()
+                .is_some_and(char::is_alphanumeric)
+                && !text
+                    .get(end..)
+                    .and_then(|rest| rest.chars().next())
+                    .is_some_and(char::is_alphanumeric);
+            let free = taken.iter().all(|&(s, e)| end <= s || start >= e);
+
+            (matches && bounded && free).then_some((start, end))
+        })
+}
+
+fn ingredient_markup(
+    ingredient: &IngredientWithAmount,
+    name: &str,
+    hidden: bool,
+    marked: &mut Vec<Uuid>,
+) -> String {
+    let modifiers = match (hidden, ingredient.optional) {
+        (true, true) => "-?",
+        (true, false) => "-",
+        (false, true) => "?",
+        (false, false) => "",
+    };
+    // The amount goes on the first mention, later ones only refer to the ingredient
+    let first = !marked.contains(&ingredient.ingredient.id);
+    let mut markup = format!("@{modifiers}{}{{", escape(name));
+    if first {
+        marked.push(ingredient.ingredient.id);
+        markup.push_str(&ingredient.amount.to_string().replacen(' ', "%", 1));
+    }
+    markup.push('}');
+    if let Some(notes) = ingredient.notes.as_ref().filter(|_| first) {
+        markup.push_str(&format!("({notes})"));
+    }
+    markup
+}
+
+fn step_markup(recipe: &Recipe, step: &RecipeStep, marked: &mut Vec<Uuid>) -> String {
+    let text = step.text.replace('\n', " ");
+    let mut found: Vec<((usize, usize), String)> = vec![];
+    let mut hidden: Vec<String> = vec![];
+
+    let taken = |found: &[((usize, usize), String)]| -> Vec<(usize, usize)> {
+        found.iter().map(|(range, _)| *range).collect()
+    };
+    for id in &step.ingredients {
+        let Some(ingredient) = recipe.ingredients.iter().find(|i| i.ingredient.id == *id) else {
+            continue;
+        };
+        let name = ingredient.ingredient.name.to_string();
+        match find_phrase(&text, &name, &taken(&found)) {
+            Some((start, end)) => {
+                let markup = ingredient_markup(ingredient, &text[start..end], false, marked);
+                found.push(((start, end), markup));
+            }
+            None => hidden.push(ingredient_markup(ingredient, &name, true, marked)),
+        }
+    }
+
+    if let Some(duration) = step.duration {
+        let phrase = duration_phrases(duration)
+            .into_iter()
+            .find_map(|phrase| find_phrase(&text, &phrase, &taken(&found)));
+        match phrase {
+            Some((start, end)) => {
+                let (amount, unit) = text[start..end].split_once(' ').unwrap_or_default();
+                found.push(((start, end), format!("~{{{amount}%{unit}}}")));
+            }
+            None => {
+                let formatted = format_duration(duration);
+                let (amount, unit) = formatted.split_once(' ').unwrap_or_default();
+                hidden.push(format!("~{{{amount}%{unit}}}"));
+            }
+        }
+    }
+
+    found.sort_by_key(|((start, _), _)| *start);
+    let mut markup = String::new();
+    let mut position = 0;
+    for ((start, end), component) in found {
+        markup.push_str(&escape(&text[position..start]));
+        markup.push_str(&component);
+        position = end;
+    }
+    markup.push_str(&escape(&text[position..]));
+    for component in hidden {
+        markup.push(' ');
+        markup.push_str(&component);
+    }
+
+    // Lines starting with these would be read as metadata, notes or sections
+    match markup.starts_with(['>', '=']) {
+        true => format!("\\{markup}"),
+        false => markup,
+    }
+}
+
+/// Escapes everything that would be read as markup or a comment
+fn escape(text: &str) -> String {
+    let mut escaped = String::new();
+    let mut previous = None;
+    for c in text.chars() {
+        let comment = c == '-' && matches!(previous, Some('-') | Some('['));
+        if matches!(c, '@' | '#' | '~' | '{' | '}' | '\\') || comment {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+        previous = Some(c);
+    }
+    escaped
+}
+
+fn unescape(text: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unescaped.extend(chars.next()),
+            c => unescaped.push(c),
+        }
+    }
+    unescaped
+}
+
+/// Removes `-- line comments` and `[- block comments -]`, leaving escaped dashes alone
+fn strip_comments(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, chars.peek()) {
+            ('\\', Some(_)) => {
+                stripped.push(c);
+                stripped.extend(chars.next());
+            }
+            ('-', Some('-')) => {
+                while chars.peek().is_some_and(|c| *c != '\n') {
+                    chars.next();
+                }
+            }
+            ('[', Some('-')) => {
+                chars.next();
+                let mut previous = None;
+                for c in chars.by_ref() {
+                    if previous == Some('-') && c == ']' {
+                        break;
+                    }
+                    previous = Some(c);
+                }
+            }
+            _ => stripped.push(c),
+        }
+    }
+    stripped
+}
+
+#[cfg(test)]
+mod tests;
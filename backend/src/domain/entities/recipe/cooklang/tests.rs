@@ -0,0 +1,286 @@
+use std::time::Duration;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::entities::recipe::{
+        draft::{DraftStep, RecipeDraft},
+        time::{RecipeTime, TimeKind},
+        IngredientLine, IngredientUnit, Recipe, RecipeStep, ServingsType,
+    },
+    test_utils::recipe_fixture,
+};
+
+use super::{parse, serialize};
+
+/// The recipe as it reads back, with the ingredients of its steps given by name
+fn expected_draft(recipe: &Recipe) -> RecipeDraft {
+    RecipeDraft {
+        name: recipe.name.clone(),
+        description: recipe.description.clone(),
+        steps: recipe
+            .steps
+            .as_ref()
+            .iter()
+            .map(|step| DraftStep {
+                step: RecipeStep {
+                    ingredients: vec![],
+                    ..step.clone()
+                },
+                ingredients: step
+                    .ingredients
+                    .iter()
+                    .filter_map(|id| recipe.ingredients.iter().find(|i| i.ingredient.id == *id))
+                    .map(|i| i.ingredient.name.to_string())
+                    .collect(),
+            })
+            .collect(),
+        time: recipe.time.clone(),
+        servings: recipe.servings.clone(),
+        ingredients: recipe
+            .ingredients
+            .iter()
+            .map(IngredientLine::from)
+            .collect(),
+    }
+}
+
+/// Steps mark ingredients up with the same spelling as their text, so names can differ in case
+fn lowercase_names(mut draft: RecipeDraft) -> RecipeDraft {
+    for line in &mut draft.ingredients {
+        line.name = line.name.to_lowercase();
+    }
+    for step in &mut draft.steps {
+        for name in &mut step.ingredients {
+            *name = name.to_lowercase();
+        }
+    }
+    draft
+}
+
+#[test]
+fn recipe_fixture_round_trips() {
+    let recipe = recipe_fixture();
+
+    let text = serialize(&recipe);
+
+    assert!(text.starts_with(
+        ">> title: Hoisin Tofu and Broccoli\n\
+         >> servings: 4\n\
+         >> prep time: 15 minutes\n\
+         >> cook time: 10 minutes\n\
+         > If necessary"
+    ));
+    assert!(text.contains("\n\n@-Firm tofu{400%g} @-Broccoli{1%head} @-Garlic{4%clove}\n\n"));
+    assert_eq!(parse(&text), expected_draft(&recipe));
+}
+
+#[test]
+fn recipe_fixture_with_marked_up_steps_round_trips() {
+    let mut recipe = recipe_fixture();
+    recipe.servings = ServingsType::FromTo(2, 4);
+    recipe.time = [
+        ("prep", Duration::from_secs(15 * 60)),
+        ("soaking", Duration::from_secs(90 * 60)),
+    ]
+    .into_iter()
+    .collect();
+    recipe.ingredients[2].optional = true;
+    recipe.ingredients[2].notes = Some("minced".to_string());
+    let (tofu, broccoli, garlic) = (
+        recipe.ingredients[0].ingredient.id,
+        recipe.ingredients[1].ingredient.id,
+        recipe.ingredients[2].ingredient.id,
+    );
+    let mut steps = recipe.steps.as_ref().to_vec();
+    steps[0].ingredients = vec![tofu];
+    steps[0].section = Some("Tofu".to_string());
+    steps[2].section = Some("Tofu".to_string());
+    steps[4].ingredients = vec![broccoli];
+    steps[5].ingredients = vec![broccoli];
+    steps[5].duration = Some(Duration::from_secs(3 * 60));
+    steps[6].ingredients = vec![tofu];
+    steps[7].ingredients = vec![garlic];
+    steps[7].duration = Some(Duration::from_secs(30));
+    recipe.steps = steps.try_into().unwrap();
+
+    let text = serialize(&recipe);
+
+    assert!(text.contains(
+        "== Tofu ==\nCut the tofu into 3/4” (2 cm) cubes and place it in a sealable ziplock bag. \
+         @-Firm tofu{400%g}\n"
+    ));
+    assert!(text.contains("\n=\nCombine 1/4 cup soy sauce"));
+    assert!(text.contains("Cut the florets off the stalks of @broccoli{1%head}."));
+    assert!(text.contains("Steam for 2-~{3%minutes} depending"));
+    assert!(text.contains("Add @broccoli{} and cover with a lid."));
+    assert!(text.contains("Add more oil, @?garlic{4%clove}(minced), ginger."));
+    assert_eq!(
+        lowercase_names(parse(&text)),
+        lowercase_names(RecipeDraft {
+            // Timers that aren't in the text of the step are added to it
+            steps: expected_draft(&recipe)
+                .steps
+                .into_iter()
+                .enumerate()
+                .map(|(i, mut step)| {
+                    if i == 7 {
+                        step.step.text.push_str(" 30 seconds");
+                    }
+                    step
+                })
+                .collect(),
+            ..expected_draft(&recipe)
+        })
+    );
+}
+
+#[test]
+fn parsing_reads_ingredients_cookware_and_timers() {
+    let draft = parse(
+        ">> title: Garlic bread
+>> description: Crispy -- and buttery
+>> servings: 2-3
+>> time: 1h 5m
+-- Grandma's recipe
+= Bread
+
+Crush @garlic{3%cloves}(peeled) and mix it with @butter{50%g} [- softened -]
+in a #small bowl{}. Add @salt and @?dried parsley{}.
+
+Spread it on the @baguette{1} with a #knife, then add @-butter{25%g}.
+
+== Baking ==
+Bake for ~{10%minutes}, then ~crisp{1/2%minute} more. Mail \\@grandma about it.
+",
+    );
+
+    assert_eq!(draft.name, "Garlic bread");
+    assert_eq!(draft.description, "Crispy");
+    assert_eq!(draft.servings, ServingsType::FromTo(2, 3));
+    assert_eq!(
+        draft.time.get(&TimeKind::Total),
+        Some(&Duration::from_secs(65 * 60))
+    );
+    assert_eq!(
+        draft.ingredients,
+        vec![
+            IngredientLine {
+                name: "garlic".to_string(),
+                amount: IngredientUnit::Other {
+                    amount: 3.0,
+                    unit: "cloves".to_string()
+                },
+                notes: Some("peeled".to_string()),
+                optional: false
+            },
+            IngredientLine {
+                name: "butter".to_string(),
+                amount: IngredientUnit::Grams(75.0),
+                notes: None,
+                optional: false
+            },
+            IngredientLine {
+                name: "salt".to_string(),
+                amount: IngredientUnit::Other {
+                    amount: 1.0,
+                    unit: "".to_string()
+                },
+                notes: None,
+                optional: false
+            },
+            IngredientLine {
+                name: "dried parsley".to_string(),
+                amount: IngredientUnit::Other {
+                    amount: 1.0,
+                    unit: "".to_string()
+                },
+                notes: None,
+                optional: true
+            },
+            IngredientLine {
+                name: "baguette".to_string(),
+                amount: IngredientUnit::Other {
+                    amount: 1.0,
+                    unit: "".to_string()
+                },
+                notes: None,
+                optional: false
+            },
+        ]
+    );
+    assert_eq!(
+        draft.steps,
+        vec![
+            DraftStep {
+                step: RecipeStep {
+                    section: Some("Bread".to_string()),
+                    ..RecipeStep::from(
+                        "Crush garlic and mix it with butter in a small bowl. \
+                         Add salt and dried parsley."
+                            .to_string()
+                    )
+                },
+                ingredients: vec![
+                    "garlic".to_string(),
+                    "butter".to_string(),
+                    "salt".to_string(),
+                    "dried parsley".to_string()
+                ]
+            },
+            DraftStep {
+                step: RecipeStep {
+                    section: Some("Bread".to_string()),
+                    ..RecipeStep::from(
+                        "Spread it on the baguette with a knife, then add .".to_string()
+                    )
+                },
+                ingredients: vec!["baguette".to_string(), "butter".to_string()]
+            },
+            DraftStep {
+                step: RecipeStep {
+                    section: Some("Baking".to_string()),
+                    duration: Some(Duration::from_secs(10 * 60 + 30)),
+                    ..RecipeStep::from(
+                        "Bake for 10 minutes, then 1/2 minute more. Mail @grandma about it."
+                            .to_string()
+                    )
+                },
+                ingredients: vec![]
+            },
+        ]
+    );
+}
+
+#[test]
+fn text_that_looks_like_markup_is_escaped() {
+    let mut recipe = recipe_fixture();
+    recipe.name = "Tofu -- the #1 dish".to_string();
+    recipe.description = "Email me@example.com\n\n[- not a comment -]".to_string();
+    let mut steps = recipe.steps.as_ref().to_vec();
+    steps[0].text = "= not a section, ~{not a timer} and @not an ingredient{}".to_string();
+    steps[1].text = ">> not: metadata -- or a comment".to_string();
+    recipe.steps = steps.try_into().unwrap();
+    recipe.ingredients[0].ingredient.name = "Tofu @home #1".try_into().unwrap();
+    recipe.ingredients[1].ingredient.name = "Broccoli ~{2%min} or two".try_into().unwrap();
+
+    let text = serialize(&recipe);
+
+    assert!(text.contains(r"@-Tofu \@home \#1{400%g}"));
+    assert_eq!(parse(&text), expected_draft(&recipe));
+}
+
+#[test]
+fn timers_too_long_to_make_sense_are_left_out() {
+    let draft = parse(
+        ">> prep time: 99999999999999999999 hours\n\
+         >> cook time: 400 days\n\n\
+         Leave it for ~{99999999999999999999%hours}, then ~{10%minutes}.",
+    );
+
+    assert_eq!(draft.time, RecipeTime::default());
+    assert_eq!(
+        draft.steps[0].step.duration,
+        Some(Duration::from_secs(10 * 60))
+    );
+}
@@ -0,0 +1,29 @@
+use super::{time::RecipeTime, IngredientLine, RecipeStep, ServingsType};
+
+/// A recipe read from another format, with its ingredients not yet matched to ours
+#[derive(PartialEq, Debug, Clone)]
+pub struct RecipeDraft {
+    pub name: String,
+    pub description: String,
+    pub steps: Vec<DraftStep>,
+    pub time: RecipeTime,
+    pub servings: ServingsType,
+    pub ingredients: Vec<IngredientLine>,
+}
+
+/// A step of a draft, referring to the ingredients it uses by name
+#[derive(PartialEq, Debug, Clone)]
+pub struct DraftStep {
+    /// Its ingredients are left empty until the names are matched
+    pub step: RecipeStep,
+    pub ingredients: Vec<String>,
+}
+
+impl From<RecipeStep> for DraftStep {
+    fn from(step: RecipeStep) -> Self {
+        Self {
+            step,
+            ingredients: vec![],
+        }
+    }
+}
@@ -8,9 +8,21 @@ pub enum ValidationError {
     #[error("Field {0} does not match any of: {1:?}")]
     DoesNotMatch(&'static str, &'static [&'static str]),
 
+    #[error("Field {0} has to be a positive number")]
+    NotPositive(&'static str),
+
+    #[error("Field {0} has unknown values: {1:?}")]
+    UnknownValues(&'static str, Vec<String>),
+
     #[error("Failed to deserialize field {0}")]
     DeserializationFailed(&'static str, #[source] serde_json::Error),
 
+    #[error("A step refers to the ingredient {0}, which is not in the recipe")]
+    UnknownStepIngredient(uuid::Uuid),
+
+    #[error("The {0} time was given more than once")]
+    DuplicateTime(String),
+
     #[error("Failed to compute measurement from the following string: {0}")]
     MeasurementComputation(String),
 
@@ -23,6 +35,8 @@ impl From<IngredientValidationError> for ValidationError {
         match value {
             IngredientValidationError::EmptyField(e) => Self::EmptyField(e),
             IngredientValidationError::DoesNotMatch(a, b) => Self::DoesNotMatch(a, b),
+            IngredientValidationError::NotPositive(a) => Self::NotPositive(a),
+            IngredientValidationError::UnknownValues(a, b) => Self::UnknownValues(a, b),
             IngredientValidationError::Unknown(e) => Self::Unknown(e),
         }
     }
@@ -1,19 +1,36 @@
+pub mod conversion;
+pub mod cookable;
+pub mod cooklang;
+pub mod draft;
 pub mod errors;
+mod parser;
+pub mod revision;
+pub mod schema_org;
+pub mod search;
+pub mod time;
 use derive_more::DerefMut;
-use std::collections::BTreeMap;
 
 use common::{
-    IngredientAmountDTO, IngredientUnitDTO, IngredientWithAmountDTO, RecipeDTO, ServingsTypeDTO,
+    IngredientAmountDTO, IngredientUnitDTO, IngredientUnitInputDTO, IngredientWithAmountDTO,
+    RecipeAllergensDTO, RecipeDTO, RecipeListQueryDTO, RecipePageDTO, RecipeSortByDTO,
+    RecipeStepDTO, RecipeStepInputDTO, ServingsTypeDTO,
 };
-use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use serde::{Deserialize, Serialize};
 use shrinkwraprs::Shrinkwrap;
 use sqlx::FromRow;
 use uuid::Uuid;
 
 use self::errors::ValidationError;
+pub use self::parser::IngredientLine;
+use self::time::RecipeTime;
 
-use super::ingredient::{Ingredient, IngredientModel};
+use super::ingredient::{
+    types::{Allergens, WhichDiets},
+    Ingredient, IngredientModel,
+};
+use super::pagination::{page_size, Page, Pagination, SortOrder};
+use super::version::Version;
 
 #[derive(PartialEq, Debug, Clone)]
 pub struct Recipe {
@@ -22,9 +39,87 @@ pub struct Recipe {
     pub description: String,
     pub steps: RecipeSteps,
     pub ingredients: RecipeIngredients,
-    pub time: BTreeMap<String, std::time::Duration>,
+    pub time: RecipeTime,
     pub servings: ServingsType,
+    /// `None` for recipes created before user accounts existed
+    pub author_id: Option<Uuid>,
+    /// Private recipes are only visible to their author
+    pub public: bool,
+    pub version: Version,
+}
+impl Recipe {
+    /// Only the author of a recipe may change it. Recipes without an author, i.e. ones created
+    /// before user accounts existed, can't be changed by anyone until someone claims them.
+    pub fn is_authored_by(&self, user_id: &Uuid) -> bool {
+        self.author_id.as_ref() == Some(user_id)
+    }
+
+    pub fn is_visible_to(&self, user_id: Option<&Uuid>) -> bool {
+        self.public || user_id.is_some_and(|id| self.is_authored_by(id))
+    }
+
+    /// A recipe fits a diet only if every non-optional ingredient does
+    pub fn diet_friendly(&self) -> WhichDiets {
+        self.ingredients
+            .iter()
+            .filter(|i| !i.optional)
+            .fold(WhichDiets::all(), |diets, i| {
+                diets.intersect(&i.ingredient.diet_friendly())
+            })
+    }
+
+    /// Like [`Recipe::diet_friendly`], but the given ingredients count as fitting every diet,
+    /// i.e. because they have a variant that does
+    pub fn fits_diets(&self, diets: &WhichDiets, fitting: &[Uuid]) -> bool {
+        self.ingredients
+            .iter()
+            .filter(|i| !i.optional && !fitting.contains(&i.ingredient.id))
+            .all(|i| i.ingredient.diet_violations.allows_all(diets))
+    }
+
+    /// The required ingredients that aren't among the available ones
+    pub fn missing_ingredients(&self, available: &[Uuid]) -> Vec<&IngredientWithAmount> {
+        self.ingredients
+            .iter()
+            .filter(|i| !i.optional && !available.contains(&i.ingredient.id))
+            .collect()
+    }
+
+    pub fn allergens(&self) -> RecipeAllergens {
+        let collect = |optional: bool| {
+            self.ingredients
+                .iter()
+                .filter(|i| i.optional == optional)
+                .fold(Allergens::new(), |allergens, i| {
+                    allergens.union(&i.ingredient.allergens)
+                })
+        };
+        let contains = collect(false);
+
+        RecipeAllergens {
+            optional: collect(true).difference(&contains),
+            contains,
+        }
+    }
+}
+
+/// Allergens of a recipe, split by whether they can be avoided by leaving out optional ingredients
+#[derive(PartialEq, Debug, Clone, Default)]
+pub struct RecipeAllergens {
+    pub contains: Allergens,
+    /// Only the allergens that none of the required ingredients contain
+    pub optional: Allergens,
 }
+
+impl From<RecipeAllergens> for RecipeAllergensDTO {
+    fn from(value: RecipeAllergens) -> Self {
+        Self {
+            contains: value.contains.into(),
+            optional: value.optional.into(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Shrinkwrap, DerefMut)]
 pub struct RecipeIngredients(Vec<IngredientWithAmount>);
 
@@ -70,59 +165,239 @@ impl TryFrom<Vec<IngredientWithAmount>> for RecipeIngredients {
     }
 }
 
+/// A single step of a recipe
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "StoredRecipeStep")]
+pub struct RecipeStep {
+    /// Heading of the section the step belongs to, i.e. "For the sauce"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    pub text: String,
+    /// How long the step takes, for running a timer
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration: Option<std::time::Duration>,
+    /// IDs of the recipe's ingredients used in the step
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub ingredients: Vec<Uuid>,
+}
+
+/// Steps used to be plain text, and older revisions still store them that way
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum StoredRecipeStep {
+    Structured {
+        #[serde(default)]
+        section: Option<String>,
+        text: String,
+        #[serde(default)]
+        duration: Option<std::time::Duration>,
+        #[serde(default)]
+        ingredients: Vec<Uuid>,
+    },
+    Text(String),
+}
+
+impl From<StoredRecipeStep> for RecipeStep {
+    fn from(value: StoredRecipeStep) -> Self {
+        match value {
+            StoredRecipeStep::Structured {
+                section,
+                text,
+                duration,
+                ingredients,
+            } => Self {
+                section,
+                text,
+                duration,
+                ingredients,
+            },
+            StoredRecipeStep::Text(text) => text.into(),
+        }
+    }
+}
+
+impl From<String> for RecipeStep {
+    fn from(value: String) -> Self {
+        Self {
+            section: None,
+            text: value,
+            duration: None,
+            ingredients: vec![],
+        }
+    }
+}
+
+impl From<RecipeStepInputDTO> for RecipeStep {
+    fn from(value: RecipeStepInputDTO) -> Self {
+        match value {
+            RecipeStepInputDTO::Structured(step) => Self {
+                section: step.section,
+                text: step.text,
+                duration: step.duration.map(std::time::Duration::from_secs),
+                ingredients: step.ingredients,
+            },
+            RecipeStepInputDTO::Text(text) => text.into(),
+        }
+    }
+}
+
+impl From<RecipeStep> for RecipeStepDTO {
+    fn from(value: RecipeStep) -> Self {
+        Self {
+            section: value.section,
+            text: value.text,
+            duration: value.duration.map(|d| d.as_secs()),
+            ingredients: value.ingredients,
+        }
+    }
+}
+
 #[derive(PartialEq, Debug, Clone)]
-pub struct RecipeSteps(Vec<String>);
+pub struct RecipeSteps(Vec<RecipeStep>);
+
+impl RecipeSteps {
+    /// The sections and text of every step, i.e. for searching through them
+    pub fn text(&self) -> String {
+        self.0
+            .iter()
+            .flat_map(|step| step.section.iter().chain(std::iter::once(&step.text)))
+            .cloned()
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
 
-impl AsRef<[String]> for RecipeSteps {
-    fn as_ref(&self) -> &[String] {
+    /// Makes sure every step only refers to ingredients of the recipe
+    pub fn check_ingredients(
+        &self,
+        ingredients: &RecipeIngredients,
+    ) -> Result<(), ValidationError> {
+        let unknown = self
+            .0
+            .iter()
+            .flat_map(|step| step.ingredients.iter())
+            .find(|id| !ingredients.iter().any(|i| i.ingredient.id == **id));
+
+        match unknown {
+            Some(id) => Err(ValidationError::UnknownStepIngredient(*id)),
+            None => Ok(()),
+        }
+    }
+
+    /// Forgets the references to ingredients that aren't in the recipe anymore
+    pub fn retain_ingredients(&mut self, ingredients: &RecipeIngredients) {
+        for step in self.0.iter_mut() {
+            step.ingredients
+                .retain(|id| ingredients.iter().any(|i| i.ingredient.id == *id));
+        }
+    }
+
+    /// Makes every step referring to the ingredient refer to the replacement instead
+    pub fn replace_ingredient(&mut self, ingredient_id: &Uuid, replacement_id: &Uuid) {
+        for id in self
+            .0
+            .iter_mut()
+            .flat_map(|step| step.ingredients.iter_mut())
+        {
+            if id == ingredient_id {
+                *id = *replacement_id;
+            }
+        }
+    }
+}
+
+impl AsRef<[RecipeStep]> for RecipeSteps {
+    fn as_ref(&self) -> &[RecipeStep] {
         &self.0
     }
 }
 
-impl TryFrom<Vec<String>> for RecipeSteps {
+impl TryFrom<Vec<RecipeStep>> for RecipeSteps {
     type Error = ValidationError;
-    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
-        // Filter out empty steps
-        let data: Vec<String> = value
-            .to_owned()
-            .par_iter()
-            .filter(|step| !step.trim().is_empty())
-            .cloned()
+    fn try_from(value: Vec<RecipeStep>) -> Result<Self, Self::Error> {
+        // Filter out empty steps, and sections with nothing in their heading
+        let data: Vec<RecipeStep> = value
+            .into_par_iter()
+            .filter(|step| !step.text.trim().is_empty())
+            .map(|step| RecipeStep {
+                section: step.section.filter(|section| !section.trim().is_empty()),
+                ..step
+            })
             .collect();
 
         if data.is_empty() {
             Err(ValidationError::EmptyField(vec!["steps"]))
         } else {
-            Ok(Self(data.to_owned()))
+            Ok(Self(data))
         }
     }
 }
 
-impl TryFrom<&Vec<String>> for RecipeSteps {
+impl TryFrom<Vec<String>> for RecipeSteps {
     type Error = ValidationError;
-    fn try_from(value: &Vec<String>) -> Result<Self, Self::Error> {
-        RecipeSteps::try_from(value.clone())
+    fn try_from(value: Vec<String>) -> Result<Self, Self::Error> {
+        RecipeSteps::try_from(value.into_iter().map(RecipeStep::from).collect::<Vec<_>>())
     }
 }
 
 impl From<Recipe> for RecipeDTO {
     fn from(value: Recipe) -> Self {
         Self {
+            diet_friendly: value.diet_friendly().into(),
+            allergens: value.allergens().into(),
             id: value.id.to_string(),
             ingredients: value.ingredients.iter().map(|i| i.clone().into()).collect(),
             name: value.name,
             description: value.description,
-            steps: value.steps.0,
-            time: value
-                .time
-                .into_iter()
-                .map(|(k, v)| (k, v.as_secs()))
-                .collect(),
+            steps: value.steps.0.into_iter().map(Into::into).collect(),
+            total_time: value.time.total().map(|total| total.as_secs()),
+            time: value.time.into(),
             servings: value.servings.into(),
+            author_id: value.author_id,
+            public: value.public,
         }
     }
 }
 
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct RecipeModel {
+    pub id: Uuid,
+    pub name: String,
+    pub description: String,
+    pub steps: serde_json::Value,
+    pub time: serde_json::Value,
+    pub servings: serde_json::Value,
+    pub author_id: Option<Uuid>,
+    pub public: bool,
+    pub version: i32,
+}
+
+impl RecipeModel {
+    pub fn into_recipe(
+        self,
+        ingredients: Vec<IngredientWithAmount>,
+    ) -> Result<Recipe, ValidationError> {
+        let ingredients: RecipeIngredients = ingredients.try_into()?;
+        let steps: RecipeSteps = serde_json::from_value::<Vec<RecipeStep>>(self.steps)
+            .map_err(|e| ValidationError::DeserializationFailed("steps", e))?
+            .try_into()?;
+
+        Ok(Recipe {
+            id: self.id,
+            name: self.name,
+            description: self.description,
+            steps,
+            time: serde_json::from_value(self.time)
+                .map_err(|e| ValidationError::DeserializationFailed("time", e))?,
+            servings: serde_json::from_value(self.servings)
+                .map_err(|e| ValidationError::DeserializationFailed("servings", e))?,
+            ingredients,
+            author_id: self.author_id,
+            public: self.public,
+            version: Version(self.version),
+        })
+    }
+}
+
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ServingsType {
@@ -130,6 +405,17 @@ pub enum ServingsType {
     Exact(u16),
 }
 
+impl ServingsType {
+    /// The number of servings a recipe is treated as yielding when it is scaled,
+    /// i.e. the midpoint of a range
+    pub fn average(&self) -> f64 {
+        match self {
+            Self::Exact(a) => f64::from(*a),
+            Self::FromTo(a, b) => (f64::from(*a) + f64::from(*b)) / 2.0,
+        }
+    }
+}
+
 impl From<ServingsType> for ServingsTypeDTO {
     fn from(value: ServingsType) -> Self {
         match value {
@@ -206,9 +492,15 @@ impl TryFrom<IngredientWithAmountModel> for IngredientWithAmount {
 #[serde(rename_all = "snake_case")]
 pub enum IngredientUnit {
     Mililiters(f64),
-    Grams(f64),
+    Liters(f64),
     Teaspoons(f64),
+    Tablespoons(f64),
     Cups(f64),
+    FluidOunces(f64),
+    Grams(f64),
+    Kilograms(f64),
+    Ounces(f64),
+    Pounds(f64),
     Other { amount: f64, unit: String },
 }
 
@@ -221,22 +513,44 @@ impl Default for IngredientUnit {
 impl From<IngredientUnitDTO> for IngredientUnit {
     fn from(value: IngredientUnitDTO) -> Self {
         match value {
-            IngredientUnitDTO::Cups(amount) => Self::Cups(amount),
-            IngredientUnitDTO::Grams(amount) => Self::Grams(amount),
             IngredientUnitDTO::Mililiters(amount) => Self::Mililiters(amount),
+            IngredientUnitDTO::Liters(amount) => Self::Liters(amount),
             IngredientUnitDTO::Teaspoons(amount) => Self::Teaspoons(amount),
+            IngredientUnitDTO::Tablespoons(amount) => Self::Tablespoons(amount),
+            IngredientUnitDTO::Cups(amount) => Self::Cups(amount),
+            IngredientUnitDTO::FluidOunces(amount) => Self::FluidOunces(amount),
+            IngredientUnitDTO::Grams(amount) => Self::Grams(amount),
+            IngredientUnitDTO::Kilograms(amount) => Self::Kilograms(amount),
+            IngredientUnitDTO::Ounces(amount) => Self::Ounces(amount),
+            IngredientUnitDTO::Pounds(amount) => Self::Pounds(amount),
             IngredientUnitDTO::Other { amount, unit } => Self::Other { amount, unit },
         }
     }
 }
 
+impl TryFrom<IngredientUnitInputDTO> for IngredientUnit {
+    type Error = ValidationError;
+    fn try_from(value: IngredientUnitInputDTO) -> Result<Self, Self::Error> {
+        match value {
+            IngredientUnitInputDTO::Structured(unit) => Ok(unit.into()),
+            IngredientUnitInputDTO::Text(text) => text.parse(),
+        }
+    }
+}
+
 impl From<IngredientUnit> for IngredientUnitDTO {
     fn from(value: IngredientUnit) -> Self {
         match value {
-            IngredientUnit::Cups(amount) => Self::Cups(amount),
-            IngredientUnit::Grams(amount) => Self::Grams(amount),
             IngredientUnit::Mililiters(amount) => Self::Mililiters(amount),
+            IngredientUnit::Liters(amount) => Self::Liters(amount),
             IngredientUnit::Teaspoons(amount) => Self::Teaspoons(amount),
+            IngredientUnit::Tablespoons(amount) => Self::Tablespoons(amount),
+            IngredientUnit::Cups(amount) => Self::Cups(amount),
+            IngredientUnit::FluidOunces(amount) => Self::FluidOunces(amount),
+            IngredientUnit::Grams(amount) => Self::Grams(amount),
+            IngredientUnit::Kilograms(amount) => Self::Kilograms(amount),
+            IngredientUnit::Ounces(amount) => Self::Ounces(amount),
+            IngredientUnit::Pounds(amount) => Self::Pounds(amount),
             IngredientUnit::Other { amount, unit } => Self::Other { amount, unit },
         }
     }
@@ -253,6 +567,17 @@ impl IngredientUnit {
     pub fn from_tablespoons(tablespoons: f64) -> Self {
         Self::Teaspoons(tablespoons * 3.0)
     }
+
+    /// Multiplies the amount by the given factor, keeping the unit
+    /// ```rust
+    /// use crate::backend::domain::entities::recipe::IngredientUnit;
+    ///
+    /// assert_eq!(IngredientUnit::Grams(100.0).scale(1.5), IngredientUnit::Grams(150.0))
+    /// ```
+    pub fn scale(self, factor: f64) -> Self {
+        let amount = self.amount() * factor;
+        self.with_amount(amount)
+    }
 }
 
 #[derive(Default)]
@@ -260,8 +585,9 @@ pub struct RecipeChangeset {
     pub name: Option<String>,
     pub description: Option<String>,
     pub steps: Option<RecipeSteps>,
-    pub time: Option<BTreeMap<String, std::time::Duration>>,
+    pub time: Option<RecipeTime>,
     pub servings: Option<ServingsType>,
+    pub public: Option<bool>,
 }
 
 impl RecipeChangeset {
@@ -272,6 +598,7 @@ impl RecipeChangeset {
             steps,
             time,
             servings,
+            public,
         } = self;
 
         name.is_none()
@@ -279,6 +606,106 @@ impl RecipeChangeset {
             && steps.is_none()
             && time.is_none()
             && servings.is_none()
+            && public.is_none()
+    }
+}
+
+#[derive(strum::Display, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[strum(serialize_all = "snake_case")]
+pub enum RecipeSortBy {
+    Name,
+    /// Recipe IDs are UUIDv7, so sorting by them sorts by creation time
+    #[default]
+    CreatedAt,
+    /// Recipes without any time come last, whichever the order
+    TotalTime,
+}
+
+impl From<RecipeSortByDTO> for RecipeSortBy {
+    fn from(value: RecipeSortByDTO) -> Self {
+        match value {
+            RecipeSortByDTO::Name => Self::Name,
+            RecipeSortByDTO::CreatedAt => Self::CreatedAt,
+            RecipeSortByDTO::TotalTime => Self::TotalTime,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeListQuery {
+    pub pagination: Pagination,
+    pub limit: u64,
+    pub sort_by: RecipeSortBy,
+    pub order: SortOrder,
+    /// Case-insensitive substring of the recipe name
+    pub name: Option<String>,
+    /// Diets every returned recipe has to be compatible with
+    pub diet_friendly: WhichDiets,
+    /// Count a required ingredient as fitting the diets when one of its variants does
+    pub include_variants: bool,
+    /// Ingredients with a variant that fits the diets, looked up when variants are included
+    pub ingredients_with_fitting_variants: Vec<Uuid>,
+    /// Allergens none of the required ingredients of a returned recipe may contain
+    pub exclude_allergens: Allergens,
+    /// Longest total time a returned recipe may take, recipes without any time are left out
+    pub max_total_time: Option<std::time::Duration>,
+    /// List all recipes of this author, including private ones, instead of public recipes
+    pub author_id: Option<Uuid>,
+}
+
+impl Default for RecipeListQuery {
+    fn default() -> Self {
+        Self {
+            pagination: Pagination::default(),
+            limit: page_size(None),
+            sort_by: RecipeSortBy::default(),
+            order: SortOrder::default(),
+            name: None,
+            diet_friendly: WhichDiets::new(),
+            include_variants: false,
+            ingredients_with_fitting_variants: vec![],
+            exclude_allergens: Allergens::new(),
+            max_total_time: None,
+            author_id: None,
+        }
+    }
+}
+
+impl TryFrom<RecipeListQueryDTO> for RecipeListQuery {
+    type Error = ValidationError;
+    fn try_from(value: RecipeListQueryDTO) -> Result<Self, Self::Error> {
+        Ok(Self {
+            pagination: Pagination::new(value.offset, value.cursor),
+            limit: page_size(value.limit),
+            sort_by: value.sort_by.unwrap_or_default().into(),
+            order: value.order.unwrap_or_default().into(),
+            name: value.name.filter(|name| !name.trim().is_empty()),
+            diet_friendly: value
+                .diet_friendly
+                .map(|diets| diets.parse::<WhichDiets>())
+                .transpose()?
+                .unwrap_or_default(),
+            include_variants: value.include_variants.unwrap_or(false),
+            ingredients_with_fitting_variants: vec![],
+            exclude_allergens: value
+                .exclude_allergens
+                .map(|allergens| allergens.parse::<Allergens>())
+                .transpose()?
+                .unwrap_or_default(),
+            max_total_time: value.max_total_time.map(std::time::Duration::from_secs),
+            author_id: None,
+        })
+    }
+}
+
+impl From<Page<Recipe>> for RecipePageDTO {
+    fn from(value: Page<Recipe>) -> Self {
+        Self {
+            items: value.items.into_iter().map(RecipeDTO::from).collect(),
+            total: value.total,
+            limit: value.limit,
+            next_cursor: value.next_cursor,
+        }
     }
 }
 
@@ -301,22 +728,23 @@ impl From<IngredientWithAmount> for IngredientAmountData {
     }
 }
 
-impl From<IngredientAmountDTO> for IngredientAmountData {
-    fn from(
+impl TryFrom<IngredientAmountDTO> for IngredientAmountData {
+    type Error = ValidationError;
+    fn try_from(
         IngredientAmountDTO {
             ingredient_id,
             amount,
             optional,
             notes,
         }: IngredientAmountDTO,
-    ) -> Self {
-        let amount = amount.into();
-        Self {
+    ) -> Result<Self, Self::Error> {
+        let amount = amount.try_into()?;
+        Ok(Self {
             ingredient_id,
             amount,
             optional,
             notes,
-        }
+        })
     }
 }
 
@@ -0,0 +1,329 @@
+use std::str::FromStr;
+
+use super::{conversion::Unit, errors::ValidationError, IngredientUnit, IngredientWithAmount};
+
+/// Parses amounts typed by a human, i.e. "1 1/2 cups", "250g", "½ tsp" or "3-4 cloves".
+///
+/// Units we know how to convert are turned into their own variant, everything else becomes
+/// [`IngredientUnit::Other`]. Ranges resolve to their upper bound, so that there's enough
+/// of the ingredient at hand.
+/// ```rust
+/// use crate::backend::domain::entities::recipe::IngredientUnit;
+///
+/// assert_eq!("1 1/2 cups".parse::<IngredientUnit>().unwrap(), IngredientUnit::Cups(1.5));
+/// assert!("a handful".parse::<IngredientUnit>().is_err());
+/// ```
+impl FromStr for IngredientUnit {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let error = || ValidationError::MeasurementComputation(s.to_string());
+
+        let (amount, rest) = parse_range(s.trim()).ok_or_else(error)?;
+        if !amount.is_finite() || amount <= 0.0 {
+            return Err(error());
+        }
+
+        let unit = rest.trim().trim_end_matches('.').trim();
+        if unit.chars().next().is_some_and(|c| !c.is_alphabetic()) {
+            return Err(error());
+        }
+
+        Ok(match Unit::from_str(unit) {
+            Ok(unit) => unit.with_amount(amount),
+            Err(_) => IngredientUnit::Other {
+                amount,
+                unit: unit.to_string(),
+            },
+        })
+    }
+}
+
+/// A quantity, optionally followed by a range separator and another quantity
+fn parse_range(s: &str) -> Option<(f64, &str)> {
+    let (from, rest) = parse_quantity(s)?;
+
+    let after_separator = rest
+        .trim_start()
+        .strip_prefix(['-', '–'])
+        .or_else(|| rest.trim_start().strip_prefix("to "));
+
+    match after_separator.and_then(|r| parse_quantity(r.trim_start())) {
+        Some((to, rest)) if to >= from => Some((to, rest)),
+        Some(_) => None,
+        None => Some((from, rest)),
+    }
+}
+
+/// A whole number, decimal, fraction or a mixed number like "1 1/2" or "1½"
+fn parse_quantity(s: &str) -> Option<(f64, &str)> {
+    if let Some((fraction, rest)) = parse_vulgar_fraction(s) {
+        return Some((fraction, rest));
+    }
+
+    let (whole, rest) = parse_decimal(s)?;
+
+    if let Some(denominator) = rest.strip_prefix('/') {
+        let (denominator, rest) = parse_decimal(denominator)?;
+        return (denominator != 0.0).then_some((whole / denominator, rest));
+    }
+
+    if let Some((fraction, rest)) = parse_vulgar_fraction(rest.trim_start()) {
+        return Some((whole + fraction, rest));
+    }
+
+    let trimmed = rest.trim_start();
+    if trimmed.len() != rest.len() {
+        if let Some((numerator, after)) = parse_decimal(trimmed) {
+            if let Some(denominator) = after.strip_prefix('/') {
+                let (denominator, after) = parse_decimal(denominator)?;
+                if denominator == 0.0 {
+                    return None;
+                }
+                return Some((whole + numerator / denominator, after));
+            }
+        }
+    }
+
+    Some((whole, rest))
+}
+
+fn parse_decimal(s: &str) -> Option<(f64, &str)> {
+    let end = s
+        .char_indices()
+        .find(|(_, c)| !(c.is_ascii_digit() || *c == '.' || *c == ','))
+        .map_or(s.len(), |(i, _)| i);
+    let (number, rest) = s.split_at(end);
+    let number = match is_grouped_in_thousands(number) {
+        true => number.replace(',', ""),
+        false => number.replace(',', "."),
+    };
+    Some((number.parse().ok()?, rest))
+}
+
+/// Whether the commas separate thousands, i.e. "1,000" or "12,500.5", rather than being a
+/// decimal separator, i.e. "1,5" or "0,250"
+fn is_grouped_in_thousands(number: &str) -> bool {
+    let mut groups = number.split(',');
+    let first = groups.next().unwrap_or_default();
+    let groups: Vec<&str> = groups.collect();
+    let last = groups.len().saturating_sub(1);
+
+    !groups.is_empty()
+        && !first.is_empty()
+        && first != "0"
+        && !first.contains('.')
+        && groups.iter().enumerate().all(|(i, group)| {
+            let (digits, decimals) = group.split_once('.').unwrap_or((group, ""));
+            digits.len() == 3 && (i == last || !group.contains('.')) && !decimals.contains('.')
+        })
+}
+
+fn parse_vulgar_fraction(s: &str) -> Option<(f64, &str)> {
+    let c = s.chars().next()?;
+    let fraction = match c {
+        '¼' => 1.0 / 4.0,
+        '½' => 1.0 / 2.0,
+        '¾' => 3.0 / 4.0,
+        '⅓' => 1.0 / 3.0,
+        '⅔' => 2.0 / 3.0,
+        '⅕' => 1.0 / 5.0,
+        '⅖' => 2.0 / 5.0,
+        '⅗' => 3.0 / 5.0,
+        '⅘' => 4.0 / 5.0,
+        '⅙' => 1.0 / 6.0,
+        '⅚' => 5.0 / 6.0,
+        '⅛' => 1.0 / 8.0,
+        '⅜' => 3.0 / 8.0,
+        '⅝' => 5.0 / 8.0,
+        '⅞' => 7.0 / 8.0,
+        _ => return None,
+    };
+    Some((fraction, &s[c.len_utf8()..]))
+}
+
+/// Units that can't be converted, but still aren't part of an ingredient's name
+const COUNTED_UNITS: &[&str] = &[
+    "bunch", "bunches", "can", "cans", "clove", "cloves", "dash", "dashes", "handful", "handfuls",
+    "head", "heads", "jar", "jars", "leaf", "leaves", "package", "packages", "piece", "pieces",
+    "pinch", "pinches", "slice", "slices", "sprig", "sprigs", "stalk", "stalks", "stick", "sticks",
+];
+
+/// Formats the amount the way [`IngredientUnit::from_str`] parses it back, i.e. "1.5 cups"
+impl std::fmt::Display for IngredientUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let amount = (self.amount() * 100.0).round() / 100.0;
+        let unit = match self {
+            Self::Mililiters(_) => "ml",
+            Self::Liters(_) => "l",
+            Self::Teaspoons(_) => "tsp",
+            Self::Tablespoons(_) => "tbsp",
+            Self::Cups(_) => "cups",
+            Self::FluidOunces(_) => "fl oz",
+            Self::Grams(_) => "g",
+            Self::Kilograms(_) => "kg",
+            Self::Ounces(_) => "oz",
+            Self::Pounds(_) => "lb",
+            Self::Other { unit, .. } => unit,
+        };
+
+        if unit.is_empty() {
+            write!(f, "{amount}")
+        } else {
+            write!(f, "{amount} {unit}")
+        }
+    }
+}
+
+/// An ingredient written out on a single line, the way recipes on the web list them.
+/// Its name still has to be matched to an ingredient.
+#[derive(PartialEq, Debug, Clone)]
+pub struct IngredientLine {
+    pub name: String,
+    pub amount: IngredientUnit,
+    pub notes: Option<String>,
+    pub optional: bool,
+}
+
+/// Splits a line like "2 cloves garlic, minced (optional)" into its parts.
+/// Lines without an amount, i.e. "salt", count as one of the ingredient.
+/// ```rust
+/// use crate::backend::domain::entities::recipe::{IngredientLine, IngredientUnit};
+///
+/// let line: IngredientLine = "1 1/2 cups of flour, sifted".parse().unwrap();
+/// assert_eq!(line.name, "flour");
+/// assert_eq!(line.amount, IngredientUnit::Cups(1.5));
+/// assert_eq!(line.notes.as_deref(), Some("sifted"));
+/// ```
+impl FromStr for IngredientLine {
+    type Err = ValidationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut line = s.trim().to_string();
+        let mut optional = false;
+        if let Some(start) = line.to_ascii_lowercase().find("(optional)") {
+            line.replace_range(start..start + "(optional)".len(), "");
+            optional = true;
+        }
+
+        let (amount, rest) = parse_amount(&line);
+        let rest = rest.trim_start();
+        let rest = rest.strip_prefix("of ").unwrap_or(rest);
+
+        let (name, mut notes) = match rest.split_once(',') {
+            Some((name, notes)) => (name, Some(notes.trim().to_string())),
+            None => (rest, None),
+        };
+
+        // Parentheses in the name hold notes, i.e. "tomatoes (about 400 g)"
+        let mut name = name.to_string();
+        while let Some((start, end)) = name.find('(').zip(name.find(')')) {
+            if end < start {
+                break;
+            }
+            let note = name[start + 1..end].trim().to_string();
+            name.replace_range(start..=end, "");
+            notes = Some(match notes {
+                Some(notes) if !notes.is_empty() => format!("{note}, {notes}"),
+                _ => note,
+            });
+        }
+
+        let mut notes = notes.filter(|notes| !notes.is_empty());
+        if notes
+            .as_deref()
+            .is_some_and(|n| n.eq_ignore_ascii_case("optional"))
+        {
+            notes = None;
+            optional = true;
+        }
+
+        let name = name.split_whitespace().collect::<Vec<_>>().join(" ");
+        if name.is_empty() {
+            return Err(ValidationError::EmptyField(vec!["ingredient"]));
+        }
+
+        Ok(Self {
+            name,
+            amount,
+            notes,
+            optional,
+        })
+    }
+}
+
+/// The amount at the start of a line and what's left of it
+fn parse_amount(line: &str) -> (IngredientUnit, &str) {
+    let counted = |amount| IngredientUnit::Other {
+        amount,
+        unit: String::new(),
+    };
+
+    let Some((amount, rest)) = parse_range(line).filter(|(amount, _)| *amount > 0.0) else {
+        return (counted(1.0), line);
+    };
+
+    let trimmed = rest.trim_start();
+    // Units like "fl oz" span two words, so the two first words are tried before the first one
+    for end in word_ends(trimmed).into_iter().take(2).rev() {
+        let unit = &trimmed[..end];
+        if let Ok(unit) = Unit::from_str(unit.trim_end_matches('.')) {
+            return (unit.with_amount(amount), &trimmed[end..]);
+        }
+        if COUNTED_UNITS.contains(&unit.to_lowercase().as_str()) {
+            return (
+                IngredientUnit::Other {
+                    amount,
+                    unit: unit.to_string(),
+                },
+                &trimmed[end..],
+            );
+        }
+    }
+
+    (counted(amount), rest)
+}
+
+/// Byte offsets right after each of the words
+fn word_ends(s: &str) -> Vec<usize> {
+    let mut ends = vec![];
+    let mut in_word = false;
+    for (i, c) in s.char_indices() {
+        if c.is_whitespace() && in_word {
+            ends.push(i);
+        }
+        in_word = !c.is_whitespace();
+    }
+    if in_word {
+        ends.push(s.len());
+    }
+    ends
+}
+
+impl From<&IngredientWithAmount> for IngredientLine {
+    fn from(value: &IngredientWithAmount) -> Self {
+        Self {
+            name: value.ingredient.name.to_string(),
+            amount: value.amount.clone(),
+            notes: value.notes.clone(),
+            optional: value.optional,
+        }
+    }
+}
+
+/// Writes the line out so that it parses back the same, i.e. "2 cloves garlic, minced (optional)"
+impl std::fmt::Display for IngredientLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}", self.amount, self.name)?;
+        if let Some(notes) = &self.notes {
+            write!(f, ", {notes}")?;
+        }
+        if self.optional {
+            write!(f, " (optional)")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests;
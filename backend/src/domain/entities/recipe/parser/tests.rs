@@ -0,0 +1,166 @@
+use crate::domain::entities::recipe::{errors::ValidationError, IngredientLine, IngredientUnit};
+
+fn parse(s: &str) -> IngredientUnit {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parsing_whole_numbers_and_decimals_works() {
+    assert_eq!(parse("2 tbsp"), IngredientUnit::Tablespoons(2.0));
+    assert_eq!(parse("1.5 l"), IngredientUnit::Liters(1.5));
+    assert_eq!(parse("0,5 kg"), IngredientUnit::Kilograms(0.5));
+}
+
+#[test]
+fn parsing_thousands_separators_works() {
+    assert_eq!(parse("1,000 g"), IngredientUnit::Grams(1000.0));
+    assert_eq!(parse("1,250.5 ml"), IngredientUnit::Mililiters(1250.5));
+    assert_eq!(parse("2,000,000 g"), IngredientUnit::Grams(2_000_000.0));
+    assert_eq!(parse("0,250 kg"), IngredientUnit::Kilograms(0.25));
+    assert_eq!(parse("1,25 l"), IngredientUnit::Liters(1.25));
+}
+
+#[test]
+fn parsing_units_without_a_space_works() {
+    assert_eq!(parse("250g"), IngredientUnit::Grams(250.0));
+    assert_eq!(parse("100ml"), IngredientUnit::Mililiters(100.0));
+}
+
+#[test]
+fn parsing_fractions_works() {
+    assert_eq!(parse("1/2 cup"), IngredientUnit::Cups(0.5));
+    assert_eq!(parse("1 1/2 cups"), IngredientUnit::Cups(1.5));
+    assert_eq!(parse("½ tsp"), IngredientUnit::Teaspoons(0.5));
+    assert_eq!(parse("1½ tsp"), IngredientUnit::Teaspoons(1.5));
+    assert_eq!(parse("2 ¾ lb"), IngredientUnit::Pounds(2.75));
+}
+
+#[test]
+fn parsing_ranges_takes_the_upper_bound() {
+    assert_eq!(
+        parse("3-4 cloves"),
+        IngredientUnit::Other {
+            amount: 4.0,
+            unit: "cloves".to_string()
+        }
+    );
+    assert_eq!(parse("1 - 1 1/2 cups"), IngredientUnit::Cups(1.5));
+    assert_eq!(parse("2 to 3 oz"), IngredientUnit::Ounces(3.0));
+}
+
+#[test]
+fn parsing_unit_names_is_lenient() {
+    assert_eq!(parse("2 Tablespoons"), IngredientUnit::Tablespoons(2.0));
+    assert_eq!(parse("8 fl. oz."), IngredientUnit::FluidOunces(8.0));
+    assert_eq!(parse("  1 TSP  "), IngredientUnit::Teaspoons(1.0));
+}
+
+#[test]
+fn parsing_unknown_units_keeps_them_as_other() {
+    assert_eq!(
+        parse("1 head"),
+        IngredientUnit::Other {
+            amount: 1.0,
+            unit: "head".to_string()
+        }
+    );
+    assert_eq!(
+        parse("3"),
+        IngredientUnit::Other {
+            amount: 3.0,
+            unit: "".to_string()
+        }
+    );
+}
+
+#[test]
+fn parsing_garbage_fails() {
+    for input in [
+        "",
+        "a handful",
+        "cups",
+        "0 g",
+        "1/0 cup",
+        "4-3 cloves",
+        "2 (400g) cans",
+        "-1 cup",
+    ] {
+        let error = input.parse::<IngredientUnit>().unwrap_err();
+
+        assert!(
+            matches!(&error, ValidationError::MeasurementComputation(s) if s == input),
+            "{input:?} should not be parsed, got {error:?}"
+        );
+    }
+}
+
+fn line(s: &str) -> IngredientLine {
+    s.parse().unwrap()
+}
+
+#[test]
+fn parsing_ingredient_lines_splits_amount_name_and_notes() {
+    assert_eq!(
+        line("2 cloves garlic, minced"),
+        IngredientLine {
+            name: "garlic".to_string(),
+            amount: IngredientUnit::Other {
+                amount: 2.0,
+                unit: "cloves".to_string()
+            },
+            notes: Some("minced".to_string()),
+            optional: false,
+        }
+    );
+    assert_eq!(
+        line("8 fl. oz. whole milk").amount,
+        IngredientUnit::FluidOunces(8.0)
+    );
+    assert_eq!(line("250g flour").name, "flour");
+    assert_eq!(line("1 1/2 cups of sugar").name, "sugar");
+}
+
+#[test]
+fn parsing_ingredient_lines_without_an_amount_counts_one() {
+    let salt = line("Salt, to taste");
+
+    assert_eq!(salt.name, "Salt");
+    assert_eq!(
+        salt.amount,
+        IngredientUnit::Other {
+            amount: 1.0,
+            unit: "".to_string()
+        }
+    );
+    assert_eq!(salt.notes.as_deref(), Some("to taste"));
+}
+
+#[test]
+fn parsing_ingredient_lines_picks_up_optional_ingredients_and_parentheses() {
+    let tomatoes = line("1 can tomatoes (about 400 g) (optional)");
+    assert_eq!(tomatoes.name, "tomatoes");
+    assert_eq!(tomatoes.notes.as_deref(), Some("about 400 g"));
+    assert!(tomatoes.optional);
+
+    let parsley = line("Parsley, optional");
+    assert_eq!(parsley.notes, None);
+    assert!(parsley.optional);
+
+    assert!(matches!(
+        "2 cups".parse::<IngredientLine>(),
+        Err(ValidationError::EmptyField(_))
+    ));
+}
+
+#[test]
+fn formatted_ingredient_lines_parse_back() {
+    for input in [
+        "1.5 cups flour, sifted",
+        "4 clove Garlic",
+        "2 eggs (optional)",
+        "0.33 tsp salt",
+    ] {
+        assert_eq!(line(input).to_string(), input);
+        assert_eq!(line(&line(input).to_string()), line(input));
+    }
+}
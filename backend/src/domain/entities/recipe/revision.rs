@@ -0,0 +1,342 @@
+use chrono::{DateTime, Utc};
+use common::{
+    RecipeChangeDTO, RecipeRevisionDTO, RecipeRevisionIngredientDTO, RecipeRevisionSummaryDTO,
+    RecipeSnapshotDTO,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use crate::domain::entities::version::Version;
+
+use super::{
+    errors::ValidationError, time::RecipeTime, IngredientUnit, IngredientWithAmount, Recipe,
+    RecipeStep, ServingsType,
+};
+
+/// An ingredient of a recipe as it was at the time of a revision.
+/// The name is kept, so the history still reads well after the ingredient gets renamed.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct RevisionIngredient {
+    pub ingredient_id: Uuid,
+    pub name: String,
+    pub amount: IngredientUnit,
+    pub notes: Option<String>,
+    pub optional: bool,
+}
+
+impl From<&IngredientWithAmount> for RevisionIngredient {
+    fn from(value: &IngredientWithAmount) -> Self {
+        Self {
+            ingredient_id: value.ingredient.id,
+            name: value.ingredient.name.to_string(),
+            amount: value.amount.clone(),
+            notes: value.notes.clone(),
+            optional: value.optional,
+        }
+    }
+}
+
+/// Everything about a recipe that can be changed, as it was at the time of a revision
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct RecipeSnapshot {
+    pub name: String,
+    pub description: String,
+    pub steps: Vec<RecipeStep>,
+    pub time: RecipeTime,
+    pub servings: ServingsType,
+    pub public: bool,
+    pub ingredients: Vec<RevisionIngredient>,
+}
+
+impl From<&Recipe> for RecipeSnapshot {
+    fn from(value: &Recipe) -> Self {
+        let mut ingredients: Vec<RevisionIngredient> = value
+            .ingredients
+            .iter()
+            .map(RevisionIngredient::from)
+            .collect();
+        ingredients.sort_by_key(|i| i.ingredient_id);
+
+        Self {
+            name: value.name.clone(),
+            description: value.description.clone(),
+            steps: value.steps.as_ref().to_vec(),
+            time: value.time.clone(),
+            servings: value.servings.clone(),
+            public: value.public,
+            ingredients,
+        }
+    }
+}
+
+/// A single difference between two snapshots of a recipe
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+#[serde(tag = "field", rename_all = "snake_case")]
+pub enum RecipeChange {
+    Name {
+        from: String,
+        to: String,
+    },
+    Description {
+        from: String,
+        to: String,
+    },
+    Steps {
+        from: Vec<RecipeStep>,
+        to: Vec<RecipeStep>,
+    },
+    Time {
+        from: RecipeTime,
+        to: RecipeTime,
+    },
+    Servings {
+        from: ServingsType,
+        to: ServingsType,
+    },
+    Public {
+        from: bool,
+        to: bool,
+    },
+    IngredientAdded {
+        ingredient: RevisionIngredient,
+    },
+    IngredientRemoved {
+        ingredient: RevisionIngredient,
+    },
+    /// The amount, notes or optionality of an ingredient changed
+    IngredientChanged {
+        from: RevisionIngredient,
+        to: RevisionIngredient,
+    },
+}
+
+impl RecipeSnapshot {
+    /// The changes that turn this snapshot into the other one, ingredients are matched by their ID
+    pub fn diff(&self, other: &RecipeSnapshot) -> Vec<RecipeChange> {
+        let mut changes = vec![];
+
+        if self.name != other.name {
+            changes.push(RecipeChange::Name {
+                from: self.name.clone(),
+                to: other.name.clone(),
+            });
+        }
+        if self.description != other.description {
+            changes.push(RecipeChange::Description {
+                from: self.description.clone(),
+                to: other.description.clone(),
+            });
+        }
+        if self.steps != other.steps {
+            changes.push(RecipeChange::Steps {
+                from: self.steps.clone(),
+                to: other.steps.clone(),
+            });
+        }
+        if self.time != other.time {
+            changes.push(RecipeChange::Time {
+                from: self.time.clone(),
+                to: other.time.clone(),
+            });
+        }
+        if self.servings != other.servings {
+            changes.push(RecipeChange::Servings {
+                from: self.servings.clone(),
+                to: other.servings.clone(),
+            });
+        }
+        if self.public != other.public {
+            changes.push(RecipeChange::Public {
+                from: self.public,
+                to: other.public,
+            });
+        }
+
+        let find = |ingredients: &[RevisionIngredient], id: Uuid| {
+            ingredients.iter().find(|i| i.ingredient_id == id).cloned()
+        };
+        for before in &self.ingredients {
+            match find(&other.ingredients, before.ingredient_id) {
+                None => changes.push(RecipeChange::IngredientRemoved {
+                    ingredient: before.clone(),
+                }),
+                Some(after)
+                    if after.amount != before.amount
+                        || after.notes != before.notes
+                        || after.optional != before.optional =>
+                {
+                    changes.push(RecipeChange::IngredientChanged {
+                        from: before.clone(),
+                        to: after,
+                    })
+                }
+                Some(_) => {}
+            }
+        }
+        for after in &other.ingredients {
+            if find(&self.ingredients, after.ingredient_id).is_none() {
+                changes.push(RecipeChange::IngredientAdded {
+                    ingredient: after.clone(),
+                });
+            }
+        }
+
+        changes
+    }
+}
+
+/// An immutable record of a change made to a recipe
+#[derive(PartialEq, Debug, Clone)]
+pub struct RecipeRevision {
+    pub recipe_id: Uuid,
+    /// The version of the recipe the change resulted in
+    pub revision: Version,
+    /// The user who made the change
+    pub author_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    /// The revision the recipe was rolled back to, if that's what the change was
+    pub restored_from: Option<Version>,
+    /// The recipe after the change
+    pub snapshot: RecipeSnapshot,
+    pub changes: Vec<RecipeChange>,
+}
+
+impl RecipeRevision {
+    /// Records the change from `before` to `after`, `before` being `None` when the recipe was just created
+    pub fn new(before: Option<&Recipe>, after: &Recipe, author_id: Option<Uuid>) -> Self {
+        let snapshot = RecipeSnapshot::from(after);
+        let changes = match before {
+            Some(before) => RecipeSnapshot::from(before).diff(&snapshot),
+            None => vec![],
+        };
+
+        Self {
+            recipe_id: after.id,
+            revision: after.version,
+            author_id,
+            created_at: Utc::now(),
+            restored_from: None,
+            snapshot,
+            changes,
+        }
+    }
+
+    pub fn restored_from(self, revision: Version) -> Self {
+        Self {
+            restored_from: Some(revision),
+            ..self
+        }
+    }
+}
+
+impl From<RevisionIngredient> for RecipeRevisionIngredientDTO {
+    fn from(value: RevisionIngredient) -> Self {
+        Self {
+            ingredient_id: value.ingredient_id,
+            name: value.name,
+            amount: value.amount.into(),
+            notes: value.notes,
+            optional: value.optional,
+        }
+    }
+}
+
+impl From<RecipeSnapshot> for RecipeSnapshotDTO {
+    fn from(value: RecipeSnapshot) -> Self {
+        Self {
+            name: value.name,
+            description: value.description,
+            steps: value.steps.into_iter().map(Into::into).collect(),
+            time: value.time.into(),
+            servings: value.servings.into(),
+            public: value.public,
+            ingredients: value.ingredients.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<RecipeChange> for RecipeChangeDTO {
+    fn from(value: RecipeChange) -> Self {
+        match value {
+            RecipeChange::Name { from, to } => Self::Name { from, to },
+            RecipeChange::Description { from, to } => Self::Description { from, to },
+            RecipeChange::Steps { from, to } => Self::Steps {
+                from: from.into_iter().map(Into::into).collect(),
+                to: to.into_iter().map(Into::into).collect(),
+            },
+            RecipeChange::Time { from, to } => Self::Time {
+                from: from.into(),
+                to: to.into(),
+            },
+            RecipeChange::Servings { from, to } => Self::Servings {
+                from: from.into(),
+                to: to.into(),
+            },
+            RecipeChange::Public { from, to } => Self::Public { from, to },
+            RecipeChange::IngredientAdded { ingredient } => Self::IngredientAdded {
+                ingredient: ingredient.into(),
+            },
+            RecipeChange::IngredientRemoved { ingredient } => Self::IngredientRemoved {
+                ingredient: ingredient.into(),
+            },
+            RecipeChange::IngredientChanged { from, to } => Self::IngredientChanged {
+                from: from.into(),
+                to: to.into(),
+            },
+        }
+    }
+}
+
+impl From<RecipeRevision> for RecipeRevisionSummaryDTO {
+    fn from(value: RecipeRevision) -> Self {
+        Self {
+            revision: *value.revision,
+            author_id: value.author_id,
+            created_at: value.created_at,
+            restored_from: value.restored_from.map(|v| *v),
+            changes: value.changes.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<RecipeRevision> for RecipeRevisionDTO {
+    fn from(value: RecipeRevision) -> Self {
+        Self {
+            revision: *value.revision,
+            author_id: value.author_id,
+            created_at: value.created_at,
+            restored_from: value.restored_from.map(|v| *v),
+            changes: value.changes.into_iter().map(Into::into).collect(),
+            snapshot: value.snapshot.into(),
+        }
+    }
+}
+
+#[derive(FromRow, PartialEq, Debug, Clone)]
+pub struct RecipeRevisionModel {
+    pub recipe_id: Uuid,
+    pub revision: i32,
+    pub author_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub restored_from: Option<i32>,
+    pub snapshot: serde_json::Value,
+    pub changes: serde_json::Value,
+}
+
+impl TryFrom<RecipeRevisionModel> for RecipeRevision {
+    type Error = ValidationError;
+    fn try_from(value: RecipeRevisionModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            recipe_id: value.recipe_id,
+            revision: Version(value.revision),
+            author_id: value.author_id,
+            created_at: value.created_at,
+            restored_from: value.restored_from.map(Version),
+            snapshot: serde_json::from_value(value.snapshot)
+                .map_err(|e| ValidationError::DeserializationFailed("snapshot", e))?,
+            changes: serde_json::from_value(value.changes)
+                .map_err(|e| ValidationError::DeserializationFailed("changes", e))?,
+        })
+    }
+}
@@ -0,0 +1,114 @@
+use common::SchemaOrgRecipeDTO;
+use scraper::{ElementRef, Html, Selector};
+use serde_json::{Map, Value};
+
+use super::find_recipe;
+
+/// Properties that hold a list, even when a page only gives one value
+const LIST_PROPERTIES: &[&str] = &["recipeIngredient", "recipeInstructions", "itemListElement"];
+
+/// Finds the first recipe embedded in a web page, either as JSON-LD or as microdata.
+/// Only the page itself is read, nothing it links to gets fetched.
+pub fn find_recipe_in_html(html: &str) -> Option<SchemaOrgRecipeDTO> {
+    let document = Html::parse_document(html);
+
+    json_ld_recipe(&document).or_else(|| microdata_recipe(&document))
+}
+
+fn json_ld_recipe(document: &Html) -> Option<SchemaOrgRecipeDTO> {
+    let scripts = Selector::parse(r#"script[type="application/ld+json"]"#).ok()?;
+
+    document
+        .select(&scripts)
+        .filter_map(|script| serde_json::from_str(&script.text().collect::<String>()).ok())
+        .find_map(|json_ld: Value| find_recipe(&json_ld))
+}
+
+fn microdata_recipe(document: &Html) -> Option<SchemaOrgRecipeDTO> {
+    let items = Selector::parse("[itemscope][itemtype]").ok()?;
+
+    document
+        .select(&items)
+        .map(microdata_item)
+        .find_map(|item| find_recipe(&item))
+}
+
+/// Turns a microdata item into the JSON-LD node it stands for
+fn microdata_item(item: ElementRef) -> Value {
+    let mut properties: Map<String, Value> = Map::new();
+    if let Some(kind) = item.value().attr("itemtype") {
+        // `http://schema.org/Recipe` is just a `Recipe`
+        let mut kinds: Vec<Value> = kind
+            .split_whitespace()
+            .map(|kind| kind.rsplit('/').next().unwrap_or(kind).into())
+            .collect();
+        let kind = match kinds.len() {
+            1 => kinds.remove(0),
+            _ => Value::Array(kinds),
+        };
+        properties.insert("@type".to_string(), kind);
+    }
+
+    let mut values: Vec<(String, Value)> = vec![];
+    collect_properties(item, &mut values);
+    for (name, value) in values {
+        // Older pages still use the deprecated `ingredients`
+        let name = match name.as_str() {
+            "ingredients" => "recipeIngredient".to_string(),
+            _ => name,
+        };
+        match properties.get_mut(&name) {
+            Some(Value::Array(list)) if LIST_PROPERTIES.contains(&name.as_str()) => {
+                list.push(value)
+            }
+            // Anything else only has one value, the first one wins
+            Some(_) => {}
+            None if LIST_PROPERTIES.contains(&name.as_str()) => {
+                properties.insert(name, Value::Array(vec![value]));
+            }
+            None => {
+                properties.insert(name, value);
+            }
+        }
+    }
+
+    Value::Object(properties)
+}
+
+/// Properties of the item, without the ones belonging to items nested in it
+fn collect_properties(element: ElementRef, values: &mut Vec<(String, Value)>) {
+    for child in element.children().filter_map(ElementRef::wrap) {
+        if let Some(names) = child.value().attr("itemprop") {
+            let value = property_value(child);
+            for name in names.split_whitespace() {
+                values.push((name.to_string(), value.clone()));
+            }
+        }
+        if child.value().attr("itemscope").is_none() {
+            collect_properties(child, values);
+        }
+    }
+}
+
+fn property_value(element: ElementRef) -> Value {
+    let value = element.value();
+    if value.attr("itemscope").is_some() {
+        return microdata_item(element);
+    }
+
+    let attr = match value.name() {
+        "meta" => value.attr("content"),
+        "a" | "link" | "area" => value.attr("href"),
+        "img" | "audio" | "video" | "source" | "iframe" | "embed" => value.attr("src"),
+        "object" => value.attr("data"),
+        "data" | "meter" => value.attr("value"),
+        "time" => value.attr("datetime"),
+        _ => None,
+    };
+    let text = match attr {
+        Some(attr) => attr.to_string(),
+        None => element.text().collect::<Vec<_>>().join(" "),
+    };
+
+    Value::String(text.split_whitespace().collect::<Vec<_>>().join(" "))
+}
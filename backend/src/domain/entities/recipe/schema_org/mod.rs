@@ -0,0 +1,270 @@
+pub mod html;
+
+use std::time::Duration;
+
+use common::{
+    SchemaOrgInstructionDTO, SchemaOrgInstructionsDTO, SchemaOrgRecipeDTO, SchemaOrgTypeDTO,
+    SchemaOrgYieldDTO,
+};
+
+use super::{
+    draft::{DraftStep, RecipeDraft},
+    time::TimeKind,
+    IngredientLine, Recipe, RecipeStep, ServingsType,
+};
+
+const SCHEMA_ORG_CONTEXT: &str = "https://schema.org";
+
+/// Anything longer is taken to be a mistake rather than a time a recipe takes
+pub(super) const LONGEST_DURATION_SECS: f64 = 366.0 * 86_400.0;
+
+/// Formats the duration as ISO 8601, the way schema.org expects times
+/// ```rust
+/// use std::time::Duration;
+/// use crate::backend::domain::entities::recipe::schema_org::format_duration;
+///
+/// assert_eq!(format_duration(Duration::from_secs(90 * 60)), "PT1H30M");
+/// assert_eq!(format_duration(Duration::ZERO), "PT0S");
+/// ```
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs % 3600 / 60, secs % 60);
+
+    let mut formatted = "PT".to_string();
+    if hours > 0 {
+        formatted.push_str(&format!("{hours}H"));
+    }
+    if minutes > 0 {
+        formatted.push_str(&format!("{minutes}M"));
+    }
+    if seconds > 0 || secs == 0 {
+        formatted.push_str(&format!("{seconds}S"));
+    }
+    formatted
+}
+
+/// Parses an ISO 8601 duration like `PT1H30M` or `P1DT2H`. Years and months aren't supported,
+/// since they don't have a fixed length, and neither is anything longer than a year.
+/// ```rust
+/// use std::time::Duration;
+/// use crate::backend::domain::entities::recipe::schema_org::parse_duration;
+///
+/// assert_eq!(parse_duration("PT1H30M"), Some(Duration::from_secs(90 * 60)));
+/// assert_eq!(parse_duration("1 hour"), None);
+/// assert_eq!(parse_duration("P9999999999W"), None);
+/// ```
+pub fn parse_duration(s: &str) -> Option<Duration> {
+    let rest = s.trim().strip_prefix(['P', 'p'])?;
+    let (date, time) = match rest.split_once(['T', 't']) {
+        Some((date, time)) => (date, time),
+        None => (rest, ""),
+    };
+    if date.is_empty() && time.is_empty() {
+        return None;
+    }
+
+    let seconds = |part: &str, units: &[(char, f64)]| -> Option<f64> {
+        let mut total = 0.0;
+        let mut number = String::new();
+        for c in part.chars() {
+            match units.iter().find(|(unit, _)| c.eq_ignore_ascii_case(unit)) {
+                Some((_, multiplier)) => {
+                    total += number.replace(',', ".").parse::<f64>().ok()? * multiplier;
+                    number.clear();
+                }
+                None => number.push(c),
+            }
+        }
+        number.is_empty().then_some(total)
+    };
+
+    let total = seconds(date, &[('W', 604_800.0), ('D', 86_400.0)])?
+        + seconds(time, &[('H', 3600.0), ('M', 60.0), ('S', 1.0)])?;
+    (total.is_finite() && (0.0..=LONGEST_DURATION_SECS).contains(&total))
+        .then(|| Duration::from_secs(total.round() as u64))
+}
+
+impl From<Recipe> for SchemaOrgRecipeDTO {
+    fn from(value: Recipe) -> Self {
+        let time = |kind: TimeKind| value.time.get(&kind).copied().map(format_duration);
+
+        Self {
+            context: Some(SCHEMA_ORG_CONTEXT.to_string()),
+            kind: SchemaOrgTypeDTO::One("Recipe".to_string()),
+            id: None,
+            name: value.name.clone(),
+            description: Some(value.description.clone()).filter(|d| !d.is_empty()),
+            recipe_yield: Some(SchemaOrgYieldDTO::Text(match value.servings {
+                ServingsType::Exact(servings) => format!("{servings} servings"),
+                ServingsType::FromTo(from, to) => format!("{from}-{to} servings"),
+            })),
+            prep_time: time(TimeKind::Prep),
+            cook_time: time(TimeKind::Cook),
+            total_time: value.time.total().map(format_duration),
+            recipe_ingredient: value
+                .ingredients
+                .iter()
+                .map(|i| IngredientLine::from(i).to_string())
+                .collect(),
+            recipe_instructions: SchemaOrgInstructionsDTO::Many(instructions(value.steps.as_ref())),
+        }
+    }
+}
+
+/// Steps in a row that share a section are grouped in a `HowToSection`
+fn instructions(steps: &[RecipeStep]) -> Vec<SchemaOrgInstructionDTO> {
+    let to_step = |step: &RecipeStep| SchemaOrgInstructionDTO::Step {
+        kind: "HowToStep".to_string(),
+        name: None,
+        text: step.text.clone(),
+    };
+
+    let mut instructions = vec![];
+    for group in steps.chunk_by(|a, b| a.section == b.section) {
+        match &group[0].section {
+            Some(section) => instructions.push(SchemaOrgInstructionDTO::Section {
+                kind: "HowToSection".to_string(),
+                name: section.clone(),
+                item_list_element: group.iter().map(to_step).collect(),
+            }),
+            None => instructions.extend(group.iter().map(to_step)),
+        }
+    }
+    instructions
+}
+
+impl From<SchemaOrgRecipeDTO> for RecipeDraft {
+    /// Anything that can't be made sense of is left out, recipes without a yield serve one
+    fn from(value: SchemaOrgRecipeDTO) -> Self {
+        let prep = value.prep_time.as_deref().and_then(parse_duration);
+        let cook = value.cook_time.as_deref().and_then(parse_duration);
+        let total = value.total_time.as_deref().and_then(parse_duration);
+
+        let mut time: Vec<(&str, Duration)> = vec![];
+        time.extend(prep.map(|prep| ("prep", prep)));
+        time.extend(cook.map(|cook| ("cook", cook)));
+        // The total is only worth keeping when it isn't just the sum of the others
+        let sum = prep
+            .unwrap_or_default()
+            .checked_add(cook.unwrap_or_default());
+        time.extend(
+            total
+                .filter(|total| Some(*total) != sum)
+                .map(|t| ("total", t)),
+        );
+
+        Self {
+            name: value.name.trim().to_string(),
+            description: value.description.unwrap_or_default().trim().to_string(),
+            steps: steps(value.recipe_instructions)
+                .into_iter()
+                .map(DraftStep::from)
+                .collect(),
+            time: time.into_iter().collect(),
+            servings: value
+                .recipe_yield
+                .as_ref()
+                .and_then(servings)
+                .unwrap_or(ServingsType::Exact(1)),
+            ingredients: value
+                .recipe_ingredient
+                .iter()
+                .filter_map(|line| line.parse().ok())
+                .collect(),
+        }
+    }
+}
+
+fn servings(value: &SchemaOrgYieldDTO) -> Option<ServingsType> {
+    match value {
+        SchemaOrgYieldDTO::Number(n) => to_servings(*n).map(ServingsType::Exact),
+        SchemaOrgYieldDTO::Text(text) => parse_servings(text),
+        SchemaOrgYieldDTO::Many(many) => many.iter().find_map(servings),
+    }
+}
+
+fn to_servings(n: f64) -> Option<u16> {
+    (n >= 1.0 && n <= u16::MAX as f64).then_some(n.round() as u16)
+}
+
+/// Reads servings out of text like "Serves 4" or "4-6 servings"
+pub(super) fn parse_servings(text: &str) -> Option<ServingsType> {
+    let mut numbers = text
+        .split(|c: char| !c.is_ascii_digit())
+        .filter_map(|n| n.parse::<f64>().ok())
+        .filter_map(to_servings);
+    match (numbers.next(), numbers.next()) {
+        (Some(from), Some(to)) if from < to => Some(ServingsType::FromTo(from, to)),
+        (Some(exact), _) => Some(ServingsType::Exact(exact)),
+        (None, _) => None,
+    }
+}
+
+fn steps(value: SchemaOrgInstructionsDTO) -> Vec<RecipeStep> {
+    fn flatten(instruction: SchemaOrgInstructionDTO, section: Option<&str>) -> Vec<RecipeStep> {
+        let step = |text: String| RecipeStep {
+            section: section.map(str::to_string),
+            ..RecipeStep::from(text)
+        };
+
+        match instruction {
+            SchemaOrgInstructionDTO::Section {
+                name,
+                item_list_element,
+                ..
+            } => item_list_element
+                .into_iter()
+                .flat_map(|item| flatten(item, Some(&name)))
+                .collect(),
+            SchemaOrgInstructionDTO::Step { name, text, .. } if text.trim().is_empty() => {
+                name.into_iter().map(step).collect()
+            }
+            SchemaOrgInstructionDTO::Step { text, .. } | SchemaOrgInstructionDTO::Text(text) => {
+                vec![step(text)]
+            }
+        }
+    }
+
+    let steps: Vec<RecipeStep> = match value {
+        SchemaOrgInstructionsDTO::Many(many) => many
+            .into_iter()
+            .flat_map(|instruction| flatten(instruction, None))
+            .collect(),
+        SchemaOrgInstructionsDTO::Text(text) => {
+            text.lines().map(|l| l.to_string().into()).collect()
+        }
+    };
+
+    steps
+        .into_iter()
+        .map(|step| RecipeStep {
+            text: step.text.trim().to_string(),
+            ..step
+        })
+        .filter(|step| !step.text.is_empty())
+        .collect()
+}
+
+/// Finds the first recipe in a JSON-LD document, which can be a list of nodes,
+/// a graph of them, or the recipe itself
+pub fn find_recipe(document: &serde_json::Value) -> Option<SchemaOrgRecipeDTO> {
+    match document {
+        serde_json::Value::Array(nodes) => nodes.iter().find_map(find_recipe),
+        serde_json::Value::Object(node) => {
+            let is_recipe = serde_json::from_value::<SchemaOrgTypeDTO>(
+                node.get("@type").cloned().unwrap_or_default(),
+            )
+            .is_ok_and(|kind| kind.is("Recipe"));
+
+            if is_recipe {
+                serde_json::from_value(document.clone()).ok()
+            } else {
+                node.get("@graph").and_then(find_recipe)
+            }
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests;
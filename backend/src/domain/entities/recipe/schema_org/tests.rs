@@ -0,0 +1,317 @@
+use std::time::Duration;
+
+use common::{SchemaOrgInstructionsDTO, SchemaOrgRecipeDTO, SchemaOrgYieldDTO};
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::entities::recipe::{
+        draft::{DraftStep, RecipeDraft},
+        time::TimeKind,
+        IngredientLine, IngredientUnit, RecipeStep, ServingsType,
+    },
+    test_utils::recipe_fixture,
+};
+
+use super::{find_recipe, format_duration, html::find_recipe_in_html, parse_duration};
+
+#[test]
+fn durations_round_trip() {
+    for secs in [0, 45, 15 * 60, 90 * 60, 26 * 3600 + 5] {
+        let duration = Duration::from_secs(secs);
+        assert_eq!(parse_duration(&format_duration(duration)), Some(duration));
+    }
+
+    assert_eq!(
+        parse_duration("P1DT2H"),
+        Some(Duration::from_secs(26 * 3600))
+    );
+    assert_eq!(parse_duration("PT1.5H"), Some(Duration::from_secs(90 * 60)));
+    for invalid in [
+        "",
+        "P",
+        "PT",
+        "PT5",
+        "PTXM",
+        "15 minutes",
+        "P53W",
+        "PT1e300S",
+    ] {
+        assert_eq!(parse_duration(invalid), None, "{invalid}");
+    }
+}
+
+#[test]
+fn exporting_a_recipe_maps_its_servings_times_and_ingredients() {
+    let mut recipe = recipe_fixture();
+    recipe.steps = vec![
+        RecipeStep {
+            section: Some("Tofu".to_string()),
+            ..RecipeStep::from("Cut the tofu".to_string())
+        },
+        RecipeStep {
+            section: Some("Tofu".to_string()),
+            ..RecipeStep::from("Marinate it".to_string())
+        },
+        RecipeStep::from("Serve".to_string()),
+    ]
+    .try_into()
+    .unwrap();
+
+    let document = serde_json::to_value(SchemaOrgRecipeDTO::from(recipe.clone())).unwrap();
+
+    assert_eq!(document["@context"], "https://schema.org");
+    assert_eq!(document["@type"], "Recipe");
+    assert_eq!(document["name"], "Hoisin Tofu and Broccoli");
+    assert_eq!(document["recipeYield"], "4 servings");
+    assert_eq!(document["prepTime"], "PT15M");
+    assert_eq!(document["cookTime"], "PT10M");
+    assert_eq!(document["totalTime"], "PT25M");
+    assert_eq!(
+        document["recipeIngredient"].as_array().unwrap().len(),
+        recipe.ingredients.len()
+    );
+    assert_eq!(document["recipeIngredient"][2], "4 clove Garlic");
+    assert_eq!(
+        document["recipeInstructions"],
+        serde_json::json!([
+            {
+                "@type": "HowToSection",
+                "name": "Tofu",
+                "itemListElement": [
+                    { "@type": "HowToStep", "text": "Cut the tofu" },
+                    { "@type": "HowToStep", "text": "Marinate it" }
+                ]
+            },
+            { "@type": "HowToStep", "text": "Serve" }
+        ])
+    );
+}
+
+#[test]
+fn exported_recipes_import_back() {
+    let recipe = recipe_fixture();
+
+    let draft = RecipeDraft::from(SchemaOrgRecipeDTO::from(recipe.clone()));
+
+    assert_eq!(draft.name, recipe.name);
+    assert_eq!(draft.description, recipe.description);
+    assert_eq!(draft.servings, recipe.servings);
+    assert_eq!(draft.time, recipe.time);
+    assert_eq!(
+        draft.steps,
+        recipe
+            .steps
+            .as_ref()
+            .iter()
+            .cloned()
+            .map(DraftStep::from)
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(
+        draft.ingredients,
+        recipe
+            .ingredients
+            .iter()
+            .map(IngredientLine::from)
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn importing_is_lenient_about_the_shape_of_the_document() {
+    let document = serde_json::json!({
+        "@context": "https://schema.org",
+        "@graph": [
+            { "@type": "WebPage", "name": "Some page" },
+            {
+                "@type": ["Recipe", "NewsArticle"],
+                "name": " Pancakes ",
+                "recipeYield": ["12", "12 pancakes"],
+                "prepTime": "PT10M",
+                "totalTime": "PT1H",
+                "recipeIngredient": ["250g flour", "2 eggs", "", "a pinch of salt"],
+                "recipeInstructions": "Mix everything\n\nFry the pancakes",
+                "image": "https://example.com/pancakes.jpg"
+            }
+        ]
+    });
+
+    let draft = RecipeDraft::from(find_recipe(&document).unwrap());
+
+    assert_eq!(draft.name, "Pancakes");
+    assert_eq!(draft.servings, ServingsType::Exact(12));
+    assert_eq!(
+        draft.time.get(&TimeKind::Prep),
+        Some(&Duration::from_secs(600))
+    );
+    assert_eq!(draft.time.total(), Some(Duration::from_secs(3600)));
+    assert_eq!(
+        draft
+            .ingredients
+            .iter()
+            .map(|i| (i.name.as_str(), i.amount.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("flour", IngredientUnit::Grams(250.0)),
+            (
+                "eggs",
+                IngredientUnit::Other {
+                    amount: 2.0,
+                    unit: "".to_string()
+                }
+            ),
+            (
+                "a pinch of salt",
+                IngredientUnit::Other {
+                    amount: 1.0,
+                    unit: "".to_string()
+                }
+            ),
+        ]
+    );
+    assert_eq!(
+        draft.steps,
+        vec![
+            DraftStep::from(RecipeStep::from("Mix everything".to_string())),
+            DraftStep::from(RecipeStep::from("Fry the pancakes".to_string()))
+        ]
+    );
+}
+
+#[test]
+fn importing_reads_servings_from_ranges_and_numbers() {
+    let draft = |recipe_yield: SchemaOrgYieldDTO| {
+        RecipeDraft::from(SchemaOrgRecipeDTO {
+            recipe_yield: Some(recipe_yield),
+            ..find_recipe(&serde_json::json!({ "@type": "Recipe", "name": "Soup" })).unwrap()
+        })
+        .servings
+    };
+
+    assert_eq!(
+        draft(SchemaOrgYieldDTO::Text("Serves 4 to 6".to_string())),
+        ServingsType::FromTo(4, 6)
+    );
+    assert_eq!(
+        draft(SchemaOrgYieldDTO::Number(2.0)),
+        ServingsType::Exact(2)
+    );
+    assert_eq!(
+        draft(SchemaOrgYieldDTO::Text("a big pot".to_string())),
+        ServingsType::Exact(1)
+    );
+}
+
+#[test]
+fn documents_without_a_recipe_are_rejected() {
+    for document in [
+        serde_json::json!({ "@type": "WebPage", "name": "Some page" }),
+        serde_json::json!([{ "@type": "Recipe" }]),
+        serde_json::json!("Recipe"),
+    ] {
+        assert_eq!(find_recipe(&document), None);
+    }
+
+    let recipe = find_recipe(&serde_json::json!({ "@type": "Recipe", "name": "Soup" })).unwrap();
+    assert_eq!(
+        recipe.recipe_instructions,
+        SchemaOrgInstructionsDTO::Many(vec![])
+    );
+}
+
+#[test]
+fn recipes_are_found_in_json_ld_of_web_pages() {
+    let html = r#"<!DOCTYPE html>
+        <html>
+            <head>
+                <script type="application/ld+json">{ not json</script>
+                <script type="application/ld+json">
+                    { "@context": "https://schema.org", "@type": "WebSite", "name": "Blog" }
+                </script>
+                <script type="application/ld+json">
+                    {
+                        "@context": "https://schema.org",
+                        "@type": "Recipe",
+                        "name": "Pancakes",
+                        "recipeIngredient": ["250g flour"],
+                        "recipeInstructions": [{ "@type": "HowToStep", "text": "Fry" }]
+                    }
+                </script>
+            </head>
+            <body><h1>Pancakes</h1></body>
+        </html>"#;
+
+    let recipe = find_recipe_in_html(html).unwrap();
+
+    assert_eq!(recipe.name, "Pancakes");
+    assert_eq!(recipe.recipe_ingredient, vec!["250g flour"]);
+}
+
+#[test]
+fn recipes_are_found_in_microdata_of_web_pages() {
+    let html = r#"<html><body>
+        <article itemscope itemtype="http://schema.org/Recipe">
+            <h1 itemprop="name">Tomato   soup</h1>
+            <p itemprop="description">Warms you up</p>
+            <meta itemprop="prepTime" content="PT10M">
+            Cooks in <time itemprop="cookTime" datetime="PT30M">half an hour</time>
+            <span itemprop="recipeYield">Serves 4</span>
+            <ul>
+                <li itemprop="recipeIngredient">1 kg tomatoes, chopped</li>
+                <li itemprop="ingredients">2 cloves garlic</li>
+            </ul>
+            <div itemprop="author" itemscope itemtype="http://schema.org/Person">
+                <span itemprop="name">Someone</span>
+            </div>
+            <ol>
+                <li itemprop="recipeInstructions" itemscope itemtype="http://schema.org/HowToStep">
+                    <span itemprop="text">Cook the tomatoes</span>
+                </li>
+                <li itemprop="recipeInstructions">Blend them</li>
+            </ol>
+        </article>
+    </body></html>"#;
+
+    let draft = RecipeDraft::from(find_recipe_in_html(html).unwrap());
+
+    assert_eq!(draft.name, "Tomato soup");
+    assert_eq!(draft.description, "Warms you up");
+    assert_eq!(draft.servings, ServingsType::Exact(4));
+    assert_eq!(
+        draft.time.get(&TimeKind::Cook),
+        Some(&Duration::from_secs(30 * 60))
+    );
+    assert_eq!(
+        draft
+            .ingredients
+            .iter()
+            .map(|i| (i.name.as_str(), i.amount.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("tomatoes", IngredientUnit::Kilograms(1.0)),
+            (
+                "garlic",
+                IngredientUnit::Other {
+                    amount: 2.0,
+                    unit: "cloves".to_string()
+                }
+            ),
+        ]
+    );
+    assert_eq!(
+        draft.steps,
+        vec![
+            DraftStep::from(RecipeStep::from("Cook the tomatoes".to_string())),
+            DraftStep::from(RecipeStep::from("Blend them".to_string()))
+        ]
+    );
+}
+
+#[test]
+fn web_pages_without_a_recipe_are_rejected() {
+    assert_eq!(
+        find_recipe_in_html("<html><body><h1>Not a recipe</h1></body></html>"),
+        None
+    );
+    assert_eq!(find_recipe_in_html(""), None);
+}
@@ -0,0 +1,97 @@
+use common::RecipeSearchResultDTO;
+use uuid::Uuid;
+
+use super::{errors::ValidationError, Recipe};
+
+/// Splits text into lowercase words, dropping punctuation
+/// ```rust
+/// use crate::backend::domain::entities::recipe::search::tokenize;
+///
+/// let words: Vec<String> = tokenize("Stir-fry the TOFU!").collect();
+/// assert_eq!(words, vec!["stir", "fry", "the", "tofu"]);
+/// ```
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+}
+
+/// Escapes the characters that would be read as HTML, so that the snippets only ever
+/// contain the `<mark>` tags added around matches
+/// ```rust
+/// use crate::backend::domain::entities::recipe::search::escape_html;
+///
+/// assert_eq!(
+///     escape_html("<img src=x onerror=\"alert('hi')\"> & co"),
+///     "&lt;img src=x onerror=&quot;alert(&#39;hi&#39;)&quot;&gt; &amp; co"
+/// );
+/// ```
+pub fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Words a recipe has to contain, every one of them matching any word that starts with it
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchTerms(Vec<String>);
+
+impl SearchTerms {
+    pub fn iter(&self) -> impl Iterator<Item = &str> {
+        self.0.iter().map(String::as_str)
+    }
+
+    pub fn matches(&self, word: &str) -> bool {
+        self.iter().any(|term| word.starts_with(term))
+    }
+}
+
+impl TryFrom<&str> for SearchTerms {
+    type Error = ValidationError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let terms: Vec<String> = tokenize(value).collect();
+
+        if terms.is_empty() {
+            Err(ValidationError::EmptyField(vec!["q"]))
+        } else {
+            Ok(Self(terms))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeSearchQuery {
+    pub terms: SearchTerms,
+    pub limit: u64,
+    /// Private recipes of this user are searched too
+    pub viewer: Option<Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeSearchResult {
+    pub recipe: Recipe,
+    /// Higher is better, only comparable between results of the same search
+    pub rank: f64,
+    /// A fragment of the description and steps as escaped HTML, with the matched words wrapped
+    /// in `<mark>` tags
+    pub snippet: String,
+}
+
+impl From<RecipeSearchResult> for RecipeSearchResultDTO {
+    fn from(value: RecipeSearchResult) -> Self {
+        Self {
+            recipe: value.recipe.into(),
+            rank: value.rank,
+            snippet: value.snippet,
+        }
+    }
+}
@@ -1,14 +1,23 @@
 use uuid::Uuid;
 
-use crate::domain::entities::{
-    ingredient::{
-        types::{IngredientDescription, IngredientName, WhichDiets},
-        Ingredient, IngredientModel,
+use crate::{
+    domain::entities::{
+        ingredient::{
+            types::{
+                Allergen, Allergens, DietFriendly, DietViolation, DietViolations,
+                IngredientDescription, IngredientName, WhichDiets,
+            },
+            Ingredient, IngredientModel,
+        },
+        recipe::errors::ValidationError,
     },
-    recipe::errors::ValidationError,
+    test_utils::{ingredient_fixture, recipe_fixture},
 };
 
-use super::{IngredientWithAmount, IngredientWithAmountModel};
+use super::{
+    revision::RecipeSnapshot, IngredientWithAmount, IngredientWithAmountModel, Recipe,
+    RecipeAllergens,
+};
 
 #[test]
 fn converting_ingredient_with_amount_works() {
@@ -17,7 +26,11 @@ fn converting_ingredient_with_amount_works() {
             id: Uuid::nil(),
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
-            diet_friendly: vec![],
+            diet_violations: vec![],
+            allergens: vec![],
+            density: None,
+            parent_id: None,
+            version: 1,
         },
         amount: serde_json::json!({
             "grams": 20
@@ -31,7 +44,11 @@ fn converting_ingredient_with_amount_works() {
             id: Uuid::nil(),
             name: IngredientName("Ingredient name".to_owned()),
             description: IngredientDescription("Ingredient description".to_owned()),
-            diet_friendly: WhichDiets::new(),
+            diet_violations: DietViolations::new(),
+            allergens: Allergens::new(),
+            density: None,
+            parent_id: None,
+            version: Default::default(),
         },
         amount: super::IngredientUnit::Grams(20.0),
         notes: None,
@@ -50,7 +67,11 @@ fn converting_ingredient_with_custom_amount_unit_works() {
             id: Uuid::nil(),
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
-            diet_friendly: vec![],
+            diet_violations: vec![],
+            allergens: vec![],
+            density: None,
+            parent_id: None,
+            version: 1,
         },
         amount: serde_json::json!({
             "other": {
@@ -67,7 +88,11 @@ fn converting_ingredient_with_custom_amount_unit_works() {
             id: Uuid::nil(),
             name: IngredientName("Ingredient name".to_owned()),
             description: IngredientDescription("Ingredient description".to_owned()),
-            diet_friendly: WhichDiets::new(),
+            diet_violations: DietViolations::new(),
+            allergens: Allergens::new(),
+            density: None,
+            parent_id: None,
+            version: Default::default(),
         },
         amount: super::IngredientUnit::Other {
             unit: "cloves".to_owned(),
@@ -89,7 +114,11 @@ fn converting_ingredient_with_custom_amount_unit_but_without_unit_descriptor_fai
             id: Uuid::nil(),
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
-            diet_friendly: vec![],
+            diet_violations: vec![],
+            allergens: vec![],
+            density: None,
+            parent_id: None,
+            version: 1,
         },
         amount: serde_json::json!({
             "other": {
@@ -116,7 +145,11 @@ fn malformed_ingredient_amount_fails() {
             id: Uuid::nil(),
             name: "Ingredient name".to_owned(),
             description: "Ingredient description".to_owned(),
-            diet_friendly: vec![],
+            diet_violations: vec![],
+            allergens: vec![],
+            density: None,
+            parent_id: None,
+            version: 1,
         },
         amount: serde_json::json!("10 grams"),
         notes: None,
@@ -131,3 +164,112 @@ fn malformed_ingredient_amount_fails() {
         ValidationError::DeserializationFailed("amount", _)
     ))
 }
+
+#[test]
+fn recipe_is_only_as_diet_friendly_as_its_required_ingredients() {
+    let mut recipe = recipe_fixture();
+    assert_eq!(recipe.diet_friendly(), WhichDiets::all());
+
+    recipe.ingredients.push(IngredientWithAmount {
+        ingredient: Ingredient {
+            diet_violations: vec![DietViolation::Meat].into(),
+            ..ingredient_fixture()
+        },
+        amount: super::IngredientUnit::Grams(20.0),
+        notes: None,
+        optional: false,
+    });
+    assert_eq!(
+        recipe.diet_friendly(),
+        vec![
+            DietFriendly::Halal,
+            DietFriendly::KosherStyle,
+            DietFriendly::Keto,
+            DietFriendly::GlutenFree,
+        ]
+        .into()
+    );
+}
+
+#[test]
+fn optional_ingredients_do_not_affect_recipe_diets() {
+    let mut recipe = recipe_fixture();
+    recipe.ingredients.push(IngredientWithAmount {
+        ingredient: ingredient_fixture(),
+        amount: super::IngredientUnit::Grams(20.0),
+        notes: None,
+        optional: true,
+    });
+
+    assert!(recipe.diet_friendly().contains(&DietFriendly::Vegan));
+}
+
+#[test]
+fn recipe_allergens_separate_the_ones_from_optional_ingredients() {
+    let mut recipe = recipe_fixture();
+    assert_eq!(recipe.allergens().contains, vec![Allergen::Soybeans].into());
+
+    let with_allergens = |allergens: Vec<Allergen>, optional: bool| IngredientWithAmount {
+        ingredient: Ingredient {
+            allergens: allergens.into(),
+            ..ingredient_fixture()
+        },
+        amount: super::IngredientUnit::Grams(20.0),
+        notes: None,
+        optional,
+    };
+    recipe.ingredients.push(with_allergens(
+        vec![Allergen::Milk, Allergen::Gluten],
+        false,
+    ));
+    recipe
+        .ingredients
+        .push(with_allergens(vec![Allergen::Milk, Allergen::Nuts], true));
+
+    assert_eq!(
+        recipe.allergens(),
+        RecipeAllergens {
+            contains: vec![Allergen::Gluten, Allergen::Soybeans, Allergen::Milk].into(),
+            optional: vec![Allergen::Nuts].into(),
+        }
+    );
+}
+
+#[test]
+fn diffing_snapshots_lists_every_change() {
+    let before = recipe_fixture();
+    let mut after = Recipe {
+        name: "Tofu and Broccoli".to_string(),
+        public: false,
+        ..before.clone()
+    };
+    after
+        .ingredients
+        .retain(|i| i.ingredient.id != Uuid::from_u128(2));
+    after.ingredients[0].amount = super::IngredientUnit::Grams(500.0);
+    after.ingredients.push(IngredientWithAmount {
+        ingredient: ingredient_fixture(),
+        amount: super::IngredientUnit::Grams(20.0),
+        notes: None,
+        optional: true,
+    });
+
+    let changes = RecipeSnapshot::from(&before).diff(&RecipeSnapshot::from(&after));
+
+    assert_eq!(
+        changes
+            .iter()
+            .map(|c| serde_json::to_value(c).unwrap()["field"].clone())
+            .collect::<Vec<_>>(),
+        vec![
+            "name",
+            "public",
+            "ingredient_changed",
+            "ingredient_removed",
+            "ingredient_added"
+        ]
+    );
+    assert!(RecipeSnapshot::from(&after)
+        .diff(&RecipeSnapshot::from(&after))
+        .is_empty());
+}
@@ -0,0 +1,135 @@
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use shrinkwraprs::Shrinkwrap;
+
+use super::errors::ValidationError;
+
+/// What the time of a recipe is spent on. Labels that aren't well known are kept as they are.
+#[derive(Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+#[serde(from = "String", into = "String")]
+pub enum TimeKind {
+    Prep,
+    Cook,
+    Rest,
+    Marinate,
+    /// Overrides the computed total, i.e. when the other times overlap
+    Total,
+    Custom(String),
+}
+
+impl From<String> for TimeKind {
+    /// Recognizes the well known kinds in labels like `Prep time` or `cooking`
+    /// ```rust
+    /// use crate::backend::domain::entities::recipe::time::TimeKind;
+    ///
+    /// assert_eq!(TimeKind::from("Prep time".to_string()), TimeKind::Prep);
+    /// assert_eq!(TimeKind::from("cooking".to_string()), TimeKind::Cook);
+    /// assert_eq!(TimeKind::from("Proofing".to_string()), TimeKind::Custom("Proofing".to_string()));
+    /// ```
+    fn from(value: String) -> Self {
+        let label = value.trim();
+        let normalized = label.to_lowercase();
+        let normalized = normalized
+            .strip_suffix(" time")
+            .unwrap_or(&normalized)
+            .trim();
+
+        match normalized {
+            "prep" | "preparation" => Self::Prep,
+            "cook" | "cooking" => Self::Cook,
+            "rest" | "resting" => Self::Rest,
+            "marinate" | "marinating" | "marinade" => Self::Marinate,
+            "total" => Self::Total,
+            _ => Self::Custom(label.to_string()),
+        }
+    }
+}
+
+impl From<TimeKind> for String {
+    fn from(value: TimeKind) -> Self {
+        match value {
+            TimeKind::Prep => "prep".to_string(),
+            TimeKind::Cook => "cook".to_string(),
+            TimeKind::Rest => "rest".to_string(),
+            TimeKind::Marinate => "marinate".to_string(),
+            TimeKind::Total => "total".to_string(),
+            TimeKind::Custom(label) => label,
+        }
+    }
+}
+
+/// How long each part of making a recipe takes
+#[derive(Serialize, Deserialize, Shrinkwrap, PartialEq, Debug, Clone, Default)]
+#[serde(transparent)]
+pub struct RecipeTime(BTreeMap<TimeKind, Duration>);
+
+impl RecipeTime {
+    /// The total given for the recipe, or the sum of all its times otherwise.
+    /// `None` if the recipe has no times at all.
+    pub fn total(&self) -> Option<Duration> {
+        if let Some(total) = self.0.get(&TimeKind::Total) {
+            return Some(*total);
+        }
+        if self.0.is_empty() {
+            return None;
+        }
+        // Saturates rather than overflowing, as every time can be as long as the client likes
+        Some(
+            self.0
+                .values()
+                .fold(Duration::ZERO, |total, time| total.saturating_add(*time)),
+        )
+    }
+
+    /// The total in whole seconds, the way it's stored for filtering and sorting
+    pub fn total_secs(&self) -> Option<i64> {
+        self.total()
+            .map(|total| i64::try_from(total.as_secs()).unwrap_or(i64::MAX))
+    }
+}
+
+impl<K: Into<String>> FromIterator<(K, Duration)> for RecipeTime {
+    /// Labels without any text in them are left out
+    fn from_iter<T: IntoIterator<Item = (K, Duration)>>(iter: T) -> Self {
+        Self(
+            iter.into_iter()
+                .map(|(label, duration)| (label.into(), duration))
+                .filter(|(label, _)| !label.trim().is_empty())
+                .map(|(label, duration)| (TimeKind::from(label), duration))
+                .collect(),
+        )
+    }
+}
+
+impl TryFrom<HashMap<String, u64>> for RecipeTime {
+    type Error = ValidationError;
+    /// Labels for the same kind of time, like `Prep time` and `prep`, are refused
+    /// instead of one of them being dropped
+    fn try_from(value: HashMap<String, u64>) -> Result<Self, Self::Error> {
+        let mut time = BTreeMap::new();
+        for (label, secs) in value {
+            if label.trim().is_empty() {
+                continue;
+            }
+            let kind = TimeKind::from(label);
+            if time.contains_key(&kind) {
+                return Err(ValidationError::DuplicateTime(kind.into()));
+            }
+            time.insert(kind, Duration::from_secs(secs));
+        }
+
+        Ok(Self(time))
+    }
+}
+
+impl From<RecipeTime> for HashMap<String, u64> {
+    fn from(value: RecipeTime) -> Self {
+        value
+            .0
+            .into_iter()
+            .map(|(kind, duration)| (kind.into(), duration.as_secs()))
+            .collect()
+    }
+}
@@ -0,0 +1,38 @@
+use chrono::{DateTime, Duration, Utc};
+use rand::{distributions::Alphanumeric, Rng};
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+pub const SESSION_TOKEN_LENGTH: usize = 64;
+
+/// How long a session lasts after logging in
+pub fn session_lifetime() -> Duration {
+    Duration::days(7)
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Session {
+    pub token: String,
+    pub user_id: Uuid,
+    pub expires_at: DateTime<Utc>,
+}
+
+impl Session {
+    pub fn new(user_id: Uuid) -> Self {
+        let token = rand::thread_rng()
+            .sample_iter(&Alphanumeric)
+            .take(SESSION_TOKEN_LENGTH)
+            .map(char::from)
+            .collect();
+
+        Self {
+            token,
+            user_id,
+            expires_at: Utc::now() + session_lifetime(),
+        }
+    }
+
+    pub fn is_expired(&self) -> bool {
+        self.expires_at <= Utc::now()
+    }
+}
@@ -0,0 +1,59 @@
+use chrono::{DateTime, Utc};
+use common::{TrashDTO, TrashedIngredientDTO, TrashedRecipeDTO};
+
+use super::{ingredient::Ingredient, recipe::Recipe};
+
+/// A deleted recipe or ingredient, kept around until it's restored or purged
+#[derive(PartialEq, Debug, Clone)]
+pub struct Trashed<T> {
+    pub item: T,
+    pub deleted_at: DateTime<Utc>,
+}
+
+impl<T> Trashed<T> {
+    pub fn new(item: T) -> Self {
+        Self {
+            item,
+            deleted_at: Utc::now(),
+        }
+    }
+
+    /// Whether it was deleted before the cutoff, which makes it due to be purged
+    pub fn is_expired(&self, cutoff: DateTime<Utc>) -> bool {
+        self.deleted_at < cutoff
+    }
+}
+
+/// The trash as a user sees it, with their own recipes and every ingredient
+#[derive(PartialEq, Debug, Clone)]
+pub struct Trash {
+    pub recipes: Vec<Trashed<Recipe>>,
+    pub ingredients: Vec<Trashed<Ingredient>>,
+}
+
+impl From<Trashed<Recipe>> for TrashedRecipeDTO {
+    fn from(value: Trashed<Recipe>) -> Self {
+        Self {
+            recipe: value.item.into(),
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+impl From<Trashed<Ingredient>> for TrashedIngredientDTO {
+    fn from(value: Trashed<Ingredient>) -> Self {
+        Self {
+            ingredient: value.item.into(),
+            deleted_at: value.deleted_at,
+        }
+    }
+}
+
+impl From<Trash> for TrashDTO {
+    fn from(value: Trash) -> Self {
+        Self {
+            recipes: value.recipes.into_iter().map(Into::into).collect(),
+            ingredients: value.ingredients.into_iter().map(Into::into).collect(),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ValidationError {
+    #[error("The fields {0:?} was empty")]
+    EmptyField(Vec<&'static str>),
+
+    #[error("{0} is not a valid e-mail address")]
+    InvalidEmail(String),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
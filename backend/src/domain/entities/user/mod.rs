@@ -0,0 +1,48 @@
+pub mod errors;
+pub mod types;
+
+use common::user::UserDataDTO;
+use sqlx::FromRow;
+use uuid::Uuid;
+
+use self::{
+    errors::ValidationError,
+    types::{Email, PasswordHash, Username},
+};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct User {
+    pub id: Uuid,
+    pub username: Username,
+    pub email: Email,
+    pub password_hash: PasswordHash,
+}
+
+impl From<User> for UserDataDTO {
+    fn from(value: User) -> Self {
+        Self {
+            username: value.username.to_string(),
+            email: value.email.to_string(),
+        }
+    }
+}
+
+#[derive(FromRow, Debug, Clone, PartialEq)]
+pub struct UserModel {
+    pub id: Uuid,
+    pub username: String,
+    pub email: String,
+    pub password_hash: String,
+}
+
+impl TryFrom<UserModel> for User {
+    type Error = ValidationError;
+    fn try_from(value: UserModel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: value.id,
+            username: value.username.try_into()?,
+            email: value.email.try_into()?,
+            password_hash: PasswordHash(value.password_hash),
+        })
+    }
+}
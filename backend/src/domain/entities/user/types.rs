@@ -0,0 +1,118 @@
+use argon2::{
+    password_hash::{rand_core::OsRng, PasswordHasher, PasswordVerifier, SaltString},
+    Argon2,
+};
+use serde::{Deserialize, Serialize};
+use shrinkwraprs::Shrinkwrap;
+
+use super::errors::ValidationError;
+
+#[derive(Serialize, Deserialize, Shrinkwrap, sqlx::Type, Debug, Clone, PartialEq, Eq, Hash)]
+#[sqlx(transparent)]
+pub struct Username(pub String);
+
+impl std::fmt::Display for Username {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl TryFrom<String> for Username {
+    type Error = ValidationError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim().to_string();
+        if value.is_empty() {
+            return Err(ValidationError::EmptyField(vec!["username"]));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<&str> for Username {
+    type Error = ValidationError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_string())
+    }
+}
+
+#[derive(Serialize, Deserialize, Shrinkwrap, sqlx::Type, Debug, Clone, PartialEq, Eq, Hash)]
+#[sqlx(transparent)]
+pub struct Email(pub String);
+
+impl std::fmt::Display for Email {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Only checks that there's something on both sides of the `@`,
+/// the only real validation of an e-mail address is sending something to it
+/// ```rust
+/// use crate::backend::domain::entities::user::types::Email;
+///
+/// assert!(Email::try_from("user@example.com").is_ok());
+/// assert!(Email::try_from("user.example.com").is_err());
+/// assert!(Email::try_from("@example.com").is_err());
+/// ```
+impl TryFrom<String> for Email {
+    type Error = ValidationError;
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let value = value.trim().to_string();
+        if value.is_empty() {
+            return Err(ValidationError::EmptyField(vec!["email"]));
+        }
+        match value.split_once('@') {
+            Some((user, domain)) if !user.is_empty() && !domain.is_empty() => Ok(Self(value)),
+            _ => Err(ValidationError::InvalidEmail(value)),
+        }
+    }
+}
+
+impl TryFrom<&str> for Email {
+    type Error = ValidationError;
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_string())
+    }
+}
+
+/// An Argon2 hash of a password, in the PHC string format
+#[derive(Shrinkwrap, Clone, PartialEq, Eq)]
+pub struct PasswordHash(pub String);
+
+impl std::fmt::Debug for PasswordHash {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("PasswordHash(..)")
+    }
+}
+
+impl PasswordHash {
+    /// ```rust
+    /// use crate::backend::domain::entities::user::types::PasswordHash;
+    ///
+    /// let hash = PasswordHash::new("hunter2").unwrap();
+    /// assert!(hash.verify("hunter2"));
+    /// assert!(!hash.verify("hunter3"));
+    /// ```
+    pub fn new(password: &str) -> Result<Self, ValidationError> {
+        if password.is_empty() {
+            return Err(ValidationError::EmptyField(vec!["password"]));
+        }
+
+        let salt = SaltString::generate(&mut OsRng);
+        let hash = Argon2::default()
+            .hash_password(password.as_bytes(), &salt)
+            .map_err(|e| eyre::eyre!("Could not hash the password: {e}"))?;
+
+        Ok(Self(hash.to_string()))
+    }
+
+    pub fn verify(&self, password: &str) -> bool {
+        argon2::PasswordHash::new(&self.0)
+            .map(|hash| {
+                Argon2::default()
+                    .verify_password(password.as_bytes(), &hash)
+                    .is_ok()
+            })
+            .unwrap_or(false)
+    }
+}
@@ -0,0 +1,49 @@
+use shrinkwraprs::Shrinkwrap;
+
+/// Counts the changes made to a recipe or an ingredient, starting at 1.
+/// Clients get it as an `ETag`, so they can make sure they only change what they last saw.
+#[derive(Shrinkwrap, sqlx::Type, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[sqlx(transparent)]
+pub struct Version(pub i32);
+
+impl Version {
+    pub fn next(self) -> Self {
+        Self(self.0 + 1)
+    }
+}
+
+impl Default for Version {
+    fn default() -> Self {
+        Self(1)
+    }
+}
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+/// Which versions a change may be applied to, as given by the `If-Match` header
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum ExpectedVersion {
+    #[default]
+    Any,
+    OneOf(Vec<Version>),
+}
+
+impl ExpectedVersion {
+    /// ```rust
+    /// use crate::backend::domain::entities::version::{ExpectedVersion, Version};
+    ///
+    /// assert!(ExpectedVersion::Any.matches(Version(3)));
+    /// assert!(ExpectedVersion::OneOf(vec![Version(2), Version(3)]).matches(Version(3)));
+    /// assert!(!ExpectedVersion::OneOf(vec![]).matches(Version(3)));
+    /// ```
+    pub fn matches(&self, version: Version) -> bool {
+        match self {
+            Self::Any => true,
+            Self::OneOf(versions) => versions.contains(&version),
+        }
+    }
+}
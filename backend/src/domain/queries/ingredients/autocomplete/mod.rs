@@ -0,0 +1,54 @@
+use crate::domain::{
+    entities::{
+        ingredient::{
+            autocomplete::{
+                AutocompleteText, IngredientAutocompleteQuery, DEFAULT_AUTOCOMPLETE_LIMIT,
+            },
+            errors::ValidationError,
+            Ingredient,
+        },
+        pagination::MAX_PAGE_SIZE,
+    },
+    repositories::ingredients::{
+        errors::GetAllIngredientsError as GetAllIngredientsErrorInternal,
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum AutocompleteIngredientsError {
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetAllIngredientsErrorInternal> for AutocompleteIngredientsError {
+    fn from(value: GetAllIngredientsErrorInternal) -> Self {
+        match value {
+            GetAllIngredientsErrorInternal::ValidationError(e) => Self::ValidationError(e),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+/// Suggests ingredients for what was typed so far, tolerating case, accents and typos
+#[tracing::instrument("[QUERY] Autocomplete ingredients", skip(repo))]
+pub async fn autocomplete_ingredients(
+    repo: IngredientRepositoryService,
+    text: &str,
+    limit: Option<u64>,
+) -> Result<Vec<Ingredient>, AutocompleteIngredientsError> {
+    let query = IngredientAutocompleteQuery {
+        text: AutocompleteText::try_from(text)?,
+        limit: limit
+            .unwrap_or(DEFAULT_AUTOCOMPLETE_LIMIT)
+            .clamp(1, MAX_PAGE_SIZE),
+    };
+
+    Ok(repo.autocomplete(&query).await?)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,126 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::{
+        errors::ValidationError,
+        types::{Allergens, DietViolations, IngredientDescription, IngredientName},
+        Ingredient,
+    },
+    queries::ingredients::autocomplete::{autocomplete_ingredients, AutocompleteIngredientsError},
+    repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+};
+
+use pretty_assertions::assert_eq;
+
+fn ingredient(name: &str) -> Ingredient {
+    Ingredient {
+        id: Uuid::now_v7(),
+        name: IngredientName(name.into()),
+        description: IngredientDescription(format!("Description of {name}")),
+        diet_violations: DietViolations::new(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: None,
+        version: Default::default(),
+    }
+}
+
+async fn insert_all(repo: &IngredientRepositoryService, names: &[&str]) {
+    for name in names {
+        repo.insert(ingredient(name)).await.unwrap();
+    }
+}
+
+fn names(ingredients: Vec<Ingredient>) -> Vec<String> {
+    ingredients
+        .into_iter()
+        .map(|ingredient| ingredient.name.to_string())
+        .collect()
+}
+
+pub async fn ranks_prefix_matches_first(repo: impl IngredientRepository) {
+    // GIVEN
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    insert_all(
+        &repo,
+        &["Cherry tomato", "Tomato", "Sundried tomatoes", "Potato"],
+    )
+    .await;
+
+    // WHEN
+    let result = autocomplete_ingredients(repo, "TOMA", None).await.unwrap();
+
+    // THEN
+    assert_eq!(
+        names(result),
+        vec!["Tomato", "Cherry tomato", "Sundried tomatoes"]
+    );
+}
+
+pub async fn ignores_accents(repo: impl IngredientRepository) {
+    // GIVEN
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    insert_all(&repo, &["Jalapeño", "Crème fraîche", "Cream"]).await;
+
+    // WHEN
+    let jalapeno = autocomplete_ingredients(repo.clone(), "jalapeno", None)
+        .await
+        .unwrap();
+    let creme = autocomplete_ingredients(repo, "Crême", None).await.unwrap();
+
+    // THEN
+    assert_eq!(names(jalapeno), vec!["Jalapeño"]);
+    assert_eq!(names(creme), vec!["Crème fraîche"]);
+}
+
+pub async fn tolerates_typos(repo: impl IngredientRepository) {
+    // GIVEN
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    insert_all(&repo, &["Cheddar cheese", "Tomato", "Tofu"]).await;
+
+    // WHEN
+    let cheddar = autocomplete_ingredients(repo.clone(), "chedar", None)
+        .await
+        .unwrap();
+    let tomato = autocomplete_ingredients(repo.clone(), "tomatoe", None)
+        .await
+        .unwrap();
+    let short = autocomplete_ingredients(repo, "ton", None).await.unwrap();
+
+    // THEN
+    assert_eq!(names(cheddar), vec!["Cheddar cheese"]);
+    assert_eq!(names(tomato), vec!["Tomato"]);
+    assert_eq!(names(short), Vec::<String>::new());
+}
+
+pub async fn respects_the_limit(repo: impl IngredientRepository) {
+    // GIVEN
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    insert_all(&repo, &["Tomato", "Tomato paste", "Tomato juice"]).await;
+
+    // WHEN
+    let result = autocomplete_ingredients(repo, "tomato", Some(2))
+        .await
+        .unwrap();
+
+    // THEN
+    assert_eq!(names(result), vec!["Tomato", "Tomato juice"]);
+}
+
+pub async fn empty_text_is_rejected(repo: impl IngredientRepository) {
+    // GIVEN
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    // WHEN
+    let result = autocomplete_ingredients(repo, "   ", None).await;
+
+    // THEN
+    assert!(matches!(
+        result,
+        Err(AutocompleteIngredientsError::ValidationError(
+            ValidationError::EmptyField(_)
+        ))
+    ));
+}
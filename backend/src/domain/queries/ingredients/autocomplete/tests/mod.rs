@@ -0,0 +1,73 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn ranks_prefix_matches_first() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::ranks_prefix_matches_first(repo).await;
+    }
+
+    #[tokio::test]
+    async fn ignores_accents() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::ignores_accents(repo).await;
+    }
+
+    #[tokio::test]
+    async fn tolerates_typos() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::tolerates_typos(repo).await;
+    }
+
+    #[tokio::test]
+    async fn respects_the_limit() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::respects_the_limit(repo).await;
+    }
+
+    #[tokio::test]
+    async fn empty_text_is_rejected() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::empty_text_is_rejected(repo).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn ranks_prefix_matches_first(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::ranks_prefix_matches_first(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn ignores_accents(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::ignores_accents(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn tolerates_typos(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::tolerates_typos(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn respects_the_limit(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::respects_the_limit(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn empty_text_is_rejected(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::empty_text_is_rejected(repo).await;
+    }
+}
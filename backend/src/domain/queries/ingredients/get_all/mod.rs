@@ -1,5 +1,5 @@
 use crate::domain::{
-    entities::ingredient::Ingredient,
+    entities::ingredient::{Ingredient, IngredientListQuery},
     repositories::ingredients::{
         errors::GetAllIngredientsError as GetAllIngredientsErrorInternal,
         IngredientRepositoryService,
@@ -21,10 +21,12 @@ impl From<GetAllIngredientsErrorInternal> for GetAllIngredientsError {
 #[tracing::instrument("[QUERY] Get all ingredients", skip(repo))]
 pub async fn get_all_ingredients(
     repo: IngredientRepositoryService,
+    query: &IngredientListQuery,
 ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
-    repo.get_all().await.map_err(GetAllIngredientsError::from)
+    repo.get_all(query)
+        .await
+        .map_err(GetAllIngredientsError::from)
 }
 
 #[cfg(test)]
 mod tests;
-
@@ -4,8 +4,11 @@ use uuid::Uuid;
 
 use crate::domain::{
     entities::ingredient::{
-        types::{DietFriendly, IngredientDescription, IngredientName, WhichDiets},
-        Ingredient,
+        types::{
+            Allergens, DietFriendly, DietViolation, DietViolations, IngredientDescription,
+            IngredientName,
+        },
+        Ingredient, IngredientListQuery,
     },
     queries::ingredients::get_all::get_all_ingredients,
     repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
@@ -18,7 +21,9 @@ pub async fn returns_empty_vec_when_no_items_inside(repo: impl IngredientReposit
     let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
 
     // WHEN
-    let result = get_all_ingredients(repo).await.unwrap();
+    let result = get_all_ingredients(repo, &IngredientListQuery::default())
+        .await
+        .unwrap();
 
     // THEN
     assert_eq!(result, vec![]);
@@ -31,21 +36,31 @@ pub async fn returns_vec_of_items_inside(repo: impl IngredientRepository) {
         id: Uuid::now_v7(),
         name: IngredientName("Tomato".into()),
         description: IngredientDescription("Description of a tomato".into()),
-        diet_friendly: vec![DietFriendly::Vegan, DietFriendly::Vegetarian].into(),
+        diet_violations: DietViolations::new(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: None,
+        version: Default::default(),
     };
 
     let given_2 = Ingredient {
         id: Uuid::now_v7(),
         name: IngredientName("Meat fries".into()),
         description: IngredientDescription("Description of meat fries (whatever they are)".into()),
-        diet_friendly: WhichDiets::new(),
+        diet_violations: DietViolations::new(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: None,
+        version: Default::default(),
     };
 
     repo.insert(given_1.clone()).await.unwrap();
     repo.insert(given_2.clone()).await.unwrap();
 
     // WHEN
-    let mut result = get_all_ingredients(repo).await.unwrap();
+    let mut result = get_all_ingredients(repo, &IngredientListQuery::default())
+        .await
+        .unwrap();
     result.sort_by_key(|k| k.id);
 
     let mut expected = vec![given_1, given_2];
@@ -54,3 +69,99 @@ pub async fn returns_vec_of_items_inside(repo: impl IngredientRepository) {
     // THEN
     assert_eq!(result, expected);
 }
+
+fn soy_sauces() -> [Ingredient; 3] {
+    let parent = Ingredient {
+        id: Uuid::from_u128(1),
+        name: IngredientName("Soy sauce".into()),
+        description: IngredientDescription("Salty".into()),
+        diet_violations: vec![DietViolation::Gluten].into(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: None,
+        version: Default::default(),
+    };
+    let variant = Ingredient {
+        id: Uuid::from_u128(2),
+        name: IngredientName("Tamari".into()),
+        description: IngredientDescription("Salty, without wheat".into()),
+        diet_violations: DietViolations::new(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: Some(Uuid::from_u128(1)),
+        version: Default::default(),
+    };
+    let other = Ingredient {
+        id: Uuid::from_u128(3),
+        name: IngredientName("Fish sauce".into()),
+        description: IngredientDescription("Fishy".into()),
+        diet_violations: vec![DietViolation::Fish].into(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: None,
+        version: Default::default(),
+    };
+
+    [parent, variant, other]
+}
+
+pub async fn filters_by_name_and_diet(repo: impl IngredientRepository) {
+    // GIVEN
+    let [parent, variant, other] = soy_sauces();
+    repo.insert(parent.clone()).await.unwrap();
+    repo.insert(variant.clone()).await.unwrap();
+    repo.insert(other.clone()).await.unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    // WHEN
+    let mut by_name = get_all_ingredients(
+        repo.clone(),
+        &IngredientListQuery {
+            name: Some("SAUCE".into()),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    let mut by_diet = get_all_ingredients(
+        repo,
+        &IngredientListQuery {
+            diet_friendly: vec![DietFriendly::Vegan].into(),
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    by_name.sort_by_key(|k| k.id);
+    by_diet.sort_by_key(|k| k.id);
+
+    // THEN
+    assert_eq!(by_name, vec![parent.clone(), other]);
+    assert_eq!(by_diet, vec![parent, variant]);
+}
+
+pub async fn includes_variants_of_matching_ingredients(repo: impl IngredientRepository) {
+    // GIVEN
+    let [parent, variant, other] = soy_sauces();
+    repo.insert(parent.clone()).await.unwrap();
+    repo.insert(variant.clone()).await.unwrap();
+    repo.insert(other.clone()).await.unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    // WHEN
+    let mut result = get_all_ingredients(
+        repo,
+        &IngredientListQuery {
+            name: Some("soy".into()),
+            include_variants: true,
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+    result.sort_by_key(|k| k.id);
+
+    // THEN
+    assert_eq!(result, vec![parent, variant]);
+}
@@ -15,6 +15,18 @@ mod in_memory {
         let repo = InMemoryIngredientRepository::new();
         __tests__::returns_vec_of_items_inside(repo).await;
     }
+
+    #[tokio::test]
+    async fn filters_by_name_and_diet() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::filters_by_name_and_diet(repo).await;
+    }
+
+    #[tokio::test]
+    async fn includes_variants_of_matching_ingredients() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::includes_variants_of_matching_ingredients(repo).await;
+    }
 }
 
 mod sql {
@@ -34,4 +46,16 @@ mod sql {
         let repo = PostgresIngredientRepository::new(pool);
         __tests__::returns_vec_of_items_inside(repo).await;
     }
+
+    #[sqlx::test]
+    async fn filters_by_name_and_diet(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::filters_by_name_and_diet(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn includes_variants_of_matching_ingredients(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::includes_variants_of_matching_ingredients(repo).await;
+    }
 }
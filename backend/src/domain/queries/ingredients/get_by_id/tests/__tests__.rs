@@ -2,16 +2,25 @@ use std::sync::Arc;
 
 use uuid::Uuid;
 
-use crate::domain::{entities::ingredient::{types::WhichDiets, Ingredient}, queries::ingredients::get_by_id::{
-    get_ingredient_by_id, GetIngredientError,
-}, repositories::ingredients::{IngredientRepository, IngredientRepositoryService}};
+use crate::domain::{
+    entities::ingredient::{
+        types::{Allergens, DietViolations},
+        Ingredient,
+    },
+    queries::ingredients::get_by_id::{get_ingredient_by_id, GetIngredientError},
+    repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+};
 
 pub async fn get_by_id_returns_ingredient(repo: impl IngredientRepository) {
     repo.insert(Ingredient {
         id: Uuid::from_u128(1),
         name: "Ingredient name".try_into().unwrap(),
         description: "Ingredient description".try_into().unwrap(),
-        diet_friendly: WhichDiets::new(),
+        diet_violations: DietViolations::new(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: None,
+        version: Default::default(),
     })
     .await
     .unwrap();
@@ -0,0 +1,21 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::IngredientSubstitute,
+    queries::ingredients::get_by_id::GetIngredientError,
+    repositories::ingredients::IngredientRepositoryService,
+};
+
+#[tracing::instrument("[QUERY] Get substitutes of ingredient", skip(repo))]
+pub async fn get_ingredient_substitutes(
+    repo: IngredientRepositoryService,
+    input: &Uuid,
+) -> Result<Vec<IngredientSubstitute>, GetIngredientError> {
+    let ingredient = repo.get_by_id(input).await?;
+    let substitutes = repo.get_substitutes(&ingredient).await?;
+
+    Ok(substitutes)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::ingredient::{types::SubstituteRatio, IngredientSubstitute},
+        queries::ingredients::{
+            get_by_id::GetIngredientError, get_substitutes::get_ingredient_substitutes,
+        },
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::named_ingredient,
+};
+
+pub async fn returns_substitutes_sorted_by_name(repo: impl IngredientRepository) {
+    let egg = repo.insert(named_ingredient(1, "Egg")).await.unwrap();
+    let flax = repo.insert(named_ingredient(2, "Flax egg")).await.unwrap();
+    let applesauce = repo
+        .insert(named_ingredient(3, "Applesauce"))
+        .await
+        .unwrap();
+    for (substitute, ratio) in [(flax, 1.0), (applesauce, 60.0)] {
+        repo.set_substitute(
+            &egg,
+            &IngredientSubstitute {
+                ingredient: substitute,
+                ratio: SubstituteRatio(ratio),
+                unit: None,
+                notes: Some("For baking".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+    }
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_ingredient_substitutes(repo, &Uuid::from_u128(1))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result
+            .iter()
+            .map(|s| (s.ingredient.id, *s.ratio))
+            .collect::<Vec<_>>(),
+        vec![(Uuid::from_u128(3), 60.0), (Uuid::from_u128(2), 1.0)]
+    );
+}
+
+pub async fn returns_error_when_missing(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_ingredient_substitutes(repo, &Uuid::from_u128(1))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, GetIngredientError::NotFound(id) if id == Uuid::from_u128(1)));
+}
@@ -0,0 +1,30 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::ingredient::IngredientWithVariants,
+    queries::ingredients::get_by_id::GetIngredientError,
+    repositories::ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+};
+
+impl From<GetAllIngredientsError> for GetIngredientError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Internal(value.into())
+    }
+}
+
+#[tracing::instrument("[QUERY] Get ingredient with its variants", skip(repo))]
+pub async fn get_ingredient_with_variants(
+    repo: IngredientRepositoryService,
+    input: &Uuid,
+) -> Result<IngredientWithVariants, GetIngredientError> {
+    let ingredient = repo.get_by_id(input).await?;
+    let variants = repo.get_variants(&ingredient).await?;
+
+    Ok(IngredientWithVariants {
+        ingredient,
+        variants,
+    })
+}
+
+#[cfg(test)]
+mod tests;
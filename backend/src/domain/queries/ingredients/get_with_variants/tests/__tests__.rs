@@ -0,0 +1,54 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::ingredient::Ingredient,
+        queries::ingredients::{
+            get_by_id::GetIngredientError, get_with_variants::get_ingredient_with_variants,
+        },
+        repositories::ingredients::{IngredientRepository, IngredientRepositoryService},
+    },
+    test_utils::named_ingredient,
+};
+
+pub async fn returns_ingredient_with_variants(repo: impl IngredientRepository) {
+    repo.insert(named_ingredient(1, "Soy sauce")).await.unwrap();
+    repo.insert(Ingredient {
+        parent_id: Some(Uuid::from_u128(1)),
+        ..named_ingredient(2, "Light soy sauce")
+    })
+    .await
+    .unwrap();
+    repo.insert(Ingredient {
+        parent_id: Some(Uuid::from_u128(1)),
+        ..named_ingredient(3, "Dark soy sauce")
+    })
+    .await
+    .unwrap();
+    repo.insert(named_ingredient(4, "Fish sauce"))
+        .await
+        .unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_ingredient_with_variants(repo, &Uuid::from_u128(1))
+        .await
+        .unwrap();
+
+    assert_eq!(result.ingredient.id, Uuid::from_u128(1));
+    assert_eq!(
+        result.variants.iter().map(|v| v.id).collect::<Vec<_>>(),
+        vec![Uuid::from_u128(3), Uuid::from_u128(2)]
+    );
+}
+
+pub async fn returns_error_when_missing(repo: impl IngredientRepository) {
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_ingredient_with_variants(repo, &Uuid::from_u128(1))
+        .await
+        .unwrap_err();
+
+    assert!(matches!(result, GetIngredientError::NotFound(id) if id == Uuid::from_u128(1)));
+}
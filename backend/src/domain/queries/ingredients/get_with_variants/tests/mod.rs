@@ -0,0 +1,37 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::in_memory::InMemoryIngredientRepository;
+
+    #[tokio::test]
+    async fn returns_ingredient_with_variants() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::returns_ingredient_with_variants(repo).await;
+    }
+
+    #[tokio::test]
+    async fn returns_error_when_missing() {
+        let repo = InMemoryIngredientRepository::new();
+        __tests__::returns_error_when_missing(repo).await;
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::ingredients::postgres::PostgresIngredientRepository;
+
+    #[sqlx::test]
+    async fn returns_ingredient_with_variants(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::returns_ingredient_with_variants(repo).await;
+    }
+
+    #[sqlx::test]
+    async fn returns_error_when_missing(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool);
+        __tests__::returns_error_when_missing(repo).await;
+    }
+}
@@ -0,0 +1,45 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        ingredient::{errors::ValidationError, Ingredient, IngredientPageQuery},
+        pagination::Page,
+    },
+    repositories::ingredients::{
+        errors::GetAllIngredientsError as GetAllIngredientsErrorInternal,
+        IngredientRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ListIngredientsError {
+    #[error("The cursor {0} does not point to an existing ingredient")]
+    InvalidCursor(Uuid),
+
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+
+    #[error(transparent)]
+    Internal(#[from] eyre::Error),
+}
+
+impl From<GetAllIngredientsErrorInternal> for ListIngredientsError {
+    fn from(value: GetAllIngredientsErrorInternal) -> Self {
+        match value {
+            GetAllIngredientsErrorInternal::InvalidCursor(id) => Self::InvalidCursor(id),
+            e => Self::Internal(e.into()),
+        }
+    }
+}
+
+/// Lists a page of ingredients, optionally only the ones used in at least one recipe
+#[tracing::instrument("[QUERY] List ingredients", skip(repo))]
+pub async fn list_ingredients(
+    repo: IngredientRepositoryService,
+    query: &IngredientPageQuery,
+) -> Result<Page<Ingredient>, ListIngredientsError> {
+    Ok(repo.list(query).await?)
+}
+
+#[cfg(test)]
+mod tests;
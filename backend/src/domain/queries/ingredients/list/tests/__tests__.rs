@@ -0,0 +1,147 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            ingredient::{
+                types::{DietFriendly, DietViolation, WhichDiets},
+                Ingredient, IngredientListQuery, IngredientPageQuery,
+            },
+            pagination::Pagination,
+        },
+        queries::ingredients::list::{list_ingredients, ListIngredientsError},
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::RecipeRepository,
+        },
+    },
+    test_utils::{
+        ingredient_fixture, insert_all_ingredients_of_recipe, named_ingredient, recipe_fixture,
+    },
+};
+
+use pretty_assertions::assert_eq;
+
+fn names(ingredients: &[Ingredient]) -> Vec<String> {
+    ingredients
+        .iter()
+        .map(|ingredient| ingredient.name.to_string())
+        .collect()
+}
+
+pub async fn pages_through_ingredients_by_name(repo: impl IngredientRepository) {
+    // GIVEN
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    for (id, name) in [
+        (1, "Egg"),
+        (2, "dill"),
+        (3, "Carrot"),
+        (4, "basil"),
+        (5, "Apple"),
+    ] {
+        repo.insert(named_ingredient(id, name)).await.unwrap();
+    }
+    let mut query = IngredientPageQuery {
+        limit: 2,
+        ..Default::default()
+    };
+
+    // WHEN
+    let first = list_ingredients(repo.clone(), &query).await.unwrap();
+    query.pagination = Pagination::After(first.next_cursor.unwrap());
+    let second = list_ingredients(repo.clone(), &query).await.unwrap();
+    query.pagination = Pagination::Offset(4);
+    let last = list_ingredients(repo, &query).await.unwrap();
+
+    // THEN
+    assert_eq!(names(&first.items), vec!["Apple", "basil"]);
+    assert_eq!(first.total, 5);
+    assert_eq!(first.next_cursor, Some(Uuid::from_u128(4)));
+    assert_eq!(names(&second.items), vec!["Carrot", "dill"]);
+    assert_eq!(names(&last.items), vec!["Egg"]);
+    assert_eq!(last.next_cursor, None);
+}
+
+pub async fn filters_by_name_prefix_and_diet(repo: impl IngredientRepository) {
+    // GIVEN
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    repo.insert(Ingredient {
+        diet_violations: vec![DietViolation::Meat].into(),
+        ..named_ingredient(1, "Bacon")
+    })
+    .await
+    .unwrap();
+    repo.insert(named_ingredient(2, "Broccoli")).await.unwrap();
+    repo.insert(named_ingredient(3, "Bok choy")).await.unwrap();
+    repo.insert(named_ingredient(4, "Tofu")).await.unwrap();
+    let query = IngredientPageQuery {
+        filters: IngredientListQuery {
+            name_prefix: Some("b".into()),
+            diet_friendly: WhichDiets(vec![DietFriendly::Vegan]),
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    // WHEN
+    let result = list_ingredients(repo, &query).await.unwrap();
+
+    // THEN
+    assert_eq!(names(&result.items), vec!["Bok choy", "Broccoli"]);
+    assert_eq!(result.total, 2);
+}
+
+pub async fn filters_by_usage_in_recipes(
+    repo: impl IngredientRepository,
+    recipe_repo: impl RecipeRepository,
+) {
+    // GIVEN
+    let recipe = recipe_fixture();
+    insert_all_ingredients_of_recipe(&repo, &recipe).await;
+    repo.insert(ingredient_fixture()).await.unwrap();
+    recipe_repo.insert(recipe.clone()).await.unwrap();
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+
+    // WHEN
+    let result = list_ingredients(
+        repo,
+        &IngredientPageQuery {
+            filters: IngredientListQuery {
+                used_in_recipes: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        },
+    )
+    .await
+    .unwrap();
+
+    // THEN
+    let mut expected: Vec<Ingredient> = recipe
+        .ingredients
+        .iter()
+        .map(|i| i.ingredient.clone())
+        .collect();
+    expected.sort_by_key(|i| i.name.to_lowercase());
+    assert_eq!(result.items, expected);
+}
+
+pub async fn unknown_cursor_errors(repo: impl IngredientRepository) {
+    // GIVEN
+    let repo: IngredientRepositoryService = Arc::new(Box::new(repo));
+    let query = IngredientPageQuery {
+        pagination: Pagination::After(Uuid::from_u128(42)),
+        ..Default::default()
+    };
+
+    // WHEN
+    let result = list_ingredients(repo, &query).await;
+
+    // THEN
+    assert!(matches!(
+        result,
+        Err(ListIngredientsError::InvalidCursor(id)) if id == Uuid::from_u128(42)
+    ));
+}
@@ -0,0 +1,63 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository,
+    };
+
+    #[tokio::test]
+    async fn pages_through_ingredients_by_name() {
+        __tests__::pages_through_ingredients_by_name(InMemoryIngredientRepository::new()).await
+    }
+
+    #[tokio::test]
+    async fn filters_by_name_prefix_and_diet() {
+        __tests__::filters_by_name_prefix_and_diet(InMemoryIngredientRepository::new()).await
+    }
+
+    #[tokio::test]
+    async fn filters_by_usage_in_recipes() {
+        let recipe_repo = InMemoryRecipeRepository::new();
+        let repo = InMemoryIngredientRepository::new().with_recipes(&recipe_repo);
+        __tests__::filters_by_usage_in_recipes(repo, recipe_repo).await
+    }
+
+    #[tokio::test]
+    async fn unknown_cursor_errors() {
+        __tests__::unknown_cursor_errors(InMemoryIngredientRepository::new()).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository,
+    };
+
+    #[sqlx::test]
+    async fn pages_through_ingredients_by_name(pool: PgPool) {
+        __tests__::pages_through_ingredients_by_name(PostgresIngredientRepository::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn filters_by_name_prefix_and_diet(pool: PgPool) {
+        __tests__::filters_by_name_prefix_and_diet(PostgresIngredientRepository::new(pool)).await
+    }
+
+    #[sqlx::test]
+    async fn filters_by_usage_in_recipes(pool: PgPool) {
+        let repo = PostgresIngredientRepository::new(pool.clone());
+        let recipe_repo = PostgresRecipeRepository::new(pool);
+        __tests__::filters_by_usage_in_recipes(repo, recipe_repo).await
+    }
+
+    #[sqlx::test]
+    async fn unknown_cursor_errors(pool: PgPool) {
+        __tests__::unknown_cursor_errors(PostgresIngredientRepository::new(pool)).await
+    }
+}
@@ -0,0 +1,22 @@
+use crate::domain::entities::recipe::{
+    conversion::MeasurementSystem, IngredientWithAmount, Recipe,
+};
+
+/// Converts every ingredient amount of a recipe to the units of the given system.
+///
+/// See [`IngredientUnit::to_system`](crate::domain::entities::recipe::IngredientUnit::to_system)
+/// for which amounts get converted and to what.
+pub fn convert_recipe_units(recipe: Recipe, system: MeasurementSystem) -> Recipe {
+    let mut recipe = recipe;
+    for IngredientWithAmount {
+        ingredient, amount, ..
+    } in recipe.ingredients.iter_mut()
+    {
+        *amount = std::mem::take(amount).to_system(system, ingredient.density);
+    }
+
+    recipe
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,60 @@
+use crate::{
+    domain::{
+        entities::{
+            ingredient::types::IngredientDensity,
+            recipe::{conversion::MeasurementSystem, IngredientUnit, Recipe},
+        },
+        queries::recipes::convert_units::convert_recipe_units,
+    },
+    test_utils::recipe_fixture,
+};
+
+fn amounts(recipe: &Recipe) -> Vec<IngredientUnit> {
+    recipe
+        .ingredients
+        .iter()
+        .map(|i| i.amount.clone())
+        .collect()
+}
+
+#[test]
+fn converting_to_imperial_converts_every_amount() {
+    let recipe = recipe_fixture();
+
+    let result = convert_recipe_units(recipe.clone(), MeasurementSystem::Imperial);
+
+    assert_eq!(
+        amounts(&result),
+        vec![
+            IngredientUnit::Ounces(14.11),
+            IngredientUnit::Other {
+                unit: "head".to_string(),
+                amount: 1.0
+            },
+            IngredientUnit::Other {
+                unit: "clove".to_string(),
+                amount: 4.0
+            },
+        ]
+    );
+    assert_eq!(result.servings, recipe.servings);
+}
+
+#[test]
+fn converting_to_metric_leaves_metric_amounts_alone() {
+    let recipe = recipe_fixture();
+
+    let result = convert_recipe_units(recipe.clone(), MeasurementSystem::Metric);
+
+    assert_eq!(result, recipe);
+}
+
+#[test]
+fn converting_uses_the_density_of_each_ingredient() {
+    let mut recipe = recipe_fixture();
+    recipe.ingredients[0].ingredient.density = Some(IngredientDensity::try_from(0.5).unwrap());
+
+    let result = convert_recipe_units(recipe, MeasurementSystem::Imperial);
+
+    assert_eq!(amounts(&result)[0], IngredientUnit::Cups(3.38));
+}
@@ -0,0 +1,45 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        pagination::page_size,
+        recipe::cookable::{CookableRecipe, CookableRecipesQuery, DEFAULT_MAX_MISSING},
+        user::User,
+    },
+    repositories::recipe::{errors::ListRecipesError, RecipeRepositoryService},
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ListCookableRecipesError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<ListRecipesError> for ListCookableRecipesError {
+    fn from(value: ListRecipesError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// Lists the recipes the viewer can cook with the available ingredients, fully cookable ones
+/// first, followed by the ones missing up to `max_missing` required ingredients
+#[tracing::instrument("[QUERY] List cookable recipes", skip(recipe_repo, viewer))]
+pub async fn list_cookable_recipes(
+    recipe_repo: RecipeRepositoryService,
+    available: &[Uuid],
+    max_missing: Option<u64>,
+    limit: Option<u64>,
+    viewer: Option<&User>,
+) -> Result<Vec<CookableRecipe>, ListCookableRecipesError> {
+    let query = CookableRecipesQuery {
+        available: available.to_vec(),
+        max_missing: max_missing.unwrap_or(DEFAULT_MAX_MISSING),
+        limit: page_size(limit),
+        viewer: viewer.map(|user| user.id),
+    };
+
+    Ok(recipe_repo.list_cookable(&query).await?)
+}
+
+#[cfg(test)]
+mod tests;
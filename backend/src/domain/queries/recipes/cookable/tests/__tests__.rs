@@ -0,0 +1,154 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::recipe::{cookable::CookableRecipe, Recipe},
+        queries::recipes::cookable::list_cookable_recipes,
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture, user_fixture},
+};
+
+use pretty_assertions::assert_eq;
+
+const TOFU: Uuid = Uuid::nil();
+const BROCCOLI: Uuid = Uuid::from_u128(1);
+const GARLIC: Uuid = Uuid::from_u128(2);
+
+/// Builds a recipe out of the fixture ingredients with the given IDs, and whether they're optional
+fn recipe(id: u128, name: &str, ingredients: &[(Uuid, bool)]) -> Recipe {
+    let fixture = recipe_fixture();
+
+    Recipe {
+        id: Uuid::from_u128(id),
+        name: name.to_string(),
+        ingredients: ingredients
+            .iter()
+            .map(|(id, optional)| {
+                let mut ingredient = fixture
+                    .ingredients
+                    .iter()
+                    .find(|i| i.ingredient.id == *id)
+                    .unwrap()
+                    .clone();
+                ingredient.optional = *optional;
+                ingredient
+            })
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+        ..fixture.clone()
+    }
+}
+
+async fn seed(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) -> RecipeRepositoryService {
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe_fixture()).await;
+
+    for recipe in [
+        recipe(
+            1,
+            "Tofu stir fry",
+            &[(TOFU, false), (BROCCOLI, false), (GARLIC, false)],
+        ),
+        recipe(2, "Garlic broccoli", &[(BROCCOLI, false), (GARLIC, false)]),
+        recipe(3, "Tofu with garlic", &[(TOFU, false), (GARLIC, true)]),
+    ] {
+        repo.insert(recipe).await.unwrap();
+    }
+
+    Arc::new(Box::new(repo))
+}
+
+fn names(recipes: &[CookableRecipe]) -> Vec<&str> {
+    recipes.iter().map(|r| r.recipe.name.as_str()).collect()
+}
+
+fn missing(recipe: &CookableRecipe) -> Vec<Uuid> {
+    let mut missing: Vec<Uuid> = recipe.missing.iter().map(|i| i.ingredient.id).collect();
+    missing.sort();
+    missing
+}
+
+pub async fn fully_cookable_recipes_come_first(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = seed(repo, ingredient_repo).await;
+
+    let result = list_cookable_recipes(repo, &[BROCCOLI, GARLIC], None, None, None)
+        .await
+        .unwrap();
+
+    // Tofu with garlic is left out, as tofu is its only required ingredient
+    assert_eq!(names(&result), vec!["Garlic broccoli", "Tofu stir fry"]);
+    assert_eq!(missing(&result[0]), vec![]);
+    assert_eq!(missing(&result[1]), vec![TOFU]);
+}
+
+pub async fn optional_ingredients_are_never_missing(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = seed(repo, ingredient_repo).await;
+
+    let result = list_cookable_recipes(repo, &[TOFU], None, None, None)
+        .await
+        .unwrap();
+
+    assert_eq!(names(&result), vec!["Tofu with garlic", "Tofu stir fry"]);
+    assert_eq!(missing(&result[0]), vec![]);
+    assert_eq!(missing(&result[1]), vec![BROCCOLI, GARLIC]);
+}
+
+pub async fn recipes_missing_too_many_ingredients_are_left_out(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = seed(repo, ingredient_repo).await;
+
+    let result = list_cookable_recipes(repo.clone(), &[TOFU], Some(0), None, None)
+        .await
+        .unwrap();
+    assert_eq!(names(&result), vec!["Tofu with garlic"]);
+
+    let result = list_cookable_recipes(repo, &[], None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(result, vec![]);
+}
+
+pub async fn private_recipes_are_only_listed_for_their_author(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let author = user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe_fixture()).await;
+    repo.insert(Recipe {
+        author_id: Some(author.id),
+        public: false,
+        ..recipe(1, "Secret broccoli", &[(BROCCOLI, false)])
+    })
+    .await
+    .unwrap();
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let result = list_cookable_recipes(repo.clone(), &[BROCCOLI], None, None, None)
+        .await
+        .unwrap();
+    assert_eq!(result, vec![]);
+
+    let result = list_cookable_recipes(repo, &[BROCCOLI], None, None, Some(&author))
+        .await
+        .unwrap();
+    assert_eq!(names(&result), vec!["Secret broccoli"]);
+}
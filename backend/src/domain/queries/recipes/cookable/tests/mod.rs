@@ -0,0 +1,87 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn fully_cookable_recipes_come_first() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::fully_cookable_recipes_come_first(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn optional_ingredients_are_never_missing() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::optional_ingredients_are_never_missing(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn recipes_missing_too_many_ingredients_are_left_out() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::recipes_missing_too_many_ingredients_are_left_out(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn private_recipes_are_only_listed_for_their_author() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::private_recipes_are_only_listed_for_their_author(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn fully_cookable_recipes_come_first(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::fully_cookable_recipes_come_first(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn optional_ingredients_are_never_missing(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::optional_ingredients_are_never_missing(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn recipes_missing_too_many_ingredients_are_left_out(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::recipes_missing_too_many_ingredients_are_left_out(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn private_recipes_are_only_listed_for_their_author(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::private_recipes_are_only_listed_for_their_author(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+}
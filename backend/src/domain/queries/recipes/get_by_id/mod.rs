@@ -1,7 +1,11 @@
 use uuid::Uuid;
 
 use crate::domain::{
-    entities::recipe::{errors::ValidationError, Recipe},
+    entities::{
+        recipe::{errors::ValidationError, Recipe},
+        user::User,
+    },
+    queries::recipes::scale::ScaleRecipeError,
     repositories::recipe::{
         errors::GetRecipeByIdError as GetRecipeByIdErrorInternal, RecipeRepositoryService,
     },
@@ -15,6 +19,9 @@ pub enum GetRecipeError {
     #[error(transparent)]
     ValidationError(#[from] ValidationError),
 
+    #[error(transparent)]
+    Scaling(#[from] ScaleRecipeError),
+
     #[error(transparent)]
     Unknown(#[from] eyre::Error),
 }
@@ -23,7 +30,7 @@ impl From<GetRecipeByIdErrorInternal> for GetRecipeError {
     fn from(value: GetRecipeByIdErrorInternal) -> Self {
         match value {
             GetRecipeByIdErrorInternal::NotFound(id) => GetRecipeError::NotFound(id),
-            e => e.into(),
+            e => Self::Unknown(e.into()),
         }
     }
 }
@@ -31,12 +38,18 @@ impl From<GetRecipeByIdErrorInternal> for GetRecipeError {
 pub async fn get_recipe_by_id(
     recipe_repo: RecipeRepositoryService,
     input: &Uuid,
+    viewer: Option<&User>,
 ) -> Result<Recipe, GetRecipeError> {
     let result = recipe_repo
         .get_by_id(input)
         .await
         .map_err(GetRecipeError::from)?;
 
+    // Private recipes are hidden from everyone but their author, without leaking that they exist
+    if !result.is_visible_to(viewer.map(|user| &user.id)) {
+        return Err(GetRecipeError::NotFound(result.id));
+    }
+
     Ok(result)
 }
 
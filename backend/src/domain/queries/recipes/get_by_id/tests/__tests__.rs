@@ -5,13 +5,17 @@ use uuid::Uuid;
 
 use crate::{
     domain::{
+        entities::{recipe::Recipe, user::User},
         queries::recipes::get_by_id::get_recipe_by_id,
         repositories::{
             ingredients::IngredientRepository,
             recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
         },
     },
-    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture},
+    test_utils::{
+        authored_recipe_fixture, insert_all_ingredients_of_recipe, recipe_fixture, user_fixture,
+    },
 };
 
 pub async fn getting_recipe_by_id_works(
@@ -25,16 +29,52 @@ pub async fn getting_recipe_by_id_works(
     repo.insert(recipe.clone()).await.unwrap();
 
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let result = get_recipe_by_id(recipe_repo, &recipe.id).await.unwrap();
+    let result = get_recipe_by_id(recipe_repo, &recipe.id, None)
+        .await
+        .unwrap();
 
     assert_eq!(result, recipe);
 }
 
 pub async fn getting_a_nonexistent_recipe_errors(repo: impl RecipeRepository) {
     let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
-    let error = get_recipe_by_id(recipe_repo, &Uuid::nil())
+    let error = get_recipe_by_id(recipe_repo, &Uuid::nil(), None)
         .await
         .unwrap_err();
 
     assert!(matches!(error, GetRecipeError::NotFound(id) if id == Uuid::nil()));
 }
+
+pub async fn getting_a_private_recipe_only_works_for_its_author(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let author = user_fixture();
+    let stranger = User {
+        id: Uuid::from_u128(256),
+        ..user_fixture()
+    };
+    let recipe = Recipe {
+        public: false,
+        ..authored_recipe_fixture()
+    };
+
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    user_repo.insert(author.clone()).await.unwrap();
+    repo.insert(recipe.clone()).await.unwrap();
+
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let result = get_recipe_by_id(recipe_repo.clone(), &recipe.id, Some(&author))
+        .await
+        .unwrap();
+    assert_eq!(result, recipe);
+
+    for viewer in [None, Some(&stranger)] {
+        let error = get_recipe_by_id(recipe_repo.clone(), &recipe.id, viewer)
+            .await
+            .unwrap_err();
+        assert!(matches!(error, GetRecipeError::NotFound(id) if id == recipe.id));
+    }
+}
@@ -1,18 +1,16 @@
 mod __tests__;
 
 mod in_memory {
-
+    use super::__tests__;
     use crate::domain::repositories::{
         ingredients::in_memory::InMemoryIngredientRepository,
-        recipe::in_memory::InMemoryRecipeRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
     };
 
-    use super::__tests__;
-
     #[tokio::test]
     async fn getting_recipe_by_id_works() {
-        let repo = InMemoryRecipeRepository::new();
         let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
         __tests__::getting_recipe_by_id_works(repo, ingredient_repo).await
     }
 
@@ -21,27 +19,53 @@ mod in_memory {
         let repo = InMemoryRecipeRepository::new();
         __tests__::getting_a_nonexistent_recipe_errors(repo).await
     }
+
+    #[tokio::test]
+    async fn getting_a_private_recipe_only_works_for_its_author() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::getting_a_private_recipe_only_works_for_its_author(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
 }
 
 mod sql {
+    use super::__tests__;
     use sqlx::PgPool;
 
-    use super::__tests__;
     use crate::domain::repositories::{
         ingredients::postgres::PostgresIngredientRepository,
-        recipe::postgres::PostgresRecipeRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
     };
 
     #[sqlx::test]
     async fn getting_recipe_by_id_works(pool: PgPool) {
-        let repo = PostgresRecipeRepository::new(pool.clone());
-        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
         __tests__::getting_recipe_by_id_works(repo, ingredient_repo).await
     }
 
     #[sqlx::test]
     async fn getting_a_nonexistent_recipe_errors(pool: PgPool) {
-        let repo = PostgresRecipeRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
         __tests__::getting_a_nonexistent_recipe_errors(repo).await
     }
+
+    #[sqlx::test]
+    async fn getting_a_private_recipe_only_works_for_its_author(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::getting_a_private_recipe_only_works_for_its_author(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
 }
@@ -0,0 +1,65 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        pagination::Page,
+        recipe::{errors::ValidationError, Recipe, RecipeListQuery},
+    },
+    repositories::{
+        ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+        recipe::{errors::ListRecipesError as ListRecipesErrorInternal, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ListRecipesError {
+    #[error("The cursor {0} does not point to an existing recipe")]
+    InvalidCursor(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<ListRecipesErrorInternal> for ListRecipesError {
+    fn from(value: ListRecipesErrorInternal) -> Self {
+        match value {
+            ListRecipesErrorInternal::InvalidCursor(id) => Self::InvalidCursor(id),
+            ListRecipesErrorInternal::ValidationError(e) => Self::ValidationError(e),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetAllIngredientsError> for ListRecipesError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[tracing::instrument("[QUERY] List recipes", skip(recipe_repo, ingredient_repo))]
+pub async fn list_recipes(
+    recipe_repo: RecipeRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    query: &RecipeListQuery,
+) -> Result<Page<Recipe>, ListRecipesError> {
+    if query.include_variants && !query.diet_friendly.is_empty() {
+        let query = RecipeListQuery {
+            ingredients_with_fitting_variants: ingredient_repo
+                .get_parents_with_fitting_variants(&query.diet_friendly)
+                .await?,
+            ..query.clone()
+        };
+
+        return Ok(recipe_repo.list(&query).await?);
+    }
+
+    let result = recipe_repo.list(query).await?;
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,569 @@
+use std::{sync::Arc, time::Duration};
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            ingredient::{
+                types::{Allergen, DietFriendly, DietViolation},
+                Ingredient,
+            },
+            pagination::{Pagination, SortOrder},
+            recipe::{IngredientUnit, IngredientWithAmount, Recipe, RecipeListQuery, RecipeSortBy},
+        },
+        queries::recipes::list::{list_recipes, ListRecipesError},
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{
+        ingredient_fixture, insert_all_ingredients_of_recipe, named_ingredient, recipe_fixture,
+        user_fixture,
+    },
+};
+
+use pretty_assertions::assert_eq;
+
+/// Inserts one recipe per name, with IDs increasing in the order of the names
+async fn insert_recipes(
+    repo: &impl RecipeRepository,
+    ingredient_repo: &impl IngredientRepository,
+    names: &[&str],
+) -> Vec<Recipe> {
+    let fixture = recipe_fixture();
+    insert_all_ingredients_of_recipe(ingredient_repo, &fixture).await;
+
+    let mut recipes = vec![];
+    for (i, name) in names.iter().enumerate() {
+        let recipe = Recipe {
+            id: Uuid::from_u128(100 + i as u128),
+            name: name.to_string(),
+            ..fixture.clone()
+        };
+        repo.insert(recipe.clone()).await.unwrap();
+        recipes.push(recipe);
+    }
+
+    recipes
+}
+
+/// Inserts one recipe per name with the given times in minutes, with IDs increasing in their order
+async fn insert_timed_recipes(
+    repo: &impl RecipeRepository,
+    ingredient_repo: &impl IngredientRepository,
+    recipes: &[(&str, &[(&str, u64)])],
+) {
+    let fixture = recipe_fixture();
+    insert_all_ingredients_of_recipe(ingredient_repo, &fixture).await;
+
+    for (i, (name, time)) in recipes.iter().enumerate() {
+        let recipe = Recipe {
+            id: Uuid::from_u128(100 + i as u128),
+            name: name.to_string(),
+            time: time
+                .iter()
+                .map(|(label, minutes)| (*label, Duration::from_secs(minutes * 60)))
+                .collect(),
+            ..fixture.clone()
+        };
+        repo.insert(recipe).await.unwrap();
+    }
+}
+
+fn names(recipes: &[Recipe]) -> Vec<&str> {
+    recipes.iter().map(|r| r.name.as_str()).collect()
+}
+
+pub async fn listing_without_recipes_returns_empty_page(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let result = list_recipes(recipe_repo, ingredient_repo, &RecipeListQuery::default())
+        .await
+        .unwrap();
+
+    assert_eq!(result.items, vec![]);
+    assert_eq!(result.total, 0);
+    assert_eq!(result.next_cursor, None);
+}
+
+pub async fn listing_returns_whole_recipes(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipes = insert_recipes(&repo, &ingredient_repo, &["Pancakes"]).await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let result = list_recipes(recipe_repo, ingredient_repo, &RecipeListQuery::default())
+        .await
+        .unwrap();
+
+    assert_eq!(result.items, recipes);
+    assert_eq!(result.total, 1);
+}
+
+pub async fn listing_paginates_with_offset(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    insert_recipes(&repo, &ingredient_repo, &["A", "B", "C", "D", "E"]).await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let query = RecipeListQuery {
+        pagination: Pagination::Offset(2),
+        limit: 2,
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo.clone(), ingredient_repo.clone(), &query)
+        .await
+        .unwrap();
+
+    assert_eq!(names(&result.items), vec!["C", "D"]);
+    assert_eq!(result.total, 5);
+    assert_eq!(result.next_cursor, Some(Uuid::from_u128(103)));
+
+    let query = RecipeListQuery {
+        pagination: Pagination::Offset(4),
+        limit: 2,
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo, ingredient_repo, &query)
+        .await
+        .unwrap();
+
+    assert_eq!(names(&result.items), vec!["E"]);
+    assert_eq!(result.next_cursor, None);
+}
+
+pub async fn listing_paginates_with_cursor(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    insert_recipes(&repo, &ingredient_repo, &["A", "B", "C", "D", "E"]).await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let mut query = RecipeListQuery {
+        limit: 2,
+        ..Default::default()
+    };
+    let mut pages = vec![];
+
+    loop {
+        let result = list_recipes(recipe_repo.clone(), ingredient_repo.clone(), &query)
+            .await
+            .unwrap();
+        assert_eq!(result.total, 5);
+        pages.push(names(&result.items).join(""));
+
+        match result.next_cursor {
+            Some(cursor) => query.pagination = Pagination::After(cursor),
+            None => break,
+        }
+    }
+
+    assert_eq!(pages, vec!["AB", "CD", "E"]);
+}
+
+pub async fn listing_sorts_by_name(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    insert_recipes(
+        &repo,
+        &ingredient_repo,
+        &["banana bread", "Apple pie", "cherry tart"],
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let query = RecipeListQuery {
+        sort_by: RecipeSortBy::Name,
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo.clone(), ingredient_repo.clone(), &query)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        names(&result.items),
+        vec!["Apple pie", "banana bread", "cherry tart"]
+    );
+
+    let query = RecipeListQuery {
+        sort_by: RecipeSortBy::Name,
+        order: SortOrder::Descending,
+        limit: 2,
+        ..Default::default()
+    };
+    let first_page = list_recipes(recipe_repo.clone(), ingredient_repo.clone(), &query)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        names(&first_page.items),
+        vec!["cherry tart", "banana bread"]
+    );
+
+    let query = RecipeListQuery {
+        pagination: Pagination::After(first_page.next_cursor.unwrap()),
+        ..query
+    };
+    let second_page = list_recipes(recipe_repo, ingredient_repo, &query)
+        .await
+        .unwrap();
+
+    assert_eq!(names(&second_page.items), vec!["Apple pie"]);
+}
+
+pub async fn listing_sorts_by_total_time(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    insert_timed_recipes(
+        &repo,
+        &ingredient_repo,
+        &[
+            ("Stew", &[("Prep time", 20), ("Cook time", 120)]),
+            ("Toast", &[]),
+            ("Salad", &[("prep", 10)]),
+            // The given total wins over the sum, the dough rests while other things get done
+            ("Bread", &[("prep", 30), ("rest", 600), ("total", 200)]),
+        ],
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let query = RecipeListQuery {
+        sort_by: RecipeSortBy::TotalTime,
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo.clone(), ingredient_repo.clone(), &query)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        names(&result.items),
+        vec!["Salad", "Stew", "Bread", "Toast"]
+    );
+
+    let mut query = RecipeListQuery {
+        sort_by: RecipeSortBy::TotalTime,
+        order: SortOrder::Descending,
+        limit: 2,
+        ..Default::default()
+    };
+    let mut pages = vec![];
+
+    loop {
+        let result = list_recipes(recipe_repo.clone(), ingredient_repo.clone(), &query)
+            .await
+            .unwrap();
+        pages.push(names(&result.items).join(", "));
+
+        match result.next_cursor {
+            Some(cursor) => query.pagination = Pagination::After(cursor),
+            None => break,
+        }
+    }
+
+    assert_eq!(pages, vec!["Bread, Stew", "Salad, Toast"]);
+}
+
+pub async fn listing_filters_by_max_total_time(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    insert_timed_recipes(
+        &repo,
+        &ingredient_repo,
+        &[
+            ("Stew", &[("Prep time", 20), ("Cook time", 120)]),
+            ("Toast", &[]),
+            ("Salad", &[("prep", 10)]),
+            ("Bread", &[("prep", 30), ("rest", 600), ("total", 200)]),
+        ],
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let query = RecipeListQuery {
+        max_total_time: Some(Duration::from_secs(140 * 60)),
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo, ingredient_repo, &query)
+        .await
+        .unwrap();
+
+    assert_eq!(names(&result.items), vec!["Stew", "Salad"]);
+    assert_eq!(result.total, 2);
+}
+
+pub async fn listing_filters_by_name(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    insert_recipes(
+        &repo,
+        &ingredient_repo,
+        &["Tomato soup", "Pumpkin soup", "Pancakes"],
+    )
+    .await;
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let query = RecipeListQuery {
+        name: Some("SOUP".to_string()),
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo, ingredient_repo, &query)
+        .await
+        .unwrap();
+
+    assert_eq!(names(&result.items), vec!["Tomato soup", "Pumpkin soup"]);
+    assert_eq!(result.total, 2);
+}
+
+pub async fn listing_filters_by_diet(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipes = insert_recipes(&repo, &ingredient_repo, &["Tofu stir fry"]).await;
+    let beef = ingredient_repo.insert(ingredient_fixture()).await.unwrap();
+
+    let with_beef = |id: u128, name: &str, optional: bool| {
+        let mut recipe = Recipe {
+            id: Uuid::from_u128(id),
+            name: name.to_string(),
+            ..recipes[0].clone()
+        };
+        recipe.ingredients.push(IngredientWithAmount {
+            ingredient: beef.clone(),
+            amount: IngredientUnit::Grams(300.0),
+            notes: None,
+            optional,
+        });
+        recipe
+    };
+
+    repo.insert(with_beef(200, "Beef stir fry", false))
+        .await
+        .unwrap();
+    repo.insert(with_beef(201, "Tofu stir fry with optional beef", true))
+        .await
+        .unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let query = RecipeListQuery {
+        diet_friendly: vec![DietFriendly::Vegan].into(),
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo.clone(), ingredient_repo.clone(), &query)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        names(&result.items),
+        vec!["Tofu stir fry", "Tofu stir fry with optional beef"]
+    );
+    assert_eq!(result.total, 2);
+
+    let query = RecipeListQuery {
+        diet_friendly: vec![DietFriendly::GlutenFree].into(),
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo, ingredient_repo, &query)
+        .await
+        .unwrap();
+
+    assert_eq!(result.total, 3);
+}
+
+pub async fn listing_filters_by_diet_through_variants(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipes = insert_recipes(&repo, &ingredient_repo, &["Tofu stir fry"]).await;
+    let soy_sauce = ingredient_repo
+        .insert(Ingredient {
+            diet_violations: vec![DietViolation::Gluten].into(),
+            ..named_ingredient(10, "Soy sauce")
+        })
+        .await
+        .unwrap();
+    ingredient_repo
+        .insert(Ingredient {
+            parent_id: Some(soy_sauce.id),
+            ..named_ingredient(11, "Tamari")
+        })
+        .await
+        .unwrap();
+
+    let mut with_soy_sauce = Recipe {
+        id: Uuid::from_u128(200),
+        name: "Tofu stir fry with soy sauce".to_string(),
+        ..recipes[0].clone()
+    };
+    with_soy_sauce.ingredients.push(IngredientWithAmount {
+        ingredient: soy_sauce,
+        amount: IngredientUnit::Mililiters(30.0),
+        notes: None,
+        optional: false,
+    });
+    repo.insert(with_soy_sauce).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let query = RecipeListQuery {
+        diet_friendly: vec![DietFriendly::GlutenFree].into(),
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo.clone(), ingredient_repo.clone(), &query)
+        .await
+        .unwrap();
+
+    assert_eq!(names(&result.items), vec!["Tofu stir fry"]);
+
+    let query = RecipeListQuery {
+        include_variants: true,
+        ..query
+    };
+    let result = list_recipes(recipe_repo, ingredient_repo, &query)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        names(&result.items),
+        vec!["Tofu stir fry", "Tofu stir fry with soy sauce"]
+    );
+    assert_eq!(result.total, 2);
+}
+
+pub async fn listing_excludes_recipes_with_allergens(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipes = insert_recipes(&repo, &ingredient_repo, &["Tofu stir fry"]).await;
+
+    // Tofu contains soybeans, but it can be left out of this one
+    let mut optional_tofu = Recipe {
+        id: Uuid::from_u128(200),
+        name: "Broccoli stir fry with optional tofu".to_string(),
+        ..recipes[0].clone()
+    };
+    for ingredient in optional_tofu.ingredients.iter_mut() {
+        ingredient.optional = ingredient.ingredient.name.as_str() == "Firm tofu";
+    }
+    repo.insert(optional_tofu).await.unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let query = RecipeListQuery {
+        exclude_allergens: vec![Allergen::Soybeans].into(),
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo.clone(), ingredient_repo.clone(), &query)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        names(&result.items),
+        vec!["Broccoli stir fry with optional tofu"]
+    );
+    assert_eq!(result.total, 1);
+
+    let query = RecipeListQuery {
+        exclude_allergens: vec![Allergen::Milk, Allergen::Peanuts].into(),
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo, ingredient_repo, &query)
+        .await
+        .unwrap();
+
+    assert_eq!(result.total, 2);
+}
+
+pub async fn listing_separates_public_recipes_from_the_authors_own(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipes = insert_recipes(&repo, &ingredient_repo, &["Someone else's soup"]).await;
+    let author = user_fixture();
+    user_repo.insert(author.clone()).await.unwrap();
+
+    let authored = |id: u128, name: &str, public: bool| Recipe {
+        id: Uuid::from_u128(id),
+        name: name.to_string(),
+        author_id: Some(author.id),
+        public,
+        ..recipes[0].clone()
+    };
+
+    repo.insert(authored(200, "My public soup", true))
+        .await
+        .unwrap();
+    repo.insert(authored(201, "My secret soup", false))
+        .await
+        .unwrap();
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let result = list_recipes(
+        recipe_repo.clone(),
+        ingredient_repo.clone(),
+        &RecipeListQuery::default(),
+    )
+    .await
+    .unwrap();
+
+    assert_eq!(
+        names(&result.items),
+        vec!["Someone else's soup", "My public soup"]
+    );
+    assert_eq!(result.total, 2);
+
+    let query = RecipeListQuery {
+        author_id: Some(author.id),
+        ..Default::default()
+    };
+    let result = list_recipes(recipe_repo, ingredient_repo, &query)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        names(&result.items),
+        vec!["My public soup", "My secret soup"]
+    );
+    assert_eq!(result.total, 2);
+}
+
+pub async fn listing_with_unknown_cursor_errors(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let query = RecipeListQuery {
+        pagination: Pagination::After(Uuid::nil()),
+        ..Default::default()
+    };
+    let error = list_recipes(recipe_repo, ingredient_repo, &query)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, ListRecipesError::InvalidCursor(id) if id == Uuid::nil()));
+}
@@ -0,0 +1,214 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn listing_without_recipes_returns_empty_page() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_without_recipes_returns_empty_page(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn listing_returns_whole_recipes() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_returns_whole_recipes(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn listing_paginates_with_offset() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_paginates_with_offset(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn listing_paginates_with_cursor() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_paginates_with_cursor(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn listing_sorts_by_name() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_sorts_by_name(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn listing_sorts_by_total_time() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_sorts_by_total_time(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn listing_filters_by_max_total_time() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_filters_by_max_total_time(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn listing_filters_by_name() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_filters_by_name(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn listing_filters_by_diet() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_filters_by_diet(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn listing_filters_by_diet_through_variants() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_filters_by_diet_through_variants(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn listing_excludes_recipes_with_allergens() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_excludes_recipes_with_allergens(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn listing_separates_public_recipes_from_the_authors_own() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        __tests__::listing_separates_public_recipes_from_the_authors_own(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn listing_with_unknown_cursor_errors() {
+        let repo = InMemoryRecipeRepository::new();
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        __tests__::listing_with_unknown_cursor_errors(repo, ingredient_repo).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn listing_without_recipes_returns_empty_page(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_without_recipes_returns_empty_page(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn listing_returns_whole_recipes(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_returns_whole_recipes(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn listing_paginates_with_offset(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_paginates_with_offset(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn listing_paginates_with_cursor(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_paginates_with_cursor(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn listing_sorts_by_name(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_sorts_by_name(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn listing_sorts_by_total_time(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_sorts_by_total_time(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn listing_filters_by_max_total_time(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_filters_by_max_total_time(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn listing_filters_by_name(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_filters_by_name(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn listing_filters_by_diet(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_filters_by_diet(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn listing_filters_by_diet_through_variants(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_filters_by_diet_through_variants(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn listing_excludes_recipes_with_allergens(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_excludes_recipes_with_allergens(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn listing_separates_public_recipes_from_the_authors_own(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool);
+        __tests__::listing_separates_public_recipes_from_the_authors_own(
+            repo,
+            ingredient_repo,
+            user_repo,
+        )
+        .await
+    }
+
+    #[sqlx::test]
+    async fn listing_with_unknown_cursor_errors(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool.clone());
+        let ingredient_repo = PostgresIngredientRepository::new(pool);
+        __tests__::listing_with_unknown_cursor_errors(repo, ingredient_repo).await
+    }
+}
@@ -1 +1,8 @@
+pub mod convert_units;
+pub mod cookable;
 pub mod get_by_id;
+pub mod list;
+pub mod revisions;
+pub mod scale;
+pub mod search;
+pub mod substitutions;
@@ -0,0 +1,77 @@
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{recipe::revision::RecipeRevision, user::User, version::Version},
+    repositories::recipe::{
+        errors::{GetRecipeByIdError, RecipeRevisionError},
+        RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetRecipeRevisionsError {
+    #[error("Could not found the recipe with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error("The recipe with ID {0} has no revision {1}")]
+    RevisionNotFound(Uuid, Version),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeByIdError> for GetRecipeRevisionsError {
+    fn from(value: GetRecipeByIdError) -> Self {
+        match value {
+            GetRecipeByIdError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<RecipeRevisionError> for GetRecipeRevisionsError {
+    fn from(value: RecipeRevisionError) -> Self {
+        match value {
+            RecipeRevisionError::NotFound(id, revision) => Self::RevisionNotFound(id, revision),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+/// The history of a recipe is only visible to those who can see the recipe itself
+async fn ensure_visible(
+    recipe_repo: &RecipeRepositoryService,
+    recipe_id: &Uuid,
+    viewer: Option<&User>,
+) -> Result<(), GetRecipeRevisionsError> {
+    let recipe = recipe_repo.get_by_id(recipe_id).await?;
+    if !recipe.is_visible_to(viewer.map(|user| &user.id)) {
+        return Err(GetRecipeRevisionsError::NotFound(recipe.id));
+    }
+
+    Ok(())
+}
+
+pub async fn list_recipe_revisions(
+    recipe_repo: RecipeRepositoryService,
+    recipe_id: &Uuid,
+    viewer: Option<&User>,
+) -> Result<Vec<RecipeRevision>, GetRecipeRevisionsError> {
+    ensure_visible(&recipe_repo, recipe_id, viewer).await?;
+
+    Ok(recipe_repo.list_revisions(recipe_id).await?)
+}
+
+pub async fn get_recipe_revision(
+    recipe_repo: RecipeRepositoryService,
+    recipe_id: &Uuid,
+    revision: Version,
+    viewer: Option<&User>,
+) -> Result<RecipeRevision, GetRecipeRevisionsError> {
+    ensure_visible(&recipe_repo, recipe_id, viewer).await?;
+
+    Ok(recipe_repo.get_revision(recipe_id, revision).await?)
+}
+
+#[cfg(test)]
+mod tests;
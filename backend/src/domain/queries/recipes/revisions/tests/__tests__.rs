@@ -0,0 +1,140 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+    domain::{
+        commands::recipes::{
+            create::create_recipe, ingredients::delete::delete_ingredient_from_recipe,
+            ingredients::update::update_ingredient_in_recipe, update::update_recipe,
+        },
+        entities::{
+            recipe::{
+                revision::{RecipeChange, RevisionIngredient},
+                IngredientUnit, Recipe,
+            },
+            version::{ExpectedVersion, Version},
+        },
+        queries::recipes::revisions::{
+            get_recipe_revision, list_recipe_revisions, GetRecipeRevisionsError,
+        },
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{
+        authored_recipe_fixture, insert_all_ingredients_of_recipe, recipe_changeset, user_fixture,
+    },
+};
+
+pub async fn every_change_is_recorded(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let user = user_fixture();
+    let recipe = authored_recipe_fixture();
+    user_repo.insert(user.clone()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let created = create_recipe(recipe_repo.clone(), ingredient_repo, &recipe.into())
+        .await
+        .unwrap();
+    update_recipe(
+        recipe_repo.clone(),
+        &created.id,
+        recipe_changeset(),
+        &user,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap();
+    update_ingredient_in_recipe(
+        recipe_repo.clone(),
+        &created.id,
+        &uuid::Uuid::nil(),
+        IngredientUnit::Grams(500.0),
+        &user,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap();
+    delete_ingredient_from_recipe(
+        recipe_repo.clone(),
+        &created.id,
+        &uuid::Uuid::from_u128(2),
+        &user,
+        &ExpectedVersion::Any,
+    )
+    .await
+    .unwrap();
+
+    let revisions = list_recipe_revisions(recipe_repo.clone(), &created.id, Some(&user))
+        .await
+        .unwrap();
+
+    assert_eq!(
+        revisions.iter().map(|r| r.revision).collect::<Vec<_>>(),
+        vec![Version(4), Version(3), Version(2), Version(1)]
+    );
+    assert!(revisions[3].changes.is_empty());
+    assert_eq!(revisions[2].changes.len(), 4);
+    assert!(matches!(
+        &revisions[1].changes[..],
+        [RecipeChange::IngredientChanged { from, to }]
+            if from.amount == IngredientUnit::Grams(400.0) && to.amount == IngredientUnit::Grams(500.0)
+    ));
+    assert_eq!(
+        revisions[0].changes,
+        vec![RecipeChange::IngredientRemoved {
+            ingredient: RevisionIngredient {
+                ingredient_id: uuid::Uuid::from_u128(2),
+                name: "Garlic".to_string(),
+                amount: IngredientUnit::Other {
+                    unit: "clove".to_string(),
+                    amount: 4.0
+                },
+                notes: None,
+                optional: false,
+            }
+        }]
+    );
+
+    let revision = get_recipe_revision(recipe_repo, &created.id, Version(2), Some(&user))
+        .await
+        .unwrap();
+    assert_eq!(revision.snapshot.name, "WE UPDATED THIS THING");
+    assert_eq!(revision.snapshot.ingredients.len(), 3);
+}
+
+pub async fn revisions_of_a_private_recipe_are_hidden(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let recipe_repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let recipe = Recipe {
+        public: false,
+        ..authored_recipe_fixture()
+    };
+    user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+    let created = create_recipe(recipe_repo.clone(), ingredient_repo, &recipe.into())
+        .await
+        .unwrap();
+
+    let result = list_recipe_revisions(recipe_repo.clone(), &created.id, None)
+        .await
+        .unwrap_err();
+    assert!(matches!(result, GetRecipeRevisionsError::NotFound(id) if id == created.id));
+
+    let result = get_recipe_revision(recipe_repo, &created.id, Version(1), None)
+        .await
+        .unwrap_err();
+    assert!(matches!(result, GetRecipeRevisionsError::NotFound(id) if id == created.id));
+}
@@ -0,0 +1,41 @@
+use crate::domain::entities::recipe::{IngredientWithAmount, Recipe, ServingsType};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ScaleRecipeError {
+    #[error("Cannot scale a recipe to {0} servings")]
+    InvalidServings(u16),
+
+    #[error("The recipe does not specify a number of servings to scale from")]
+    NoServingsToScaleFrom,
+}
+
+/// Rescales a recipe so that it yields the given number of servings.
+///
+/// Every ingredient amount is multiplied by the ratio between the target servings and the
+/// original ones. A range of servings (i.e. "4 to 6") is treated as its midpoint.
+/// Amounts in [`IngredientUnit::Other`](crate::domain::entities::recipe::IngredientUnit::Other)
+/// units are scaled the same way as any other amount, and their unit is kept as-is,
+/// so "1 head" of broccoli becomes "1.5 head" when going from 4 to 6 servings.
+pub fn scale_recipe(recipe: Recipe, servings: u16) -> Result<Recipe, ScaleRecipeError> {
+    if servings == 0 {
+        return Err(ScaleRecipeError::InvalidServings(servings));
+    }
+
+    let original = recipe.servings.average();
+    if original == 0.0 {
+        return Err(ScaleRecipeError::NoServingsToScaleFrom);
+    }
+
+    let factor = f64::from(servings) / original;
+
+    let mut recipe = recipe;
+    for IngredientWithAmount { amount, .. } in recipe.ingredients.iter_mut() {
+        *amount = std::mem::take(amount).scale(factor);
+    }
+    recipe.servings = ServingsType::Exact(servings);
+
+    Ok(recipe)
+}
+
+#[cfg(test)]
+mod tests;
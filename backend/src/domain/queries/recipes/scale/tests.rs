@@ -0,0 +1,102 @@
+use crate::{
+    domain::{
+        entities::recipe::{IngredientUnit, Recipe, ServingsType},
+        queries::recipes::scale::{scale_recipe, ScaleRecipeError},
+    },
+    test_utils::recipe_fixture,
+};
+
+fn amounts(recipe: &Recipe) -> Vec<IngredientUnit> {
+    recipe
+        .ingredients
+        .iter()
+        .map(|i| i.amount.clone())
+        .collect()
+}
+
+#[test]
+fn scaling_up_multiplies_every_amount() {
+    let recipe = recipe_fixture();
+
+    let result = scale_recipe(recipe.clone(), 8).unwrap();
+
+    assert_eq!(result.servings, ServingsType::Exact(8));
+    assert_eq!(
+        amounts(&result),
+        vec![
+            IngredientUnit::Grams(800.0),
+            IngredientUnit::Other {
+                unit: "head".to_string(),
+                amount: 2.0
+            },
+            IngredientUnit::Other {
+                unit: "clove".to_string(),
+                amount: 8.0
+            },
+        ]
+    );
+    assert_eq!(result.name, recipe.name);
+    assert_eq!(result.steps, recipe.steps);
+}
+
+#[test]
+fn scaling_down_divides_every_amount() {
+    let recipe = recipe_fixture();
+
+    let result = scale_recipe(recipe, 2).unwrap();
+
+    assert_eq!(result.servings, ServingsType::Exact(2));
+    assert_eq!(
+        amounts(&result),
+        vec![
+            IngredientUnit::Grams(200.0),
+            IngredientUnit::Other {
+                unit: "head".to_string(),
+                amount: 0.5
+            },
+            IngredientUnit::Other {
+                unit: "clove".to_string(),
+                amount: 2.0
+            },
+        ]
+    );
+}
+
+#[test]
+fn scaling_to_the_same_servings_changes_nothing() {
+    let recipe = recipe_fixture();
+
+    let result = scale_recipe(recipe.clone(), 4).unwrap();
+
+    assert_eq!(result, recipe);
+}
+
+#[test]
+fn scaling_a_range_uses_its_midpoint() {
+    let mut recipe = recipe_fixture();
+    recipe.servings = ServingsType::FromTo(2, 6);
+
+    let result = scale_recipe(recipe, 8).unwrap();
+
+    assert_eq!(result.servings, ServingsType::Exact(8));
+    assert_eq!(amounts(&result)[0], IngredientUnit::Grams(800.0));
+}
+
+#[test]
+fn scaling_to_zero_servings_errors() {
+    let recipe = recipe_fixture();
+
+    let error = scale_recipe(recipe, 0).unwrap_err();
+
+    assert!(matches!(error, ScaleRecipeError::InvalidServings(0)));
+}
+
+#[test]
+fn scaling_a_recipe_without_servings_errors() {
+    let mut recipe = recipe_fixture();
+    recipe.servings = ServingsType::Exact(0);
+
+    let error = scale_recipe(recipe, 4).unwrap_err();
+
+    assert!(matches!(error, ScaleRecipeError::NoServingsToScaleFrom));
+}
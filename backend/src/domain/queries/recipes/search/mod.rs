@@ -0,0 +1,51 @@
+use crate::domain::{
+    entities::{
+        pagination::page_size,
+        recipe::{
+            errors::ValidationError,
+            search::{RecipeSearchQuery, RecipeSearchResult, SearchTerms},
+        },
+        user::User,
+    },
+    repositories::recipe::{
+        errors::SearchRecipesError as SearchRecipesErrorInternal, RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum SearchRecipesError {
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<SearchRecipesErrorInternal> for SearchRecipesError {
+    fn from(value: SearchRecipesErrorInternal) -> Self {
+        match value {
+            SearchRecipesErrorInternal::ValidationError(e) => Self::ValidationError(e),
+            SearchRecipesErrorInternal::UnknownError(e) => Self::Unknown(e),
+        }
+    }
+}
+
+/// Searches the recipes the viewer can see, best matches first
+#[tracing::instrument("[QUERY] Search recipes", skip(recipe_repo, viewer))]
+pub async fn search_recipes(
+    recipe_repo: RecipeRepositoryService,
+    text: &str,
+    limit: Option<u64>,
+    viewer: Option<&User>,
+) -> Result<Vec<RecipeSearchResult>, SearchRecipesError> {
+    let query = RecipeSearchQuery {
+        terms: SearchTerms::try_from(text)?,
+        limit: page_size(limit),
+        viewer: viewer.map(|user| user.id),
+    };
+
+    Ok(recipe_repo.search(&query).await?)
+}
+
+#[cfg(test)]
+mod tests;
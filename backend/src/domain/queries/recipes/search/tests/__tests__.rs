@@ -0,0 +1,214 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::recipe::{errors::ValidationError, search::RecipeSearchResult, Recipe},
+        queries::recipes::search::{search_recipes, SearchRecipesError},
+        repositories::{
+            ingredients::IngredientRepository,
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{insert_all_ingredients_of_recipe, recipe_fixture, user_fixture},
+};
+
+use pretty_assertions::assert_eq;
+
+/// Builds a recipe out of the fixture, with the fixture ingredients at the given indices
+fn recipe(
+    id: u128,
+    name: &str,
+    description: &str,
+    steps: &[&str],
+    ingredients: &[usize],
+) -> Recipe {
+    let fixture = recipe_fixture();
+
+    Recipe {
+        id: Uuid::from_u128(id),
+        name: name.to_string(),
+        description: description.to_string(),
+        steps: steps
+            .iter()
+            .map(|s| s.to_string())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+        ingredients: ingredients
+            .iter()
+            .map(|&i| fixture.ingredients[i].clone())
+            .collect::<Vec<_>>()
+            .try_into()
+            .unwrap(),
+        ..fixture
+    }
+}
+
+/// The fixture ingredients are firm tofu, broccoli and garlic
+async fn seed(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) -> RecipeRepositoryService {
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe_fixture()).await;
+
+    for recipe in [
+        recipe(
+            1,
+            "Garlic broccoli",
+            "Quick side dish",
+            &["Steam the broccoli", "Fry the garlic"],
+            &[1, 2],
+        ),
+        recipe(
+            2,
+            "Tofu scramble",
+            "Like scrambled eggs, but with tofu",
+            &["Crumble the tofu into a pan", "Fry it until golden"],
+            &[0],
+        ),
+        recipe(
+            3,
+            "Fried rice",
+            "Leftover rice, fried",
+            &["Fry the rice with garlic"],
+            &[2],
+        ),
+    ] {
+        repo.insert(recipe).await.unwrap();
+    }
+
+    Arc::new(Box::new(repo))
+}
+
+fn names(results: &[RecipeSearchResult]) -> Vec<&str> {
+    results.iter().map(|r| r.recipe.name.as_str()).collect()
+}
+
+pub async fn name_matches_rank_above_other_matches(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = seed(repo, ingredient_repo).await;
+
+    let result = search_recipes(repo, "garl", None, None).await.unwrap();
+
+    assert_eq!(names(&result), vec!["Garlic broccoli", "Fried rice"]);
+    assert!(result[0].rank > result[1].rank);
+}
+
+pub async fn every_term_has_to_match(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = seed(repo, ingredient_repo).await;
+
+    let result = search_recipes(repo.clone(), "Tofu, eggs", None, None)
+        .await
+        .unwrap();
+    assert_eq!(names(&result), vec!["Tofu scramble"]);
+
+    let result = search_recipes(repo, "garlic tofu", None, None)
+        .await
+        .unwrap();
+    assert_eq!(result, vec![]);
+}
+
+pub async fn searches_ingredient_names(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = seed(repo, ingredient_repo).await;
+
+    let result = search_recipes(repo, "firm", None, None).await.unwrap();
+
+    assert_eq!(names(&result), vec!["Tofu scramble"]);
+}
+
+pub async fn snippets_highlight_matches(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = seed(repo, ingredient_repo).await;
+
+    let result = search_recipes(repo, "crumb", None, None).await.unwrap();
+
+    assert_eq!(names(&result), vec!["Tofu scramble"]);
+    assert!(result[0].snippet.contains("<mark>Crumble</mark> the tofu"));
+}
+
+pub async fn snippets_escape_html(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe_fixture()).await;
+    repo.insert(recipe(
+        4,
+        "Sneaky soup",
+        "<img src=x onerror=alert(1)> soup & bread",
+        &["Boil the soup"],
+        &[0],
+    ))
+    .await
+    .unwrap();
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let result = search_recipes(repo, "soup", None, None).await.unwrap();
+
+    assert_eq!(names(&result), vec!["Sneaky soup"]);
+    assert!(!result[0].snippet.contains("<img"));
+    assert!(result[0]
+        .snippet
+        .contains("alert(1)&gt; <mark>soup</mark> &amp; bread"));
+}
+
+pub async fn limit_caps_the_results(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let repo = seed(repo, ingredient_repo).await;
+
+    let result = search_recipes(repo, "fry", Some(2), None).await.unwrap();
+
+    assert_eq!(result.len(), 2);
+}
+
+pub async fn searching_without_words_fails(repo: impl RecipeRepository) {
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let error = search_recipes(repo, " ?! ", None, None).await.unwrap_err();
+
+    assert!(matches!(
+        error,
+        SearchRecipesError::ValidationError(ValidationError::EmptyField(fields)) if fields == ["q"]
+    ));
+}
+
+pub async fn private_recipes_are_only_found_by_their_author(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let author = user_repo.insert(user_fixture()).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe_fixture()).await;
+    repo.insert(Recipe {
+        author_id: Some(author.id),
+        public: false,
+        ..recipe(1, "Secret tofu", "Family recipe", &["Fry the tofu"], &[0])
+    })
+    .await
+    .unwrap();
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+
+    let result = search_recipes(repo.clone(), "secret", None, None)
+        .await
+        .unwrap();
+    assert_eq!(result, vec![]);
+
+    let result = search_recipes(repo, "secret", None, Some(&author))
+        .await
+        .unwrap();
+    assert_eq!(names(&result), vec!["Secret tofu"]);
+}
@@ -0,0 +1,133 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn name_matches_rank_above_other_matches() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::name_matches_rank_above_other_matches(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn every_term_has_to_match() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::every_term_has_to_match(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn searches_ingredient_names() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::searches_ingredient_names(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn snippets_highlight_matches() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::snippets_highlight_matches(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn snippets_escape_html() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::snippets_escape_html(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn limit_caps_the_results() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::limit_caps_the_results(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn searching_without_words_fails() {
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::searching_without_words_fails(repo).await
+    }
+
+    #[tokio::test]
+    async fn private_recipes_are_only_found_by_their_author() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::private_recipes_are_only_found_by_their_author(repo, ingredient_repo, user_repo)
+            .await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn name_matches_rank_above_other_matches(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::name_matches_rank_above_other_matches(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn every_term_has_to_match(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::every_term_has_to_match(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn searches_ingredient_names(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::searches_ingredient_names(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn snippets_highlight_matches(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::snippets_highlight_matches(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn snippets_escape_html(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::snippets_escape_html(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn limit_caps_the_results(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::limit_caps_the_results(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn searching_without_words_fails(pool: PgPool) {
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::searching_without_words_fails(repo).await
+    }
+
+    #[sqlx::test]
+    async fn private_recipes_are_only_found_by_their_author(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::private_recipes_are_only_found_by_their_author(repo, ingredient_repo, user_repo)
+            .await
+    }
+}
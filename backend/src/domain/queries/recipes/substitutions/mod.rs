@@ -0,0 +1,169 @@
+use std::str::FromStr;
+
+use common::{ProposedSubstituteDTO, RecipeSubstitutionsDTO, SubstitutionProposalDTO};
+use uuid::Uuid;
+
+use crate::domain::{
+    entities::{
+        ingredient::{errors::ValidationError, types::WhichDiets, IngredientSubstitute},
+        recipe::{conversion::Unit, IngredientUnit, IngredientWithAmount},
+        user::User,
+    },
+    queries::recipes::get_by_id::{get_recipe_by_id, GetRecipeError},
+    repositories::{
+        ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+        recipe::RecipeRepositoryService,
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum ProposeSubstitutionsError {
+    #[error("Could not found the recipe with the ID {0}")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    Validation(#[from] ValidationError),
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetRecipeError> for ProposeSubstitutionsError {
+    fn from(value: GetRecipeError) -> Self {
+        match value {
+            GetRecipeError::NotFound(id) => Self::NotFound(id),
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetAllIngredientsError> for ProposeSubstitutionsError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProposedSubstitute {
+    pub substitute: IngredientSubstitute,
+    pub amount: IngredientUnit,
+}
+
+/// An ingredient of the recipe that doesn't fit the diets, with the substitutes that do
+#[derive(Debug, Clone, PartialEq)]
+pub struct SubstitutionProposal {
+    pub ingredient: IngredientWithAmount,
+    pub substitutes: Vec<ProposedSubstitute>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecipeSubstitutions {
+    pub satisfiable: bool,
+    pub substitutions: Vec<SubstitutionProposal>,
+}
+
+impl From<ProposedSubstitute> for ProposedSubstituteDTO {
+    fn from(value: ProposedSubstitute) -> Self {
+        Self {
+            substitute: value.substitute.into(),
+            amount: value.amount.into(),
+        }
+    }
+}
+
+impl From<SubstitutionProposal> for SubstitutionProposalDTO {
+    fn from(value: SubstitutionProposal) -> Self {
+        Self {
+            ingredient: value.ingredient.into(),
+            substitutes: value
+                .substitutes
+                .into_iter()
+                .map(ProposedSubstituteDTO::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<RecipeSubstitutions> for RecipeSubstitutionsDTO {
+    fn from(value: RecipeSubstitutions) -> Self {
+        Self {
+            satisfiable: value.satisfiable,
+            substitutions: value
+                .substitutions
+                .into_iter()
+                .map(SubstitutionProposalDTO::from)
+                .collect(),
+        }
+    }
+}
+
+/// How much of the substitute replaces the amount of the original ingredient, i.e. 60 g of
+/// applesauce for one egg. Without a unit of its own, the substitute keeps the original's unit.
+fn substitute_amount(amount: &IngredientUnit, substitute: &IngredientSubstitute) -> IngredientUnit {
+    let scaled = amount.amount() * *substitute.ratio;
+
+    match &substitute.unit {
+        Some(unit) => match Unit::from_str(unit) {
+            Ok(unit) => unit.with_amount(scaled),
+            Err(_) => IngredientUnit::Other {
+                amount: scaled,
+                unit: unit.clone(),
+            },
+        },
+        None => amount.clone().with_amount(scaled),
+    }
+}
+
+/// Proposes substitutes for every ingredient of the recipe that doesn't fit the given diets.
+///
+/// Only substitutes that fit the diets themselves are proposed, with the recipe's amount
+/// multiplied by the substitute's ratio, in the substitute's unit. The recipe is satisfiable if every ingredient that
+/// doesn't fit is either optional, or has at least one substitute.
+#[tracing::instrument(
+    "[QUERY] Proposing substitutions for a recipe",
+    skip(recipe_repo, ingredient_repo, viewer)
+)]
+pub async fn propose_substitutions(
+    recipe_repo: RecipeRepositoryService,
+    ingredient_repo: IngredientRepositoryService,
+    recipe_id: &Uuid,
+    diets: &WhichDiets,
+    viewer: Option<&User>,
+) -> Result<RecipeSubstitutions, ProposeSubstitutionsError> {
+    let recipe = get_recipe_by_id(recipe_repo, recipe_id, viewer).await?;
+
+    let mut substitutions = Vec::new();
+    for ingredient in recipe.ingredients.iter() {
+        if ingredient.ingredient.diet_violations.allows_all(diets) {
+            continue;
+        }
+
+        let substitutes = ingredient_repo
+            .get_substitutes(&ingredient.ingredient)
+            .await?
+            .into_iter()
+            .filter(|s| s.ingredient.diet_violations.allows_all(diets))
+            .map(|substitute| ProposedSubstitute {
+                amount: substitute_amount(&ingredient.amount, &substitute),
+                substitute,
+            })
+            .collect();
+
+        substitutions.push(SubstitutionProposal {
+            ingredient: ingredient.clone(),
+            substitutes,
+        });
+    }
+
+    let satisfiable = substitutions
+        .iter()
+        .all(|s| s.ingredient.optional || !s.substitutes.is_empty());
+
+    Ok(RecipeSubstitutions {
+        satisfiable,
+        substitutions,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,269 @@
+use std::sync::Arc;
+
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{
+            ingredient::{
+                types::{DietFriendly, DietViolation, SubstituteRatio},
+                Ingredient, IngredientSubstitute,
+            },
+            recipe::{IngredientUnit, IngredientWithAmount, Recipe},
+        },
+        queries::recipes::substitutions::{propose_substitutions, ProposeSubstitutionsError},
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{
+        authored_recipe_fixture, insert_all_ingredients_of_recipe, named_ingredient,
+        recipe_fixture, user_fixture,
+    },
+};
+
+/// The recipe fixture with an egg, which can be replaced with a flax egg, but not with mayonnaise
+async fn seed(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    recipe: Recipe,
+    optional: bool,
+) -> (RecipeRepositoryService, IngredientRepositoryService) {
+    let egg = Ingredient {
+        diet_violations: vec![DietViolation::Eggs].into(),
+        ..named_ingredient(10, "Egg")
+    };
+    let mut ingredients = recipe.ingredients.to_vec();
+    ingredients.push(IngredientWithAmount {
+        ingredient: egg.clone(),
+        amount: IngredientUnit::Other {
+            amount: 1.0,
+            unit: "piece".to_string(),
+        },
+        notes: None,
+        optional,
+    });
+    let recipe = Recipe {
+        ingredients: ingredients.try_into().unwrap(),
+        ..recipe
+    };
+
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    let flax_egg = named_ingredient(11, "Flax egg");
+    let mayonnaise = Ingredient {
+        diet_violations: vec![DietViolation::Eggs].into(),
+        ..named_ingredient(12, "Mayonnaise")
+    };
+    for (substitute, ratio) in [(flax_egg, 1.0), (mayonnaise, 0.5)] {
+        let substitute = ingredient_repo.insert(substitute).await.unwrap();
+        ingredient_repo
+            .set_substitute(
+                &egg,
+                &IngredientSubstitute {
+                    ingredient: substitute,
+                    ratio: SubstituteRatio(ratio),
+                    unit: None,
+                    notes: None,
+                },
+            )
+            .await
+            .unwrap();
+    }
+    repo.insert(recipe).await.unwrap();
+
+    (
+        Arc::new(Box::new(repo)),
+        Arc::new(Box::new(ingredient_repo)),
+    )
+}
+
+pub async fn proposes_substitutes_fitting_the_diets(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    let (repo, ingredient_repo) = seed(repo, ingredient_repo, recipe.clone(), false).await;
+
+    let result = propose_substitutions(
+        repo,
+        ingredient_repo,
+        &recipe.id,
+        &vec![DietFriendly::Vegan].into(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.satisfiable);
+    assert_eq!(result.substitutions.len(), 1);
+
+    let proposal = &result.substitutions[0];
+    assert_eq!(proposal.ingredient.ingredient.id, Uuid::from_u128(10));
+    assert_eq!(proposal.substitutes.len(), 1);
+    assert_eq!(
+        proposal.substitutes[0].substitute.ingredient.id,
+        Uuid::from_u128(11)
+    );
+    assert_eq!(
+        proposal.substitutes[0].amount,
+        IngredientUnit::Other {
+            amount: 1.0,
+            unit: "piece".to_string()
+        }
+    );
+}
+
+pub async fn proposes_substitutes_in_their_own_unit(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    let (repo, ingredient_repo) = seed(repo, ingredient_repo, recipe.clone(), false).await;
+    let egg = ingredient_repo
+        .get_by_id(&Uuid::from_u128(10))
+        .await
+        .unwrap();
+    let applesauce = ingredient_repo
+        .insert(named_ingredient(13, "Applesauce"))
+        .await
+        .unwrap();
+    ingredient_repo
+        .set_substitute(
+            &egg,
+            &IngredientSubstitute {
+                ingredient: applesauce,
+                ratio: SubstituteRatio(60.0),
+                unit: Some("g".to_string()),
+                notes: Some("For baking".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+    let result = propose_substitutions(
+        repo,
+        ingredient_repo,
+        &recipe.id,
+        &vec![DietFriendly::Vegan].into(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let substitutes = &result.substitutions[0].substitutes;
+    assert_eq!(substitutes.len(), 2);
+    assert_eq!(
+        substitutes[0].substitute.ingredient.name.as_str(),
+        "Applesauce"
+    );
+    assert_eq!(substitutes[0].amount, IngredientUnit::Grams(60.0));
+}
+
+pub async fn proposes_nothing_when_recipe_fits_the_diets(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    let (repo, ingredient_repo) = seed(repo, ingredient_repo, recipe.clone(), false).await;
+
+    let result = propose_substitutions(
+        repo,
+        ingredient_repo,
+        &recipe.id,
+        &vec![DietFriendly::GlutenFree].into(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.satisfiable);
+    assert!(result.substitutions.is_empty());
+}
+
+async fn remove_flax_egg(ingredient_repo: &IngredientRepositoryService) {
+    let egg = ingredient_repo
+        .get_by_id(&Uuid::from_u128(10))
+        .await
+        .unwrap();
+    let flax_egg = ingredient_repo
+        .get_by_id(&Uuid::from_u128(11))
+        .await
+        .unwrap();
+    ingredient_repo
+        .delete_substitute(&egg, &flax_egg)
+        .await
+        .unwrap();
+}
+
+pub async fn recipe_is_unsatisfiable_without_substitutes(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    let (repo, ingredient_repo) = seed(repo, ingredient_repo, recipe.clone(), false).await;
+    remove_flax_egg(&ingredient_repo).await;
+
+    let result = propose_substitutions(
+        repo,
+        ingredient_repo,
+        &recipe.id,
+        &vec![DietFriendly::Vegan].into(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(!result.satisfiable);
+    assert_eq!(result.substitutions.len(), 1);
+    assert!(result.substitutions[0].substitutes.is_empty());
+}
+
+pub async fn optional_ingredients_do_not_need_substitutes(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+) {
+    let recipe = recipe_fixture();
+    let (repo, ingredient_repo) = seed(repo, ingredient_repo, recipe.clone(), true).await;
+    remove_flax_egg(&ingredient_repo).await;
+
+    let result = propose_substitutions(
+        repo,
+        ingredient_repo,
+        &recipe.id,
+        &vec![DietFriendly::Vegan].into(),
+        None,
+    )
+    .await
+    .unwrap();
+
+    assert!(result.satisfiable);
+    assert_eq!(result.substitutions.len(), 1);
+    assert!(result.substitutions[0].ingredient.optional);
+}
+
+pub async fn proposing_for_a_hidden_recipe_errors(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    user_repo.insert(user_fixture()).await.unwrap();
+    let recipe = Recipe {
+        public: false,
+        ..authored_recipe_fixture()
+    };
+    let (repo, ingredient_repo) = seed(repo, ingredient_repo, recipe.clone(), false).await;
+
+    let result = propose_substitutions(
+        repo,
+        ingredient_repo,
+        &recipe.id,
+        &vec![DietFriendly::Vegan].into(),
+        None,
+    )
+    .await
+    .unwrap_err();
+
+    assert!(matches!(result, ProposeSubstitutionsError::NotFound(id) if id == recipe.id));
+}
@@ -0,0 +1,105 @@
+mod __tests__;
+
+mod in_memory {
+    use super::__tests__;
+    use crate::domain::repositories::{
+        ingredients::in_memory::InMemoryIngredientRepository,
+        recipe::in_memory::InMemoryRecipeRepository, users::in_memory::InMemoryUserRepository,
+    };
+
+    #[tokio::test]
+    async fn proposes_substitutes_fitting_the_diets() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::proposes_substitutes_fitting_the_diets(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn proposes_substitutes_in_their_own_unit() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::proposes_substitutes_in_their_own_unit(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn proposes_nothing_when_recipe_fits_the_diets() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::proposes_nothing_when_recipe_fits_the_diets(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn recipe_is_unsatisfiable_without_substitutes() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::recipe_is_unsatisfiable_without_substitutes(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn optional_ingredients_do_not_need_substitutes() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::optional_ingredients_do_not_need_substitutes(repo, ingredient_repo).await
+    }
+
+    #[tokio::test]
+    async fn proposing_for_a_hidden_recipe_errors() {
+        let ingredient_repo = InMemoryIngredientRepository::new();
+        let user_repo = InMemoryUserRepository::new();
+        let repo = InMemoryRecipeRepository::new();
+        __tests__::proposing_for_a_hidden_recipe_errors(repo, ingredient_repo, user_repo).await
+    }
+}
+
+mod sql {
+    use super::__tests__;
+    use sqlx::PgPool;
+
+    use crate::domain::repositories::{
+        ingredients::postgres::PostgresIngredientRepository,
+        recipe::postgres::PostgresRecipeRepository, users::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn proposes_substitutes_fitting_the_diets(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::proposes_substitutes_fitting_the_diets(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn proposes_substitutes_in_their_own_unit(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::proposes_substitutes_in_their_own_unit(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn proposes_nothing_when_recipe_fits_the_diets(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::proposes_nothing_when_recipe_fits_the_diets(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn recipe_is_unsatisfiable_without_substitutes(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::recipe_is_unsatisfiable_without_substitutes(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn optional_ingredients_do_not_need_substitutes(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::optional_ingredients_do_not_need_substitutes(repo, ingredient_repo).await
+    }
+
+    #[sqlx::test]
+    async fn proposing_for_a_hidden_recipe_errors(pool: PgPool) {
+        let ingredient_repo = PostgresIngredientRepository::new(pool.clone());
+        let user_repo = PostgresUserRepository::new(pool.clone());
+        let repo = PostgresRecipeRepository::new(pool);
+        __tests__::proposing_for_a_hidden_recipe_errors(repo, ingredient_repo, user_repo).await
+    }
+}
@@ -0,0 +1,44 @@
+use crate::domain::{
+    entities::{trash::Trash, user::User},
+    repositories::{
+        ingredients::{errors::GetAllIngredientsError, IngredientRepositoryService},
+        recipe::{errors::ListRecipesError, RecipeRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetTrashError {
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<ListRecipesError> for GetTrashError {
+    fn from(value: ListRecipesError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+impl From<GetAllIngredientsError> for GetTrashError {
+    fn from(value: GetAllIngredientsError) -> Self {
+        Self::Unknown(value.into())
+    }
+}
+
+/// The recipes the user deleted, along with every deleted ingredient,
+/// as ingredients are shared between everyone
+pub async fn get_trash(
+    ingredient_repo: IngredientRepositoryService,
+    recipe_repo: RecipeRepositoryService,
+    user: &User,
+) -> Result<Trash, GetTrashError> {
+    let recipes = recipe_repo.list_deleted(Some(&user.id)).await?;
+    let ingredients = ingredient_repo.list_deleted().await?;
+
+    Ok(Trash {
+        recipes,
+        ingredients,
+    })
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,60 @@
+use std::sync::Arc;
+
+use pretty_assertions::assert_eq;
+use uuid::Uuid;
+
+use crate::{
+    domain::{
+        entities::{recipe::Recipe, user::User},
+        queries::trash::get::get_trash,
+        repositories::{
+            ingredients::{IngredientRepository, IngredientRepositoryService},
+            recipe::{RecipeRepository, RecipeRepositoryService},
+            users::UserRepository,
+        },
+    },
+    test_utils::{
+        authored_recipe_fixture, ingredient_fixture, insert_all_ingredients_of_recipe, user_fixture,
+    },
+};
+
+pub async fn the_trash_only_has_the_users_own_recipes(
+    repo: impl RecipeRepository,
+    ingredient_repo: impl IngredientRepository,
+    user_repo: impl UserRepository,
+) {
+    let stranger = User {
+        id: Uuid::from_u128(256),
+        username: "stranger".try_into().unwrap(),
+        email: "stranger@example.com".try_into().unwrap(),
+        ..user_fixture()
+    };
+    let recipe = authored_recipe_fixture();
+    let strangers_recipe = Recipe {
+        id: Uuid::from_u128(1),
+        author_id: Some(stranger.id),
+        ..authored_recipe_fixture()
+    };
+    let ingredient = ingredient_fixture();
+    user_repo.insert(user_fixture()).await.unwrap();
+    user_repo.insert(stranger).await.unwrap();
+    insert_all_ingredients_of_recipe(&ingredient_repo, &recipe).await;
+    ingredient_repo.insert(ingredient.clone()).await.unwrap();
+    for recipe in [&recipe, &strangers_recipe] {
+        repo.insert(recipe.clone()).await.unwrap();
+        repo.delete(recipe).await.unwrap();
+    }
+    ingredient_repo.delete(ingredient.clone()).await.unwrap();
+
+    let repo: RecipeRepositoryService = Arc::new(Box::new(repo));
+    let ingredient_repo: IngredientRepositoryService = Arc::new(Box::new(ingredient_repo));
+
+    let trash = get_trash(ingredient_repo, repo, &user_fixture())
+        .await
+        .unwrap();
+
+    let recipes: Vec<Recipe> = trash.recipes.into_iter().map(|t| t.item).collect();
+    assert_eq!(recipes, vec![recipe]);
+    let ingredients: Vec<_> = trash.ingredients.into_iter().map(|t| t.item).collect();
+    assert_eq!(ingredients, vec![ingredient]);
+}
@@ -0,0 +1,48 @@
+use crate::domain::{
+    entities::user::User,
+    repositories::{
+        sessions::{errors::GetSessionError, SessionStoreService},
+        users::{errors::GetUserByIdError, UserRepositoryService},
+    },
+};
+
+#[derive(thiserror::Error, Debug, strum::AsRefStr)]
+pub enum GetUserBySessionError {
+    #[error("You are not logged in or your session has expired")]
+    NotLoggedIn,
+
+    #[error(transparent)]
+    Unknown(#[from] eyre::Error),
+}
+
+impl From<GetSessionError> for GetUserBySessionError {
+    fn from(value: GetSessionError) -> Self {
+        match value {
+            GetSessionError::NotFound => Self::NotLoggedIn,
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+impl From<GetUserByIdError> for GetUserBySessionError {
+    fn from(value: GetUserByIdError) -> Self {
+        match value {
+            GetUserByIdError::NotFound(_) => Self::NotLoggedIn,
+            e => Self::Unknown(e.into()),
+        }
+    }
+}
+
+pub async fn get_user_by_session(
+    user_repo: UserRepositoryService,
+    session_store: SessionStoreService,
+    token: &str,
+) -> Result<User, GetUserBySessionError> {
+    let session = session_store.get(token).await?;
+    let user = user_repo.get_by_id(&session.user_id).await?;
+
+    Ok(user)
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,87 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use crate::{
+    domain::{
+        commands::users::logout::logout,
+        entities::session::Session,
+        queries::users::get_by_session::{get_user_by_session, GetUserBySessionError},
+        repositories::{
+            sessions::{SessionStore, SessionStoreService},
+            users::{UserRepository, UserRepositoryService},
+        },
+    },
+    test_utils::user_fixture,
+};
+
+pub async fn getting_user_by_session_works(
+    repo: impl UserRepository,
+    session_store: impl SessionStore,
+) {
+    let user = user_fixture();
+    repo.insert(user.clone()).await.unwrap();
+    let session = session_store.insert(Session::new(user.id)).await.unwrap();
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+    let session_store: SessionStoreService = Arc::new(Box::new(session_store));
+
+    let result = get_user_by_session(repo, session_store, &session.token)
+        .await
+        .unwrap();
+
+    assert_eq!(result, user);
+}
+
+pub async fn getting_user_by_unknown_session_fails(
+    repo: impl UserRepository,
+    session_store: impl SessionStore,
+) {
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+    let session_store: SessionStoreService = Arc::new(Box::new(session_store));
+
+    let error = get_user_by_session(repo, session_store, "nonexistent")
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetUserBySessionError::NotLoggedIn));
+}
+
+pub async fn getting_user_by_expired_session_fails(
+    repo: impl UserRepository,
+    session_store: impl SessionStore,
+) {
+    let user = user_fixture();
+    repo.insert(user.clone()).await.unwrap();
+    let session = Session {
+        expires_at: Utc::now() - Duration::minutes(1),
+        ..Session::new(user.id)
+    };
+    session_store.insert(session.clone()).await.unwrap();
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+    let session_store: SessionStoreService = Arc::new(Box::new(session_store));
+
+    let error = get_user_by_session(repo, session_store, &session.token)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetUserBySessionError::NotLoggedIn));
+}
+
+pub async fn getting_user_by_session_after_logout_fails(
+    repo: impl UserRepository,
+    session_store: impl SessionStore,
+) {
+    let user = user_fixture();
+    repo.insert(user.clone()).await.unwrap();
+    let session = session_store.insert(Session::new(user.id)).await.unwrap();
+    let repo: UserRepositoryService = Arc::new(Box::new(repo));
+    let session_store: SessionStoreService = Arc::new(Box::new(session_store));
+
+    logout(session_store.clone(), &session.token).await.unwrap();
+
+    let error = get_user_by_session(repo, session_store, &session.token)
+        .await
+        .unwrap_err();
+
+    assert!(matches!(error, GetUserBySessionError::NotLoggedIn));
+}
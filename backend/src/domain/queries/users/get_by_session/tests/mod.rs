@@ -0,0 +1,74 @@
+mod __tests__;
+
+mod in_memory {
+    use crate::domain::repositories::{
+        sessions::in_memory::InMemorySessionStore, users::in_memory::InMemoryUserRepository,
+    };
+
+    use super::__tests__;
+
+    #[tokio::test]
+    async fn getting_user_by_session_works() {
+        let repo = InMemoryUserRepository::new();
+        let session_store = InMemorySessionStore::new();
+        __tests__::getting_user_by_session_works(repo, session_store).await
+    }
+
+    #[tokio::test]
+    async fn getting_user_by_unknown_session_fails() {
+        let repo = InMemoryUserRepository::new();
+        let session_store = InMemorySessionStore::new();
+        __tests__::getting_user_by_unknown_session_fails(repo, session_store).await
+    }
+
+    #[tokio::test]
+    async fn getting_user_by_expired_session_fails() {
+        let repo = InMemoryUserRepository::new();
+        let session_store = InMemorySessionStore::new();
+        __tests__::getting_user_by_expired_session_fails(repo, session_store).await
+    }
+
+    #[tokio::test]
+    async fn getting_user_by_session_after_logout_fails() {
+        let repo = InMemoryUserRepository::new();
+        let session_store = InMemorySessionStore::new();
+        __tests__::getting_user_by_session_after_logout_fails(repo, session_store).await
+    }
+}
+
+mod sql {
+    use sqlx::PgPool;
+
+    use super::__tests__;
+    use crate::domain::repositories::{
+        sessions::in_memory::InMemorySessionStore, users::postgres::PostgresUserRepository,
+    };
+
+    #[sqlx::test]
+    async fn getting_user_by_session_works(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        let session_store = InMemorySessionStore::new();
+        __tests__::getting_user_by_session_works(repo, session_store).await
+    }
+
+    #[sqlx::test]
+    async fn getting_user_by_unknown_session_fails(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        let session_store = InMemorySessionStore::new();
+        __tests__::getting_user_by_unknown_session_fails(repo, session_store).await
+    }
+
+    #[sqlx::test]
+    async fn getting_user_by_expired_session_fails(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        let session_store = InMemorySessionStore::new();
+        __tests__::getting_user_by_expired_session_fails(repo, session_store).await
+    }
+
+    #[sqlx::test]
+    async fn getting_user_by_session_after_logout_fails(pool: PgPool) {
+        let repo = PostgresUserRepository::new(pool);
+        let session_store = InMemorySessionStore::new();
+        __tests__::getting_user_by_session_after_logout_fails(repo, session_store).await
+    }
+}
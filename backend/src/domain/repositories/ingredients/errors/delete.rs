@@ -1,9 +1,13 @@
 use eyre::eyre;
 use std::sync::PoisonError;
 use thiserror::Error;
+use uuid::Uuid;
 
 #[derive(Error, Debug)]
 pub enum DeleteIngredientError {
+    #[error("The ingredient {0} was changed by someone else in the meantime")]
+    VersionMismatch(Uuid),
+
     #[error(transparent)]
     UnknownError(#[from] eyre::Error),
 }
@@ -19,4 +23,3 @@ impl From<sqlx::Error> for DeleteIngredientError {
         Self::UnknownError(e.into())
     }
 }
-
@@ -10,6 +10,9 @@ pub enum GetAllIngredientsError {
     #[error("The ingredients with IDs of {0:?} were not found")]
     MultipleIngredientsMissing(Vec<Uuid>),
 
+    #[error("The cursor {0} does not point to an existing ingredient")]
+    InvalidCursor(Uuid),
+
     #[error(transparent)]
     ValidationError(#[from] ValidationError),
 
@@ -1,6 +1,7 @@
 use eyre::eyre;
 use std::sync::PoisonError;
 use thiserror::Error;
+use uuid::Uuid;
 
 use crate::domain::entities::ingredient::errors::ValidationError;
 
@@ -9,6 +10,9 @@ pub enum UpdateIngredientError {
     #[error("The ingredient with field {0} of the given value already exists")]
     Conflict(String),
 
+    #[error("The ingredient {0} was changed by someone else in the meantime")]
+    VersionMismatch(Uuid),
+
     #[error(transparent)]
     ValidationError(#[from] ValidationError),
 
@@ -1,13 +1,26 @@
 use std::{
-    collections::{BTreeMap, HashSet},
-    sync::Mutex,
+    collections::{BTreeMap, BTreeSet, HashSet},
+    sync::{Arc, Mutex},
 };
 
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::domain::entities::ingredient::{
-    errors::ValidationError, Ingredient, IngredientChangeset,
+use crate::domain::entities::{
+    ingredient::{
+        autocomplete::IngredientAutocompleteQuery,
+        errors::ValidationError,
+        types::{SubstituteRatio, WhichDiets},
+        Ingredient, IngredientChangeset, IngredientListQuery, IngredientPageQuery,
+        IngredientSubstitute,
+    },
+    pagination::{Page, Pagination},
+    trash::Trashed,
+};
+
+use crate::domain::repositories::{
+    recipe::in_memory::{InMemoryRecipeRepository, Recipes},
+    unit_of_work::UnitOfWork,
 };
 
 use super::{
@@ -18,18 +31,39 @@ use super::{
     IngredientRepository,
 };
 
-pub struct InMemoryIngredientRepository(pub Mutex<BTreeMap<Uuid, Ingredient>>);
+/// Ratio, unit and notes of substitutes, keyed by the replaced ingredient's ID and the substitute's ID
+type Substitutes = BTreeMap<(Uuid, Uuid), (SubstituteRatio, Option<String>, Option<String>)>;
+
+/// The substitutes are always locked after the ingredients, and the trash after the substitutes.
+/// The recipes are those of the recipe repository, and are locked before anything else.
+pub struct InMemoryIngredientRepository(
+    pub Arc<Mutex<BTreeMap<Uuid, Ingredient>>>,
+    pub Mutex<Substitutes>,
+    pub Arc<Mutex<BTreeMap<Uuid, Trashed<Ingredient>>>>,
+    pub Recipes,
+);
 
 #[async_trait]
 impl IngredientRepository for InMemoryIngredientRepository {
+    async fn begin(&self) -> eyre::Result<UnitOfWork> {
+        Ok(UnitOfWork::in_memory())
+    }
+
     #[tracing::instrument(
         "[INGREDIENT REPOSITORY] [IN MEMORY] Insert a new ingredient",
-        skip(self)
+        skip(self, uow)
     )]
-    async fn insert(&self, ingredient: Ingredient) -> Result<Ingredient, InsertIngredientError> {
+    async fn insert_in(
+        &self,
+        uow: &mut UnitOfWork,
+        ingredient: Ingredient,
+    ) -> Result<Ingredient, InsertIngredientError> {
         let mut lock = self.0.lock()?;
+        let trash_lock = self.2.lock()?;
 
-        if lock.iter().any(|(id, _)| id == &ingredient.id) {
+        if lock.iter().any(|(id, _)| id == &ingredient.id)
+            || trash_lock.contains_key(&ingredient.id)
+        {
             tracing::error!("The ingredient with ID {} already exists.", ingredient.id);
             return Err(InsertIngredientError::Conflict("id".to_string()));
         };
@@ -42,6 +76,12 @@ impl IngredientRepository for InMemoryIngredientRepository {
             return Err(InsertIngredientError::Conflict("name".to_string()));
         };
 
+        let (ingredients, id) = (self.0.clone(), ingredient.id);
+        uow.on_rollback(move || {
+            if let Ok(mut lock) = ingredients.lock() {
+                lock.remove(&id);
+            }
+        })?;
         lock.insert(ingredient.id, ingredient.clone());
 
         Ok(ingredient)
@@ -62,11 +102,178 @@ impl IngredientRepository for InMemoryIngredientRepository {
         Ok(ingredient.clone())
     }
 
+    async fn get_by_id_for_update(
+        &self,
+        _uow: &mut UnitOfWork,
+        id: &Uuid,
+    ) -> Result<Ingredient, GetIngredientByIdError> {
+        self.get_by_id(id).await
+    }
+
     #[tracing::instrument("[INGREDIENT REPOSITORY] [IN MEMORY] Get all ingredients", skip(self))]
-    async fn get_all(&self) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+    async fn get_all(
+        &self,
+        query: &IngredientListQuery,
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let used: HashSet<Uuid> = match query.used_in_recipes {
+            true => self
+                .3
+                .lock()?
+                .values()
+                .flat_map(|recipe| recipe.ingredients.iter().map(|i| i.ingredient.id))
+                .collect(),
+            false => HashSet::new(),
+        };
+        let lock = self.0.lock()?;
+
+        let matches = |ingredient: &Ingredient| {
+            query.matches(ingredient) && (!query.used_in_recipes || used.contains(&ingredient.id))
+        };
+        let is_variant_of_match = |ingredient: &Ingredient| {
+            query.include_variants
+                && ingredient
+                    .parent_id
+                    .and_then(|parent_id| lock.get(&parent_id))
+                    .is_some_and(matches)
+        };
+
+        Ok(lock
+            .values()
+            .filter(|ingredient| matches(ingredient) || is_variant_of_match(ingredient))
+            .cloned()
+            .collect())
+    }
+
+    #[tracing::instrument("[INGREDIENT REPOSITORY] [IN MEMORY] List ingredients", skip(self))]
+    async fn list(
+        &self,
+        query: &IngredientPageQuery,
+    ) -> Result<Page<Ingredient>, GetAllIngredientsError> {
+        let mut ingredients = self.get_all(&query.filters).await?;
+        ingredients
+            .sort_by_cached_key(|ingredient| (ingredient.name.to_lowercase(), ingredient.id));
+
+        let total = ingredients.len() as u64;
+        let take = (query.limit + 1) as usize;
+
+        let ingredients: Vec<Ingredient> = match query.pagination {
+            Pagination::Offset(offset) => ingredients
+                .into_iter()
+                .skip(offset as usize)
+                .take(take)
+                .collect(),
+            Pagination::After(id) => {
+                let cursor = self
+                    .0
+                    .lock()?
+                    .get(&id)
+                    .map(|cursor| (cursor.name.to_lowercase(), cursor.id))
+                    .ok_or(GetAllIngredientsError::InvalidCursor(id))?;
+
+                ingredients
+                    .into_iter()
+                    .filter(|ingredient| (ingredient.name.to_lowercase(), ingredient.id) > cursor)
+                    .take(take)
+                    .collect()
+            }
+        };
+
+        Ok(Page::from_overfetched(
+            ingredients,
+            total,
+            query.limit,
+            |i| i.id,
+        ))
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Autocomplete ingredients",
+        skip(self)
+    )]
+    async fn autocomplete(
+        &self,
+        query: &IngredientAutocompleteQuery,
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let lock = self.0.lock()?;
+
+        let mut matches: Vec<(f64, &Ingredient)> = lock
+            .values()
+            .filter_map(|ingredient| Some((query.score(&ingredient.name)?, ingredient)))
+            .collect();
+        matches.sort_by(|(a_score, a), (b_score, b)| {
+            b_score
+                .total_cmp(a_score)
+                .then_with(|| a.name.cmp(&b.name))
+                .then_with(|| a.id.cmp(&b.id))
+        });
+
+        Ok(matches
+            .into_iter()
+            .take(query.limit as usize)
+            .map(|(_, ingredient)| ingredient.clone())
+            .collect())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Get variants of ingredient",
+        skip(self)
+    )]
+    async fn get_variants(
+        &self,
+        ingredient: &Ingredient,
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let lock = self.0.lock()?;
+
+        let mut variants: Vec<Ingredient> = lock
+            .values()
+            .filter(|variant| variant.parent_id == Some(ingredient.id))
+            .cloned()
+            .collect();
+        variants.sort_by(|a, b| (&a.name.0, a.id).cmp(&(&b.name.0, b.id)));
+
+        Ok(variants)
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Get parents with variants fitting diets",
+        skip(self)
+    )]
+    async fn get_parents_with_fitting_variants(
+        &self,
+        diets: &WhichDiets,
+    ) -> Result<Vec<Uuid>, GetAllIngredientsError> {
         let lock = self.0.lock()?;
 
-        Ok(lock.values().cloned().collect())
+        let parent_ids: BTreeSet<Uuid> = lock
+            .values()
+            .filter(|variant| variant.diet_violations.allows_all(diets))
+            .filter_map(|variant| variant.parent_id)
+            .collect();
+
+        Ok(parent_ids.into_iter().collect())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Set parent of ingredient",
+        skip(self)
+    )]
+    async fn set_parent(
+        &self,
+        ingredient: &Ingredient,
+        parent: Option<&Ingredient>,
+    ) -> Result<(), UpdateIngredientError> {
+        let mut lock = self.0.lock()?;
+
+        let ingredient =
+            lock.get_mut(&ingredient.id)
+                .ok_or(UpdateIngredientError::UnknownError(eyre::eyre!(
+                "For some reason this ingredient wasn't found, even though we made sure it was."
+            )))?;
+
+        ingredient.parent_id = parent.map(|p| p.id);
+        ingredient.version = ingredient.version.next();
+
+        Ok(())
     }
 
     #[tracing::instrument("[INGREDIENT REPOSITORY] [IN MEMORY] Update ingredient", skip(self))]
@@ -77,6 +284,7 @@ impl IngredientRepository for InMemoryIngredientRepository {
     ) -> Result<(), UpdateIngredientError> {
         let mut lock = self.0.lock()?;
         let id = &ingredient.id;
+        let expected_version = ingredient.version;
 
         let ingredient =
             lock.get_mut(id)
@@ -86,14 +294,31 @@ impl IngredientRepository for InMemoryIngredientRepository {
 
         let name: Option<String> = changeset.name.map(|n| n.to_string());
         let description: Option<String> = changeset.description.map(|n| n.to_string());
-        let diet_friendly: Option<Vec<String>> = changeset.diet_friendly.map(|df| df.into());
+        let diet_violations = changeset.diet_violations;
+        let allergens = changeset.allergens;
+        let density: Option<f64> = changeset.density.map(|d| *d);
 
-        if name.is_none() && description.is_none() && diet_friendly.is_none() {
+        if name.is_none()
+            && description.is_none()
+            && diet_violations.is_none()
+            && allergens.is_none()
+            && density.is_none()
+        {
             return Err(UpdateIngredientError::ValidationError(
-                ValidationError::EmptyField(vec!["name", "description", "diet_friendly"]),
+                ValidationError::EmptyField(vec![
+                    "name",
+                    "description",
+                    "diet_violations",
+                    "allergens",
+                    "density",
+                ]),
             ));
         };
 
+        if ingredient.version != expected_version {
+            return Err(UpdateIngredientError::VersionMismatch(*id));
+        }
+
         if let Some(new_name) = name {
             ingredient.name = new_name.try_into()?;
         }
@@ -102,53 +327,251 @@ impl IngredientRepository for InMemoryIngredientRepository {
             ingredient.description = new_description.try_into()?;
         }
 
-        if let Some(new_diets) = diet_friendly {
-            ingredient.diet_friendly = new_diets.into();
+        if let Some(new_violations) = diet_violations {
+            ingredient.diet_violations = new_violations;
+        }
+
+        if let Some(new_allergens) = allergens {
+            ingredient.allergens = new_allergens;
+        }
+
+        if let Some(new_density) = density {
+            ingredient.density = Some(new_density.try_into()?);
         }
 
+        ingredient.version = ingredient.version.next();
+
         Ok(())
     }
 
-    #[tracing::instrument("[INGREDIENT REPOSITORY] [IN MEMORY] Delete an ingredient", skip(self))]
-    async fn delete(&self, ingredient: Ingredient) -> Result<(), DeleteIngredientError> {
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Get substitutes of ingredient",
+        skip(self)
+    )]
+    async fn get_substitutes(
+        &self,
+        ingredient: &Ingredient,
+    ) -> Result<Vec<IngredientSubstitute>, GetAllIngredientsError> {
+        let lock = self.0.lock()?;
+        let substitutes_lock = self.1.lock()?;
+
+        let mut substitutes: Vec<IngredientSubstitute> = substitutes_lock
+            .iter()
+            .filter(|((ingredient_id, _), _)| *ingredient_id == ingredient.id)
+            .filter_map(|((_, substitute_id), (ratio, unit, notes))| {
+                lock.get(substitute_id)
+                    .map(|substitute| IngredientSubstitute {
+                        ingredient: substitute.clone(),
+                        ratio: *ratio,
+                        unit: unit.clone(),
+                        notes: notes.clone(),
+                    })
+            })
+            .collect();
+        substitutes.sort_by(|a, b| {
+            (&a.ingredient.name.0, a.ingredient.id).cmp(&(&b.ingredient.name.0, b.ingredient.id))
+        });
+
+        Ok(substitutes)
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Set substitute of ingredient",
+        skip(self)
+    )]
+    async fn set_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &IngredientSubstitute,
+    ) -> Result<(), UpdateIngredientError> {
+        let mut lock = self.1.lock()?;
+
+        lock.insert(
+            (ingredient.id, substitute.ingredient.id),
+            (
+                substitute.ratio,
+                substitute.unit.clone(),
+                substitute.notes.clone(),
+            ),
+        );
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Delete substitute of ingredient",
+        skip(self)
+    )]
+    async fn delete_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &Ingredient,
+    ) -> Result<(), DeleteIngredientError> {
+        let mut lock = self.1.lock()?;
+        lock.remove(&(ingredient.id, substitute.id));
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Delete an ingredient",
+        skip(self, uow)
+    )]
+    async fn delete_in(
+        &self,
+        uow: &mut UnitOfWork,
+        ingredient: Ingredient,
+    ) -> Result<(), DeleteIngredientError> {
         let mut lock = self.0.lock()?;
-        lock.remove(&ingredient.id);
+        let mut trash_lock = self.2.lock()?;
+
+        if lock.get(&ingredient.id).map(|i| i.version) != Some(ingredient.version) {
+            return Err(DeleteIngredientError::VersionMismatch(ingredient.id));
+        }
+
+        let (ingredients, trash, id) = (self.0.clone(), self.2.clone(), ingredient.id);
+        uow.on_rollback(move || {
+            let (Ok(mut lock), Ok(mut trash_lock)) = (ingredients.lock(), trash.lock()) else {
+                return;
+            };
+            if let Some(trashed) = trash_lock.remove(&id) {
+                lock.insert(id, trashed.item);
+            }
+        })?;
+
+        if let Some(ingredient) = lock.remove(&ingredient.id) {
+            trash_lock.insert(ingredient.id, Trashed::new(ingredient));
+        }
 
         Ok(())
     }
 
-    async fn get_all_by_id(&self, ids: &[Uuid]) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
-        let lock = self.0.lock()?;
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] List deleted ingredients",
+        skip(self)
+    )]
+    async fn list_deleted(&self) -> Result<Vec<Trashed<Ingredient>>, GetAllIngredientsError> {
+        let lock = self.2.lock()?;
 
-        let mut missing_ids: HashSet<Uuid> = HashSet::from_iter(ids.iter().cloned());
+        let mut trashed: Vec<Trashed<Ingredient>> = lock.values().cloned().collect();
+        trashed.sort_by(|a, b| {
+            b.deleted_at
+                .cmp(&a.deleted_at)
+                .then_with(|| a.item.id.cmp(&b.item.id))
+        });
 
-        let collect = lock
-            .clone()
-            .into_iter()
-            .filter_map(|(id, ingredient)| {
-                if ids.contains(&id) {
-                    missing_ids.remove(&id);
-                    Some(ingredient)
-                } else {
-                    None
-                }
-            })
-            .collect();
+        Ok(trashed)
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Get deleted ingredient with ID",
+        skip(self)
+    )]
+    async fn get_deleted_by_id(
+        &self,
+        id: &Uuid,
+    ) -> Result<Trashed<Ingredient>, GetIngredientByIdError> {
+        let lock = self.2.lock()?;
+
+        lock.get(id)
+            .cloned()
+            .ok_or(GetIngredientByIdError::NotFound(*id))
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [IN MEMORY] Restore an ingredient",
+        skip(self)
+    )]
+    async fn restore(&self, ingredient: &Ingredient) -> Result<(), UpdateIngredientError> {
+        let mut lock = self.0.lock()?;
+        let mut trash_lock = self.2.lock()?;
+
+        let Some(trashed) = trash_lock.get(&ingredient.id) else {
+            return Err(UpdateIngredientError::UnknownError(eyre::eyre!(
+                "For some reason this ingredient wasn't in the trash, even though we made sure it was."
+            )));
+        };
+
+        if lock.values().any(|x| x.name == trashed.item.name) {
+            return Err(UpdateIngredientError::Conflict("name".to_string()));
+        }
+
+        if let Some(trashed) = trash_lock.remove(&ingredient.id) {
+            lock.insert(trashed.item.id, trashed.item);
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[INGREDIENT REPOSITORY] [IN MEMORY] Purge an ingredient", skip(self))]
+    async fn purge(&self, ingredient: &Ingredient) -> Result<(), DeleteIngredientError> {
+        let mut lock = self.0.lock()?;
+        let mut substitutes_lock = self.1.lock()?;
+        let mut trash_lock = self.2.lock()?;
+
+        if trash_lock.remove(&ingredient.id).is_none() {
+            return Ok(());
+        }
+
+        substitutes_lock.retain(|(ingredient_id, substitute_id), _| {
+            *ingredient_id != ingredient.id && *substitute_id != ingredient.id
+        });
+
+        // Variants of the purged ingredient become standalone ones, like Postgres does it
+        let variants = lock
+            .values_mut()
+            .chain(trash_lock.values_mut().map(|t| &mut t.item));
+        for variant in variants.filter(|v| v.parent_id == Some(ingredient.id)) {
+            variant.parent_id = None;
+        }
+
+        Ok(())
+    }
+
+    async fn get_all_by_id(
+        &self,
+        _uow: &mut UnitOfWork,
+        ids: &[Uuid],
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let lock = self.0.lock()?;
+
+        // Same order as the IDs, the way Postgres returns them
+        let (found, missing_ids): (Vec<_>, Vec<_>) =
+            ids.iter().partition(|id| lock.contains_key(id));
 
         if !missing_ids.is_empty() {
             Err(GetAllIngredientsError::MultipleIngredientsMissing(
-                missing_ids.iter().cloned().collect(),
+                missing_ids.into_iter().cloned().collect(),
             ))
         } else {
-            Ok(collect)
+            Ok(found.into_iter().map(|id| lock[id].clone()).collect())
         }
     }
+
+    async fn get_all_by_name(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let lock = self.0.lock()?;
+        let names: Vec<String> = names.iter().map(|name| name.to_lowercase()).collect();
+
+        Ok(lock
+            .values()
+            .filter(|ingredient| names.contains(&ingredient.name.to_lowercase()))
+            .cloned()
+            .collect())
+    }
 }
 
 impl InMemoryIngredientRepository {
     pub fn new() -> Self {
         BTreeMap::new().into()
     }
+
+    /// Shares the recipes of the recipe repository, which tell the ingredients used in them
+    pub fn with_recipes(self, recipes: &InMemoryRecipeRepository) -> Self {
+        Self(self.0, self.1, self.2, recipes.0.clone())
+    }
 }
 
 impl Default for InMemoryIngredientRepository {
@@ -159,6 +582,11 @@ impl Default for InMemoryIngredientRepository {
 
 impl From<BTreeMap<Uuid, Ingredient>> for InMemoryIngredientRepository {
     fn from(value: BTreeMap<Uuid, Ingredient>) -> Self {
-        Self(value.into())
+        Self(
+            Arc::new(value.into()),
+            BTreeMap::new().into(),
+            Default::default(),
+            Default::default(),
+        )
     }
 }
@@ -7,7 +7,16 @@ use std::sync::Arc;
 use async_trait::async_trait;
 use uuid::Uuid;
 
-use crate::domain::entities::ingredient::{Ingredient, IngredientChangeset};
+use crate::domain::entities::{
+    ingredient::{
+        autocomplete::IngredientAutocompleteQuery, types::WhichDiets, Ingredient,
+        IngredientChangeset, IngredientListQuery, IngredientPageQuery, IngredientSubstitute,
+    },
+    pagination::Page,
+    trash::Trashed,
+};
+
+use super::unit_of_work::UnitOfWork;
 
 use self::errors::{
     DeleteIngredientError, GetAllIngredientsError, GetIngredientByIdError, InsertIngredientError,
@@ -16,16 +25,109 @@ use self::errors::{
 
 #[async_trait]
 pub trait IngredientRepository: Send + Sync + 'static {
-    async fn insert(&self, ingredient: Ingredient) -> Result<Ingredient, InsertIngredientError>;
+    /// Starts a unit of work on the store the ingredients are kept in
+    async fn begin(&self) -> eyre::Result<UnitOfWork>;
+    async fn insert(&self, ingredient: Ingredient) -> Result<Ingredient, InsertIngredientError> {
+        let mut uow = self.begin().await?;
+        let ingredient = self.insert_in(&mut uow, ingredient).await?;
+        uow.commit().await?;
+
+        Ok(ingredient)
+    }
+    async fn insert_in(
+        &self,
+        uow: &mut UnitOfWork,
+        ingredient: Ingredient,
+    ) -> Result<Ingredient, InsertIngredientError>;
     async fn get_by_id(&self, id: &Uuid) -> Result<Ingredient, GetIngredientByIdError>;
-    async fn get_all_by_id(&self, ids: &[Uuid]) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
-    async fn get_all(&self) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+    /// Keeps the ingredient from changing, or going to the trash, until the unit of work ends
+    async fn get_by_id_for_update(
+        &self,
+        uow: &mut UnitOfWork,
+        id: &Uuid,
+    ) -> Result<Ingredient, GetIngredientByIdError>;
+    /// Ingredients in the order of the IDs, kept out of the trash until the unit of work ends
+    async fn get_all_by_id(
+        &self,
+        uow: &mut UnitOfWork,
+        ids: &[Uuid],
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+    /// Ingredients with any of the names, ignoring case
+    async fn get_all_by_name(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+    async fn get_all(
+        &self,
+        query: &IngredientListQuery,
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+    async fn list(
+        &self,
+        query: &IngredientPageQuery,
+    ) -> Result<Page<Ingredient>, GetAllIngredientsError>;
+    /// Ingredients with names close to what was typed, best matches first
+    async fn autocomplete(
+        &self,
+        query: &IngredientAutocompleteQuery,
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+    async fn get_variants(
+        &self,
+        ingredient: &Ingredient,
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError>;
+    /// IDs of the ingredients with at least one variant that fits all of the diets
+    async fn get_parents_with_fitting_variants(
+        &self,
+        diets: &WhichDiets,
+    ) -> Result<Vec<Uuid>, GetAllIngredientsError>;
     async fn update(
         &self,
         ingredient: &Ingredient,
         changeset: IngredientChangeset,
     ) -> Result<(), UpdateIngredientError>;
-    async fn delete(&self, ingredient: Ingredient) -> Result<(), DeleteIngredientError>;
+    /// Makes the ingredient a variant of the parent, or a standalone ingredient if there's no parent
+    async fn set_parent(
+        &self,
+        ingredient: &Ingredient,
+        parent: Option<&Ingredient>,
+    ) -> Result<(), UpdateIngredientError>;
+    async fn get_substitutes(
+        &self,
+        ingredient: &Ingredient,
+    ) -> Result<Vec<IngredientSubstitute>, GetAllIngredientsError>;
+    /// Adds the substitute to the ingredient, or replaces its ratio, unit and notes if it already is one
+    async fn set_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &IngredientSubstitute,
+    ) -> Result<(), UpdateIngredientError>;
+    async fn delete_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &Ingredient,
+    ) -> Result<(), DeleteIngredientError>;
+    /// Moves the ingredient to the trash, which hides it from everything but the trash itself
+    async fn delete(&self, ingredient: Ingredient) -> Result<(), DeleteIngredientError> {
+        let mut uow = self.begin().await?;
+        self.delete_in(&mut uow, ingredient).await?;
+        uow.commit().await?;
+
+        Ok(())
+    }
+    async fn delete_in(
+        &self,
+        uow: &mut UnitOfWork,
+        ingredient: Ingredient,
+    ) -> Result<(), DeleteIngredientError>;
+    /// Ingredients in the trash, the most recently deleted first
+    async fn list_deleted(&self) -> Result<Vec<Trashed<Ingredient>>, GetAllIngredientsError>;
+    async fn get_deleted_by_id(
+        &self,
+        id: &Uuid,
+    ) -> Result<Trashed<Ingredient>, GetIngredientByIdError>;
+    /// Takes the ingredient out of the trash, unless another ingredient took its name in the meantime
+    async fn restore(&self, ingredient: &Ingredient) -> Result<(), UpdateIngredientError>;
+    /// Removes an ingredient from the trash for good, along with its substitutes
+    async fn purge(&self, ingredient: &Ingredient) -> Result<(), DeleteIngredientError>;
 }
 
 pub type IngredientRepositoryService = Arc<Box<dyn IngredientRepository>>;
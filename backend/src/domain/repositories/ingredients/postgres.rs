@@ -1,13 +1,22 @@
 use std::{collections::HashMap, sync::OnceLock};
 
-use crate::domain::entities::ingredient::{
-    errors::ValidationError, Ingredient, IngredientChangeset, IngredientModel,
+use crate::domain::entities::{
+    ingredient::{
+        autocomplete::IngredientAutocompleteQuery, errors::ValidationError, types::WhichDiets,
+        Ingredient, IngredientChangeset, IngredientListQuery, IngredientModel, IngredientPageQuery,
+        IngredientSubstitute, IngredientSubstituteModel,
+    },
+    pagination::Page,
+    trash::Trashed,
 };
 use async_trait::async_trait;
+use chrono::{DateTime, Utc};
 use rayon::iter::{IntoParallelIterator, IntoParallelRefIterator, ParallelIterator};
 use sqlx::{error::Error as SQLXError, PgPool};
 use uuid::Uuid;
 
+use crate::domain::repositories::unit_of_work::UnitOfWork;
+
 use super::{
     errors::{
         DeleteIngredientError, GetAllIngredientsError, GetIngredientByIdError,
@@ -28,20 +37,58 @@ fn constraint_to_field(field: &str) -> &str {
     m.get(field).unwrap_or(&field)
 }
 
+/// The columns of an ingredient in the trash, along with when it was deleted
+struct TrashedIngredientModel {
+    id: Uuid,
+    name: String,
+    description: String,
+    diet_violations: Vec<String>,
+    allergens: Vec<String>,
+    density: Option<f64>,
+    parent_id: Option<Uuid>,
+    version: i32,
+    deleted_at: DateTime<Utc>,
+}
+
+impl TryFrom<TrashedIngredientModel> for Trashed<Ingredient> {
+    type Error = ValidationError;
+
+    fn try_from(value: TrashedIngredientModel) -> Result<Self, Self::Error> {
+        let ingredient = IngredientModel {
+            id: value.id,
+            name: value.name,
+            description: value.description,
+            diet_violations: value.diet_violations,
+            allergens: value.allergens,
+            density: value.density,
+            parent_id: value.parent_id,
+            version: value.version,
+        };
+
+        Ok(Self {
+            item: ingredient.try_into()?,
+            deleted_at: value.deleted_at,
+        })
+    }
+}
+
 #[async_trait]
 impl IngredientRepository for PostgresIngredientRepository {
+    async fn begin(&self) -> eyre::Result<UnitOfWork> {
+        UnitOfWork::postgres(&self.0).await
+    }
+
     #[tracing::instrument(
         "[INGREDIENT REPOSITORY] [POSTGRES] Insert a new ingredient",
-        skip(self)
+        skip(self, uow)
     )]
-    async fn insert(&self, ingredient: Ingredient) -> Result<Ingredient, InsertIngredientError> {
-        let diet_friendly: Vec<String> = ingredient
-            .clone()
-            .diet_friendly
-            .0
-            .into_iter()
-            .map(|d| d.to_string())
-            .collect();
+    async fn insert_in(
+        &self,
+        uow: &mut UnitOfWork,
+        ingredient: Ingredient,
+    ) -> Result<Ingredient, InsertIngredientError> {
+        let diet_violations: Vec<String> = ingredient.diet_violations.clone().into();
+        let allergens: Vec<String> = ingredient.allergens.clone().into();
 
         let ingredient = sqlx::query_file_as!(
             IngredientModel,
@@ -49,9 +96,13 @@ impl IngredientRepository for PostgresIngredientRepository {
             ingredient.id,
             &ingredient.name,
             &ingredient.description,
-            &diet_friendly
+            &diet_violations,
+            &allergens,
+            ingredient.density.map(|d| *d),
+            ingredient.parent_id,
+            *ingredient.version
         )
-        .fetch_one(&self.0)
+        .fetch_one(uow.connection()?)
         .await
         .map_err(|e| match e {
             SQLXError::Database(dberror) if dberror.is_unique_violation() => {
@@ -85,11 +136,45 @@ impl IngredientRepository for PostgresIngredientRepository {
         Ok(ingredient.try_into()?)
     }
 
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Get ingredient with ID for update",
+        skip(self, uow)
+    )]
+    async fn get_by_id_for_update(
+        &self,
+        uow: &mut UnitOfWork,
+        id: &Uuid,
+    ) -> Result<Ingredient, GetIngredientByIdError> {
+        let ingredient = sqlx::query_file_as!(
+            IngredientModel,
+            "queries/ingredients/get_ingredient_by_id_for_update.sql",
+            id
+        )
+        .fetch_one(uow.connection()?)
+        .await
+        .map_err(|e| match e {
+            SQLXError::RowNotFound => GetIngredientByIdError::NotFound(*id),
+            _ => GetIngredientByIdError::UnknownError(e.into()),
+        })?;
+
+        Ok(ingredient.try_into()?)
+    }
+
     #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Get all ingredients", skip(self))]
-    async fn get_all(&self) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+    async fn get_all(
+        &self,
+        query: &IngredientListQuery,
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let diet_violations: Vec<String> = query.diet_friendly.violations().into();
+
         let ingredients = sqlx::query_file_as!(
             IngredientModel,
             "queries/ingredients/get_all_ingredients.sql",
+            query.name,
+            &diet_violations,
+            query.include_variants,
+            query.name_prefix,
+            query.used_in_recipes
         )
         .fetch_all(&self.0)
         .await?
@@ -100,6 +185,213 @@ impl IngredientRepository for PostgresIngredientRepository {
         Ok(ingredients)
     }
 
+    #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] List ingredients", skip(self))]
+    async fn list(
+        &self,
+        query: &IngredientPageQuery,
+    ) -> Result<Page<Ingredient>, GetAllIngredientsError> {
+        let cursor = match query.pagination.cursor() {
+            Some(id) => Some(
+                sqlx::query_file!("queries/ingredients/get_ingredient_cursor.sql", id)
+                    .fetch_optional(&self.0)
+                    .await?
+                    .ok_or(GetAllIngredientsError::InvalidCursor(id))?,
+            ),
+            None => None,
+        };
+
+        let filters = &query.filters;
+        let diet_violations: Vec<String> = filters.diet_friendly.violations().into();
+
+        let total = sqlx::query_file!(
+            "queries/ingredients/count_ingredients.sql",
+            filters.name,
+            &diet_violations,
+            filters.include_variants,
+            filters.name_prefix,
+            filters.used_in_recipes
+        )
+        .fetch_one(&self.0)
+        .await?
+        .count;
+
+        let ingredients = sqlx::query_file_as!(
+            IngredientModel,
+            "queries/ingredients/list_ingredients.sql",
+            filters.name,
+            &diet_violations,
+            filters.include_variants,
+            filters.name_prefix,
+            filters.used_in_recipes,
+            cursor.as_ref().map(|c| c.id),
+            cursor.map(|c| c.name),
+            (query.limit + 1) as i64,
+            query.pagination.offset() as i64
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(Ingredient::try_from)
+        .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Page::from_overfetched(
+            ingredients,
+            total as u64,
+            query.limit,
+            |i| i.id,
+        ))
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Autocomplete ingredients",
+        skip(self)
+    )]
+    async fn autocomplete(
+        &self,
+        query: &IngredientAutocompleteQuery,
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let ingredients = sqlx::query_file_as!(
+            IngredientModel,
+            "queries/ingredients/autocomplete_ingredients.sql",
+            query.text.as_str(),
+            query.limit as i64
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(Ingredient::try_from)
+        .collect::<Result<_, _>>()?;
+
+        Ok(ingredients)
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Get variants of ingredient",
+        skip(self)
+    )]
+    async fn get_variants(
+        &self,
+        ingredient: &Ingredient,
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let variants = sqlx::query_file_as!(
+            IngredientModel,
+            "queries/ingredients/get_ingredient_variants.sql",
+            ingredient.id
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(Ingredient::try_from)
+        .collect::<Result<_, _>>()?;
+
+        Ok(variants)
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Get parents with variants fitting diets",
+        skip(self)
+    )]
+    async fn get_parents_with_fitting_variants(
+        &self,
+        diets: &WhichDiets,
+    ) -> Result<Vec<Uuid>, GetAllIngredientsError> {
+        let violations: Vec<String> = diets.violations().into();
+
+        let parent_ids = sqlx::query_file_scalar!(
+            "queries/ingredients/get_parents_with_fitting_variants.sql",
+            &violations
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        Ok(parent_ids)
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Set parent of ingredient",
+        skip(self)
+    )]
+    async fn set_parent(
+        &self,
+        ingredient: &Ingredient,
+        parent: Option<&Ingredient>,
+    ) -> Result<(), UpdateIngredientError> {
+        sqlx::query_file!(
+            "queries/ingredients/set_ingredient_parent.sql",
+            ingredient.id,
+            parent.map(|p| p.id)
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Get substitutes of ingredient",
+        skip(self)
+    )]
+    async fn get_substitutes(
+        &self,
+        ingredient: &Ingredient,
+    ) -> Result<Vec<IngredientSubstitute>, GetAllIngredientsError> {
+        let substitutes = sqlx::query_file_as!(
+            IngredientSubstituteModel,
+            "queries/ingredients/get_ingredient_substitutes.sql",
+            ingredient.id
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(IngredientSubstitute::try_from)
+        .collect::<Result<_, _>>()?;
+
+        Ok(substitutes)
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Set substitute of ingredient",
+        skip(self)
+    )]
+    async fn set_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &IngredientSubstitute,
+    ) -> Result<(), UpdateIngredientError> {
+        sqlx::query_file!(
+            "queries/ingredients/set_ingredient_substitute.sql",
+            ingredient.id,
+            substitute.ingredient.id,
+            *substitute.ratio,
+            substitute.unit,
+            substitute.notes
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Delete substitute of ingredient",
+        skip(self)
+    )]
+    async fn delete_substitute(
+        &self,
+        ingredient: &Ingredient,
+        substitute: &Ingredient,
+    ) -> Result<(), DeleteIngredientError> {
+        sqlx::query_file!(
+            "queries/ingredients/delete_ingredient_substitute.sql",
+            ingredient.id,
+            substitute.id
+        )
+        .execute(&self.0)
+        .await?;
+
+        Ok(())
+    }
+
     #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Update ingredient", skip(self))]
     async fn update(
         &self,
@@ -111,15 +403,40 @@ impl IngredientRepository for PostgresIngredientRepository {
 
         let name: Option<String> = changeset.name.map(|n| n.to_string());
         let description: Option<String> = changeset.description.map(|n| n.to_string());
-        let diet_friendly: Option<Vec<String>> = changeset.diet_friendly.map(|df| df.into());
+        let diet_violations: Option<Vec<String>> = changeset.diet_violations.map(|dv| dv.into());
+        let allergens: Option<Vec<String>> = changeset.allergens.map(|a| a.into());
+        let density: Option<f64> = changeset.density.map(|d| *d);
 
-        if name.is_none() && description.is_none() && diet_friendly.is_none() {
+        if name.is_none()
+            && description.is_none()
+            && diet_violations.is_none()
+            && allergens.is_none()
+            && density.is_none()
+        {
             return Err(UpdateIngredientError::ValidationError(
-                ValidationError::EmptyField(vec!["name", "description", "diet_friendly"]),
+                ValidationError::EmptyField(vec![
+                    "name",
+                    "description",
+                    "diet_violations",
+                    "allergens",
+                    "density",
+                ]),
             ));
         };
 
-        let tx = self.0.begin().await?;
+        let mut tx = self.0.begin().await?;
+
+        let bumped = sqlx::query_file!(
+            "queries/ingredients/bump_ingredient_version.sql",
+            id,
+            Some(*ingredient.version)
+        )
+        .execute(&mut *tx)
+        .await?;
+
+        if bumped.rows_affected() == 0 {
+            return Err(UpdateIngredientError::VersionMismatch(*id));
+        }
 
         if let Some(name) = name {
             if name != ingredient_to_update.name {
@@ -133,7 +450,7 @@ impl IngredientRepository for PostgresIngredientRepository {
                     id,
                     name,
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
                 .await?;
             }
         };
@@ -150,24 +467,58 @@ impl IngredientRepository for PostgresIngredientRepository {
                     id,
                     description,
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
+                .await?;
+            }
+        };
+
+        if let Some(diet_violations) = diet_violations {
+            if diet_violations != ingredient_to_update.diet_violations {
+                sqlx::query!(
+                    r#"
+                    UPDATE ingredients
+                    SET
+                    diet_violations = $2
+                    WHERE id = $1
+                    "#,
+                    id,
+                    &diet_violations
+                )
+                .execute(&mut *tx)
                 .await?;
             }
         };
 
-        if let Some(diet_friendly) = diet_friendly {
-            if diet_friendly != ingredient_to_update.diet_friendly {
+        if let Some(allergens) = allergens {
+            if allergens != ingredient_to_update.allergens {
                 sqlx::query!(
                     r#"
                     UPDATE ingredients
                     SET
-                    diet_friendly = $2
+                    allergens = $2
                     WHERE id = $1
                     "#,
                     id,
-                    &diet_friendly
+                    &allergens
                 )
-                .execute(&self.0)
+                .execute(&mut *tx)
+                .await?;
+            }
+        };
+
+        if let Some(density) = density {
+            if Some(density) != ingredient_to_update.density {
+                sqlx::query!(
+                    r#"
+                    UPDATE ingredients
+                    SET
+                    density = $2
+                    WHERE id = $1
+                    "#,
+                    id,
+                    density
+                )
+                .execute(&mut *tx)
                 .await?;
             }
         };
@@ -177,22 +528,125 @@ impl IngredientRepository for PostgresIngredientRepository {
         Ok(())
     }
 
-    #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Delete an ingredient", skip(self))]
-    async fn delete(&self, ingredient: Ingredient) -> Result<(), DeleteIngredientError> {
-        sqlx::query_file!("queries/ingredients/delete_ingredient.sql", ingredient.id)
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Delete an ingredient",
+        skip(self, uow)
+    )]
+    async fn delete_in(
+        &self,
+        uow: &mut UnitOfWork,
+        ingredient: Ingredient,
+    ) -> Result<(), DeleteIngredientError> {
+        let deleted = sqlx::query_file!(
+            "queries/ingredients/delete_ingredient.sql",
+            ingredient.id,
+            *ingredient.version
+        )
+        .execute(uow.connection()?)
+        .await?;
+
+        if deleted.rows_affected() == 0 {
+            return Err(DeleteIngredientError::VersionMismatch(ingredient.id));
+        }
+
+        Ok(())
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] List deleted ingredients",
+        skip(self)
+    )]
+    async fn list_deleted(&self) -> Result<Vec<Trashed<Ingredient>>, GetAllIngredientsError> {
+        let trashed = sqlx::query_file_as!(
+            TrashedIngredientModel,
+            "queries/ingredients/list_deleted_ingredients.sql"
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(Trashed::try_from)
+        .collect::<Result<_, _>>()?;
+
+        Ok(trashed)
+    }
+
+    #[tracing::instrument(
+        "[INGREDIENT REPOSITORY] [POSTGRES] Get deleted ingredient with ID",
+        skip(self)
+    )]
+    async fn get_deleted_by_id(
+        &self,
+        id: &Uuid,
+    ) -> Result<Trashed<Ingredient>, GetIngredientByIdError> {
+        let trashed = sqlx::query_file_as!(
+            TrashedIngredientModel,
+            "queries/ingredients/get_deleted_ingredient_by_id.sql",
+            id
+        )
+        .fetch_optional(&self.0)
+        .await
+        .map_err(|e| GetIngredientByIdError::UnknownError(e.into()))?
+        .ok_or(GetIngredientByIdError::NotFound(*id))?;
+
+        Ok(trashed.try_into()?)
+    }
+
+    #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Restore an ingredient", skip(self))]
+    async fn restore(&self, ingredient: &Ingredient) -> Result<(), UpdateIngredientError> {
+        sqlx::query_file!("queries/ingredients/restore_ingredient.sql", ingredient.id)
+            .execute(&self.0)
+            .await
+            .map_err(|e| match e {
+                SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                    UpdateIngredientError::Conflict(
+                        constraint_to_field(dberror.constraint().unwrap_or_default()).to_string(),
+                    )
+                }
+                _ => UpdateIngredientError::UnknownError(e.into()),
+            })?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument("[INGREDIENT REPOSITORY] [POSTGRES] Purge an ingredient", skip(self))]
+    async fn purge(&self, ingredient: &Ingredient) -> Result<(), DeleteIngredientError> {
+        sqlx::query_file!("queries/ingredients/purge_ingredient.sql", ingredient.id)
             .execute(&self.0)
             .await?;
 
         Ok(())
     }
 
-    async fn get_all_by_id(&self, ids: &[Uuid]) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+    async fn get_all_by_name(
+        &self,
+        names: &[String],
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
+        let names: Vec<String> = names.iter().map(|name| name.to_lowercase()).collect();
+
+        sqlx::query_file_as!(
+            IngredientModel,
+            "queries/ingredients/get_all_ingredients_by_name.sql",
+            &names
+        )
+        .fetch_all(&self.0)
+        .await
+        .map_err(|e| GetAllIngredientsError::UnknownError(e.into()))?
+        .into_par_iter()
+        .map(|ingredient| ingredient.try_into().map_err(GetAllIngredientsError::from))
+        .collect()
+    }
+
+    async fn get_all_by_id(
+        &self,
+        uow: &mut UnitOfWork,
+        ids: &[Uuid],
+    ) -> Result<Vec<Ingredient>, GetAllIngredientsError> {
         let results: Result<Vec<Ingredient>, GetAllIngredientsError> = sqlx::query_file_as!(
             IngredientModel,
             "queries/ingredients/get_all_ingredients_by_id.sql",
             ids
         )
-        .fetch_all(&self.0)
+        .fetch_all(uow.connection()?)
         .await
         .map_err(|e| match e {
             SQLXError::RowNotFound => {
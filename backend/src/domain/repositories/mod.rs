@@ -1,2 +1,5 @@
 pub mod ingredients;
 pub mod recipe;
+pub mod sessions;
+pub mod unit_of_work;
+pub mod users;
@@ -2,9 +2,13 @@ use eyre::eyre;
 use sqlx::Error as SQLXError;
 use std::sync::PoisonError;
 use thiserror::Error;
+use uuid::Uuid;
 
 #[derive(Error, Debug)]
 pub enum DeleteRecipeError {
+    #[error("The recipe {0} was changed by someone else in the meantime")]
+    VersionMismatch(Uuid),
+
     #[error(transparent)]
     UnknownError(#[from] eyre::Error),
 }
@@ -2,6 +2,7 @@ use eyre::eyre;
 use sqlx::Error as SQLXError;
 use std::sync::PoisonError;
 use thiserror::Error;
+use uuid::Uuid;
 
 use crate::domain::repositories::recipe::errors::constraint_to_field;
 
@@ -10,6 +11,9 @@ pub enum AddIngredientIntoRecipeError {
     #[error("The recipe with field {0} of the given value already exists")]
     Conflict(String),
 
+    #[error("The recipe {0} was changed by someone else in the meantime")]
+    VersionMismatch(Uuid),
+
     #[error(transparent)]
     UnknownError(#[from] eyre::Error),
 }
@@ -2,6 +2,7 @@ use eyre::eyre;
 use sqlx::Error as SQLXError;
 use std::sync::PoisonError;
 use thiserror::Error;
+use uuid::Uuid;
 
 use crate::domain::entities::recipe::errors::ValidationError;
 
@@ -10,6 +11,9 @@ pub enum DeleteIngredientFromRecipeError {
     #[error(transparent)]
     ValidationError(ValidationError),
 
+    #[error("The recipe {0} was changed by someone else in the meantime")]
+    VersionMismatch(Uuid),
+
     #[error(transparent)]
     UnknownError(#[from] eyre::Error),
 }
@@ -2,6 +2,7 @@ use eyre::eyre;
 use sqlx::Error as SQLXError;
 use std::sync::PoisonError;
 use thiserror::Error;
+use uuid::Uuid;
 
 use crate::domain::entities::recipe::errors::ValidationError;
 
@@ -10,6 +11,12 @@ pub enum UpdateIngredientInRecipeError {
     #[error(transparent)]
     ValidationError(ValidationError),
 
+    #[error("The recipe {0} was changed by someone else in the meantime")]
+    VersionMismatch(Uuid),
+
+    #[error("The ingredient is already in one of the recipes")]
+    AlreadyInRecipe,
+
     #[error(transparent)]
     UnknownError(#[from] eyre::Error),
 }
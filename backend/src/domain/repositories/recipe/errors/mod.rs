@@ -18,10 +18,16 @@ mod delete;
 mod get;
 mod ingredients;
 mod insert;
+mod list;
+mod revision;
+mod search;
 mod update;
 
 pub use delete::*;
 pub use get::*;
 pub use ingredients::*;
 pub use insert::*;
+pub use list::*;
+pub use revision::*;
+pub use search::*;
 pub use update::*;
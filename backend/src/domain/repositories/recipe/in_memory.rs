@@ -3,7 +3,8 @@ use eyre::eyre;
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 
 use std::{
-    collections::HashMap,
+    cmp::Ordering,
+    collections::{btree_map::Entry, BTreeMap, HashMap},
     sync::{Arc, Mutex},
 };
 use uuid::Uuid;
@@ -11,31 +12,198 @@ use uuid::Uuid;
 use crate::domain::{
     entities::{
         ingredient::Ingredient,
-        recipe::{IngredientUnit, IngredientWithAmount, Recipe, RecipeChangeset},
+        pagination::{Page, Pagination, SortOrder},
+        recipe::{
+            cookable::{CookableRecipe, CookableRecipesQuery},
+            revision::RecipeRevision,
+            search::{escape_html, tokenize, RecipeSearchQuery, RecipeSearchResult, SearchTerms},
+            IngredientUnit, IngredientWithAmount, Recipe, RecipeChangeset, RecipeListQuery,
+            RecipeSortBy,
+        },
+        trash::Trashed,
+        version::Version,
     },
-    repositories::recipe::errors::InsertRecipeError,
+    repositories::{recipe::errors::InsertRecipeError, unit_of_work::UnitOfWork},
 };
 
 use super::{
     errors::{
         AddIngredientIntoRecipeError, DeleteIngredientFromRecipeError, DeleteRecipeError,
-        GetRecipeByIdError, UpdateIngredientInRecipeError, UpdateRecipeError,
+        GetRecipeByIdError, ListRecipesError, RecipeRevisionError, SearchRecipesError,
+        UpdateIngredientInRecipeError, UpdateRecipeError,
     },
     RecipeRepository, RecipeRepositoryService,
 };
 
-pub struct InMemoryRecipeRepository(pub Mutex<HashMap<uuid::Uuid, Recipe>>);
+/// Revisions of recipes, keyed by the recipe's ID and the revision number
+type Revisions = BTreeMap<(Uuid, Version), RecipeRevision>;
+
+/// Recipes outside of the trash, shared with the ingredients so they can tell which are used
+pub type Recipes = Arc<Mutex<HashMap<uuid::Uuid, Recipe>>>;
+
+/// The revisions are always locked after the recipes, and the trash after the revisions
+pub struct InMemoryRecipeRepository(
+    pub Recipes,
+    pub Arc<Mutex<Revisions>>,
+    pub Mutex<HashMap<uuid::Uuid, Trashed<Recipe>>>,
+);
+
+fn compare_recipes(query: &RecipeListQuery, a: &Recipe, b: &Recipe) -> Ordering {
+    // Recipes without any time come last, whichever the order
+    let without_time = match query.sort_by {
+        RecipeSortBy::TotalTime => a.time.total().is_none().cmp(&b.time.total().is_none()),
+        _ => Ordering::Equal,
+    };
+
+    let ordering = match query.sort_by {
+        RecipeSortBy::Name => a
+            .name
+            .to_lowercase()
+            .cmp(&b.name.to_lowercase())
+            .then(a.id.cmp(&b.id)),
+        RecipeSortBy::CreatedAt => a.id.cmp(&b.id),
+        RecipeSortBy::TotalTime => a.time.total().cmp(&b.time.total()).then(a.id.cmp(&b.id)),
+    };
+
+    without_time.then(match query.order {
+        SortOrder::Ascending => ordering,
+        SortOrder::Descending => ordering.reverse(),
+    })
+}
+
+fn matches_filters(query: &RecipeListQuery, recipe: &Recipe) -> bool {
+    let matches_name = query
+        .name
+        .as_ref()
+        .is_none_or(|name| recipe.name.to_lowercase().contains(&name.to_lowercase()));
+
+    let matches_visibility = match query.author_id {
+        Some(author_id) => recipe.is_authored_by(&author_id),
+        None => recipe.public,
+    };
+
+    let matches_time = query
+        .max_total_time
+        .is_none_or(|max| recipe.time.total().is_some_and(|total| total <= max));
+
+    matches_name
+        && matches_visibility
+        && matches_time
+        && recipe.fits_diets(
+            &query.diet_friendly,
+            &query.ingredients_with_fitting_variants,
+        )
+        && !recipe
+            .allergens()
+            .contains
+            .contains_any(&query.exclude_allergens)
+}
+
+/// Weights of the name, ingredient names, description and steps, the same ones
+/// Postgres uses for the A, B, C and D weights when ranking
+const SEARCH_WEIGHTS: [f64; 4] = [1.0, 0.4, 0.2, 0.1];
+/// Words of context to show before the first match in a snippet
+const SNIPPET_CONTEXT: usize = 5;
+const SNIPPET_WORDS: usize = 20;
+
+/// Sums up the weights of every word matching a term, or `None` if any term doesn't match at all
+fn search_rank(terms: &SearchTerms, recipe: &Recipe) -> Option<f64> {
+    let ingredient_names = recipe
+        .ingredients
+        .iter()
+        .map(|i| i.ingredient.name.to_string())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let fields = [
+        recipe.name.clone(),
+        ingredient_names,
+        recipe.description.clone(),
+        recipe.steps.text(),
+    ];
+    let words: Vec<Vec<String>> = fields.iter().map(|f| tokenize(f).collect()).collect();
+
+    terms.iter().try_fold(0.0, |rank, term| {
+        let term_rank: f64 = words
+            .iter()
+            .zip(SEARCH_WEIGHTS)
+            .map(|(words, weight)| {
+                words.iter().filter(|w| w.starts_with(term)).count() as f64 * weight
+            })
+            .sum();
+
+        (term_rank > 0.0).then_some(rank + term_rank)
+    })
+}
+
+/// Wraps every part of the word that matches a term in `<mark>` tags
+fn highlight(word: &str, terms: &SearchTerms) -> String {
+    let mut highlighted = String::new();
+    let mut rest = word;
+
+    while let Some(start) = rest.find(char::is_alphanumeric) {
+        highlighted.push_str(&escape_html(&rest[..start]));
+        rest = &rest[start..];
+
+        let end = rest
+            .find(|c: char| !c.is_alphanumeric())
+            .unwrap_or(rest.len());
+        let token = &rest[..end];
+        if terms.matches(&token.to_lowercase()) {
+            highlighted.push_str(&format!("<mark>{token}</mark>"));
+        } else {
+            highlighted.push_str(token);
+        }
+        rest = &rest[end..];
+    }
+
+    highlighted.push_str(&escape_html(rest));
+    highlighted
+}
+
+/// A fragment of the description and steps around the first match, like `ts_headline` makes
+fn search_snippet(terms: &SearchTerms, recipe: &Recipe) -> String {
+    let text = format!("{} {}", recipe.description, recipe.steps.text());
+    let words: Vec<&str> = text.split_whitespace().collect();
+
+    let first_match = words
+        .iter()
+        .position(|word| tokenize(word).any(|token| terms.matches(&token)))
+        .unwrap_or_default();
+
+    words
+        .iter()
+        .skip(first_match.saturating_sub(SNIPPET_CONTEXT))
+        .take(SNIPPET_WORDS)
+        .map(|word| highlight(word, terms))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
 
 #[async_trait]
 impl RecipeRepository for InMemoryRecipeRepository {
-    async fn insert(&self, input: Recipe) -> Result<(), InsertRecipeError> {
+    async fn begin(&self) -> eyre::Result<UnitOfWork> {
+        Ok(UnitOfWork::in_memory())
+    }
+
+    async fn insert_in(
+        &self,
+        uow: &mut UnitOfWork,
+        input: Recipe,
+    ) -> Result<(), InsertRecipeError> {
         let mut lock = self.0.lock()?;
+        let trash_lock = self.2.lock()?;
 
-        if lock.iter().any(|(id, _)| id == &input.id) {
+        if lock.iter().any(|(id, _)| id == &input.id) || trash_lock.contains_key(&input.id) {
             tracing::error!("The recipe with ID {} already exists.", input.id);
             return Err(InsertRecipeError::Conflict("recipe id".to_string()));
         };
 
+        let (recipes, id) = (self.0.clone(), input.id);
+        uow.on_rollback(move || {
+            if let Ok(mut lock) = recipes.lock() {
+                lock.remove(&id);
+            }
+        })?;
         lock.insert(input.id, input.clone());
 
         Ok(())
@@ -52,30 +220,198 @@ impl RecipeRepository for InMemoryRecipeRepository {
         Ok(result)
     }
 
+    async fn get_by_id_for_update(
+        &self,
+        _uow: &mut UnitOfWork,
+        id: &Uuid,
+    ) -> Result<Recipe, GetRecipeByIdError> {
+        self.get_by_id(id).await
+    }
+
+    async fn list(&self, query: &RecipeListQuery) -> Result<Page<Recipe>, ListRecipesError> {
+        let lock = self.0.lock()?;
+
+        let mut recipes: Vec<&Recipe> = lock
+            .values()
+            .filter(|recipe| matches_filters(query, recipe))
+            .collect();
+        recipes.sort_by(|a, b| compare_recipes(query, a, b));
+
+        let total = recipes.len() as u64;
+        let take = (query.limit + 1) as usize;
+
+        let recipes: Vec<Recipe> = match query.pagination {
+            Pagination::Offset(offset) => recipes
+                .into_iter()
+                .skip(offset as usize)
+                .take(take)
+                .cloned()
+                .collect(),
+            Pagination::After(id) => {
+                let cursor = lock.get(&id).ok_or(ListRecipesError::InvalidCursor(id))?;
+
+                recipes
+                    .into_iter()
+                    .filter(|recipe| compare_recipes(query, recipe, cursor) == Ordering::Greater)
+                    .take(take)
+                    .cloned()
+                    .collect()
+            }
+        };
+
+        Ok(Page::from_overfetched(recipes, total, query.limit, |r| {
+            r.id
+        }))
+    }
+
+    async fn list_cookable(
+        &self,
+        query: &CookableRecipesQuery,
+    ) -> Result<Vec<CookableRecipe>, ListRecipesError> {
+        let lock = self.0.lock()?;
+
+        let mut recipes: Vec<CookableRecipe> = lock
+            .values()
+            .filter(|recipe| recipe.is_visible_to(query.viewer.as_ref()) && query.matches(recipe))
+            .map(|recipe| CookableRecipe::new(recipe.clone(), &query.available))
+            .collect();
+
+        recipes.sort_by(|a, b| {
+            a.missing
+                .len()
+                .cmp(&b.missing.len())
+                .then(
+                    a.recipe
+                        .name
+                        .to_lowercase()
+                        .cmp(&b.recipe.name.to_lowercase()),
+                )
+                .then(a.recipe.id.cmp(&b.recipe.id))
+        });
+        recipes.truncate(query.limit as usize);
+
+        Ok(recipes)
+    }
+
+    async fn search(
+        &self,
+        query: &RecipeSearchQuery,
+    ) -> Result<Vec<RecipeSearchResult>, SearchRecipesError> {
+        let lock = self.0.lock()?;
+
+        let mut results: Vec<RecipeSearchResult> = lock
+            .values()
+            .filter(|recipe| recipe.is_visible_to(query.viewer.as_ref()))
+            .filter_map(|recipe| {
+                Some(RecipeSearchResult {
+                    rank: search_rank(&query.terms, recipe)?,
+                    snippet: search_snippet(&query.terms, recipe),
+                    recipe: recipe.clone(),
+                })
+            })
+            .collect();
+
+        results.sort_by(|a, b| {
+            b.rank
+                .total_cmp(&a.rank)
+                .then(a.recipe.id.cmp(&b.recipe.id))
+        });
+        results.truncate(query.limit as usize);
+
+        Ok(results)
+    }
+
     async fn delete(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError> {
-        let id = &recipe.id;
         let mut lock = self.0.lock()?;
 
-        lock.remove(id)
+        if lock.get(&recipe.id).map(|r| r.version) != Some(recipe.version) {
+            return Err(DeleteRecipeError::VersionMismatch(recipe.id));
+        }
+
+        let recipe = lock
+            .remove(&recipe.id)
             .ok_or(DeleteRecipeError::UnknownError(eyre!(
                 "The recipe could not be found somehow"
             )))?;
+        self.2.lock()?.insert(recipe.id, Trashed::new(recipe));
+
+        Ok(())
+    }
+
+    async fn list_deleted(
+        &self,
+        author_id: Option<&Uuid>,
+    ) -> Result<Vec<Trashed<Recipe>>, ListRecipesError> {
+        let lock = self.2.lock()?;
+
+        let mut trashed: Vec<Trashed<Recipe>> = lock
+            .values()
+            .filter(|t| author_id.is_none_or(|author_id| t.item.is_authored_by(author_id)))
+            .cloned()
+            .collect();
+        trashed.sort_by(|a, b| {
+            b.deleted_at
+                .cmp(&a.deleted_at)
+                .then_with(|| a.item.id.cmp(&b.item.id))
+        });
+
+        Ok(trashed)
+    }
+
+    async fn get_deleted_by_id(&self, id: &Uuid) -> Result<Trashed<Recipe>, GetRecipeByIdError> {
+        let lock = self.2.lock()?;
+
+        lock.get(id)
+            .cloned()
+            .ok_or_else(|| GetRecipeByIdError::NotFound(*id))
+    }
+
+    async fn restore(&self, recipe: &Recipe) -> Result<(), UpdateRecipeError> {
+        let mut lock = self.0.lock()?;
+
+        let trashed = self
+            .2
+            .lock()?
+            .remove(&recipe.id)
+            .ok_or(UpdateRecipeError::UnknownError(eyre!(
+                "The recipe could not be found in the trash somehow"
+            )))?;
+        lock.insert(trashed.item.id, trashed.item);
+
+        Ok(())
+    }
+
+    async fn purge(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError> {
+        let id = &recipe.id;
+        let mut revisions_lock = self.1.lock()?;
+        let mut trash_lock = self.2.lock()?;
+
+        if trash_lock.remove(id).is_some() {
+            revisions_lock.retain(|(recipe_id, _), _| recipe_id != id);
+        }
 
         Ok(())
     }
 
     async fn update(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         changeset: RecipeChangeset,
     ) -> Result<(), UpdateRecipeError> {
         let mut lock = self.0.lock()?;
+        let expected_version = recipe.version;
         let recipe = lock
             .get_mut(&recipe.id)
             .ok_or(UpdateRecipeError::UnknownError(eyre!(
                 "The recipe could not be found somehow"
             )))?;
 
+        if recipe.version != expected_version {
+            return Err(UpdateRecipeError::VersionMismatch(recipe.id));
+        }
+        self.restore_on_rollback(uow, recipe)?;
+
         if let Some(v) = changeset.name {
             recipe.name = v;
         };
@@ -96,38 +432,105 @@ impl RecipeRepository for InMemoryRecipeRepository {
             recipe.description = v;
         };
 
+        if let Some(v) = changeset.public {
+            recipe.public = v;
+        };
+
+        recipe.version = recipe.version.next();
+
+        Ok(())
+    }
+
+    async fn claim(&self, recipe: &Recipe, author_id: &Uuid) -> Result<(), UpdateRecipeError> {
+        let mut lock = self.0.lock()?;
+        let stored = lock
+            .get_mut(&recipe.id)
+            .ok_or(UpdateRecipeError::UnknownError(eyre!(
+                "The recipe could not be found somehow"
+            )))?;
+
+        if stored.version != recipe.version || stored.author_id.is_some() {
+            return Err(UpdateRecipeError::VersionMismatch(recipe.id));
+        }
+
+        stored.author_id = Some(*author_id);
+        stored.version = stored.version.next();
+
+        Ok(())
+    }
+
+    async fn replace(
+        &self,
+        uow: &mut UnitOfWork,
+        recipe: &Recipe,
+        replacement: &Recipe,
+    ) -> Result<(), UpdateRecipeError> {
+        let mut lock = self.0.lock()?;
+        let stored = lock
+            .get_mut(&recipe.id)
+            .ok_or(UpdateRecipeError::UnknownError(eyre!(
+                "The recipe could not be found somehow"
+            )))?;
+
+        if stored.version != recipe.version {
+            return Err(UpdateRecipeError::VersionMismatch(recipe.id));
+        }
+        self.restore_on_rollback(uow, stored)?;
+
+        *stored = Recipe {
+            id: stored.id,
+            author_id: stored.author_id,
+            version: stored.version.next(),
+            ..replacement.clone()
+        };
+
         Ok(())
     }
 
     async fn add_ingredient(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         ingredient: IngredientWithAmount,
     ) -> Result<(), AddIngredientIntoRecipeError> {
         let mut lock = self.0.lock()?;
+        let expected_version = recipe.version;
         let recipe = lock
             .get_mut(&recipe.id)
             .ok_or(AddIngredientIntoRecipeError::UnknownError(eyre!(
                 "Recipe is not in the repo"
             )))?;
 
+        if recipe.version != expected_version {
+            return Err(AddIngredientIntoRecipeError::VersionMismatch(recipe.id));
+        }
+        self.restore_on_rollback(uow, recipe)?;
+
         recipe.ingredients.push(ingredient);
+        recipe.version = recipe.version.next();
 
         Ok(())
     }
 
     async fn delete_ingredient(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
     ) -> Result<(), DeleteIngredientFromRecipeError> {
         let mut lock = self.0.lock()?;
+        let expected_version = recipe.version;
         let recipe =
             lock.get_mut(&recipe.id)
                 .ok_or(DeleteIngredientFromRecipeError::UnknownError(eyre!(
                     "Recipe is not in the repo somehow"
                 )))?;
 
+        if recipe.version != expected_version {
+            return Err(DeleteIngredientFromRecipeError::VersionMismatch(recipe.id));
+        }
+        self.restore_on_rollback(uow, recipe)?;
+
         let new_ingredients: Vec<_> = recipe
             .ingredients
             .iter()
@@ -138,23 +541,32 @@ impl RecipeRepository for InMemoryRecipeRepository {
         recipe.ingredients = new_ingredients
             .try_into()
             .map_err(DeleteIngredientFromRecipeError::ValidationError)?;
+        recipe.steps.retain_ingredients(&recipe.ingredients);
+        recipe.version = recipe.version.next();
 
         Ok(())
     }
 
     async fn update_ingredient_amount(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
         new_amount: &IngredientUnit,
     ) -> Result<(), UpdateIngredientInRecipeError> {
         let mut lock = self.0.lock()?;
+        let expected_version = recipe.version;
         let recipe =
             lock.get_mut(&recipe.id)
                 .ok_or(UpdateIngredientInRecipeError::UnknownError(eyre!(
                     "Recipe is not in the repo somehow"
                 )))?;
 
+        if recipe.version != expected_version {
+            return Err(UpdateIngredientInRecipeError::VersionMismatch(recipe.id));
+        }
+        self.restore_on_rollback(uow, recipe)?;
+
         let ingredient = recipe
             .ingredients
             .iter_mut()
@@ -164,22 +576,146 @@ impl RecipeRepository for InMemoryRecipeRepository {
             )))?;
 
         ingredient.amount = new_amount.clone();
+        recipe.version = recipe.version.next();
 
         Ok(())
     }
 
-    async fn recipes_containing_ingredient_exist(
+    async fn recipes_containing_ingredient(
         &self,
-        ingredient: Ingredient,
-    ) -> eyre::Result<bool> {
+        _uow: &mut UnitOfWork,
+        ingredient: &Ingredient,
+    ) -> eyre::Result<Vec<Recipe>> {
         let lock = self.0.lock().map_err(|_| eyre!("Poison issue"))?;
-        let some_recipe_with_ingredient = lock.par_iter().find_any(|(_id, r)| {
+        let mut recipes: Vec<Recipe> = lock
+            .par_iter()
+            .filter(|(_id, r)| {
+                r.ingredients
+                    .iter()
+                    .any(|i| i.ingredient.id == ingredient.id)
+            })
+            .map(|(_id, r)| r.clone())
+            .collect();
+        recipes.sort_by(|a, b| {
+            a.name
+                .to_lowercase()
+                .cmp(&b.name.to_lowercase())
+                .then(a.id.cmp(&b.id))
+        });
+
+        Ok(recipes)
+    }
+
+    async fn replace_ingredient(
+        &self,
+        uow: &mut UnitOfWork,
+        ingredient: &Ingredient,
+        replacement: &Ingredient,
+    ) -> Result<(), UpdateIngredientInRecipeError> {
+        let mut lock = self.0.lock()?;
+
+        let before: Vec<Recipe> = lock
+            .values()
+            .filter(|r| {
+                r.ingredients
+                    .iter()
+                    .any(|i| i.ingredient.id == ingredient.id)
+            })
+            .cloned()
+            .collect();
+        if before.iter().any(|r| {
             r.ingredients
+                .iter()
+                .any(|i| i.ingredient.id == replacement.id)
+        }) {
+            return Err(UpdateIngredientInRecipeError::AlreadyInRecipe);
+        }
+
+        let recipes = self.0.clone();
+        uow.on_rollback(move || {
+            if let Ok(mut lock) = recipes.lock() {
+                lock.extend(before.into_iter().map(|r| (r.id, r)));
+            }
+        })?;
+
+        for recipe in lock.values_mut() {
+            let Some(used) = recipe
+                .ingredients
+                .iter_mut()
+                .find(|i| i.ingredient.id == ingredient.id)
+            else {
+                continue;
+            };
+
+            used.ingredient = replacement.clone();
+            recipe
+                .steps
+                .replace_ingredient(&ingredient.id, &replacement.id);
+            recipe.version = recipe.version.next();
+        }
+
+        Ok(())
+    }
+
+    async fn trashed_recipes_containing_ingredient_exist(
+        &self,
+        ingredient: &Ingredient,
+    ) -> eyre::Result<bool> {
+        let lock = self.2.lock().map_err(|_| eyre!("Poison issue"))?;
+
+        Ok(lock.values().any(|t| {
+            t.item
+                .ingredients
                 .iter()
                 .any(|i| i.ingredient.id == ingredient.id)
-        });
+        }))
+    }
 
-        Ok(some_recipe_with_ingredient.is_some())
+    async fn insert_revision_in(
+        &self,
+        uow: &mut UnitOfWork,
+        revision: &RecipeRevision,
+    ) -> Result<(), RecipeRevisionError> {
+        let mut lock = self.1.lock()?;
+        let key = (revision.recipe_id, revision.revision);
+
+        if let Entry::Vacant(entry) = lock.entry(key) {
+            let revisions = self.1.clone();
+            uow.on_rollback(move || {
+                if let Ok(mut lock) = revisions.lock() {
+                    lock.remove(&key);
+                }
+            })?;
+            entry.insert(revision.clone());
+        }
+
+        Ok(())
+    }
+
+    async fn list_revisions(
+        &self,
+        recipe_id: &Uuid,
+    ) -> Result<Vec<RecipeRevision>, RecipeRevisionError> {
+        let lock = self.1.lock()?;
+        let revisions = lock
+            .range((*recipe_id, Version(i32::MIN))..=(*recipe_id, Version(i32::MAX)))
+            .rev()
+            .map(|(_, revision)| revision.clone())
+            .collect();
+
+        Ok(revisions)
+    }
+
+    async fn get_revision(
+        &self,
+        recipe_id: &Uuid,
+        revision: Version,
+    ) -> Result<RecipeRevision, RecipeRevisionError> {
+        let lock = self.1.lock()?;
+
+        lock.get(&(*recipe_id, revision))
+            .cloned()
+            .ok_or(RecipeRevisionError::NotFound(*recipe_id, revision))
     }
 }
 
@@ -191,16 +727,34 @@ impl Default for InMemoryRecipeRepository {
 
 impl InMemoryRecipeRepository {
     pub fn new() -> Self {
-        Self(Mutex::new(HashMap::new()))
+        Self(
+            Arc::new(Mutex::new(HashMap::new())),
+            Arc::new(Mutex::new(BTreeMap::new())),
+            Mutex::new(HashMap::new()),
+        )
     }
 
     pub fn service(self) -> RecipeRepositoryService {
         Arc::new(Box::new(self))
     }
+
+    /// Puts the recipe back the way it is now, should the unit of work not be committed
+    fn restore_on_rollback(&self, uow: &mut UnitOfWork, recipe: &Recipe) -> eyre::Result<()> {
+        let (recipes, before) = (self.0.clone(), recipe.clone());
+        uow.on_rollback(move || {
+            if let Ok(mut lock) = recipes.lock() {
+                lock.insert(before.id, before);
+            }
+        })
+    }
 }
 
 impl From<HashMap<uuid::Uuid, Recipe>> for InMemoryRecipeRepository {
     fn from(value: HashMap<uuid::Uuid, Recipe>) -> Self {
-        Self(Mutex::new(value))
+        Self(
+            Arc::new(Mutex::new(value)),
+            Arc::new(Mutex::new(BTreeMap::new())),
+            Mutex::new(HashMap::new()),
+        )
     }
 }
@@ -4,56 +4,178 @@ pub mod postgres;
 
 use crate::domain::entities::{
     ingredient::Ingredient,
-    recipe::{IngredientUnit, IngredientWithAmount, Recipe, RecipeChangeset},
+    pagination::Page,
+    recipe::{
+        cookable::{CookableRecipe, CookableRecipesQuery},
+        revision::RecipeRevision,
+        search::{RecipeSearchQuery, RecipeSearchResult},
+        IngredientUnit, IngredientWithAmount, Recipe, RecipeChangeset, RecipeListQuery,
+    },
+    trash::Trashed,
+    version::Version,
 };
 use async_trait::async_trait;
 use errors::AddIngredientIntoRecipeError;
 use std::sync::Arc;
 use uuid::Uuid;
 
+use super::unit_of_work::UnitOfWork;
+
 use self::errors::{
     DeleteIngredientFromRecipeError, DeleteRecipeError, GetRecipeByIdError, InsertRecipeError,
-    UpdateIngredientInRecipeError, UpdateRecipeError,
+    ListRecipesError, RecipeRevisionError, SearchRecipesError, UpdateIngredientInRecipeError,
+    UpdateRecipeError,
 };
 
 #[async_trait]
 pub trait RecipeRepository: Send + Sync + 'static {
-    // TODO: Include user information
-    async fn insert(&self, input: Recipe) -> Result<(), InsertRecipeError>;
+    /// Starts a unit of work on the store the recipes are kept in
+    async fn begin(&self) -> eyre::Result<UnitOfWork>;
+
+    async fn insert(&self, input: Recipe) -> Result<(), InsertRecipeError> {
+        let mut uow = self.begin().await?;
+        self.insert_in(&mut uow, input).await?;
+        uow.commit().await?;
+
+        Ok(())
+    }
+
+    async fn insert_in(&self, uow: &mut UnitOfWork, input: Recipe)
+        -> Result<(), InsertRecipeError>;
 
     async fn get_by_id(&self, id: &Uuid) -> Result<Recipe, GetRecipeByIdError>;
 
+    /// Keeps the recipe from being changed by anything else until the unit of work ends
+    async fn get_by_id_for_update(
+        &self,
+        uow: &mut UnitOfWork,
+        id: &Uuid,
+    ) -> Result<Recipe, GetRecipeByIdError>;
+
+    async fn list(&self, query: &RecipeListQuery) -> Result<Page<Recipe>, ListRecipesError>;
+
+    /// Lists the recipes that can be cooked with the available ingredients,
+    /// the ones missing the fewest ingredients first
+    async fn list_cookable(
+        &self,
+        query: &CookableRecipesQuery,
+    ) -> Result<Vec<CookableRecipe>, ListRecipesError>;
+
+    /// Finds the recipes containing every search term, best matches first
+    async fn search(
+        &self,
+        query: &RecipeSearchQuery,
+    ) -> Result<Vec<RecipeSearchResult>, SearchRecipesError>;
+
+    /// Moves the recipe to the trash, which hides it from everything but the trash itself
     async fn delete(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError>;
 
+    /// Recipes in the trash, of the given author if there is one, the most recently deleted first
+    async fn list_deleted(
+        &self,
+        author_id: Option<&Uuid>,
+    ) -> Result<Vec<Trashed<Recipe>>, ListRecipesError>;
+
+    async fn get_deleted_by_id(&self, id: &Uuid) -> Result<Trashed<Recipe>, GetRecipeByIdError>;
+
+    /// Takes the recipe out of the trash
+    async fn restore(&self, recipe: &Recipe) -> Result<(), UpdateRecipeError>;
+
+    /// Removes a recipe from the trash for good, along with its revisions
+    async fn purge(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError>;
+
     async fn update(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         changeset: RecipeChangeset,
     ) -> Result<(), UpdateRecipeError>;
 
+    /// Makes the user the author of a recipe that has none, as long as the recipe is still at the
+    /// version of `recipe` and nobody claimed it in the meantime
+    async fn claim(&self, recipe: &Recipe, author_id: &Uuid) -> Result<(), UpdateRecipeError>;
+
+    /// Replaces everything but the ID, author and version of the recipe with the replacement's,
+    /// as long as the recipe is still at the version of `recipe`
+    async fn replace(
+        &self,
+        uow: &mut UnitOfWork,
+        recipe: &Recipe,
+        replacement: &Recipe,
+    ) -> Result<(), UpdateRecipeError>;
+
     async fn add_ingredient(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         ingredient: IngredientWithAmount,
     ) -> Result<(), AddIngredientIntoRecipeError>;
 
     async fn delete_ingredient(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
     ) -> Result<(), DeleteIngredientFromRecipeError>;
 
     async fn update_ingredient_amount(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
         new_amount: &IngredientUnit,
     ) -> Result<(), UpdateIngredientInRecipeError>;
 
-    async fn recipes_containing_ingredient_exist(
+    /// Recipes outside of the trash that use the ingredient, sorted by name.
+    /// They're kept from being changed by anything else until the unit of work ends.
+    async fn recipes_containing_ingredient(
+        &self,
+        uow: &mut UnitOfWork,
+        ingredient: &Ingredient,
+    ) -> eyre::Result<Vec<Recipe>>;
+
+    /// Swaps the ingredient for the replacement in every recipe outside of the trash, all at once.
+    /// None of those recipes may already contain the replacement.
+    async fn replace_ingredient(
+        &self,
+        uow: &mut UnitOfWork,
+        ingredient: &Ingredient,
+        replacement: &Ingredient,
+    ) -> Result<(), UpdateIngredientInRecipeError>;
+
+    /// Whether any recipe in the trash uses the ingredient, which keeps it from being purged
+    async fn trashed_recipes_containing_ingredient_exist(
         &self,
-        ingredient: Ingredient,
+        ingredient: &Ingredient,
     ) -> eyre::Result<bool>;
+
+    /// Stores a revision, unless one of the same recipe and version is already stored,
+    /// as it then describes the very same state of the recipe
+    async fn insert_revision(&self, revision: &RecipeRevision) -> Result<(), RecipeRevisionError> {
+        let mut uow = self.begin().await?;
+        self.insert_revision_in(&mut uow, revision).await?;
+        uow.commit().await?;
+
+        Ok(())
+    }
+
+    async fn insert_revision_in(
+        &self,
+        uow: &mut UnitOfWork,
+        revision: &RecipeRevision,
+    ) -> Result<(), RecipeRevisionError>;
+
+    /// Revisions of the recipe, the latest first
+    async fn list_revisions(
+        &self,
+        recipe_id: &Uuid,
+    ) -> Result<Vec<RecipeRevision>, RecipeRevisionError>;
+
+    async fn get_revision(
+        &self,
+        recipe_id: &Uuid,
+        revision: Version,
+    ) -> Result<RecipeRevision, RecipeRevisionError>;
 }
 
 pub type RecipeRepositoryService = Arc<Box<dyn RecipeRepository>>;
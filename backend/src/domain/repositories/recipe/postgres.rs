@@ -1,18 +1,29 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use async_trait::async_trait;
-use futures::future::join_all;
-use sqlx::PgPool;
+use chrono::{DateTime, Utc};
+use sqlx::{Error as SQLXError, PgConnection, PgExecutor, PgPool};
 use uuid::Uuid;
 
 use crate::domain::entities::ingredient::{Ingredient, IngredientModel};
+use crate::domain::entities::pagination::Page;
 use crate::domain::entities::recipe::{
+    cookable::{CookableRecipe, CookableRecipesQuery},
+    errors::ValidationError,
+    revision::{RecipeRevision, RecipeRevisionModel},
+    search::{RecipeSearchQuery, RecipeSearchResult, SearchTerms},
     IngredientUnit, IngredientWithAmount, IngredientWithAmountModel, Recipe, RecipeChangeset,
+    RecipeListQuery, RecipeModel,
 };
+use crate::domain::entities::trash::Trashed;
+use crate::domain::entities::version::Version;
+use crate::domain::repositories::unit_of_work::UnitOfWork;
 
 use super::errors::{
     AddIngredientIntoRecipeError, DeleteIngredientFromRecipeError, DeleteRecipeError,
-    UpdateIngredientInRecipeError, UpdateRecipeError,
+    ListRecipesError, RecipeRevisionError, SearchRecipesError, UpdateIngredientInRecipeError,
+    UpdateRecipeError,
 };
 use super::RecipeRepositoryService;
 use super::{
@@ -22,8 +33,47 @@ use super::{
 
 pub struct PostgresRecipeRepository(pub PgPool);
 
+/// The columns of a recipe in the trash, along with when it was deleted
+struct TrashedRecipeModel {
+    id: Uuid,
+    name: String,
+    description: String,
+    steps: serde_json::Value,
+    time: serde_json::Value,
+    servings: serde_json::Value,
+    author_id: Option<Uuid>,
+    public: bool,
+    version: i32,
+    deleted_at: DateTime<Utc>,
+}
+
+impl TrashedRecipeModel {
+    fn into_trashed(
+        self,
+        ingredients: Vec<IngredientWithAmount>,
+    ) -> Result<Trashed<Recipe>, ValidationError> {
+        let recipe = RecipeModel {
+            id: self.id,
+            name: self.name,
+            description: self.description,
+            steps: self.steps,
+            time: self.time,
+            servings: self.servings,
+            author_id: self.author_id,
+            public: self.public,
+            version: self.version,
+        };
+
+        Ok(Trashed {
+            item: recipe.into_recipe(ingredients)?,
+            deleted_at: self.deleted_at,
+        })
+    }
+}
+
+/// Takes a connection rather than the pool, so it can run as part of a transaction
 async fn insert_ingredient(
-    pool: &PgPool,
+    conn: &mut PgConnection,
     id: Uuid,
     ingredient: &IngredientWithAmount,
 ) -> Result<(), AddIngredientIntoRecipeError> {
@@ -38,55 +88,123 @@ async fn insert_ingredient(
         ingredient.notes,
         ingredient.optional
     )
-    .execute(pool)
+    .execute(conn)
     .await
     .map_err(AddIngredientIntoRecipeError::from)?;
 
     Ok(())
 }
 
+async fn get_ingredients_for_recipes<'c, X, E>(
+    executor: X,
+    recipe_ids: &[Uuid],
+) -> Result<HashMap<Uuid, Vec<IngredientWithAmount>>, E>
+where
+    X: PgExecutor<'c>,
+    E: From<sqlx::Error> + From<ValidationError>,
+{
+    let mut ingredients: HashMap<Uuid, Vec<IngredientWithAmount>> = HashMap::new();
+    for row in sqlx::query_file!(
+        "queries/recipes/get_ingredients_for_recipes.sql",
+        recipe_ids
+    )
+    .fetch_all(executor)
+    .await?
+    {
+        let ingredient = IngredientWithAmountModel {
+            ingredient: row.ingredient,
+            amount: row.amount,
+            notes: row.notes,
+            optional: row.optional,
+        };
+
+        ingredients
+            .entry(row.recipe_id)
+            .or_default()
+            .push(ingredient.try_into()?);
+    }
+
+    Ok(ingredients)
+}
+
+/// Bumps the version of the recipe, which marks it as changed.
+/// When an expected version is passed, nothing is bumped unless it's the current one.
+async fn bump_version(
+    conn: &mut PgConnection,
+    id: Uuid,
+    expected: Option<Version>,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query_file!(
+        "queries/recipes/bump_recipe_version.sql",
+        id,
+        expected.map(|v| *v)
+    )
+    .execute(conn)
+    .await?;
+
+    Ok(result.rows_affected() > 0)
+}
+
+/// Builds a `to_tsquery` query out of the terms, each of them matching as a prefix.
+/// The terms only contain alphanumeric characters, so they can't break out of it.
+fn to_tsquery(terms: &SearchTerms, operator: &str) -> String {
+    terms
+        .iter()
+        .map(|term| format!("{term}:*"))
+        .collect::<Vec<_>>()
+        .join(operator)
+}
+
 #[async_trait]
 impl RecipeRepository for PostgresRecipeRepository {
-    async fn insert(&self, input: Recipe) -> Result<(), InsertRecipeError> {
+    async fn begin(&self) -> eyre::Result<UnitOfWork> {
+        UnitOfWork::postgres(&self.0).await
+    }
+
+    async fn insert_in(
+        &self,
+        uow: &mut UnitOfWork,
+        input: Recipe,
+    ) -> Result<(), InsertRecipeError> {
         let time = serde_json::to_value(&input.time)
             .map_err(|e| InsertRecipeError::UnknownError(e.into()))?;
 
         let servings = serde_json::to_value(&input.servings)
             .map_err(|e| InsertRecipeError::UnknownError(e.into()))?;
 
-        let tx = self.0.begin().await.map_err(InsertRecipeError::from)?;
+        let steps = serde_json::to_value(input.steps.as_ref())
+            .map_err(|e| InsertRecipeError::UnknownError(e.into()))?;
+
+        let conn = uow.connection()?;
 
         let result = sqlx::query_file!(
             "queries/recipes/insert_recipe.sql",
             input.id,
             input.name,
             input.description,
-            &input.steps.as_ref(),
+            steps,
             time,
+            input.time.total_secs(),
             servings,
-            serde_json::json!({})
+            serde_json::json!({}),
+            input.author_id,
+            input.public,
+            *input.version
         )
-        .fetch_one(&self.0)
+        .fetch_one(&mut *conn)
         .await
         .map_err(InsertRecipeError::from)?;
 
-        join_all(
-            input
-                .ingredients
-                .iter()
-                .map(|i| insert_ingredient(&self.0, result.id, i)),
-        )
-        .await
-        .into_iter()
-        .collect::<Result<Vec<()>, AddIngredientIntoRecipeError>>()?;
-
-        tx.commit().await.map_err(InsertRecipeError::from)?;
+        // A connection runs one statement at a time, so the ingredients are inserted in order
+        for ingredient in input.ingredients.iter() {
+            insert_ingredient(conn, result.id, ingredient).await?;
+        }
 
         Ok(())
     }
 
     async fn get_by_id(&self, id: &Uuid) -> Result<Recipe, GetRecipeByIdError> {
-        let result = sqlx::query_file!("queries/recipes/get_recipe.sql", id)
+        let result = sqlx::query_file_as!(RecipeModel, "queries/recipes/get_recipe.sql", id)
             .fetch_one(&self.0)
             .await
             .map_err(|e| GetRecipeByIdError::with_id(id, e))?;
@@ -105,35 +223,259 @@ impl RecipeRepository for PostgresRecipeRepository {
             .collect::<Result<Vec<_>, _>>()
             .map_err(GetRecipeByIdError::from)?;
 
-        let time = serde_json::from_value(result.time)?;
+        Ok(result.into_recipe(ingredients)?)
+    }
 
-        let servings = serde_json::from_value(result.servings)?;
+    async fn get_by_id_for_update(
+        &self,
+        uow: &mut UnitOfWork,
+        id: &Uuid,
+    ) -> Result<Recipe, GetRecipeByIdError> {
+        let conn = uow.connection()?;
+
+        let result =
+            sqlx::query_file_as!(RecipeModel, "queries/recipes/get_recipe_for_update.sql", id)
+                .fetch_one(&mut *conn)
+                .await
+                .map_err(|e| GetRecipeByIdError::with_id(id, e))?;
+
+        let ingredients = sqlx::query_file_as!(
+            IngredientWithAmountModel,
+            "queries/recipes/get_ingredients_for_recipe.sql",
+            id
+        )
+        .fetch_all(conn)
+        .await?
+        .iter()
+        .map(IngredientWithAmount::try_from)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(GetRecipeByIdError::from)?;
+
+        Ok(result.into_recipe(ingredients)?)
+    }
 
-        let recipe = Recipe {
-            id: result.id,
-            name: result.name,
-            description: result.description,
-            steps: result.steps.try_into()?,
-            time,
-            servings,
-            ingredients: ingredients.try_into()?,
+    async fn list(&self, query: &RecipeListQuery) -> Result<Page<Recipe>, ListRecipesError> {
+        let cursor = match query.pagination.cursor() {
+            Some(id) => Some(
+                sqlx::query_file!("queries/recipes/get_recipe_cursor.sql", id)
+                    .fetch_optional(&self.0)
+                    .await?
+                    .ok_or(ListRecipesError::InvalidCursor(id))?,
+            ),
+            None => None,
         };
 
-        Ok(recipe)
+        // A recipe fits the diets when none of its required ingredients violate any of them
+        let diet_violations: Vec<String> = query.diet_friendly.violations().into();
+        let exclude_allergens: Vec<String> = query.exclude_allergens.clone().into();
+        let max_total_time = query
+            .max_total_time
+            .map(|max| i64::try_from(max.as_secs()).unwrap_or(i64::MAX));
+
+        let total = sqlx::query_file!(
+            "queries/recipes/count_recipes.sql",
+            query.name,
+            &diet_violations,
+            query.author_id,
+            &exclude_allergens,
+            max_total_time,
+            &query.ingredients_with_fitting_variants
+        )
+        .fetch_one(&self.0)
+        .await?
+        .count;
+
+        let recipes = sqlx::query_file_as!(
+            RecipeModel,
+            "queries/recipes/list_recipes.sql",
+            query.name,
+            query.sort_by.to_string(),
+            query.order.to_string(),
+            cursor.as_ref().map(|c| c.id),
+            cursor.as_ref().map(|c| c.name.clone()),
+            (query.limit + 1) as i64,
+            query.pagination.offset() as i64,
+            &diet_violations,
+            query.author_id,
+            &exclude_allergens,
+            cursor.and_then(|c| c.total_time),
+            max_total_time,
+            &query.ingredients_with_fitting_variants
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        let recipe_ids: Vec<Uuid> = recipes.iter().map(|r| r.id).collect();
+        let mut ingredients =
+            get_ingredients_for_recipes::<_, ListRecipesError>(&self.0, &recipe_ids).await?;
+
+        let recipes = recipes
+            .into_iter()
+            .map(|r| {
+                let recipe_ingredients = ingredients.remove(&r.id).unwrap_or_default();
+                r.into_recipe(recipe_ingredients)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Page::from_overfetched(
+            recipes,
+            total as u64,
+            query.limit,
+            |r| r.id,
+        ))
+    }
+
+    async fn list_cookable(
+        &self,
+        query: &CookableRecipesQuery,
+    ) -> Result<Vec<CookableRecipe>, ListRecipesError> {
+        let recipes = sqlx::query_file_as!(
+            RecipeModel,
+            "queries/recipes/list_cookable_recipes.sql",
+            &query.available,
+            query.max_missing as i64,
+            query.viewer,
+            query.limit as i64
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        let recipe_ids: Vec<Uuid> = recipes.iter().map(|r| r.id).collect();
+        let mut ingredients =
+            get_ingredients_for_recipes::<_, ListRecipesError>(&self.0, &recipe_ids).await?;
+
+        recipes
+            .into_iter()
+            .map(|r| {
+                let recipe_ingredients = ingredients.remove(&r.id).unwrap_or_default();
+                let recipe = r.into_recipe(recipe_ingredients)?;
+                Ok(CookableRecipe::new(recipe, &query.available))
+            })
+            .collect()
+    }
+
+    async fn search(
+        &self,
+        query: &RecipeSearchQuery,
+    ) -> Result<Vec<RecipeSearchResult>, SearchRecipesError> {
+        let rows = sqlx::query_file!(
+            "queries/recipes/search_recipes.sql",
+            to_tsquery(&query.terms, " & "),
+            to_tsquery(&query.terms, " | "),
+            query.viewer,
+            query.limit as i64
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        let recipe_ids: Vec<Uuid> = rows.iter().map(|r| r.id).collect();
+        let mut ingredients =
+            get_ingredients_for_recipes::<_, SearchRecipesError>(&self.0, &recipe_ids).await?;
+
+        rows.into_iter()
+            .map(|row| {
+                let recipe = RecipeModel {
+                    id: row.id,
+                    name: row.name,
+                    description: row.description,
+                    steps: row.steps,
+                    time: row.time,
+                    servings: row.servings,
+                    author_id: row.author_id,
+                    public: row.public,
+                    version: row.version,
+                }
+                .into_recipe(ingredients.remove(&row.id).unwrap_or_default())?;
+
+                Ok(RecipeSearchResult {
+                    recipe,
+                    rank: row.rank,
+                    snippet: row.snippet,
+                })
+            })
+            .collect()
     }
 
     async fn delete(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError> {
-        let tx = self.0.begin().await?;
+        let deleted = sqlx::query_file!(
+            "queries/recipes/delete_recipe.sql",
+            recipe.id,
+            *recipe.version
+        )
+        .execute(&self.0)
+        .await?;
+
+        if deleted.rows_affected() == 0 {
+            return Err(DeleteRecipeError::VersionMismatch(recipe.id));
+        }
+
+        Ok(())
+    }
+
+    async fn list_deleted(
+        &self,
+        author_id: Option<&Uuid>,
+    ) -> Result<Vec<Trashed<Recipe>>, ListRecipesError> {
+        let recipes = sqlx::query_file_as!(
+            TrashedRecipeModel,
+            "queries/recipes/list_deleted_recipes.sql",
+            author_id
+        )
+        .fetch_all(&self.0)
+        .await?;
+
+        let recipe_ids: Vec<Uuid> = recipes.iter().map(|r| r.id).collect();
+        let mut ingredients =
+            get_ingredients_for_recipes::<_, ListRecipesError>(&self.0, &recipe_ids).await?;
+
+        let trashed = recipes
+            .into_iter()
+            .map(|r| {
+                let recipe_ingredients = ingredients.remove(&r.id).unwrap_or_default();
+                r.into_trashed(recipe_ingredients)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(trashed)
+    }
+
+    async fn get_deleted_by_id(&self, id: &Uuid) -> Result<Trashed<Recipe>, GetRecipeByIdError> {
+        let result = sqlx::query_file_as!(
+            TrashedRecipeModel,
+            "queries/recipes/get_deleted_recipe.sql",
+            id
+        )
+        .fetch_one(&self.0)
+        .await
+        .map_err(|e| GetRecipeByIdError::with_id(id, e))?;
+
+        let mut ingredients =
+            get_ingredients_for_recipes::<_, GetRecipeByIdError>(&self.0, &[*id]).await?;
+
+        Ok(result.into_trashed(ingredients.remove(id).unwrap_or_default())?)
+    }
+
+    async fn restore(&self, recipe: &Recipe) -> Result<(), UpdateRecipeError> {
+        sqlx::query_file!("queries/recipes/restore_recipe.sql", recipe.id)
+            .execute(&self.0)
+            .await?;
+
+        Ok(())
+    }
+
+    async fn purge(&self, recipe: &Recipe) -> Result<(), DeleteRecipeError> {
+        let mut tx = self.0.begin().await?;
 
         sqlx::query_file!(
-            "queries/recipes/delete_ingredients_for_recipe.sql",
+            "queries/recipes/purge_ingredients_for_recipe.sql",
             recipe.id
         )
-        .execute(&self.0)
+        .execute(&mut *tx)
         .await?;
 
-        sqlx::query_file!("queries/recipes/delete_recipe.sql", recipe.id)
-            .execute(&self.0)
+        // The revisions go along with it
+        sqlx::query_file!("queries/recipes/purge_recipe.sql", recipe.id)
+            .execute(&mut *tx)
             .await?;
 
         tx.commit().await?;
@@ -143,11 +485,16 @@ impl RecipeRepository for PostgresRecipeRepository {
 
     async fn update(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         changeset: RecipeChangeset,
     ) -> Result<(), UpdateRecipeError> {
         let id = &recipe.id;
-        let tx = self.0.begin().await?;
+        let conn = uow.connection()?;
+
+        if !bump_version(&mut *conn, *id, Some(recipe.version)).await? {
+            return Err(UpdateRecipeError::VersionMismatch(*id));
+        }
 
         if let Some(value) = changeset.name {
             if value != recipe.name {
@@ -160,7 +507,7 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *conn)
                 .await?;
             }
         };
@@ -176,7 +523,7 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *conn)
                 .await?;
             }
         };
@@ -195,33 +542,36 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *conn)
                 .await?;
             }
         }
 
         if let Some(value) = changeset.time {
             if value != recipe.time {
+                let total_time = value.total_secs();
                 let value = serde_json::to_value(value)
                     .map_err(|e| UpdateRecipeError::UnknownError(e.into()))?;
 
                 sqlx::query!(
                     r#"
                     UPDATE recipes
-                    SET time = $2
+                    SET time = $2, total_time = $3
                     WHERE id = $1
                     "#,
                     id,
-                    value
+                    value,
+                    total_time
                 )
-                .execute(&self.0)
+                .execute(&mut *conn)
                 .await?;
             }
         }
 
         if let Some(value) = changeset.steps {
             if value != recipe.steps {
-                let value = value.as_ref();
+                let value = serde_json::to_value(value.as_ref())
+                    .map_err(|e| UpdateRecipeError::UnknownError(e.into()))?;
 
                 sqlx::query!(
                     r#"
@@ -232,38 +582,139 @@ impl RecipeRepository for PostgresRecipeRepository {
                     id,
                     value
                 )
-                .execute(&self.0)
+                .execute(&mut *conn)
                 .await?;
             }
         }
 
-        tx.commit()
-            .await
+        if let Some(value) = changeset.public {
+            if value != recipe.public {
+                sqlx::query!(
+                    r#"
+                    UPDATE recipes
+                    SET public = $2
+                    WHERE id = $1
+                    "#,
+                    id,
+                    value
+                )
+                .execute(&mut *conn)
+                .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn claim(&self, recipe: &Recipe, author_id: &Uuid) -> Result<(), UpdateRecipeError> {
+        let result = sqlx::query_file!(
+            "queries/recipes/claim_recipe.sql",
+            recipe.id,
+            author_id,
+            *recipe.version
+        )
+        .execute(&self.0)
+        .await?;
+
+        if result.rows_affected() == 0 {
+            return Err(UpdateRecipeError::VersionMismatch(recipe.id));
+        }
+
+        Ok(())
+    }
+
+    async fn replace(
+        &self,
+        uow: &mut UnitOfWork,
+        recipe: &Recipe,
+        replacement: &Recipe,
+    ) -> Result<(), UpdateRecipeError> {
+        let time = serde_json::to_value(&replacement.time)
+            .map_err(|e| UpdateRecipeError::UnknownError(e.into()))?;
+        let servings = serde_json::to_value(&replacement.servings)
+            .map_err(|e| UpdateRecipeError::UnknownError(e.into()))?;
+        let steps = serde_json::to_value(replacement.steps.as_ref())
             .map_err(|e| UpdateRecipeError::UnknownError(e.into()))?;
+
+        let conn = uow.connection()?;
+
+        if !bump_version(&mut *conn, recipe.id, Some(recipe.version)).await? {
+            return Err(UpdateRecipeError::VersionMismatch(recipe.id));
+        }
+
+        sqlx::query_file!(
+            "queries/recipes/replace_recipe.sql",
+            recipe.id,
+            replacement.name,
+            replacement.description,
+            steps,
+            time,
+            replacement.time.total_secs(),
+            servings,
+            replacement.public
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        sqlx::query_file!(
+            "queries/recipes/delete_ingredients_for_recipe.sql",
+            recipe.id
+        )
+        .execute(&mut *conn)
+        .await?;
+
+        for ingredient in replacement.ingredients.iter() {
+            insert_ingredient(&mut *conn, recipe.id, ingredient)
+                .await
+                .map_err(|e| UpdateRecipeError::UnknownError(e.into()))?;
+        }
+
         Ok(())
     }
 
     async fn add_ingredient(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         ingredient: IngredientWithAmount,
     ) -> Result<(), AddIngredientIntoRecipeError> {
-        insert_ingredient(&self.0, recipe.id, &ingredient).await?;
+        let conn = uow.connection()?;
+
+        if !bump_version(&mut *conn, recipe.id, Some(recipe.version)).await? {
+            return Err(AddIngredientIntoRecipeError::VersionMismatch(recipe.id));
+        }
+
+        insert_ingredient(&mut *conn, recipe.id, &ingredient).await?;
 
         Ok(())
     }
 
     async fn delete_ingredient(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
     ) -> Result<(), DeleteIngredientFromRecipeError> {
+        let conn = uow.connection()?;
+
+        if !bump_version(&mut *conn, recipe.id, Some(recipe.version)).await? {
+            return Err(DeleteIngredientFromRecipeError::VersionMismatch(recipe.id));
+        }
+
         sqlx::query_file!(
             "queries/recipes/delete_ingredient_from_recipe_by_id.sql",
             recipe.id,
             ingredient.ingredient.id
         )
-        .execute(&self.0)
+        .execute(&mut *conn)
+        .await?;
+
+        sqlx::query_file!(
+            "queries/recipes/delete_ingredient_from_steps.sql",
+            recipe.id,
+            ingredient.ingredient.id
+        )
+        .execute(&mut *conn)
         .await?;
 
         Ok(())
@@ -271,11 +722,16 @@ impl RecipeRepository for PostgresRecipeRepository {
 
     async fn update_ingredient_amount(
         &self,
+        uow: &mut UnitOfWork,
         recipe: &Recipe,
         ingredient: &IngredientWithAmount,
         new_amount: &IngredientUnit,
     ) -> Result<(), UpdateIngredientInRecipeError> {
-        let tx = self.0.begin().await?;
+        let conn = uow.connection()?;
+
+        if !bump_version(&mut *conn, recipe.id, Some(recipe.version)).await? {
+            return Err(UpdateIngredientInRecipeError::VersionMismatch(recipe.id));
+        }
 
         let amount = serde_json::to_value(new_amount)?;
 
@@ -285,28 +741,154 @@ impl RecipeRepository for PostgresRecipeRepository {
             ingredient.ingredient.id,
             amount
         )
-        .execute(&self.0)
+        .execute(&mut *conn)
         .await?;
 
-        tx.commit().await?;
+        Ok(())
+    }
+
+    async fn recipes_containing_ingredient(
+        &self,
+        uow: &mut UnitOfWork,
+        ingredient: &Ingredient,
+    ) -> eyre::Result<Vec<Recipe>> {
+        let conn = uow.connection()?;
+
+        let recipes = sqlx::query_file_as!(
+            RecipeModel,
+            "queries/recipes/get_recipes_using_ingredient.sql",
+            ingredient.id
+        )
+        .fetch_all(&mut *conn)
+        .await?;
+
+        let recipe_ids: Vec<Uuid> = recipes.iter().map(|r| r.id).collect();
+        let mut ingredients =
+            get_ingredients_for_recipes::<_, ListRecipesError>(conn, &recipe_ids).await?;
+
+        let recipes = recipes
+            .into_iter()
+            .map(|r| {
+                let recipe_ingredients = ingredients.remove(&r.id).unwrap_or_default();
+                r.into_recipe(recipe_ingredients)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(recipes)
+    }
+
+    async fn replace_ingredient(
+        &self,
+        uow: &mut UnitOfWork,
+        ingredient: &Ingredient,
+        replacement: &Ingredient,
+    ) -> Result<(), UpdateIngredientInRecipeError> {
+        let conn = uow.connection()?;
+
+        let recipe_ids = sqlx::query_file_scalar!(
+            "queries/recipes/replace_ingredient_in_recipes.sql",
+            ingredient.id,
+            replacement.id
+        )
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| match e {
+            SQLXError::Database(dberror) if dberror.is_unique_violation() => {
+                UpdateIngredientInRecipeError::AlreadyInRecipe
+            }
+            _ => UpdateIngredientInRecipeError::UnknownError(e.into()),
+        })?;
+
+        for recipe_id in &recipe_ids {
+            bump_version(conn, *recipe_id, None).await?;
+        }
+
+        sqlx::query_file!(
+            "queries/recipes/replace_ingredient_in_steps.sql",
+            ingredient.id,
+            replacement.id,
+            &recipe_ids
+        )
+        .execute(conn)
+        .await?;
 
         Ok(())
     }
 
-    async fn recipes_containing_ingredient_exist(
+    async fn trashed_recipes_containing_ingredient_exist(
         &self,
-        ingredient: Ingredient,
+        ingredient: &Ingredient,
     ) -> eyre::Result<bool> {
-        let recipes_using_ingredient = sqlx::query_file!(
-            "queries/recipes/get_recipes_using_ingredient.sql",
+        let trashed_recipes_using_ingredient = sqlx::query_file!(
+            "queries/recipes/get_trashed_recipes_using_ingredient.sql",
             ingredient.id
         )
         .fetch_optional(&self.0)
         .await?;
 
-        dbg!(&recipes_using_ingredient);
+        Ok(trashed_recipes_using_ingredient.is_some())
+    }
+
+    async fn insert_revision_in(
+        &self,
+        uow: &mut UnitOfWork,
+        revision: &RecipeRevision,
+    ) -> Result<(), RecipeRevisionError> {
+        let snapshot = serde_json::to_value(&revision.snapshot)
+            .map_err(|e| RecipeRevisionError::UnknownError(e.into()))?;
+        let changes = serde_json::to_value(&revision.changes)
+            .map_err(|e| RecipeRevisionError::UnknownError(e.into()))?;
+
+        sqlx::query_file!(
+            "queries/recipes/insert_recipe_revision.sql",
+            revision.recipe_id,
+            *revision.revision,
+            revision.author_id,
+            revision.created_at,
+            revision.restored_from.map(|v| *v),
+            snapshot,
+            changes
+        )
+        .execute(uow.connection()?)
+        .await?;
+
+        Ok(())
+    }
+
+    async fn list_revisions(
+        &self,
+        recipe_id: &Uuid,
+    ) -> Result<Vec<RecipeRevision>, RecipeRevisionError> {
+        let revisions = sqlx::query_file_as!(
+            RecipeRevisionModel,
+            "queries/recipes/list_recipe_revisions.sql",
+            recipe_id
+        )
+        .fetch_all(&self.0)
+        .await?
+        .into_iter()
+        .map(RecipeRevision::try_from)
+        .collect::<Result<_, _>>()?;
+
+        Ok(revisions)
+    }
+
+    async fn get_revision(
+        &self,
+        recipe_id: &Uuid,
+        revision: Version,
+    ) -> Result<RecipeRevision, RecipeRevisionError> {
+        let result = sqlx::query_file_as!(
+            RecipeRevisionModel,
+            "queries/recipes/get_recipe_revision.sql",
+            recipe_id,
+            *revision
+        )
+        .fetch_optional(&self.0)
+        .await?
+        .ok_or(RecipeRevisionError::NotFound(*recipe_id, revision))?;
 
-        Ok(recipes_using_ingredient.is_some())
+        Ok(result.try_into()?)
     }
 }
 
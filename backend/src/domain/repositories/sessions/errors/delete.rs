@@ -0,0 +1,25 @@
+use eyre::eyre;
+use redis::RedisError;
+use std::sync::PoisonError;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum DeleteSessionError {
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for DeleteSessionError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!(
+            "Session store lock was poisoned during a previous access and can no longer be locked"
+        )
+        .into()
+    }
+}
+
+impl From<RedisError> for DeleteSessionError {
+    fn from(value: RedisError) -> Self {
+        Self::UnknownError(value.into())
+    }
+}
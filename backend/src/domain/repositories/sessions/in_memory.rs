@@ -0,0 +1,54 @@
+use std::{collections::HashMap, sync::Mutex};
+
+use async_trait::async_trait;
+
+use crate::domain::entities::session::Session;
+
+use super::{
+    errors::{DeleteSessionError, GetSessionError, InsertSessionError},
+    SessionStore,
+};
+
+pub struct InMemorySessionStore(pub Mutex<HashMap<String, Session>>);
+
+#[async_trait]
+impl SessionStore for InMemorySessionStore {
+    #[tracing::instrument("[SESSION STORE] [IN MEMORY] Insert a new session", skip_all)]
+    async fn insert(&self, session: Session) -> Result<Session, InsertSessionError> {
+        let mut lock = self.0.lock()?;
+        lock.retain(|_, s| !s.is_expired());
+        lock.insert(session.token.clone(), session.clone());
+
+        Ok(session)
+    }
+
+    #[tracing::instrument("[SESSION STORE] [IN MEMORY] Get a session", skip_all)]
+    async fn get(&self, token: &str) -> Result<Session, GetSessionError> {
+        let lock = self.0.lock()?;
+
+        lock.get(token)
+            .filter(|s| !s.is_expired())
+            .cloned()
+            .ok_or(GetSessionError::NotFound)
+    }
+
+    #[tracing::instrument("[SESSION STORE] [IN MEMORY] Delete a session", skip_all)]
+    async fn delete(&self, token: &str) -> Result<(), DeleteSessionError> {
+        let mut lock = self.0.lock()?;
+        lock.remove(token);
+
+        Ok(())
+    }
+}
+
+impl InMemorySessionStore {
+    pub fn new() -> Self {
+        Self(HashMap::new().into())
+    }
+}
+
+impl Default for InMemorySessionStore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,21 @@
+pub mod errors;
+pub mod in_memory;
+pub mod redis;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+
+use crate::domain::entities::session::Session;
+
+use self::errors::{DeleteSessionError, GetSessionError, InsertSessionError};
+
+#[async_trait]
+pub trait SessionStore: Send + Sync + 'static {
+    async fn insert(&self, session: Session) -> Result<Session, InsertSessionError>;
+    /// Expired sessions are treated as if they didn't exist
+    async fn get(&self, token: &str) -> Result<Session, GetSessionError>;
+    async fn delete(&self, token: &str) -> Result<(), DeleteSessionError>;
+}
+
+pub type SessionStoreService = Arc<Box<dyn SessionStore>>;
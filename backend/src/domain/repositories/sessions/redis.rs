@@ -0,0 +1,66 @@
+use async_trait::async_trait;
+use redis::{aio::ConnectionManager, AsyncCommands};
+
+use crate::domain::entities::session::Session;
+
+use super::{
+    errors::{DeleteSessionError, GetSessionError, InsertSessionError},
+    SessionStore,
+};
+
+const KEY_PREFIX: &str = "session:";
+
+/// Stores sessions as JSON, letting Redis expire them on its own
+#[derive(Clone)]
+pub struct RedisSessionStore(pub ConnectionManager);
+
+fn key(token: &str) -> String {
+    format!("{KEY_PREFIX}{token}")
+}
+
+#[async_trait]
+impl SessionStore for RedisSessionStore {
+    #[tracing::instrument("[SESSION STORE] [REDIS] Insert a new session", skip_all)]
+    async fn insert(&self, session: Session) -> Result<Session, InsertSessionError> {
+        let value = serde_json::to_string(&session).map_err(eyre::Error::from)?;
+        let ttl = (session.expires_at - chrono::Utc::now())
+            .num_seconds()
+            .max(1) as u64;
+
+        self.0
+            .clone()
+            .set_ex::<_, _, ()>(key(&session.token), value, ttl)
+            .await?;
+
+        Ok(session)
+    }
+
+    #[tracing::instrument("[SESSION STORE] [REDIS] Get a session", skip_all)]
+    async fn get(&self, token: &str) -> Result<Session, GetSessionError> {
+        let value: Option<String> = self.0.clone().get(key(token)).await?;
+        let value = value.ok_or(GetSessionError::NotFound)?;
+
+        let session: Session = serde_json::from_str(&value).map_err(eyre::Error::from)?;
+        if session.is_expired() {
+            return Err(GetSessionError::NotFound);
+        }
+
+        Ok(session)
+    }
+
+    #[tracing::instrument("[SESSION STORE] [REDIS] Delete a session", skip_all)]
+    async fn delete(&self, token: &str) -> Result<(), DeleteSessionError> {
+        self.0.clone().del::<_, ()>(key(token)).await?;
+
+        Ok(())
+    }
+}
+
+impl RedisSessionStore {
+    pub async fn new(connection_string: &str) -> Result<Self, redis::RedisError> {
+        let client = redis::Client::open(connection_string)?;
+        let manager = ConnectionManager::new(client).await?;
+
+        Ok(Self(manager))
+    }
+}
@@ -0,0 +1,66 @@
+use eyre::eyre;
+use sqlx::{PgConnection, PgPool, Postgres, Transaction};
+
+/// Writes to one or more repositories that go through together or not at all.
+///
+/// It's started by `begin` on any of the repositories of the same store, and passed along to
+/// every write that's part of it. Nothing takes effect until it's committed, and dropping it
+/// without committing rolls everything back.
+pub struct UnitOfWork(Store);
+
+enum Store {
+    Postgres(Box<Transaction<'static, Postgres>>),
+    /// Undoes the writes made so far, the latest one last
+    InMemory(Vec<Box<dyn FnOnce() + Send>>),
+    Committed,
+}
+
+impl UnitOfWork {
+    pub async fn postgres(pool: &PgPool) -> eyre::Result<Self> {
+        Ok(Self(Store::Postgres(Box::new(pool.begin().await?))))
+    }
+
+    pub fn in_memory() -> Self {
+        Self(Store::InMemory(vec![]))
+    }
+
+    pub async fn commit(mut self) -> eyre::Result<()> {
+        match std::mem::replace(&mut self.0, Store::Committed) {
+            Store::Postgres(transaction) => transaction.commit().await?,
+            Store::InMemory(_) | Store::Committed => {}
+        }
+
+        Ok(())
+    }
+
+    /// The connection the Postgres repositories run their statements on
+    pub(crate) fn connection(&mut self) -> eyre::Result<&mut PgConnection> {
+        match &mut self.0 {
+            Store::Postgres(transaction) => Ok(transaction),
+            _ => Err(eyre!("The unit of work was not started on Postgres")),
+        }
+    }
+
+    /// Remembers how the in-memory repositories undo a write, should the unit of work not be
+    /// committed
+    pub(crate) fn on_rollback(&mut self, undo: impl FnOnce() + Send + 'static) -> eyre::Result<()> {
+        match &mut self.0 {
+            Store::InMemory(undo_log) => {
+                undo_log.push(Box::new(undo));
+                Ok(())
+            }
+            _ => Err(eyre!("The unit of work was not started in memory")),
+        }
+    }
+}
+
+impl Drop for UnitOfWork {
+    fn drop(&mut self) {
+        // A Postgres transaction rolls back on its own when dropped
+        if let Store::InMemory(undo_log) = &mut self.0 {
+            while let Some(undo) = undo_log.pop() {
+                undo();
+            }
+        }
+    }
+}
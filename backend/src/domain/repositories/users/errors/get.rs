@@ -0,0 +1,55 @@
+use eyre::eyre;
+use sqlx::error::Error as SQLXError;
+use std::sync::PoisonError;
+use thiserror::Error;
+use uuid::Uuid;
+
+use crate::domain::entities::user::errors::ValidationError;
+
+#[derive(Error, Debug)]
+pub enum GetUserByIdError {
+    #[error("The user with ID of {0} was not found")]
+    NotFound(Uuid),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for GetUserByIdError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!("User repository lock was poisoned during a previous access and can no longer be locked").into()
+    }
+}
+
+impl From<SQLXError> for GetUserByIdError {
+    fn from(value: SQLXError) -> Self {
+        Self::UnknownError(value.into())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum GetUserByUsernameError {
+    #[error("The user with username {0} was not found")]
+    NotFound(String),
+
+    #[error(transparent)]
+    ValidationError(#[from] ValidationError),
+
+    #[error(transparent)]
+    UnknownError(#[from] eyre::Error),
+}
+
+impl<T> From<PoisonError<T>> for GetUserByUsernameError {
+    fn from(_value: PoisonError<T>) -> Self {
+        eyre!("User repository lock was poisoned during a previous access and can no longer be locked").into()
+    }
+}
+
+impl From<SQLXError> for GetUserByUsernameError {
+    fn from(value: SQLXError) -> Self {
+        Self::UnknownError(value.into())
+    }
+}
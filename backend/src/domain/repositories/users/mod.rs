@@ -0,0 +1,21 @@
+pub mod errors;
+pub mod in_memory;
+pub mod postgres;
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use uuid::Uuid;
+
+use crate::domain::entities::user::User;
+
+use self::errors::{GetUserByIdError, GetUserByUsernameError, InsertUserError};
+
+#[async_trait]
+pub trait UserRepository: Send + Sync + 'static {
+    async fn insert(&self, user: User) -> Result<User, InsertUserError>;
+    async fn get_by_id(&self, id: &Uuid) -> Result<User, GetUserByIdError>;
+    async fn get_by_username(&self, username: &str) -> Result<User, GetUserByUsernameError>;
+}
+
+pub type UserRepositoryService = Arc<Box<dyn UserRepository>>;
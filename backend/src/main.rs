@@ -1,6 +1,9 @@
 #![cfg_attr(coverage_nightly, feature(coverage_attribute))]
 
-use backend::{api::AppBuilder, configuration::Settings, tracing::init_tracing};
+use backend::{
+    api::AppBuilder, configuration::Settings,
+    domain::repositories::sessions::redis::RedisSessionStore, tracing::init_tracing,
+};
 use color_eyre::Result;
 
 use sqlx::PgPool;
@@ -14,7 +17,13 @@ async fn main() -> Result<()> {
 
     let config = Settings::get()?;
     let db = PgPool::connect_lazy_with(config.database.with_db());
-    let app = AppBuilder::new().with_postgres_database(db).build()?;
+    let session_store =
+        RedisSessionStore::new(&config.session.get_redis_connection_string()).await?;
+    let app = AppBuilder::new()
+        .with_postgres_database(db)
+        .with_session_store(session_store)
+        .with_trash_purge(config.trash)
+        .build()?;
     let listener = config.application.get_listener().await?;
     app.serve(listener).await?;
 
@@ -1,34 +1,84 @@
-use std::{collections::BTreeMap, time::Duration};
+use std::{sync::OnceLock, time::Duration};
 
 use futures::future::join_all;
 
 use crate::domain::commands::recipes::update::UpdateRecipe;
 use crate::domain::entities::recipe::{IngredientUnit, IngredientWithAmount, ServingsType};
 use crate::domain::entities::{
-    ingredient::{types::DietFriendly, Ingredient},
+    ingredient::{
+        types::{Allergen, Allergens, DietViolation, DietViolations},
+        Ingredient,
+    },
     recipe::Recipe,
+    user::{types::PasswordHash, User},
 };
-use crate::domain::repositories::ingredients::IngredientRepository;
+use crate::domain::repositories::ingredients::{IngredientRepository, IngredientRepositoryService};
+
+pub const USER_FIXTURE_PASSWORD: &str = "correct horse battery staple";
+
+pub fn user_fixture() -> User {
+    // Hashing is deliberately slow, so the hash is only computed once
+    static PASSWORD_HASH: OnceLock<PasswordHash> = OnceLock::new();
+
+    User {
+        id: uuid::Uuid::from_u128(128),
+        username: "testuser".try_into().unwrap(),
+        email: "testuser@example.com".try_into().unwrap(),
+        password_hash: PASSWORD_HASH
+            .get_or_init(|| PasswordHash::new(USER_FIXTURE_PASSWORD).unwrap())
+            .clone(),
+    }
+}
 
 pub fn ingredient_fixture() -> Ingredient {
     Ingredient {
         id: uuid::Uuid::from_u128(64),
         name: "Beef".try_into().unwrap(),
         description: "You killed a cow for it".try_into().unwrap(),
-        diet_friendly: vec![DietFriendly::GlutenFree].into(),
+        diet_violations: vec![DietViolation::Meat].into(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: None,
+        version: Default::default(),
+    }
+}
+
+/// An ingredient without diet violations or allergens, for tests that only care about its name
+pub fn named_ingredient(id: u128, name: &str) -> Ingredient {
+    Ingredient {
+        id: uuid::Uuid::from_u128(id),
+        name: name.try_into().unwrap(),
+        description: "Ingredient description".try_into().unwrap(),
+        diet_violations: DietViolations::new(),
+        allergens: Allergens::new(),
+        density: None,
+        parent_id: None,
+        version: Default::default(),
     }
 }
 
+/// Inserts the ingredients in order and hands out the repository as a service
+pub async fn ingredient_repo_with(
+    repo: impl IngredientRepository,
+    ingredients: impl IntoIterator<Item = Ingredient>,
+) -> IngredientRepositoryService {
+    for ingredient in ingredients {
+        repo.insert(ingredient).await.unwrap();
+    }
+
+    std::sync::Arc::new(Box::new(repo))
+}
+
 pub fn recipe_fixture() -> Recipe {
     // Recipe from https://publicdomainrecipes.com/hoisin_tofu_and_broccoli/
     Recipe {
         id: uuid::Uuid::nil(),
         name: "Hoisin Tofu and Broccoli".to_string(),
         description: "If necessary, provide a very brief description of the dish in one or two sentences. For most dishes, this will be unnecessary. If there is a title image of this dish, it should be above this paragraph. You may also include prep/cook time and the number of servings as below:".to_string(),
-        time: BTreeMap::from([
-            ("Prep time".to_string(), Duration::from_secs(15 * 60)),
-            ("Cook time".to_string(), Duration::from_secs(10 * 60))
-        ]),
+        time: [
+            ("Prep time", Duration::from_secs(15 * 60)),
+            ("Cook time", Duration::from_secs(10 * 60))
+        ].into_iter().collect(),
         servings: ServingsType::Exact(4),
         ingredients: vec![
             IngredientWithAmount {
@@ -36,11 +86,11 @@ pub fn recipe_fixture() -> Recipe {
                     id: uuid::Uuid::nil(),
                     name: "Firm tofu".try_into().unwrap(),
                     description: "It's tofu".try_into().unwrap(),
-                    diet_friendly: vec![
-                        DietFriendly::Vegan,
-                        DietFriendly::Vegetarian,
-                        DietFriendly::GlutenFree
-                    ].into(),
+                    diet_violations: DietViolations::new(),
+                    allergens: vec![Allergen::Soybeans].into(),
+                    density: None,
+                    parent_id: None,
+                    version: Default::default(),
                 },
                 amount: IngredientUnit::Grams(400.0),
                 notes: None,
@@ -51,11 +101,11 @@ pub fn recipe_fixture() -> Recipe {
                     id: uuid::Uuid::from_u128(1),
                     name: "Broccoli".try_into().unwrap(),
                     description: "It's broccoli".try_into().unwrap(),
-                    diet_friendly: vec![
-                        DietFriendly::Vegan,
-                        DietFriendly::Vegetarian,
-                        DietFriendly::GlutenFree
-                    ].into(),
+                    diet_violations: DietViolations::new(),
+                    allergens: Allergens::new(),
+                    density: None,
+                    parent_id: None,
+                    version: Default::default(),
                 },
                 amount: IngredientUnit::Other{unit: "head".to_string(), amount: 1.0},
                 notes: None,
@@ -66,11 +116,11 @@ pub fn recipe_fixture() -> Recipe {
                     id: uuid::Uuid::from_u128(2),
                     name: "Garlic".try_into().unwrap(),
                     description: "Garlic description".try_into().unwrap(),
-                    diet_friendly: vec![
-                        DietFriendly::Vegan,
-                        DietFriendly::Vegetarian,
-                        DietFriendly::GlutenFree
-                    ].into(),
+                    diet_violations: DietViolations::new(),
+                    allergens: Allergens::new(),
+                    density: None,
+                    parent_id: None,
+                    version: Default::default(),
                 },
                 amount: IngredientUnit::Other{ unit: "clove".to_string(), amount: 4.0 },
                 notes: None,
@@ -88,7 +138,18 @@ pub fn recipe_fixture() -> Recipe {
             "Add 2 tbsp of oil on the same pan. Cook tofu until the bottom is golden brown, flip and repeat. Stir a few times, then move to the side of the pan.".to_string(),
             "Add more oil, garlic, ginger. Stir until it releases fragrance - generally less than a minute, then stir to combine the two.".to_string(),
             "Cook together until sause thickens, then add broccoli and stir to combine.".to_string(),
-        ].try_into().unwrap()
+        ].try_into().unwrap(),
+        author_id: None,
+        public: true,
+        version: Default::default(),
+    }
+}
+
+/// The recipe fixture, written by the user fixture
+pub fn authored_recipe_fixture() -> Recipe {
+    Recipe {
+        author_id: Some(user_fixture().id),
+        ..recipe_fixture()
     }
 }
 
@@ -96,12 +157,14 @@ pub fn recipe_changeset() -> UpdateRecipe {
     UpdateRecipe {
         name: Some("WE UPDATED THIS THING".to_string()),
         description: Some("WE UPDATED THAT THING".to_string()),
-        time: Some(BTreeMap::from([(
-            "Prep time".to_string(),
-            Duration::from_secs(60),
-        )])),
-        steps: Some(vec!["WE UPDATED ANOTHER THING".to_string()]),
+        time: Some(
+            [("Prep time", Duration::from_secs(60))]
+                .into_iter()
+                .collect(),
+        ),
+        steps: Some(vec!["WE UPDATED ANOTHER THING".to_string().into()]),
         servings: Some(ServingsType::Exact(4).into()),
+        public: None,
     }
 }
 
@@ -0,0 +1,58 @@
+use common::IngredientDTO;
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+
+use crate::setup::TestApp;
+
+#[tokio::test]
+async fn autocomplete_tolerates_accents_and_typos() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    for name in ["Jalapeño", "Cheddar cheese", "Tomato"] {
+        client
+            .post(app.get_base("ingredient/create"))
+            .json(&json!({
+                "name": name,
+                "description": "Goes into a burrito",
+                "diet_friendly": ["vegetarian"]
+            }))
+            .send()
+            .await
+            .unwrap();
+    }
+
+    for (q, expected) in [("JALAPENO", "Jalapeño"), ("chedar", "Cheddar cheese")] {
+        let response = client
+            .get(app.get_base("ingredient/autocomplete"))
+            .query(&[("q", q)])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body: Vec<IngredientDTO> = response.json().await.unwrap();
+        let names: Vec<&str> = body.iter().map(|i| i.name.as_str()).collect();
+        assert_eq!(names, vec![expected]);
+    }
+}
+
+#[tokio::test]
+async fn autocomplete_without_text_fails() {
+    let app = TestApp::new().await;
+
+    let response = Client::new()
+        .get(app.get_base("ingredient/autocomplete"))
+        .query(&[("q", " ")])
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = response
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+    assert_eq!(body.kind, "ValidationError");
+}
@@ -1,9 +1,13 @@
-use common::IngredientDTO;
+use common::error::ErrorMessage;
+use common::{BlockingRecipeDTO, DeleteIngredientErrorDTO, IngredientDTO, RecipeDTO};
 use reqwest::{Client, StatusCode};
 use uuid::Uuid;
 
 use crate::{
-    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    fixtures::{
+        ingredient::{ingredient_fixture, ingredient_fixture_meat},
+        recipe::recipe_fixture,
+    },
     setup::TestApp,
 };
 
@@ -46,7 +50,7 @@ async fn deleting_nonexistent_ingredient_errors() {
 #[tokio::test]
 async fn deleting_ingredient_in_use_by_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -64,11 +68,14 @@ async fn deleting_ingredient_in_use_by_recipe_errors() {
 
     let data = recipe_fixture(&[ingredient.clone()]);
 
-    client
+    let recipe: RecipeDTO = client
         .post(&recipe_create_path)
         .json(&data)
         .send()
         .await
+        .unwrap()
+        .json()
+        .await
         .unwrap();
 
     let ingredient_delete_path = app.get_base(&format!("ingredient/{}", ingredient.id));
@@ -76,4 +83,144 @@ async fn deleting_ingredient_in_use_by_recipe_errors() {
     let response = client.delete(ingredient_delete_path).send().await.unwrap();
 
     assert_eq!(response.status(), StatusCode::CONFLICT);
+    let body: ErrorMessage<DeleteIngredientErrorDTO> = response.json().await.unwrap();
+    assert_eq!(
+        body.error.recipes,
+        vec![BlockingRecipeDTO {
+            id: recipe.id.parse().unwrap(),
+            name: Some(recipe.name),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn deleting_ingredient_with_a_replacement_swaps_it_in_recipes() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let ingredient_create_path = app.get_base("ingredient/create");
+
+    let ingredient: IngredientDTO = client
+        .post(&ingredient_create_path)
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let replacement: IngredientDTO = client
+        .post(&ingredient_create_path)
+        .json(&ingredient_fixture_meat())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .delete(app.get_base(&format!(
+            "ingredient/{}?replace_with={}",
+            ingredient.id, replacement.id
+        )))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let stored: RecipeDTO = client
+        .get(app.get_base(&format!("recipe/{}", recipe.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let ingredient_ids: Vec<Uuid> = stored.ingredients.iter().map(|i| i.ingredient.id).collect();
+    assert_eq!(ingredient_ids, vec![replacement.id]);
+
+    let response = client
+        .get(app.get_base(&format!("ingredient/{}", ingredient.id)))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_client_error());
+}
+
+#[tokio::test]
+async fn replacing_an_ingredient_in_recipes_of_someone_else_is_refused() {
+    let app = TestApp::new().await;
+    let author = app.client_for("testuser").await;
+    let someone_else = app.client_for("someoneelse").await;
+    let ingredient_create_path = app.get_base("ingredient/create");
+
+    let mut ingredients: Vec<IngredientDTO> = vec![];
+    for body in [ingredient_fixture(), ingredient_fixture_meat()] {
+        ingredients.push(
+            author
+                .post(&ingredient_create_path)
+                .json(&body)
+                .send()
+                .await
+                .unwrap()
+                .json()
+                .await
+                .unwrap(),
+        );
+    }
+    let [ref ingredient, ref replacement] = ingredients[..] else {
+        panic!("Something went wrong with the ingredient adding step")
+    };
+    let recipe: RecipeDTO = author
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let ingredient_delete_path = app.get_base(&format!(
+        "ingredient/{}?replace_with={}",
+        ingredient.id, replacement.id
+    ));
+
+    let response = Client::new()
+        .delete(&ingredient_delete_path)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = someone_else
+        .delete(&ingredient_delete_path)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+    let body: ErrorMessage<DeleteIngredientErrorDTO> = response.json().await.unwrap();
+    assert_eq!(
+        body.error.recipes,
+        vec![BlockingRecipeDTO {
+            id: recipe.id.parse().unwrap(),
+            name: Some(recipe.name),
+        }]
+    );
+
+    let response = author
+        .get(app.get_base(&format!("ingredient/{}", ingredient.id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
 }
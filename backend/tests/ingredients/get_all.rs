@@ -1,6 +1,15 @@
-use crate::setup::TestApp;
-use backend::domain::entities::ingredient::{types::DietFriendly, IngredientModel};
-use common::IngredientDTO;
+use crate::{
+    fixtures::{
+        ingredient::{ingredient_fixture, ingredient_fixture_evil, ingredient_fixture_meat},
+        recipe::recipe_fixture,
+    },
+    setup::TestApp,
+};
+use backend::domain::entities::ingredient::{
+    types::{Allergen, DietViolation},
+    IngredientModel,
+};
+use common::{IngredientDTO, IngredientPageDTO};
 use uuid::Uuid;
 
 #[tokio::test]
@@ -13,9 +22,11 @@ async fn getting_all_with_empty_database_returns_empty_array() {
 
     assert_eq!(request.status(), 200);
 
-    let body = request.json::<Vec<()>>().await.unwrap();
+    let body = request.json::<IngredientPageDTO>().await.unwrap();
 
-    assert_eq!(body, vec![]);
+    assert_eq!(body.items, vec![]);
+    assert_eq!(body.total, 0);
+    assert_eq!(body.next_cursor, None);
 }
 
 #[tokio::test]
@@ -26,31 +37,32 @@ async fn getting_all_with_full_database_returns_in_array() {
         id: Uuid::from_u128(1),
         name: "Tomato".to_string(),
         description: "Very yummy tomato".to_string(),
-        diet_friendly: vec![
-            DietFriendly::Vegan.to_string(),
-            DietFriendly::Vegetarian.to_string(),
+        diet_violations: vec![
+            DietViolation::Dairy.to_string(),
+            DietViolation::HighCarb.to_string(),
         ],
+        allergens: vec![Allergen::Milk.to_string()],
+        density: None,
+        parent_id: None,
+        version: 1,
     }];
 
     let tx = app.db.begin().await.unwrap();
 
     for ingredient in ingredients.clone() {
-        let diet_friendly: Vec<String> = ingredient
-            .clone()
-            .diet_friendly
-            .into_iter()
-            .map(|d| d.to_string())
-            .collect();
+        let diet_violations: Vec<String> = ingredient.clone().diet_violations;
+        let allergens: Vec<String> = ingredient.clone().allergens;
 
         sqlx::query!(
             r#"
-                INSERT INTO ingredients (id, name, description, diet_friendly)
-                VALUES ($1, $2, $3, $4)
+                INSERT INTO ingredients (id, name, description, diet_violations, allergens)
+                VALUES ($1, $2, $3, $4, $5)
             "#,
             ingredient.id,
             &ingredient.name,
             &ingredient.description,
-            &diet_friendly
+            &diet_violations,
+            &allergens
         )
         .execute(&app.db)
         .await
@@ -64,8 +76,118 @@ async fn getting_all_with_full_database_returns_in_array() {
 
     assert_eq!(request.status(), 200);
 
-    let body = request.json::<Vec<IngredientDTO>>().await.unwrap();
-    let check: Vec<IngredientDTO> = ingredients.into_iter().map(|i| i.into()).collect();
+    let body = request.json::<IngredientPageDTO>().await.unwrap();
+    let check: Vec<IngredientDTO> = ingredients
+        .into_iter()
+        .map(|i| i.try_into().unwrap())
+        .collect();
 
-    assert_eq!(body, check);
+    assert_eq!(body.items, check);
+    assert_eq!(body.total, 1);
+}
+
+#[tokio::test]
+async fn paging_through_ingredients_used_in_recipes_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let mut ingredients: Vec<IngredientDTO> = vec![];
+    for body in [ingredient_fixture(), ingredient_fixture_meat()] {
+        let ingredient = client
+            .post(app.get_base("ingredient/create"))
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        ingredients.push(ingredient);
+    }
+    client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&ingredients))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+    client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture_evil())
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let first: IngredientPageDTO = client
+        .get(app.get_base("ingredient?used_in_recipes=true&limit=1"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(first.total, 2);
+    assert_eq!(first.items[0].name, "Beef");
+
+    let second: IngredientPageDTO = client
+        .get(app.get_base(&format!(
+            "ingredient?used_in_recipes=true&limit=1&cursor={}",
+            first.next_cursor.unwrap()
+        )))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(second.items[0].name, "Cucumber");
+    assert_eq!(second.next_cursor, None);
+}
+
+#[tokio::test]
+async fn paging_through_ingredients_orders_names_byte_wise() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    for name in ["Soya", "Soy sauce"] {
+        client
+            .post(app.get_base("ingredient/create"))
+            .json(&serde_json::json!({
+                "name": name,
+                "description": format!("This is {name}"),
+                "diet_friendly": [],
+            }))
+            .send()
+            .await
+            .unwrap()
+            .error_for_status()
+            .unwrap();
+    }
+
+    let first: IngredientPageDTO = client
+        .get(app.get_base("ingredient?limit=1"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(first.items[0].name, "Soy sauce");
+
+    let second: IngredientPageDTO = client
+        .get(app.get_base(&format!(
+            "ingredient?limit=1&cursor={}",
+            first.next_cursor.unwrap()
+        )))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(second.items[0].name, "Soya");
+    assert_eq!(second.next_cursor, None);
 }
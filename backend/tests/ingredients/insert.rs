@@ -34,11 +34,19 @@ async fn inserting_ingredient_succeeds() {
             "vegetarian".to_string(),
             "gluten_free".to_string(),
         ],
+        diet_violations: vec!["alcohol".to_string(), "high_carb".to_string()],
+        allergens: vec![],
+        density: None,
+        parent_id: None,
     };
 
     assert_eq!(body.name, expected_body.name);
     assert_eq!(body.description, expected_body.description);
-    assert_eq!(body.diet_friendly, expected_body.diet_friendly);
+    assert!(expected_body
+        .diet_friendly
+        .iter()
+        .all(|diet| body.diet_friendly.contains(diet)));
+    assert_eq!(body.diet_violations, expected_body.diet_violations);
 }
 
 #[tokio::test]
@@ -81,12 +89,61 @@ async fn incorrect_diets_are_ignored() {
 
     let body = request.json::<IngredientDTO>().await.unwrap();
 
+    assert_eq!(body.diet_violations, vec!["alcohol", "high_carb"]);
+    assert!(!body.diet_friendly.contains(&"I_AM_INCORRECT".to_string()));
+}
+
+#[tokio::test]
+async fn inserting_ingredient_with_diet_violations_derives_diets() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let path = app.get_base("ingredient/create");
+
+    let request = client
+        .post(&path)
+        .json(&json!({
+            "name": "Cheddar",
+            "description": "A hard cheese",
+            "diet_violations": ["dairy"]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::CREATED);
+
+    let body = request.json::<IngredientDTO>().await.unwrap();
+
+    assert_eq!(body.diet_violations, vec!["dairy".to_string()]);
     assert_eq!(
         body.diet_friendly,
         vec![
-            "vegan".to_string(),
             "vegetarian".to_string(),
-            "gluten_free".to_string()
+            "pescatarian".to_string(),
+            "halal".to_string(),
+            "kosher_style".to_string(),
+            "keto".to_string(),
+            "gluten_free".to_string(),
         ]
     );
 }
+
+#[tokio::test]
+async fn inserting_ingredient_with_unknown_allergens_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let path = app.get_base("ingredient/create");
+
+    let request = client
+        .post(&path)
+        .json(&json!({
+            "name": "Pesto",
+            "description": "Basil, pine nuts and parmesan",
+            "allergens": ["milk", "pine nuts"]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::BAD_REQUEST);
+}
@@ -1,4 +1,7 @@
+mod autocomplete;
+mod delete;
 mod get_all;
 mod insert;
-mod delete;
+mod substitutes;
+mod variants;
 // TODO: add more e2e tests for ingredients
@@ -0,0 +1,101 @@
+use common::{error::ErrorMessageWithJsonValue, IngredientDTO, IngredientSubstituteDTO};
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::setup::TestApp;
+
+async fn create_ingredient(app: &TestApp, client: &Client, name: &str) -> IngredientDTO {
+    client
+        .post(app.get_base("ingredient/create"))
+        .json(&json!({
+            "name": name,
+            "description": format!("This is {name}"),
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn setting_and_deleting_substitutes_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let egg = create_ingredient(&app, &client, "Egg").await;
+    let applesauce = create_ingredient(&app, &client, "Applesauce").await;
+    let substitute_path = app.get_base(&format!(
+        "ingredient/{}/substitute/{}",
+        egg.id, applesauce.id
+    ));
+
+    let response = client
+        .put(&substitute_path)
+        .json(&json!({ "ratio": 60.0, "unit": "g", "notes": "For baking" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Vec<IngredientSubstituteDTO> = client
+        .get(app.get_base(&format!("ingredient/{}/substitutes", egg.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        body,
+        vec![IngredientSubstituteDTO {
+            ingredient: applesauce,
+            ratio: 60.0,
+            unit: Some("g".to_string()),
+            notes: Some("For baking".to_string()),
+        }]
+    );
+
+    let response = client.delete(&substitute_path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Vec<IngredientSubstituteDTO> = response.json().await.unwrap();
+    assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn setting_substitute_with_invalid_ratio_errors() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let egg = create_ingredient(&app, &client, "Egg").await;
+    let applesauce = create_ingredient(&app, &client, "Applesauce").await;
+
+    let response = client
+        .put(app.get_base(&format!(
+            "ingredient/{}/substitute/{}",
+            egg.id, applesauce.id
+        )))
+        .json(&json!({ "ratio": -1.0 }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body: ErrorMessageWithJsonValue = response.json().await.unwrap();
+    assert_eq!(body.kind, "NotPositive");
+}
+
+#[tokio::test]
+async fn getting_substitutes_of_missing_ingredient_errors() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let response = client
+        .get(app.get_base(&format!("ingredient/{}/substitutes", Uuid::nil())))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,171 @@
+use common::{
+    error::ErrorMessageWithJsonValue, IngredientDTO, IngredientPageDTO, IngredientWithVariantsDTO,
+};
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+use uuid::Uuid;
+
+use crate::setup::TestApp;
+
+async fn create_ingredient(
+    app: &TestApp,
+    client: &Client,
+    name: &str,
+    diet_friendly: &[&str],
+) -> IngredientDTO {
+    client
+        .post(app.get_base("ingredient/create"))
+        .json(&json!({
+            "name": name,
+            "description": format!("This is {name}"),
+            "diet_friendly": diet_friendly,
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn attaching_and_detaching_variants_works() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let soy_sauce = create_ingredient(&app, &client, "Soy sauce", &["vegan"]).await;
+    let dark = create_ingredient(&app, &client, "Dark soy sauce", &["vegan"]).await;
+    let variant_path = app.get_base(&format!("ingredient/{}/variant/{}", soy_sauce.id, dark.id));
+
+    let response = client.put(&variant_path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: IngredientWithVariantsDTO = client
+        .get(app.get_base(&format!("ingredient/{}/variants", soy_sauce.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(body.ingredient.id, soy_sauce.id);
+    assert_eq!(body.variants.len(), 1);
+    assert_eq!(body.variants[0].id, dark.id);
+    assert_eq!(body.variants[0].parent_id, Some(soy_sauce.id));
+
+    let response = client.delete(&variant_path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: IngredientWithVariantsDTO = response.json().await.unwrap();
+    assert!(body.variants.is_empty());
+}
+
+#[tokio::test]
+async fn searching_can_include_variants() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let soy_sauce = create_ingredient(&app, &client, "Soy sauce", &["vegan"]).await;
+    let tamari = create_ingredient(&app, &client, "Tamari", &["vegan", "gluten_free"]).await;
+    create_ingredient(&app, &client, "Fish sauce", &["gluten_free"]).await;
+
+    client
+        .put(app.get_base(&format!(
+            "ingredient/{}/variant/{}",
+            soy_sauce.id, tamari.id
+        )))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let without_variants: IngredientPageDTO = client
+        .get(app.get_base("ingredient?name=soy"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(without_variants.items.len(), 1);
+    assert_eq!(without_variants.items[0].id, soy_sauce.id);
+
+    let mut with_variants: Vec<IngredientDTO> = client
+        .get(app.get_base("ingredient?name=soy&include_variants=true"))
+        .send()
+        .await
+        .unwrap()
+        .json::<IngredientPageDTO>()
+        .await
+        .unwrap()
+        .items;
+    with_variants.sort_by_key(|i| i.id);
+    assert_eq!(
+        with_variants.iter().map(|i| i.id).collect::<Vec<_>>(),
+        vec![soy_sauce.id, tamari.id]
+    );
+}
+
+#[tokio::test]
+async fn attaching_nested_variant_errors() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let soy_sauce = create_ingredient(&app, &client, "Soy sauce", &[]).await;
+    let dark = create_ingredient(&app, &client, "Dark soy sauce", &[]).await;
+    let mushroom = create_ingredient(&app, &client, "Mushroom dark soy sauce", &[]).await;
+
+    client
+        .put(app.get_base(&format!("ingredient/{}/variant/{}", soy_sauce.id, dark.id)))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let response = client
+        .put(app.get_base(&format!("ingredient/{}/variant/{}", dark.id, mushroom.id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body: ErrorMessageWithJsonValue = response.json().await.unwrap();
+    assert_eq!(body.kind, "ParentIsAVariant");
+}
+
+#[tokio::test]
+async fn attaching_missing_ingredient_errors() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let soy_sauce = create_ingredient(&app, &client, "Soy sauce", &[]).await;
+
+    let response = client
+        .put(app.get_base(&format!(
+            "ingredient/{}/variant/{}",
+            soy_sauce.id,
+            Uuid::nil()
+        )))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn detaching_ingredient_that_is_not_a_variant_errors() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let soy_sauce = create_ingredient(&app, &client, "Soy sauce", &[]).await;
+    let fish_sauce = create_ingredient(&app, &client, "Fish sauce", &[]).await;
+
+    let response = client
+        .delete(app.get_base(&format!(
+            "ingredient/{}/variant/{}",
+            soy_sauce.id, fish_sauce.id
+        )))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
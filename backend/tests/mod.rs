@@ -2,3 +2,5 @@ pub mod fixtures;
 mod ingredients;
 mod recipes;
 mod setup;
+mod trash;
+mod users;
@@ -0,0 +1,67 @@
+use common::{CookableRecipeDTO, IngredientDTO, RecipeDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    fixtures::{
+        ingredient::{ingredient_fixture, ingredient_fixture_meat},
+        recipe::recipe_fixture,
+    },
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn listing_cookable_recipes_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let mut ingredients: Vec<IngredientDTO> = vec![];
+    for body in [ingredient_fixture(), ingredient_fixture_meat()] {
+        let ingredient = client
+            .post(app.get_base("ingredient/create"))
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        ingredients.push(ingredient);
+    }
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&ingredients))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .post(app.get_base("recipe/cookable"))
+        .json(&serde_json::json!({ "ingredient_ids": [ingredients[0].id] }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Vec<CookableRecipeDTO> = response.json().await.unwrap();
+    assert_eq!(body.len(), 1);
+    assert_eq!(body[0].recipe.id, recipe.id);
+    assert_eq!(body[0].missing.len(), 1);
+    assert_eq!(body[0].missing[0].ingredient, ingredients[1]);
+
+    let body: Vec<CookableRecipeDTO> = client
+        .post(app.get_base("recipe/cookable"))
+        .json(&serde_json::json!({
+            "ingredient_ids": [ingredients[0].id],
+            "max_missing": 0
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(body.is_empty());
+}
@@ -0,0 +1,96 @@
+use common::{ingredients::IngredientDTO, ImportedRecipeDTO};
+use pretty_assertions::assert_eq;
+use reqwest::{header::CONTENT_TYPE, StatusCode};
+
+use crate::{fixtures::ingredient::ingredient_fixture, setup::TestApp};
+
+#[tokio::test]
+async fn importing_and_exporting_cooklang_recipe_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let cucumber: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .post(app.get_base("recipe/import/cooklang"))
+        .body(
+            ">> title: Cucumber salad
+>> servings: 2
+>> prep time: 10 minutes
+
+Slice the @cucumber{1} with a #knife{}.
+
+Dress it with @olive oil{2%tbsp} and let it rest for ~{5%minutes}.
+",
+        )
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let result: ImportedRecipeDTO = response.json().await.unwrap();
+    assert_eq!(
+        result
+            .created_ingredients
+            .iter()
+            .map(|i| i.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["olive oil"]
+    );
+    let olive_oil = result.created_ingredients[0].id;
+    assert_eq!(
+        serde_json::to_value(&result.recipe.steps).unwrap(),
+        serde_json::json!([
+            { "text": "Slice the cucumber with a knife.", "ingredients": [cucumber.id] },
+            {
+                "text": "Dress it with olive oil and let it rest for 5 minutes.",
+                "duration": 300,
+                "ingredients": [olive_oil]
+            }
+        ])
+    );
+
+    let response = client
+        .get(app.get_base(&format!("recipe/{}", result.recipe.id)))
+        .header("Accept", "text/x-cooklang")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], "text/x-cooklang");
+    assert_eq!(
+        response.text().await.unwrap(),
+        ">> title: Cucumber salad
+>> servings: 2
+>> prep time: 10 minutes
+
+Slice the @cucumber{1} with a knife.
+
+Dress it with @olive oil{2%tbsp} and let it rest for ~{5%minutes}.
+"
+    );
+}
+
+#[tokio::test]
+async fn importing_cooklang_recipe_without_title_fails() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let response = client
+        .post(app.get_base("recipe/import/cooklang"))
+        .body("Slice the @cucumber{1}.")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
@@ -2,7 +2,7 @@ use assert_json_diff::assert_json_include;
 use common::{ingredients::IngredientDTO, RecipeDTO};
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 
 use crate::{
     fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
@@ -12,7 +12,7 @@ use crate::{
 #[tokio::test]
 async fn inserts_recipe_correctly() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -48,8 +48,9 @@ async fn inserts_recipe_correctly() {
         "name": "A diced cucumber",
         "description": "Cucumber that's been diced",
         "time": {
-            "Prep time": 6000
+            "prep": 6000
         },
+        "total_time": 6000,
         "ingredients": ingredients
             .iter()
             .map(|ing| serde_json::json!({
@@ -57,7 +58,7 @@ async fn inserts_recipe_correctly() {
                 "optional": false
             }))
             .collect::<Vec<_>>(),
-        "steps": ["Get a cucumber", "Dice it"],
+        "steps": [{ "text": "Get a cucumber" }, { "text": "Dice it" }],
         "servings": {
             "exact": 1
         },
@@ -70,7 +71,7 @@ async fn inserts_recipe_correctly() {
 #[tokio::test]
 async fn inserting_recipe_with_incorrect_ingredients_fails() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let recipe_create_path = app.get_base("recipe/create");
 
     let data = serde_json::json!({
@@ -111,10 +112,71 @@ async fn inserting_recipe_with_incorrect_ingredients_fails() {
     assert_eq!(result.kind, "IngredientsNotFound");
 }
 
+#[tokio::test]
+async fn inserting_recipe_with_times_too_long_to_add_up() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let mut data = recipe_fixture(&[ingredient]);
+    data["time"] = serde_json::json!({
+        "Prep time": u64::MAX,
+        "Cook time": u64::MAX
+    });
+
+    let result = client
+        .post(app.get_base("recipe/create"))
+        .json(&data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(result.status(), StatusCode::CREATED);
+
+    let result: RecipeDTO = result.json().await.unwrap();
+    assert_eq!(result.total_time, Some(u64::MAX));
+}
+
+#[tokio::test]
+async fn inserting_recipe_with_the_same_time_twice() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let mut data = recipe_fixture(&[ingredient]);
+    data["time"] = serde_json::json!({
+        "Prep time": 600,
+        "prep": 900
+    });
+
+    let result = client
+        .post(app.get_base("recipe/create"))
+        .json(&data)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+}
+
 #[tokio::test]
 async fn inserting_recipe_with_partially_incorrect_ingredients() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let recipe_create_path = app.get_base("recipe/create");
     let ingredient_create_path = app.get_base("ingredient/create");
 
@@ -178,3 +240,173 @@ async fn inserting_recipe_with_partially_incorrect_ingredients() {
 
     assert_eq!(result.kind, "IngredientsNotFound");
 }
+
+#[tokio::test]
+async fn inserting_recipe_with_text_amounts_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut data = recipe_fixture(std::slice::from_ref(&ingredient));
+    data["ingredients"][0]["amount"] = serde_json::json!("1 1/2 cups");
+
+    let result: serde_json::Value = client
+        .post(app.get_base("recipe/create"))
+        .json(&data)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_json_include!(
+        actual: result,
+        expected: serde_json::json!({
+            "ingredients": [{ "amount": { "cups": 1.5 } }],
+        })
+    );
+}
+
+#[tokio::test]
+async fn inserting_recipe_with_unparseable_amount_fails() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut data = recipe_fixture(std::slice::from_ref(&ingredient));
+    data["ingredients"][0]["amount"] = serde_json::json!("a handful");
+
+    let result = client
+        .post(app.get_base("recipe/create"))
+        .json(&data)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let body = result
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(body.kind, "Validation");
+}
+
+#[tokio::test]
+async fn inserting_recipe_records_its_author() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let request = reqwest::Client::new()
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::UNAUTHORIZED);
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert!(recipe.author_id.is_some());
+    assert!(recipe.public);
+}
+
+#[tokio::test]
+async fn inserting_recipe_with_structured_steps_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut data = recipe_fixture(std::slice::from_ref(&ingredient));
+    data["steps"] = serde_json::json!([
+        "Get a cucumber",
+        {
+            "section": "Cutting",
+            "text": "Dice it",
+            "duration": 120,
+            "ingredients": [ingredient.id]
+        }
+    ]);
+
+    let result: serde_json::Value = client
+        .post(app.get_base("recipe/create"))
+        .json(&data)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result["steps"],
+        serde_json::json!([
+            { "text": "Get a cucumber", "ingredients": [] },
+            {
+                "section": "Cutting",
+                "text": "Dice it",
+                "duration": 120,
+                "ingredients": [ingredient.id]
+            }
+        ])
+    );
+
+    data["steps"] = serde_json::json!([{ "text": "Dice it", "ingredients": [uuid::Uuid::nil()] }]);
+
+    let result = client
+        .post(app.get_base("recipe/create"))
+        .json(&data)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+}
@@ -1,6 +1,6 @@
 use common::{IngredientDTO, RecipeDTO};
 use futures::future::join_all;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 
 use crate::{
     fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
@@ -10,7 +10,7 @@ use crate::{
 #[tokio::test]
 async fn deleting_a_recipe_works() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -51,10 +51,57 @@ async fn deleting_a_recipe_works() {
 #[tokio::test]
 async fn deleting_a_nonexistent_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
 
     let recipe_delete_path = app.get_base(&format!("recipe/{}", uuid::Uuid::nil()));
     let result = client.delete(recipe_delete_path).send().await.unwrap();
 
     assert_eq!(result.status(), StatusCode::NOT_FOUND)
 }
+
+#[tokio::test]
+async fn deleting_someone_elses_recipe_is_forbidden() {
+    let app = TestApp::new().await;
+    let author = app.client_for("author").await;
+    let stranger = app.client_for("stranger").await;
+
+    let ingredient: IngredientDTO = author
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let inserted_recipe: RecipeDTO = author
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe_path = app.get_base(&format!("recipe/{}", inserted_recipe.id));
+    let result = stranger.delete(&recipe_path).send().await.unwrap();
+
+    assert_eq!(result.status(), StatusCode::FORBIDDEN);
+
+    let result = result
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(result.kind, "Forbidden");
+
+    let result = reqwest::Client::new()
+        .delete(&recipe_path)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::UNAUTHORIZED);
+}
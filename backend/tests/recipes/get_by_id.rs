@@ -2,7 +2,7 @@ use assert_json_diff::assert_json_include;
 use common::{ingredients::IngredientDTO, RecipeDTO};
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
@@ -13,7 +13,7 @@ use crate::{
 #[tokio::test]
 async fn getting_recipe_by_id_works_correctly() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -59,8 +59,9 @@ async fn getting_recipe_by_id_works_correctly() {
         "name": "A diced cucumber",
         "description": "Cucumber that's been diced",
         "time": {
-            "Prep time": 6000
+            "prep": 6000
         },
+        "total_time": 6000,
         "ingredients": ingredients
             .iter()
             .map(|ing| serde_json::json!({
@@ -68,7 +69,7 @@ async fn getting_recipe_by_id_works_correctly() {
                 "optional": false
             }))
             .collect::<Vec<_>>(),
-        "steps": ["Get a cucumber", "Dice it"],
+        "steps": [{ "text": "Get a cucumber" }, { "text": "Dice it" }],
         "servings": {
             "exact": 1
         },
@@ -81,7 +82,7 @@ async fn getting_recipe_by_id_works_correctly() {
 #[tokio::test]
 async fn getting_nonexistent_recipe_fails() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
 
     let get_recipe_by_id_route = app.get_base(&format!("recipe/{}", Uuid::nil()));
 
@@ -96,3 +97,171 @@ async fn getting_nonexistent_recipe_fails() {
 
     assert_eq!(body.kind, "NotFound");
 }
+
+#[tokio::test]
+async fn getting_recipe_scaled_to_servings_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let created: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result: serde_json::Value = client
+        .get(app.get_base(&format!("recipe/{}?servings=3", created.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_json_include!(
+        actual: result,
+        expected: serde_json::json!({
+            "servings": { "exact": 3 },
+            "ingredients": [{ "amount": { "grams": 300.0 } }],
+        })
+    );
+}
+
+#[tokio::test]
+async fn getting_recipe_scaled_to_zero_servings_fails() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let created: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result = client
+        .get(app.get_base(&format!("recipe/{}?servings=0", created.id)))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let body = result
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(body.kind, "Scaling");
+}
+
+#[tokio::test]
+async fn getting_recipe_in_imperial_units_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let created: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let result: serde_json::Value = client
+        .get(app.get_base(&format!("recipe/{}?servings=2&units=imperial", created.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_json_include!(
+        actual: result,
+        expected: serde_json::json!({
+            "servings": { "exact": 2 },
+            "ingredients": [{ "amount": { "ounces": 7.05 } }],
+        })
+    );
+}
+
+#[tokio::test]
+async fn getting_private_recipe_only_works_for_its_author() {
+    let app = TestApp::new().await;
+    let author = app.client_for("author").await;
+    let stranger = app.client_for("stranger").await;
+
+    let ingredient: IngredientDTO = author
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut data = recipe_fixture(&[ingredient]);
+    data["public"] = false.into();
+
+    let recipe: RecipeDTO = author
+        .post(app.get_base("recipe/create"))
+        .json(&data)
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let path = app.get_base(&format!("recipe/{}", recipe.id));
+
+    let response = author.get(&path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = stranger.get(&path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = reqwest::Client::new().get(&path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
@@ -3,7 +3,7 @@ use common::{
 };
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
@@ -17,7 +17,7 @@ use crate::{
 #[tokio::test]
 async fn adding_an_ingredient_to_a_recipe_works() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -61,7 +61,7 @@ async fn adding_an_ingredient_to_a_recipe_works() {
     let ingredient_to_add = IngredientAmountDTO {
         ingredient_id: meat.id,
         optional: true,
-        amount: IngredientUnitDTO::Grams(10.0),
+        amount: IngredientUnitDTO::Grams(10.0).into(),
         notes: None,
     };
 
@@ -89,7 +89,7 @@ async fn adding_an_ingredient_to_a_recipe_works() {
 #[tokio::test]
 async fn adding_a_nonexistent_ingredient_to_a_recipe_fails() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -132,7 +132,7 @@ async fn adding_a_nonexistent_ingredient_to_a_recipe_fails() {
     let ingredient_to_add = IngredientAmountDTO {
         ingredient_id: Uuid::nil(),
         optional: true,
-        amount: IngredientUnitDTO::Grams(10.0),
+        amount: IngredientUnitDTO::Grams(10.0).into(),
         notes: None,
     };
 
@@ -149,7 +149,7 @@ async fn adding_a_nonexistent_ingredient_to_a_recipe_fails() {
 #[tokio::test]
 async fn adding_an_ingredient_to_a_nonexistent_recipe_fails() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
 
     let ingredients_to_create = [ingredient_fixture()];
@@ -177,7 +177,7 @@ async fn adding_an_ingredient_to_a_nonexistent_recipe_fails() {
     let ingredient_to_add = IngredientAmountDTO {
         ingredient_id: veg.id,
         optional: true,
-        amount: IngredientUnitDTO::Grams(10.0),
+        amount: IngredientUnitDTO::Grams(10.0).into(),
         notes: None,
     };
 
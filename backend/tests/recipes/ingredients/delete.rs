@@ -1,7 +1,7 @@
 use common::{error::ErrorMessage, IngredientDTO, RecipeDTO};
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
@@ -15,7 +15,7 @@ use crate::{
 #[tokio::test]
 pub async fn deleting_an_existing_ingredient_works() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -76,7 +76,7 @@ pub async fn deleting_an_existing_ingredient_works() {
 #[tokio::test]
 pub async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -133,7 +133,7 @@ pub async fn deleting_an_ingredient_that_doesnt_appear_in_recipe_errors() {
 #[tokio::test]
 pub async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
 
     let ingredients_to_create = [
@@ -175,7 +175,7 @@ pub async fn deleting_an_ingredient_in_recipe_that_doesnt_exist_errors() {
 #[tokio::test]
 pub async fn deleting_the_last_ingredient_in_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -1,7 +1,7 @@
 use assert_json_diff::assert_json_include;
 use common::{error::ErrorMessage, IngredientDTO, IngredientUnitDTO, RecipeDTO};
 use futures::future::join_all;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
@@ -15,7 +15,7 @@ use crate::{
 #[tokio::test]
 async fn updating_an_ingredient_in_a_recipe_works() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -83,7 +83,7 @@ async fn updating_an_ingredient_in_a_recipe_works() {
 #[tokio::test]
 async fn updating_a_nonexistent_ingredient_in_a_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -143,7 +143,7 @@ async fn updating_a_nonexistent_ingredient_in_a_recipe_errors() {
 #[tokio::test]
 async fn updating_an_ingredient_in_a_nonexistent_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
 
     let ingredient: IngredientDTO = client
@@ -0,0 +1,324 @@
+use common::{IngredientDTO, RecipeDTO, RecipePageDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn listing_recipes_paginates() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let ingredient_create_path = app.get_base("ingredient/create");
+    let recipe_create_path = app.get_base("recipe/create");
+
+    let ingredient: IngredientDTO = client
+        .post(&ingredient_create_path)
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut created: Vec<RecipeDTO> = vec![];
+    for _ in 0..3 {
+        let recipe = client
+            .post(&recipe_create_path)
+            .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        created.push(recipe);
+    }
+
+    let first_page: RecipePageDTO = client
+        .get(app.get_base("recipe?limit=2"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(first_page.total, 3);
+    assert_eq!(first_page.items.len(), 2);
+    assert_eq!(first_page.items[0].id, created[0].id);
+    assert_eq!(first_page.items[0].ingredients.len(), 1);
+
+    let cursor = first_page.next_cursor.unwrap();
+    let second_page: RecipePageDTO = client
+        .get(app.get_base(&format!("recipe?limit=2&cursor={}", cursor)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(second_page.items.len(), 1);
+    assert_eq!(second_page.items[0].id, created[2].id);
+    assert_eq!(second_page.next_cursor, None);
+
+    let descending: RecipePageDTO = client
+        .get(app.get_base("recipe?sort_by=created_at&order=desc&offset=1"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let ids: Vec<_> = descending.items.iter().map(|r| r.id.clone()).collect();
+    assert_eq!(ids, vec![created[1].id.clone(), created[0].id.clone()]);
+}
+
+#[tokio::test]
+async fn listing_recipes_with_unknown_cursor_fails() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let result = client
+        .get(app.get_base(&format!("recipe?cursor={}", Uuid::nil())))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let body = result
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(body.kind, "InvalidCursor");
+}
+
+#[tokio::test]
+async fn listing_recipes_with_unknown_diet_fails() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let result = client
+        .get(app.get_base("recipe?diet_friendly=vegan,paleo"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let body = result
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(body.kind, "ValidationError");
+}
+
+#[tokio::test]
+async fn listing_recipes_excluding_unknown_allergens_fails() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let result = client
+        .get(app.get_base("recipe?exclude_allergens=peanuts,chocolate,gluton"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+
+    let body = result
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(body.kind, "ValidationError");
+    let message = body.error.as_str().unwrap();
+    assert!(message.contains("chocolate"));
+    assert!(message.contains("gluton"));
+    assert!(!message.contains("peanuts"));
+}
+
+#[tokio::test]
+async fn listing_separates_public_recipes_from_my_own() {
+    let app = TestApp::new().await;
+    let author = app.client_for("author").await;
+    let stranger = app.client_for("stranger").await;
+
+    let ingredient: IngredientDTO = author
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let mut private_recipe = recipe_fixture(std::slice::from_ref(&ingredient));
+    private_recipe["name"] = "A secret cucumber".into();
+    private_recipe["public"] = false.into();
+
+    for recipe in [recipe_fixture(&[ingredient]), private_recipe] {
+        author
+            .post(app.get_base("recipe/create"))
+            .json(&recipe)
+            .send()
+            .await
+            .unwrap();
+    }
+
+    let public: RecipePageDTO = stranger
+        .get(app.get_base("recipe"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(public.total, 1);
+    assert_eq!(public.items[0].name, "A diced cucumber");
+
+    let mine: RecipePageDTO = author
+        .get(app.get_base("recipe?mine=true"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(mine.total, 2);
+
+    let theirs: RecipePageDTO = stranger
+        .get(app.get_base("recipe?mine=true"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(theirs.total, 0);
+
+    let response = reqwest::Client::new()
+        .get(app.get_base("recipe?mine=true"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+}
+
+#[tokio::test]
+async fn listing_recipes_excludes_allergens() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let ingredient_create_path = app.get_base("ingredient/create");
+    let recipe_create_path = app.get_base("recipe/create");
+
+    let mut ingredients: Vec<IngredientDTO> = vec![];
+    for body in [
+        ingredient_fixture(),
+        serde_json::json!({
+            "name": "Tahini",
+            "description": "Sesame paste",
+            "diet_violations": [],
+            "allergens": ["sesame"]
+        }),
+    ] {
+        let ingredient = client
+            .post(&ingredient_create_path)
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        ingredients.push(ingredient);
+    }
+
+    let mut created: Vec<RecipeDTO> = vec![];
+    for ingredients in [&ingredients[..1], &ingredients[..]] {
+        let recipe = client
+            .post(&recipe_create_path)
+            .json(&recipe_fixture(ingredients))
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        created.push(recipe);
+    }
+
+    assert!(created[0].allergens.contains.is_empty());
+    assert_eq!(created[1].allergens.contains, vec!["sesame".to_string()]);
+
+    let page: RecipePageDTO = client
+        .get(app.get_base("recipe?exclude_allergens=sesame,peanuts"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    assert_eq!(page.total, 1);
+    assert_eq!(page.items[0].id, created[0].id);
+}
+
+#[tokio::test]
+async fn listing_recipes_filters_and_sorts_by_total_time() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    for time in [
+        serde_json::json!({ "Prep time": 600, "Cook time": 1800 }),
+        serde_json::json!({ "prep": 300 }),
+        serde_json::json!({ "prep": 600, "marinate": 7200 }),
+    ] {
+        let mut data = recipe_fixture(std::slice::from_ref(&ingredient));
+        data["time"] = time;
+
+        client
+            .post(app.get_base("recipe/create"))
+            .json(&data)
+            .send()
+            .await
+            .unwrap();
+    }
+
+    let page: RecipePageDTO = client
+        .get(app.get_base("recipe?max_total_time=3600&sort_by=total_time&order=desc"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let totals: Vec<_> = page.items.iter().map(|r| r.total_time).collect();
+    assert_eq!(page.total, 2);
+    assert_eq!(totals, vec![Some(2400), Some(300)]);
+    assert_eq!(page.items[0].time.get("cook"), Some(&1800));
+}
@@ -1,5 +1,14 @@
+mod cookable;
+mod cooklang;
 mod create;
 mod delete;
 mod get_by_id;
 mod ingredients;
+mod list;
+mod revisions;
+mod schema_org;
+mod search;
+mod substitutions;
+mod transactions;
 mod update;
+mod versions;
@@ -0,0 +1,137 @@
+use common::{
+    ingredients::IngredientDTO, RecipeChangeDTO, RecipeDTO, RecipeRevisionDTO,
+    RecipeRevisionSummaryDTO,
+};
+use reqwest::{header, StatusCode};
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn recipe_changes_can_be_listed_and_rolled_back() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe_path = app.get_base(&format!("recipe/{}", recipe.id));
+
+    client
+        .put(&recipe_path)
+        .json(&serde_json::json!({ "name": "Renamed" }))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let revisions: Vec<RecipeRevisionSummaryDTO> = client
+        .get(format!("{recipe_path}/revisions"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        revisions.iter().map(|r| r.revision).collect::<Vec<_>>(),
+        vec![2, 1]
+    );
+    assert_eq!(
+        revisions[0].changes,
+        vec![RecipeChangeDTO::Name {
+            from: recipe.name.clone(),
+            to: "Renamed".to_string()
+        }]
+    );
+
+    let revision: RecipeRevisionDTO = client
+        .get(format!("{recipe_path}/revisions/1"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(revision.snapshot.name, recipe.name);
+
+    let response = client
+        .get(format!("{recipe_path}/revisions/7"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let response = client
+        .post(format!("{recipe_path}/revisions/1/restore"))
+        .header(header::IF_MATCH, "\"2\"")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::ETAG], "\"3\"");
+    let restored: RecipeDTO = response.json().await.unwrap();
+    assert_eq!(restored.name, recipe.name);
+
+    let revisions: Vec<RecipeRevisionSummaryDTO> = client
+        .get(format!("{recipe_path}/revisions"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(revisions[0].revision, 3);
+    assert_eq!(revisions[0].restored_from, Some(1));
+}
+
+#[tokio::test]
+async fn only_the_author_can_roll_back_a_recipe() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let stranger = app.client_for("stranger").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = stranger
+        .post(app.get_base(&format!("recipe/{}/revisions/1/restore", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,226 @@
+use common::{ingredients::IngredientDTO, ImportedRecipeDTO, RecipeDTO};
+use pretty_assertions::assert_eq;
+use reqwest::{header::CONTENT_TYPE, StatusCode};
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn getting_recipe_as_json_ld_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .get(app.get_base(&format!("recipe/{}", recipe.id)))
+        .header("Accept", "application/ld+json")
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[CONTENT_TYPE], "application/ld+json");
+    let result: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(
+        result,
+        serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "Recipe",
+            "name": "A diced cucumber",
+            "description": "Cucumber that's been diced",
+            "recipeYield": "1 servings",
+            "prepTime": "PT1H40M",
+            "totalTime": "PT1H40M",
+            "recipeIngredient": ["100 g Cucumber"],
+            "recipeInstructions": [
+                { "@type": "HowToStep", "text": "Get a cucumber" },
+                { "@type": "HowToStep", "text": "Dice it" }
+            ]
+        })
+    );
+}
+
+#[tokio::test]
+async fn importing_json_ld_recipe_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let cucumber: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .post(app.get_base("recipe/import"))
+        .json(&serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "Recipe",
+            "name": "Cucumber salad",
+            "recipeYield": "2-3 servings",
+            "prepTime": "PT10M",
+            "recipeIngredient": ["1 cucumber, sliced", "2 tbsp olive oil"],
+            "recipeInstructions": [
+                { "@type": "HowToStep", "text": "Slice the cucumber" },
+                { "@type": "HowToStep", "text": "Dress it with the oil" }
+            ]
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let result: ImportedRecipeDTO = response.json().await.unwrap();
+    assert_eq!(
+        result
+            .created_ingredients
+            .iter()
+            .map(|i| i.name.as_str())
+            .collect::<Vec<_>>(),
+        vec!["olive oil"]
+    );
+    assert_eq!(
+        result
+            .recipe
+            .ingredients
+            .iter()
+            .map(|i| i.ingredient.id)
+            .collect::<Vec<_>>(),
+        vec![cucumber.id, result.created_ingredients[0].id]
+    );
+    assert_eq!(
+        result.recipe.ingredients[0].notes.as_deref(),
+        Some("sliced")
+    );
+    // Imported recipes stay private unless asked otherwise
+    assert!(!result.recipe.public);
+}
+
+#[tokio::test]
+async fn importing_document_without_recipe_fails() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let response = client
+        .post(app.get_base("recipe/import"))
+        .json(&serde_json::json!({ "@type": "WebPage", "name": "Some page" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn drafting_recipe_from_uploaded_html_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let cucumber: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let html = r#"<html><body>
+        <div itemscope itemtype="https://schema.org/Recipe">
+            <h1 itemprop="name">Cucumber salad</h1>
+            <span itemprop="recipeYield">2 servings</span>
+            <ul>
+                <li itemprop="recipeIngredient">1 cucumber, sliced</li>
+                <li itemprop="recipeIngredient">2 tbsp olive oil</li>
+            </ul>
+            <p itemprop="recipeInstructions">Slice the cucumber and dress it</p>
+        </div>
+    </body></html>"#;
+
+    let response = client
+        .post(app.get_base("recipe/import/html?public=true"))
+        .multipart(
+            reqwest::multipart::Form::new().part(
+                "file",
+                reqwest::multipart::Part::text(html)
+                    .file_name("salad.html")
+                    .mime_str("text/html")
+                    .unwrap(),
+            ),
+        )
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::OK);
+    let result: serde_json::Value = response.json().await.unwrap();
+    assert_eq!(result["recipe"]["name"], "Cucumber salad");
+    assert_eq!(result["recipe"]["public"], true);
+    assert_eq!(
+        result["recipe"]["ingredients"],
+        serde_json::json!([{
+            "ingredient_id": cucumber.id,
+            "amount": { "other": { "amount": 1.0, "unit": "" } },
+            "optional": false,
+            "notes": "sliced"
+        }])
+    );
+    assert_eq!(
+        result["unresolved_ingredients"],
+        serde_json::json!([{
+            "name": "olive oil",
+            "amount": { "tablespoons": 2.0 },
+            "optional": false,
+            "notes": null
+        }])
+    );
+
+    let recipes: serde_json::Value = client
+        .get(app.get_base("recipe"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(recipes["items"], serde_json::json!([]));
+}
+
+#[tokio::test]
+async fn drafting_recipe_without_uploaded_file_fails() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let response = client
+        .post(app.get_base("recipe/import/html"))
+        .multipart(reqwest::multipart::Form::new().text("other", "<html></html>"))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
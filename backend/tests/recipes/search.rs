@@ -0,0 +1,70 @@
+use common::{IngredientDTO, RecipeDTO, RecipeSearchResultDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn searching_recipes_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .get(app.get_base("recipe/search?q=cucumber%20dice"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: Vec<RecipeSearchResultDTO> = response.json().await.unwrap();
+    assert_eq!(body.len(), 1);
+    assert_eq!(body[0].recipe.id, recipe.id);
+    assert!(body[0].snippet.contains("<mark>diced</mark>"));
+
+    let body: Vec<RecipeSearchResultDTO> = client
+        .get(app.get_base("recipe/search?q=tomato"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(body.is_empty());
+}
+
+#[tokio::test]
+async fn searching_recipes_without_words_fails() {
+    let app = TestApp::new().await;
+
+    let response = reqwest::get(app.get_base("recipe/search?q=%20%3F"))
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let body = response
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+    assert_eq!(body.kind, "ValidationError");
+}
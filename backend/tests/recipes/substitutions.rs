@@ -0,0 +1,108 @@
+use common::{IngredientDTO, IngredientUnitDTO, RecipeDTO, RecipeSubstitutionsDTO};
+use reqwest::StatusCode;
+use uuid::Uuid;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture_meat, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn proposing_substitutions_works() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let ingredient_create_path = app.get_base("ingredient/create");
+
+    let beef: IngredientDTO = client
+        .post(&ingredient_create_path)
+        .json(&ingredient_fixture_meat())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let seitan: IngredientDTO = client
+        .post(&ingredient_create_path)
+        .json(&serde_json::json!({
+            "name": "Seitan",
+            "description": "Wheat gluten",
+            "diet_friendly": ["vegan", "vegetarian"]
+        }))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    client
+        .put(app.get_base(&format!("ingredient/{}/substitute/{}", beef.id, seitan.id)))
+        .json(&serde_json::json!({ "ratio": 1.5 }))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&beef)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = client
+        .get(app.get_base(&format!(
+            "recipe/{}/substitutions?diet_friendly=vegan",
+            recipe.id
+        )))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let body: RecipeSubstitutionsDTO = response.json().await.unwrap();
+    assert!(body.satisfiable);
+    assert_eq!(body.substitutions.len(), 1);
+    assert_eq!(body.substitutions[0].ingredient.ingredient, beef);
+
+    let substitutes = &body.substitutions[0].substitutes;
+    assert_eq!(substitutes.len(), 1);
+    assert_eq!(substitutes[0].substitute.ingredient, seitan);
+    assert_eq!(substitutes[0].amount, IngredientUnitDTO::Grams(150.0));
+
+    // Seitan is made of wheat, so it doesn't help with a gluten free diet
+    let body: RecipeSubstitutionsDTO = client
+        .get(app.get_base(&format!(
+            "recipe/{}/substitutions?diet_friendly=vegan,gluten_free",
+            recipe.id
+        )))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(!body.satisfiable);
+    assert!(body.substitutions[0].substitutes.is_empty());
+}
+
+#[tokio::test]
+async fn proposing_substitutions_for_missing_recipe_errors() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let response = client
+        .get(app.get_base(&format!(
+            "recipe/{}/substitutions?diet_friendly=vegan",
+            Uuid::nil()
+        )))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
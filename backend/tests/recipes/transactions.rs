@@ -0,0 +1,344 @@
+use common::{IngredientAmountDTO, IngredientDTO, IngredientUnitDTO, RecipeDTO};
+use pretty_assertions::assert_eq;
+use reqwest::Client;
+
+use crate::{
+    fixtures::{
+        ingredient::{ingredient_fixture, ingredient_fixture_evil, ingredient_fixture_meat},
+        recipe::recipe_fixture,
+    },
+    setup::TestApp,
+};
+
+async fn create_ingredients(app: &TestApp, client: &Client) -> Vec<IngredientDTO> {
+    let mut ingredients = vec![];
+    for body in [ingredient_fixture(), ingredient_fixture_meat()] {
+        let ingredient: IngredientDTO = client
+            .post(app.get_base("ingredient/create"))
+            .json(&body)
+            .send()
+            .await
+            .unwrap()
+            .json()
+            .await
+            .unwrap();
+        ingredients.push(ingredient);
+    }
+    ingredients
+}
+
+async fn create_recipe(app: &TestApp, client: &Client, ingredients: &[IngredientDTO]) -> RecipeDTO {
+    client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(ingredients))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+async fn get_recipe(app: &TestApp, client: &Client, recipe: &RecipeDTO) -> RecipeDTO {
+    client
+        .get(app.get_base(&format!("recipe/{}", recipe.id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn failing_to_insert_an_ingredient_leaves_no_recipe_behind() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let ingredients = create_ingredients(&app, &client).await;
+
+    // The first ingredient goes in, the second one fails
+    app.fail_writes(
+        "INSERT",
+        "ingredients_recipes",
+        &format!("NEW.ingredient_id = '{}'", ingredients[1].id),
+    )
+    .await;
+
+    let response = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&ingredients))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_server_error());
+
+    let recipes = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM recipes"#)
+        .fetch_one(&app.db)
+        .await
+        .unwrap();
+    let recipe_ingredients =
+        sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM ingredients_recipes"#)
+            .fetch_one(&app.db)
+            .await
+            .unwrap();
+    assert_eq!(recipes, 0);
+    assert_eq!(recipe_ingredients, 0);
+}
+
+#[tokio::test]
+async fn failing_to_record_the_first_revision_leaves_no_recipe_behind() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let ingredients = create_ingredients(&app, &client).await;
+
+    app.fail_writes("INSERT", "recipe_revisions", "true").await;
+
+    let response = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&ingredients))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_server_error());
+
+    let recipes = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM recipes"#)
+        .fetch_one(&app.db)
+        .await
+        .unwrap();
+    assert_eq!(recipes, 0);
+}
+
+#[tokio::test]
+async fn failing_to_delete_a_replaced_ingredient_leaves_its_recipes_alone() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let ingredients = create_ingredients(&app, &client).await;
+    let recipe = create_recipe(&app, &client, &ingredients).await;
+    let replacement: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture_evil())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    // The recipes get the replacement first, then the ingredient fails to go to the trash
+    app.fail_writes("UPDATE", "ingredients", "NEW.deleted_at IS NOT NULL")
+        .await;
+
+    let response = client
+        .delete(app.get_base(&format!(
+            "ingredient/{}?replace_with={}",
+            ingredients[0].id, replacement.id
+        )))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_server_error());
+
+    let stored = get_recipe(&app, &client, &recipe).await;
+    assert_eq!(
+        stored
+            .ingredients
+            .iter()
+            .map(|i| i.ingredient.id)
+            .collect::<Vec<_>>(),
+        vec![ingredients[0].id, ingredients[1].id]
+    );
+
+    let revisions = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM recipe_revisions"#)
+        .fetch_one(&app.db)
+        .await
+        .unwrap();
+    assert_eq!(revisions, 1);
+}
+
+#[tokio::test]
+async fn failing_to_import_a_recipe_leaves_no_ingredients_behind() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    // The ingredients get created first, then the recipe fails
+    app.fail_writes("INSERT", "recipes", "true").await;
+
+    let response = client
+        .post(app.get_base("recipe/import"))
+        .json(&serde_json::json!({
+            "@context": "https://schema.org",
+            "@type": "Recipe",
+            "name": "Cucumber salad",
+            "recipeIngredient": ["1 cucumber, sliced", "2 tbsp olive oil"],
+            "recipeInstructions": [{ "@type": "HowToStep", "text": "Slice the cucumber" }]
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_server_error());
+
+    let ingredients = sqlx::query_scalar!(r#"SELECT COUNT(*) AS "count!" FROM ingredients"#)
+        .fetch_one(&app.db)
+        .await
+        .unwrap();
+    assert_eq!(ingredients, 0);
+}
+
+#[tokio::test]
+async fn failing_halfway_through_an_update_changes_nothing() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let ingredients = create_ingredients(&app, &client).await;
+    let recipe = create_recipe(&app, &client, &ingredients).await;
+
+    // The name gets updated first, then the description fails
+    app.fail_writes(
+        "UPDATE",
+        "recipes",
+        "NEW.description IS DISTINCT FROM OLD.description",
+    )
+    .await;
+
+    let response = client
+        .put(app.get_base(&format!("recipe/{}", recipe.id)))
+        .json(&serde_json::json!({
+            "name": "A renamed cucumber",
+            "description": "This never gets saved"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_server_error());
+
+    let stored = get_recipe(&app, &client, &recipe).await;
+    assert_eq!(stored.name, recipe.name);
+    assert_eq!(stored.description, recipe.description);
+}
+
+#[tokio::test]
+async fn failing_to_purge_a_recipe_keeps_its_ingredients() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let ingredients = create_ingredients(&app, &client).await;
+    let recipe = create_recipe(&app, &client, &ingredients).await;
+    let trash_path = app.get_base(&format!("trash/recipe/{}", recipe.id));
+
+    client
+        .delete(app.get_base(&format!("recipe/{}", recipe.id)))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    // The ingredients get deleted first, then the recipe fails
+    app.fail_writes("DELETE", "recipes", "true").await;
+
+    let response = client.delete(&trash_path).send().await.unwrap();
+    assert!(response.status().is_server_error());
+
+    client
+        .post(format!("{trash_path}/restore"))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let stored = get_recipe(&app, &client, &recipe).await;
+    assert_eq!(stored.ingredients.len(), 2);
+}
+
+#[tokio::test]
+async fn failing_halfway_through_an_ingredient_update_changes_nothing() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let ingredients = create_ingredients(&app, &client).await;
+
+    // The name gets updated first, then the description fails
+    app.fail_writes(
+        "UPDATE",
+        "ingredients",
+        "NEW.description IS DISTINCT FROM OLD.description",
+    )
+    .await;
+
+    let response = client
+        .put(app.get_base(&format!("ingredient/{}", ingredients[0].id)))
+        .json(&serde_json::json!({
+            "name": "Pickle",
+            "description": "This never gets saved"
+        }))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_server_error());
+
+    let stored: IngredientDTO = client
+        .get(app.get_base(&format!("ingredient/{}", ingredients[0].id)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(stored, ingredients[0]);
+}
+
+#[tokio::test]
+async fn failing_to_record_a_revision_leaves_the_recipe_unchanged() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let ingredients = create_ingredients(&app, &client).await;
+    let recipe = create_recipe(&app, &client, &ingredients[..1]).await;
+    let recipe_path = app.get_base(&format!("recipe/{}", recipe.id));
+
+    let response = client
+        .put(&recipe_path)
+        .json(&serde_json::json!({ "name": "A renamed cucumber" }))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+
+    app.fail_writes("INSERT", "recipe_revisions", "true").await;
+
+    let response = client
+        .put(&recipe_path)
+        .json(&serde_json::json!({ "name": "This never gets saved" }))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_server_error());
+
+    let response = client
+        .post(format!("{recipe_path}/ingredient"))
+        .json(&IngredientAmountDTO {
+            ingredient_id: ingredients[1].id,
+            optional: false,
+            amount: IngredientUnitDTO::Grams(10.0).into(),
+            notes: None,
+        })
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_server_error());
+
+    let response = client
+        .post(format!("{recipe_path}/revisions/1/restore"))
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_server_error());
+
+    let stored = get_recipe(&app, &client, &recipe).await;
+    assert_eq!(stored.name, "A renamed cucumber");
+    assert_eq!(
+        stored
+            .ingredients
+            .iter()
+            .map(|i| i.ingredient.id)
+            .collect::<Vec<_>>(),
+        vec![ingredients[0].id]
+    );
+}
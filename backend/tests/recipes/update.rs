@@ -2,7 +2,7 @@ use assert_json_diff::assert_json_include;
 use common::{ingredients::IngredientDTO, RecipeDTO};
 use futures::future::join_all;
 use pretty_assertions::assert_eq;
-use reqwest::{Client, StatusCode};
+use reqwest::StatusCode;
 use uuid::Uuid;
 
 use crate::{
@@ -13,7 +13,7 @@ use crate::{
 #[tokio::test]
 async fn updates_recipe_correctly() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -75,9 +75,10 @@ async fn updates_recipe_correctly() {
         "name": "WE UPDATED THIS THING",
         "description": "WE UPDATED THAT THING",
         "time": {
-            "Prep time": 9000,
-            "Cook time": 3000
+            "prep": 9000,
+            "cook": 3000
         },
+        "total_time": 12000,
         "ingredients": ingredients
             .iter()
             .map(|ing| serde_json::json!({
@@ -85,7 +86,7 @@ async fn updates_recipe_correctly() {
                 "optional": false
             }))
             .collect::<Vec<_>>(),
-        "steps": ["WE UPDATED ANOTHER THING"],
+        "steps": [{ "text": "WE UPDATED ANOTHER THING" }],
         "servings": {
             "from_to": [3, 4]
         },
@@ -97,7 +98,7 @@ async fn updates_recipe_correctly() {
 #[tokio::test]
 async fn updating_nonexistent_recipe_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let changeset = serde_json::json!({
         "name": "WE NEED THIS TO FAIL",
     });
@@ -117,7 +118,7 @@ async fn updating_nonexistent_recipe_errors() {
 #[tokio::test]
 async fn updating_empty_changeset_errors() {
     let app = TestApp::new().await;
-    let client = Client::new();
+    let client = app.client_for("testuser").await;
     let ingredient_create_path = app.get_base("ingredient/create");
     let recipe_create_path = app.get_base("recipe/create");
 
@@ -162,3 +163,149 @@ async fn updating_empty_changeset_errors() {
 
     assert_eq!(result.status(), StatusCode::BAD_REQUEST);
 }
+
+#[tokio::test]
+async fn updating_with_the_same_time_twice_errors() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let result: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let changeset = serde_json::json!({
+        "time": { "Cook time": 600, "cooking": 900 }
+    });
+
+    let result = client
+        .put(app.get_base(&format!("recipe/{}", &result.id)))
+        .json(&changeset)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(result.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn updating_someone_elses_recipe_is_forbidden() {
+    let app = TestApp::new().await;
+    let author = app.client_for("author").await;
+    let stranger = app.client_for("stranger").await;
+
+    let ingredient: IngredientDTO = author
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = author
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let response = stranger
+        .put(app.get_base(&format!("recipe/{}", recipe.id)))
+        .json(&serde_json::json!({ "name": "Stolen cucumber" }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = response
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(response.kind, "Forbidden");
+}
+
+#[tokio::test]
+async fn recipes_without_an_author_can_be_claimed_once() {
+    let app = TestApp::new().await;
+    let author = app.client_for("author").await;
+    let claimant = app.client_for("claimant").await;
+    let latecomer = app.client_for("latecomer").await;
+
+    let ingredient: IngredientDTO = author
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    let recipe: RecipeDTO = author
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    // Like the recipes created before user accounts existed
+    sqlx::query!(
+        "UPDATE recipes SET author_id = NULL WHERE id = $1",
+        recipe.id.parse::<Uuid>().unwrap()
+    )
+    .execute(&app.db)
+    .await
+    .unwrap();
+
+    let claimed: RecipeDTO = claimant
+        .post(app.get_base(&format!("recipe/{}/claim", recipe.id)))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_ne!(claimed.author_id, recipe.author_id);
+
+    let response = latecomer
+        .post(app.get_base(&format!("recipe/{}/claim", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    claimant
+        .put(app.get_base(&format!("recipe/{}", recipe.id)))
+        .json(&serde_json::json!({ "name": "Claimed cucumber" }))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+}
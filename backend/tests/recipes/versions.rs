@@ -0,0 +1,278 @@
+use common::{ingredients::IngredientDTO, IngredientAmountDTO, IngredientUnitDTO, RecipeDTO};
+use reqwest::{header, StatusCode};
+
+use crate::{
+    fixtures::{
+        ingredient::{ingredient_fixture, ingredient_fixture_meat},
+        recipe::recipe_fixture,
+    },
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn recipe_updates_honour_if_match() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let response = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.headers()[header::ETAG], "\"1\"");
+    let recipe: RecipeDTO = response.json().await.unwrap();
+    let recipe_path = app.get_base(&format!("recipe/{}", recipe.id));
+
+    let response = client.get(&recipe_path).send().await.unwrap();
+    assert_eq!(response.headers()[header::ETAG], "\"1\"");
+
+    let changeset = serde_json::json!({ "name": "Renamed" });
+    let response = client
+        .put(&recipe_path)
+        .header(header::IF_MATCH, "\"1\"")
+        .json(&changeset)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::ETAG], "\"2\"");
+
+    let response = client
+        .put(&recipe_path)
+        .header(header::IF_MATCH, "\"1\"")
+        .json(&changeset)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+    let response = client
+        .delete(&recipe_path)
+        .header(header::IF_MATCH, "\"1\"")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+    let response = client
+        .delete(&recipe_path)
+        .header(header::IF_MATCH, "\"1\", \"2\"")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn derived_recipe_representations_get_weak_etags() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe_path = app.get_base(&format!("recipe/{}", recipe.id));
+
+    for query in ["?servings=3", "?units=imperial"] {
+        let response = client
+            .get(format!("{recipe_path}{query}"))
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.headers()[header::ETAG], "W/\"1\"");
+    }
+    for accept in ["application/ld+json", "text/x-cooklang"] {
+        let response = client
+            .get(&recipe_path)
+            .header(header::ACCEPT, accept)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.headers()[header::ETAG], "W/\"1\"");
+    }
+
+    let response = client
+        .put(&recipe_path)
+        .header(header::IF_MATCH, "W/\"1\"")
+        .json(&serde_json::json!({ "name": "Renamed" }))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+}
+
+#[tokio::test]
+async fn ingredient_updates_honour_if_match() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let ingredient_path = app.get_base(&format!("ingredient/{}", ingredient.id));
+
+    let changeset = serde_json::json!({ "name": "Pickle" });
+    let response = client
+        .put(&ingredient_path)
+        .header(header::IF_MATCH, "\"2\"")
+        .json(&changeset)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+    let response = client
+        .put(&ingredient_path)
+        .header(header::IF_MATCH, "*")
+        .json(&changeset)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::ETAG], "\"2\"");
+
+    let response = client.get(&ingredient_path).send().await.unwrap();
+    assert_eq!(response.headers()[header::ETAG], "\"2\"");
+
+    let response = client
+        .put(&ingredient_path)
+        .header(header::IF_MATCH, "not an etag")
+        .json(&changeset)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn recipe_ingredient_changes_honour_if_match() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let mut ingredients: Vec<IngredientDTO> = vec![];
+    for fixture in [ingredient_fixture(), ingredient_fixture_meat()] {
+        ingredients.push(
+            client
+                .post(app.get_base("ingredient/create"))
+                .json(&fixture)
+                .send()
+                .await
+                .unwrap()
+                .json()
+                .await
+                .unwrap(),
+        );
+    }
+    let [ref veg, ref meat] = ingredients[..] else {
+        panic!("Something went wrong with the ingredient adding step")
+    };
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(veg)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let ingredients_path = app.get_base(&format!("recipe/{}/ingredient", recipe.id));
+    let meat_path = app.get_base(&format!("recipe/{}/ingredient/{}", recipe.id, meat.id));
+
+    let meat_amount = IngredientAmountDTO {
+        ingredient_id: meat.id,
+        optional: false,
+        amount: IngredientUnitDTO::Grams(10.0).into(),
+        notes: None,
+    };
+    let response = client
+        .post(&ingredients_path)
+        .header(header::IF_MATCH, "\"2\"")
+        .json(&meat_amount)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+    let response = client
+        .post(&ingredients_path)
+        .header(header::IF_MATCH, "\"1\"")
+        .json(&meat_amount)
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::ETAG], "\"2\"");
+
+    let response = client
+        .put(&meat_path)
+        .header(header::IF_MATCH, "\"1\"")
+        .json(&IngredientUnitDTO::Grams(20.0))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+    let response = client
+        .put(&meat_path)
+        .header(header::IF_MATCH, "\"2\"")
+        .json(&IngredientUnitDTO::Grams(20.0))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    assert_eq!(response.headers()[header::ETAG], "\"3\"");
+
+    let response = client
+        .delete(&meat_path)
+        .header(header::IF_MATCH, "\"2\"")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::PRECONDITION_FAILED);
+
+    let response = client
+        .delete(&meat_path)
+        .header(header::IF_MATCH, "\"3\"")
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .get(app.get_base(&format!("recipe/{}", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.headers()[header::ETAG], "\"4\"");
+}
@@ -0,0 +1,164 @@
+use common::{IngredientDTO, IngredientPageDTO, TrashDTO};
+use reqwest::{Client, StatusCode};
+
+use crate::{fixtures::ingredient::ingredient_fixture, setup::TestApp};
+
+#[tokio::test]
+async fn deleted_ingredients_can_be_restored_and_purged() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let ingredient_path = app.get_base(&format!("ingredient/{}", ingredient.id));
+    let trash_path = app.get_base(&format!("trash/ingredient/{}", ingredient.id));
+
+    client
+        .delete(&ingredient_path)
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let response = client.get(&ingredient_path).send().await.unwrap();
+    assert!(response.status().is_client_error());
+
+    let page: IngredientPageDTO = client
+        .get(app.get_base("ingredient"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(page.total, 0);
+
+    let restored: IngredientDTO = client
+        .post(format!("{trash_path}/restore"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(restored, ingredient);
+
+    client
+        .delete(&ingredient_path)
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let response = client.delete(&trash_path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client.delete(&trash_path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn deleted_ingredients_free_up_their_name() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    client
+        .delete(app.get_base(&format!("ingredient/{}", ingredient.id)))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let response = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success());
+
+    let response = client
+        .post(app.get_base(&format!("trash/ingredient/{}/restore", ingredient.id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let trash: TrashDTO = client
+        .get(app.get_base("trash"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        trash
+            .ingredients
+            .iter()
+            .map(|t| t.ingredient.id)
+            .collect::<Vec<_>>(),
+        vec![ingredient.id]
+    );
+}
+
+#[tokio::test]
+async fn anonymous_users_cannot_restore_or_purge_ingredients() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    client
+        .delete(app.get_base(&format!("ingredient/{}", ingredient.id)))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+    let trash_path = app.get_base(&format!("trash/ingredient/{}", ingredient.id));
+
+    let anonymous = Client::new();
+    let response = anonymous
+        .post(format!("{trash_path}/restore"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = anonymous.delete(&trash_path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+
+    let response = client
+        .post(format!("{trash_path}/restore"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
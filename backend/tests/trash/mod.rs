@@ -0,0 +1,2 @@
+mod ingredients;
+mod recipes;
@@ -0,0 +1,190 @@
+use common::{IngredientDTO, RecipeDTO, TrashDTO};
+use reqwest::StatusCode;
+
+use crate::{
+    fixtures::{ingredient::ingredient_fixture, recipe::recipe_fixture},
+    setup::TestApp,
+};
+
+#[tokio::test]
+async fn deleted_recipes_can_be_restored_and_purged() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+    let stranger = app.client_for("stranger").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(&[ingredient]))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe_path = app.get_base(&format!("recipe/{}", recipe.id));
+    let trash_path = app.get_base(&format!("trash/recipe/{}", recipe.id));
+
+    client
+        .delete(&recipe_path)
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let response = client.get(&recipe_path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let trash: TrashDTO = client
+        .get(app.get_base("trash"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(
+        trash
+            .recipes
+            .iter()
+            .map(|t| &t.recipe.id)
+            .collect::<Vec<_>>(),
+        vec![&recipe.id]
+    );
+
+    let trash: TrashDTO = stranger
+        .get(app.get_base("trash"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(trash.recipes.is_empty());
+
+    let response = stranger
+        .post(format!("{trash_path}/restore"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let restored: RecipeDTO = client
+        .post(format!("{trash_path}/restore"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert_eq!(restored.id, recipe.id);
+
+    let response = client.get(&recipe_path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    client
+        .delete(&recipe_path)
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let response = client.delete(&trash_path).send().await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = client
+        .post(format!("{trash_path}/restore"))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+    let trash: TrashDTO = client
+        .get(app.get_base("trash"))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    assert!(trash.recipes.is_empty());
+}
+
+#[tokio::test]
+async fn recipes_cannot_be_restored_while_their_ingredients_are_deleted() {
+    let app = TestApp::new().await;
+    let client = app.client_for("testuser").await;
+
+    let ingredient: IngredientDTO = client
+        .post(app.get_base("ingredient/create"))
+        .json(&ingredient_fixture())
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+    let recipe: RecipeDTO = client
+        .post(app.get_base("recipe/create"))
+        .json(&recipe_fixture(std::slice::from_ref(&ingredient)))
+        .send()
+        .await
+        .unwrap()
+        .json()
+        .await
+        .unwrap();
+
+    client
+        .delete(app.get_base(&format!("recipe/{}", recipe.id)))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+    client
+        .delete(app.get_base(&format!("ingredient/{}", ingredient.id)))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let response = client
+        .post(app.get_base(&format!("trash/recipe/{}/restore", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = client
+        .delete(app.get_base(&format!("trash/ingredient/{}", ingredient.id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    client
+        .post(app.get_base(&format!("trash/ingredient/{}/restore", ingredient.id)))
+        .send()
+        .await
+        .unwrap()
+        .error_for_status()
+        .unwrap();
+
+    let response = client
+        .post(app.get_base(&format!("trash/recipe/{}/restore", recipe.id)))
+        .send()
+        .await
+        .unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+}
@@ -0,0 +1,124 @@
+use common::user::UserDataDTO;
+use reqwest::{header, Client, StatusCode};
+use serde_json::json;
+
+use crate::setup::TestApp;
+
+async fn sign_up(app: &TestApp, client: &Client) {
+    client
+        .post(app.get_base("user/signup"))
+        .json(&json!({
+            "username": "iamatestuser2",
+            "email": "testuser2@example.com",
+            "password": "meaningless"
+        }))
+        .send()
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn logging_in_without_account_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let request = client
+        .post(app.get_base("user/login"))
+        .json(&json!({
+            "username": "idontexistyet",
+            "password": "meaningless"
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::NOT_FOUND);
+
+    let result = request
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(result.error, "Could not find person");
+}
+
+#[tokio::test]
+async fn logging_in_with_wrong_password_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    sign_up(&app, &client).await;
+
+    let request = client
+        .post(app.get_base("user/login"))
+        .json(&json!({
+            "username": "iamatestuser2",
+            "password": "meaningful"
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::BAD_REQUEST);
+
+    let result = request
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(result.error, "Wrong password");
+}
+
+#[tokio::test]
+async fn logging_in_sets_session_cookie() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    sign_up(&app, &client).await;
+
+    let request = client
+        .post(app.get_base("user/login"))
+        .json(&json!({
+            "username": "iamatestuser2",
+            "password": "meaningless"
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::OK);
+
+    let cookie = request
+        .headers()
+        .get(header::SET_COOKIE)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .to_string();
+
+    assert!(cookie.starts_with("session="));
+    assert!(cookie.contains("HttpOnly"));
+
+    let session = cookie.split(';').next().unwrap().to_string();
+
+    let request = client
+        .get(app.get_base("user/me"))
+        .header(header::COOKIE, session)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::OK);
+
+    let body = request.json::<UserDataDTO>().await.unwrap();
+
+    assert_eq!(body.username, "iamatestuser2");
+}
+
+#[tokio::test]
+async fn getting_current_user_without_session_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let request = client.get(app.get_base("user/me")).send().await.unwrap();
+
+    assert_eq!(request.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,58 @@
+use reqwest::{header, Client, StatusCode};
+use serde_json::json;
+
+use crate::setup::TestApp;
+
+#[tokio::test]
+async fn logging_out_ends_the_session() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let credentials = json!({
+        "username": "iamatestuser3",
+        "email": "testuser3@example.com",
+        "password": "meaningless"
+    });
+
+    client
+        .post(app.get_base("user/signup"))
+        .json(&credentials)
+        .send()
+        .await
+        .unwrap();
+
+    let request = client
+        .post(app.get_base("user/login"))
+        .json(&credentials)
+        .send()
+        .await
+        .unwrap();
+
+    let session = request
+        .headers()
+        .get(header::SET_COOKIE)
+        .unwrap()
+        .to_str()
+        .unwrap()
+        .split(';')
+        .next()
+        .unwrap()
+        .to_string();
+
+    let request = client
+        .post(app.get_base("user/logout"))
+        .header(header::COOKIE, &session)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::NO_CONTENT);
+
+    let request = client
+        .get(app.get_base("user/me"))
+        .header(header::COOKIE, &session)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::UNAUTHORIZED);
+}
@@ -0,0 +1,82 @@
+use common::user::UserDataDTO;
+use reqwest::{Client, StatusCode};
+use serde_json::json;
+
+use crate::setup::TestApp;
+
+#[tokio::test]
+async fn signing_up_succeeds() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let request = client
+        .post(app.get_base("user/signup"))
+        .json(&json!({
+            "username": "iamatestuser1",
+            "email": "testuser1@example.com",
+            "password": "meaningless"
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::OK);
+
+    let body = request.json::<UserDataDTO>().await.unwrap();
+
+    assert_eq!(body.username, "iamatestuser1");
+    assert_eq!(body.email, "testuser1@example.com");
+}
+
+#[tokio::test]
+async fn signing_up_with_taken_username_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+    let body = json!({
+        "username": "iamatestuser1",
+        "email": "testuser1@example.com",
+        "password": "meaningless"
+    });
+
+    client
+        .post(app.get_base("user/signup"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    let request = client
+        .post(app.get_base("user/signup"))
+        .json(&body)
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::CONFLICT);
+
+    let result = request
+        .json::<common::error::ErrorMessageWithJsonValue>()
+        .await
+        .unwrap();
+
+    assert_eq!(result.kind, "Conflict");
+}
+
+#[tokio::test]
+async fn signing_up_with_invalid_email_fails() {
+    let app = TestApp::new().await;
+    let client = Client::new();
+
+    let request = client
+        .post(app.get_base("user/signup"))
+        .json(&json!({
+            "username": "iamatestuser1",
+            "email": "not an email",
+            "password": "meaningless"
+        }))
+        .send()
+        .await
+        .unwrap();
+
+    assert_eq!(request.status(), StatusCode::BAD_REQUEST);
+}
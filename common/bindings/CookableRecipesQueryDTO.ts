@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface CookableRecipesQueryDTO { ingredient_ids: Array<string>, max_missing: bigint | null, limit: bigint | null, }
\ No newline at end of file
@@ -1,5 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { IngredientAmountDTO } from "./IngredientAmountDTO";
+import type { RecipeStepInputDTO } from "./RecipeStepInputDTO";
 import type { ServingsTypeDTO } from "./ServingsTypeDTO";
 
-export interface CreateRecipeDTO { name: string, description: string, steps: Array<string>, time: Record<string, bigint>, ingredients: Array<IngredientAmountDTO>, servings: ServingsTypeDTO, }
\ No newline at end of file
+export interface CreateRecipeDTO { name: string, description: string, steps: Array<RecipeStepInputDTO>, time: Record<string, bigint>, ingredients: Array<IngredientAmountDTO>, servings: ServingsTypeDTO, public: boolean | null, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { BlockingRecipeDTO } from "./BlockingRecipeDTO";
+
+export interface DeleteIngredientErrorDTO { message: string, recipes: Array<BlockingRecipeDTO>, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { MeasurementSystemDTO } from "./MeasurementSystemDTO";
+
+export interface GetRecipeQueryDTO { servings: number | null, units: MeasurementSystemDTO | null, }
\ No newline at end of file
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientDTO } from "./IngredientDTO";
+import type { RecipeDTO } from "./RecipeDTO";
+
+export interface ImportedRecipeDTO { recipe: RecipeDTO, created_ingredients: Array<IngredientDTO>, }
\ No newline at end of file
@@ -1,4 +1,4 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
-import type { IngredientUnitDTO } from "./IngredientUnitDTO";
+import type { IngredientUnitInputDTO } from "./IngredientUnitInputDTO";
 
-export interface IngredientAmountDTO { ingredient_id: string, amount: IngredientUnitDTO, optional: boolean, notes: string | null, }
\ No newline at end of file
+export interface IngredientAmountDTO { ingredient_id: string, amount: IngredientUnitInputDTO, optional: boolean, notes: string | null, }
\ No newline at end of file
@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export interface IngredientListQueryDTO { offset: bigint | null, limit: bigint | null, cursor: string | null, name: string | null, name_prefix: string | null, diet_friendly: string | null, used_in_recipes: boolean | null, include_variants: boolean | null, }
\ No newline at end of file
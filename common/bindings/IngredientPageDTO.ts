@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientDTO } from "./IngredientDTO";
+
+export interface IngredientPageDTO { items: Array<IngredientDTO>, total: bigint, limit: bigint, next_cursor: string | null, }
\ No newline at end of file
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type IngredientUnitDTO = { "mililiters": number } | { "grams": number } | { "teaspoons": number } | { "cups": number } | { "other": { amount: number, unit: string, } };
\ No newline at end of file
+export type IngredientUnitDTO = { "mililiters": number } | { "liters": number } | { "teaspoons": number } | { "tablespoons": number } | { "cups": number } | { "fluid_ounces": number } | { "grams": number } | { "kilograms": number } | { "ounces": number } | { "pounds": number } | { "other": { amount: number, unit: string, } };
\ No newline at end of file
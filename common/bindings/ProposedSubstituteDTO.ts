@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientSubstituteDTO } from "./IngredientSubstituteDTO";
+import type { IngredientUnitDTO } from "./IngredientUnitDTO";
+
+export interface ProposedSubstituteDTO { substitute: IngredientSubstituteDTO, amount: IngredientUnitDTO, }
\ No newline at end of file
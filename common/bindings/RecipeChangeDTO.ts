@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecipeRevisionIngredientDTO } from "./RecipeRevisionIngredientDTO";
+import type { RecipeStepDTO } from "./RecipeStepDTO";
+import type { ServingsTypeDTO } from "./ServingsTypeDTO";
+
+export type RecipeChangeDTO = { "field": "name", from: string, to: string, } | { "field": "description", from: string, to: string, } | { "field": "steps", from: Array<RecipeStepDTO>, to: Array<RecipeStepDTO>, } | { "field": "time", from: Record<string, bigint>, to: Record<string, bigint>, } | { "field": "servings", from: ServingsTypeDTO, to: ServingsTypeDTO, } | { "field": "public", from: boolean, to: boolean, } | { "field": "ingredient_added", ingredient: RecipeRevisionIngredientDTO, } | { "field": "ingredient_removed", ingredient: RecipeRevisionIngredientDTO, } | { "field": "ingredient_changed", from: RecipeRevisionIngredientDTO, to: RecipeRevisionIngredientDTO, };
\ No newline at end of file
@@ -1,5 +1,7 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { IngredientWithAmountDTO } from "./IngredientWithAmountDTO";
+import type { RecipeAllergensDTO } from "./RecipeAllergensDTO";
+import type { RecipeStepDTO } from "./RecipeStepDTO";
 import type { ServingsTypeDTO } from "./ServingsTypeDTO";
 
-export interface RecipeDTO { id: string, name: string, description: string, steps: Array<string>, time: Record<string, bigint>, ingredients: Array<IngredientWithAmountDTO>, servings: ServingsTypeDTO, }
\ No newline at end of file
+export interface RecipeDTO { id: string, name: string, description: string, steps: Array<RecipeStepDTO>, time: Record<string, bigint>, total_time: bigint | null, ingredients: Array<IngredientWithAmountDTO>, servings: ServingsTypeDTO, diet_friendly: Array<string>, allergens: RecipeAllergensDTO, author_id: string | null, public: boolean, }
\ No newline at end of file
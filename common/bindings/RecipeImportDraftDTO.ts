@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { CreateRecipeDTO } from "./CreateRecipeDTO";
+import type { UnresolvedIngredientDTO } from "./UnresolvedIngredientDTO";
+
+export interface RecipeImportDraftDTO { recipe: CreateRecipeDTO, unresolved_ingredients: Array<UnresolvedIngredientDTO>, }
\ No newline at end of file
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecipeSortByDTO } from "./RecipeSortByDTO";
+import type { SortOrderDTO } from "./SortOrderDTO";
+
+export interface RecipeListQueryDTO { offset: bigint | null, limit: bigint | null, cursor: string | null, sort_by: RecipeSortByDTO | null, order: SortOrderDTO | null, name: string | null, diet_friendly: string | null, include_variants: boolean | null, exclude_allergens: string | null, max_total_time: bigint | null, mine: boolean | null, }
\ No newline at end of file
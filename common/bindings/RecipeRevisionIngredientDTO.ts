@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientUnitDTO } from "./IngredientUnitDTO";
+
+export interface RecipeRevisionIngredientDTO { ingredient_id: string, name: string, amount: IngredientUnitDTO, notes: string | null, optional: boolean, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecipeChangeDTO } from "./RecipeChangeDTO";
+
+export interface RecipeRevisionSummaryDTO { revision: number, author_id: string | null, created_at: string, restored_from: number | null, changes: Array<RecipeChangeDTO>, }
\ No newline at end of file
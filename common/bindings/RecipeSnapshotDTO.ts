@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecipeRevisionIngredientDTO } from "./RecipeRevisionIngredientDTO";
+import type { RecipeStepDTO } from "./RecipeStepDTO";
+import type { ServingsTypeDTO } from "./ServingsTypeDTO";
+
+export interface RecipeSnapshotDTO { name: string, description: string, steps: Array<RecipeStepDTO>, time: Record<string, bigint>, servings: ServingsTypeDTO, public: boolean, ingredients: Array<RecipeRevisionIngredientDTO>, }
\ No newline at end of file
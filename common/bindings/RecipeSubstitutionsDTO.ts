@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SubstitutionProposalDTO } from "./SubstitutionProposalDTO";
+
+export interface RecipeSubstitutionsDTO { satisfiable: boolean, substitutions: Array<SubstitutionProposalDTO>, }
\ No newline at end of file
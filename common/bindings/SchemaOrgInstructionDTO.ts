@@ -0,0 +1,3 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type SchemaOrgInstructionDTO = { "@type": string, name: string, itemListElement: Array<SchemaOrgInstructionDTO>, } | { "@type": string, name?: string, text: string, } | string;
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SchemaOrgInstructionDTO } from "./SchemaOrgInstructionDTO";
+
+export type SchemaOrgInstructionsDTO = Array<SchemaOrgInstructionDTO> | string;
\ No newline at end of file
@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { SchemaOrgInstructionsDTO } from "./SchemaOrgInstructionsDTO";
+import type { SchemaOrgTypeDTO } from "./SchemaOrgTypeDTO";
+import type { SchemaOrgYieldDTO } from "./SchemaOrgYieldDTO";
+
+export interface SchemaOrgRecipeDTO { "@context"?: string, "@type": SchemaOrgTypeDTO, "@id"?: string, name: string, description?: string, recipeYield?: SchemaOrgYieldDTO, prepTime?: string, cookTime?: string, totalTime?: string, recipeIngredient: Array<string>, recipeInstructions: SchemaOrgInstructionsDTO, }
\ No newline at end of file
@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientWithAmountDTO } from "./IngredientWithAmountDTO";
+import type { ProposedSubstituteDTO } from "./ProposedSubstituteDTO";
+
+export interface SubstitutionProposalDTO { ingredient: IngredientWithAmountDTO, substitutes: Array<ProposedSubstituteDTO>, }
\ No newline at end of file
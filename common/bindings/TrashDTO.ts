@@ -0,0 +1,5 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { TrashedIngredientDTO } from "./TrashedIngredientDTO";
+import type { TrashedRecipeDTO } from "./TrashedRecipeDTO";
+
+export interface TrashDTO { recipes: Array<TrashedRecipeDTO>, ingredients: Array<TrashedIngredientDTO>, }
\ No newline at end of file
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { IngredientUnitDTO } from "./IngredientUnitDTO";
+
+export interface UnresolvedIngredientDTO { name: string, amount: IngredientUnitDTO, optional: boolean, notes: string | null, }
\ No newline at end of file
@@ -1,3 +1,3 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export interface UpdateIngredientDTO { name?: string, description?: string, diet_friendly?: Array<string>, }
\ No newline at end of file
+export interface UpdateIngredientDTO { name?: string, description?: string, diet_friendly?: Array<string>, diet_violations?: Array<string>, allergens?: Array<string>, density?: number, }
\ No newline at end of file
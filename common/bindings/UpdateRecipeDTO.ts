@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RecipeStepInputDTO } from "./RecipeStepInputDTO";
 import type { ServingsTypeDTO } from "./ServingsTypeDTO";
 
-export interface UpdateRecipeDTO { name: string | null, description: string | null, steps: Array<string> | null, time: Record<string, bigint> | null, servings: ServingsTypeDTO | null, }
\ No newline at end of file
+export interface UpdateRecipeDTO { name: string | null, description: string | null, steps: Array<RecipeStepInputDTO> | null, time: Record<string, bigint> | null, servings: ServingsTypeDTO | null, public: boolean | null, }
\ No newline at end of file
@@ -8,17 +8,121 @@ use uuid::Uuid;
 pub struct CreateIngredientDTO {
     pub name: String,
     pub description: String,
+    /// Diets the ingredient fits, only used to infer `diet_violations` when those are missing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diet_friendly: Option<Vec<String>>,
+    /// What the ingredient contains that some diets don't allow, i.e. `meat`, `dairy` or `gluten`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diet_violations: Option<Vec<String>>,
+    /// Allergens the ingredient contains, i.e. `milk`, `peanuts` or `sesame`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allergens: Option<Vec<String>>,
+    /// Grams per mililiter, used to convert between volume and mass
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub density: Option<f64>,
 }
 
-#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Eq, Clone)]
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Clone)]
 #[ts(export)]
 pub struct IngredientDTO {
     pub id: Uuid,
     pub name: String,
     pub description: String,
+    /// Diets the ingredient fits, derived from its violations
     pub diet_friendly: Vec<String>,
+    pub diet_violations: Vec<String>,
+    pub allergens: Vec<String>,
+    pub density: Option<f64>,
+    /// The ingredient this one is a variant of
+    pub parent_id: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
+#[ts(export)]
+pub struct IngredientWithVariantsDTO {
+    pub ingredient: IngredientDTO,
+    pub variants: Vec<IngredientDTO>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Clone)]
+#[ts(export)]
+pub struct IngredientSubstituteDTO {
+    pub ingredient: IngredientDTO,
+    /// Amount of the substitute to use per one unit of the original ingredient
+    pub ratio: f64,
+    /// Unit of the substitute's amount, the original ingredient's unit if there is none
+    pub unit: Option<String>,
+    pub notes: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
+#[ts(export)]
+pub struct SetIngredientSubstituteDTO {
+    /// Amount of the substitute to use per one unit of the original ingredient
+    pub ratio: f64,
+    /// Unit of the substitute's amount, i.e. `g`, the original ingredient's unit if there is none
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unit: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, ToSchema, TS)]
+#[ts(export)]
+pub struct IngredientListQueryDTO {
+    pub offset: Option<u64>,
+    pub limit: Option<u64>,
+    pub cursor: Option<Uuid>,
+    pub name: Option<String>,
+    /// Case-insensitive beginning of the ingredient name
+    pub name_prefix: Option<String>,
+    /// Comma-separated list of diets, i.e. `vegan,gluten_free`
+    pub diet_friendly: Option<String>,
+    /// Only return ingredients used in at least one recipe
+    pub used_in_recipes: Option<bool>,
+    /// Also return the variants of every ingredient that matched the filters
+    pub include_variants: Option<bool>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
+#[ts(export)]
+pub struct IngredientPageDTO {
+    pub items: Vec<IngredientDTO>,
+    pub total: u64,
+    pub limit: u64,
+    pub next_cursor: Option<Uuid>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, ToSchema, TS)]
+#[ts(export)]
+pub struct IngredientAutocompleteQueryDTO {
+    /// What was typed so far, matched regardless of case, accents and small typos
+    pub q: String,
+    pub limit: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Default, ToSchema, TS)]
+#[ts(export)]
+pub struct DeleteIngredientQueryDTO {
+    /// Swap the ingredient for this one in every recipe using it, instead of refusing to delete it
+    pub replace_with: Option<Uuid>,
+}
+
+/// A recipe standing in the way of deleting an ingredient
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS, PartialEq, Clone)]
+#[ts(export)]
+pub struct BlockingRecipeDTO {
+    pub id: Uuid,
+    /// Missing for private recipes
+    pub name: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
+#[ts(export)]
+pub struct DeleteIngredientErrorDTO {
+    pub message: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub recipes: Vec<BlockingRecipeDTO>,
 }
 
 #[derive(Serialize, Deserialize, Debug, ToSchema, TS)]
@@ -37,6 +141,15 @@ pub struct UpdateIngredientDTO {
     pub name: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    /// Diets the ingredient fits, only used to infer `diet_violations` when those are missing
     #[serde(skip_serializing_if = "Option::is_none")]
     pub diet_friendly: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub diet_violations: Option<Vec<String>>,
+    /// Allergens the ingredient contains, i.e. `milk`, `peanuts` or `sesame`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allergens: Option<Vec<String>>,
+    /// Grams per mililiter, used to convert between volume and mass
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub density: Option<f64>,
 }
@@ -1,7 +1,11 @@
 pub mod error;
 pub mod ingredients;
 pub mod recipes;
+pub mod schema_org;
+pub mod trash;
 pub mod user;
 
 pub use ingredients::*;
 pub use recipes::*;
+pub use schema_org::*;
+pub use trash::*;
@@ -1,20 +1,24 @@
 use std::collections::HashMap;
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use ts_rs::TS;
 use uuid::Uuid;
 
-use crate::IngredientDTO;
+use crate::{IngredientDTO, IngredientSubstituteDTO};
 
 #[derive(Deserialize, Serialize, Debug, TS)]
 #[ts(export)]
 pub struct CreateRecipeDTO {
     pub name: String,
     pub description: String,
-    pub steps: Vec<String>,
+    pub steps: Vec<RecipeStepInputDTO>,
+    /// Seconds by label, `prep`, `cook`, `rest`, `marinate` and `total` are well known, anything else is a custom label
     pub time: HashMap<String, u64>,
     pub ingredients: Vec<IngredientAmountDTO>,
     pub servings: ServingsTypeDTO,
+    /// Whether other people can see the recipe, defaults to true
+    pub public: Option<bool>,
 }
 
 #[derive(Deserialize, Serialize, TS, Debug)]
@@ -23,10 +27,56 @@ pub struct RecipeDTO {
     pub id: String,
     pub name: String,
     pub description: String,
-    pub steps: Vec<String>,
+    pub steps: Vec<RecipeStepDTO>,
+    /// Seconds by label, `prep`, `cook`, `rest`, `marinate` and `total` are well known, anything else is a custom label
     pub time: HashMap<String, u64>,
+    /// Seconds, the given `total` or the sum of all the other times
+    pub total_time: Option<u64>,
     pub ingredients: Vec<IngredientWithAmountDTO>,
     pub servings: ServingsTypeDTO,
+    pub diet_friendly: Vec<String>,
+    pub allergens: RecipeAllergensDTO,
+    pub author_id: Option<Uuid>,
+    pub public: bool,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RecipeStepDTO {
+    /// Heading of the section the step belongs to, i.e. "For the sauce"
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub section: Option<String>,
+    pub text: String,
+    /// How many seconds the step takes, for running a timer
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub duration: Option<u64>,
+    /// IDs of the recipe's ingredients used in the step
+    #[serde(default)]
+    pub ingredients: Vec<Uuid>,
+}
+
+/// A step given either in a structured form, or as plain text
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(untagged)]
+#[ts(export)]
+pub enum RecipeStepInputDTO {
+    Structured(RecipeStepDTO),
+    Text(String),
+}
+
+impl From<String> for RecipeStepInputDTO {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct RecipeAllergensDTO {
+    /// Allergens of the ingredients the recipe can't be made without
+    pub contains: Vec<String>,
+    /// Allergens that only come from optional ingredients, so leaving those out avoids them
+    pub optional: Vec<String>,
 }
 
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
@@ -41,7 +91,7 @@ pub enum ServingsTypeDTO {
 #[ts(export)]
 pub struct IngredientAmountDTO {
     pub ingredient_id: Uuid,
-    pub amount: IngredientUnitDTO,
+    pub amount: IngredientUnitInputDTO,
     pub optional: bool,
     pub notes: Option<String>,
 }
@@ -60,18 +110,264 @@ pub struct IngredientWithAmountDTO {
 #[ts(export)]
 pub enum IngredientUnitDTO {
     Mililiters(f64),
-    Grams(f64),
+    Liters(f64),
     Teaspoons(f64),
+    Tablespoons(f64),
     Cups(f64),
+    FluidOunces(f64),
+    Grams(f64),
+    Kilograms(f64),
+    Ounces(f64),
+    Pounds(f64),
     Other { amount: f64, unit: String },
 }
 
+/// An amount given either in a structured form, or as text typed by a human, i.e. "1 1/2 cups"
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(untagged)]
+#[ts(export)]
+pub enum IngredientUnitInputDTO {
+    Structured(IngredientUnitDTO),
+    Text(String),
+}
+
+impl From<IngredientUnitDTO> for IngredientUnitInputDTO {
+    fn from(value: IngredientUnitDTO) -> Self {
+        Self::Structured(value)
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize, TS)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum MeasurementSystemDTO {
+    Metric,
+    Imperial,
+}
+
 #[derive(Deserialize, Serialize, TS, Debug)]
 #[ts(export)]
 pub struct UpdateRecipeDTO {
     pub name: Option<String>,
     pub description: Option<String>,
-    pub steps: Option<Vec<String>>,
+    pub steps: Option<Vec<RecipeStepInputDTO>>,
+    /// Seconds by label, `prep`, `cook`, `rest`, `marinate` and `total` are well known, anything else is a custom label
     pub time: Option<HashMap<String, u64>>,
     pub servings: Option<ServingsTypeDTO>,
+    pub public: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum RecipeSortByDTO {
+    Name,
+    #[default]
+    CreatedAt,
+    TotalTime,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+#[ts(export)]
+pub enum SortOrderDTO {
+    #[default]
+    Asc,
+    Desc,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Default)]
+#[ts(export)]
+pub struct GetRecipeQueryDTO {
+    /// Scale the recipe's ingredients to this number of servings
+    pub servings: Option<u16>,
+    /// Convert the recipe's ingredient amounts to units of this system
+    pub units: Option<MeasurementSystemDTO>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Default)]
+#[ts(export)]
+pub struct RecipeListQueryDTO {
+    pub offset: Option<u64>,
+    pub limit: Option<u64>,
+    pub cursor: Option<Uuid>,
+    pub sort_by: Option<RecipeSortByDTO>,
+    pub order: Option<SortOrderDTO>,
+    pub name: Option<String>,
+    /// Comma-separated list of diets, i.e. `vegan,gluten_free`
+    pub diet_friendly: Option<String>,
+    /// Let recipes fit the diets through variants of their ingredients, i.e. tamari for soy sauce
+    pub include_variants: Option<bool>,
+    /// Comma-separated list of allergens none of the recipes may contain, i.e. `peanuts,milk`
+    pub exclude_allergens: Option<String>,
+    /// Longest total time in seconds, recipes without any time are left out
+    pub max_total_time: Option<u64>,
+    /// List the logged in user's recipes, including private ones, instead of public recipes
+    pub mine: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct RecipePageDTO {
+    pub items: Vec<RecipeDTO>,
+    pub total: u64,
+    pub limit: u64,
+    pub next_cursor: Option<Uuid>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct RecipeSearchQueryDTO {
+    /// Words to look for in recipe names, descriptions, steps and ingredient names.
+    /// Every word has to match, and matches the beginning of longer words too.
+    pub q: String,
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct RecipeSearchResultDTO {
+    pub recipe: RecipeDTO,
+    /// Higher is better, only comparable between results of the same search
+    pub rank: f64,
+    /// A fragment of the description and steps as escaped HTML, with the matched words wrapped
+    /// in `<mark>` tags
+    pub snippet: String,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct CookableRecipesQueryDTO {
+    /// IDs of the ingredients at hand
+    pub ingredient_ids: Vec<Uuid>,
+    /// How many required ingredients a recipe may be missing, 2 by default
+    pub max_missing: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct CookableRecipeDTO {
+    pub recipe: RecipeDTO,
+    /// Required ingredients that aren't at hand, empty if the recipe can be cooked right away
+    pub missing: Vec<IngredientWithAmountDTO>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct RecipeSubstitutionsQueryDTO {
+    /// Comma-separated list of diets the recipe should satisfy, i.e. `vegan,gluten_free`
+    pub diet_friendly: String,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct ProposedSubstituteDTO {
+    pub substitute: IngredientSubstituteDTO,
+    /// The recipe's amount of the original ingredient, multiplied by the substitute's ratio
+    pub amount: IngredientUnitDTO,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct SubstitutionProposalDTO {
+    pub ingredient: IngredientWithAmountDTO,
+    pub substitutes: Vec<ProposedSubstituteDTO>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct RecipeSubstitutionsDTO {
+    /// Whether the recipe satisfies the diets once every required ingredient gets substituted
+    pub satisfiable: bool,
+    pub substitutions: Vec<SubstitutionProposalDTO>,
+}
+
+/// An ingredient of a recipe as it was at the time of a revision
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct RecipeRevisionIngredientDTO {
+    pub ingredient_id: Uuid,
+    /// The name the ingredient had back then
+    pub name: String,
+    pub amount: IngredientUnitDTO,
+    pub notes: Option<String>,
+    pub optional: bool,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[ts(export)]
+pub struct RecipeSnapshotDTO {
+    pub name: String,
+    pub description: String,
+    pub steps: Vec<RecipeStepDTO>,
+    pub time: HashMap<String, u64>,
+    pub servings: ServingsTypeDTO,
+    pub public: bool,
+    pub ingredients: Vec<RecipeRevisionIngredientDTO>,
+}
+
+/// A single change made in a revision, tagged with the name of the changed field
+#[derive(Deserialize, Serialize, TS, Debug, Clone, PartialEq)]
+#[serde(tag = "field", rename_all = "snake_case")]
+#[ts(export)]
+pub enum RecipeChangeDTO {
+    Name {
+        from: String,
+        to: String,
+    },
+    Description {
+        from: String,
+        to: String,
+    },
+    Steps {
+        from: Vec<RecipeStepDTO>,
+        to: Vec<RecipeStepDTO>,
+    },
+    Time {
+        from: HashMap<String, u64>,
+        to: HashMap<String, u64>,
+    },
+    Servings {
+        from: ServingsTypeDTO,
+        to: ServingsTypeDTO,
+    },
+    Public {
+        from: bool,
+        to: bool,
+    },
+    IngredientAdded {
+        ingredient: RecipeRevisionIngredientDTO,
+    },
+    IngredientRemoved {
+        ingredient: RecipeRevisionIngredientDTO,
+    },
+    IngredientChanged {
+        from: RecipeRevisionIngredientDTO,
+        to: RecipeRevisionIngredientDTO,
+    },
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct RecipeRevisionSummaryDTO {
+    /// The version of the recipe the revision resulted in, 1 being the recipe as it was created
+    pub revision: i32,
+    pub author_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    /// Set if the revision rolled the recipe back to an earlier one
+    pub restored_from: Option<i32>,
+    pub changes: Vec<RecipeChangeDTO>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct RecipeRevisionDTO {
+    pub revision: i32,
+    pub author_id: Option<Uuid>,
+    pub created_at: DateTime<Utc>,
+    pub restored_from: Option<i32>,
+    pub changes: Vec<RecipeChangeDTO>,
+    /// The recipe right after the revision
+    pub snapshot: RecipeSnapshotDTO,
 }
@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::{CreateRecipeDTO, IngredientDTO, IngredientUnitDTO, RecipeDTO};
+
+/// A schema.org `Recipe`, as embedded in web pages as JSON-LD.
+/// Only the properties that map onto a recipe are kept, everything else is ignored.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(rename_all = "camelCase")]
+#[ts(export)]
+pub struct SchemaOrgRecipeDTO {
+    /// Left out by recipes nested in a larger document
+    #[serde(rename = "@context", default, skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    #[serde(rename = "@type")]
+    pub kind: SchemaOrgTypeDTO,
+    #[serde(rename = "@id", default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub recipe_yield: Option<SchemaOrgYieldDTO>,
+    /// ISO 8601 duration, i.e. `PT15M`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prep_time: Option<String>,
+    /// ISO 8601 duration, i.e. `PT1H30M`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cook_time: Option<String>,
+    /// ISO 8601 duration, i.e. `PT1H45M`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub total_time: Option<String>,
+    /// Ingredients as text, i.e. "1 1/2 cups flour, sifted"
+    #[serde(default)]
+    pub recipe_ingredient: Vec<String>,
+    #[serde(default)]
+    pub recipe_instructions: SchemaOrgInstructionsDTO,
+}
+
+/// `@type` can hold several types at once, i.e. `["Recipe", "NewsArticle"]`
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(untagged)]
+#[ts(export)]
+pub enum SchemaOrgTypeDTO {
+    One(String),
+    Many(Vec<String>),
+}
+
+impl SchemaOrgTypeDTO {
+    pub fn is(&self, kind: &str) -> bool {
+        match self {
+            Self::One(one) => one == kind,
+            Self::Many(many) => many.iter().any(|one| one == kind),
+        }
+    }
+}
+
+/// Pages give the yield as a number, as text like "4 servings", or as a list of both
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(untagged)]
+#[ts(export)]
+pub enum SchemaOrgYieldDTO {
+    Number(f64),
+    Text(String),
+    Many(Vec<SchemaOrgYieldDTO>),
+}
+
+/// Instructions are either a list, or a single block of text
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(untagged)]
+#[ts(export)]
+pub enum SchemaOrgInstructionsDTO {
+    Many(Vec<SchemaOrgInstructionDTO>),
+    Text(String),
+}
+
+impl Default for SchemaOrgInstructionsDTO {
+    fn default() -> Self {
+        Self::Many(vec![])
+    }
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[serde(untagged)]
+#[ts(export)]
+pub enum SchemaOrgInstructionDTO {
+    /// A `HowToSection`, grouping steps under a heading
+    Section {
+        #[serde(rename = "@type", default)]
+        kind: String,
+        name: String,
+        #[serde(rename = "itemListElement")]
+        item_list_element: Vec<SchemaOrgInstructionDTO>,
+    },
+    /// A `HowToStep`
+    Step {
+        #[serde(rename = "@type", default)]
+        kind: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        text: String,
+    },
+    Text(String),
+}
+
+#[derive(Deserialize, Serialize, TS, Debug, Default)]
+#[ts(export)]
+pub struct ImportRecipeQueryDTO {
+    /// Whether everyone gets to see the imported recipe, rather than only its author
+    pub public: Option<bool>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct ImportedRecipeDTO {
+    pub recipe: RecipeDTO,
+    /// Ingredients of the recipe that didn't exist yet, and were created along with it
+    pub created_ingredients: Vec<IngredientDTO>,
+}
+
+/// A recipe read from an uploaded page, for the user to check before creating it
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct RecipeImportDraftDTO {
+    /// Only lists the ingredients that matched existing ones by name
+    pub recipe: CreateRecipeDTO,
+    /// Ingredients that have to be picked or created before the recipe can be
+    pub unresolved_ingredients: Vec<UnresolvedIngredientDTO>,
+}
+
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize, TS)]
+#[ts(export)]
+pub struct UnresolvedIngredientDTO {
+    pub name: String,
+    pub amount: IngredientUnitDTO,
+    pub optional: bool,
+    pub notes: Option<String>,
+}
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::{IngredientDTO, RecipeDTO};
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct TrashedRecipeDTO {
+    pub recipe: RecipeDTO,
+    pub deleted_at: DateTime<Utc>,
+}
+
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct TrashedIngredientDTO {
+    pub ingredient: IngredientDTO,
+    pub deleted_at: DateTime<Utc>,
+}
+
+/// Deleted recipes and ingredients, which are purged for good once they've been in the trash for long enough
+#[derive(Deserialize, Serialize, TS, Debug)]
+#[ts(export)]
+pub struct TrashDTO {
+    /// Only the recipes of the user looking at the trash
+    pub recipes: Vec<TrashedRecipeDTO>,
+    pub ingredients: Vec<TrashedIngredientDTO>,
+}
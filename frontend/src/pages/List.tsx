@@ -1,6 +1,7 @@
 import { useInfiniteQuery } from "@tanstack/react-query";
 import clsx from "clsx";
 import { RecipeDTO } from "common/bindings/RecipeDTO";
+import { RecipePageDTO } from "common/bindings/RecipePageDTO";
 import { FC } from "react";
 import { api } from "../api/base";
 import { UserInfo } from "../components/UserInfo";
@@ -28,23 +29,23 @@ const RecipeList: FC = () => {
       const params = new URLSearchParams();
 
       params.append("offset", pageParam.toString());
-      params.append("count", COUNT.toString());
+      params.append("limit", COUNT.toString());
 
-      const response = await api.get<RecipeDTO[]>("/recipes/", {
+      const response = await api.get<RecipePageDTO>("/recipe", {
         signal,
         params,
         responseType: "json",
       });
 
-      if (response.data.length === 0) throw new Error("Ran out of recipes.");
-
       return response.data;
     },
     initialPageParam: 0,
     retry: 3,
     getNextPageParam: (lastPage, allPages) => {
       const nextPage: number | undefined =
-        lastPage.length === COUNT ? allPages.flat().length : undefined;
+        lastPage.next_cursor !== null
+          ? allPages.flatMap((page) => page.items).length
+          : undefined;
 
       return nextPage;
     },
@@ -52,7 +53,7 @@ const RecipeList: FC = () => {
 
   return (
     <div className="flex flex-col gap-2 text-black">
-      {data?.pages.flat().map((recipe) => (
+      {data?.pages.flatMap((page) => page.items).map((recipe) => (
         <RecipePreview recipe={recipe} key={recipe.id} />
       ))}
       {hasNextPage && (